@@ -1,11 +1,57 @@
+use std::collections::{HashMap, HashSet};
 use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
+use chrono::Local;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use ratatui::{DefaultTerminal, Frame};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Paragraph,
+    DefaultTerminal, Frame,
+};
+use ratatui_image::picker::Picker;
 
-use crate::db::Database;
-use crate::models::{Ingredient, LogEntry};
-use crate::views::{MainMenuView, MeadDetailView, MeadListView, NewMeadView};
+use crate::config::Config;
+use crate::db::{data_dir, is_valid_profile_name, list_profiles, looks_non_local, Database, ImportMode, SQL_FILE_NAME};
+use crate::export;
+use crate::export::comparison::ComparisonFormat;
+use crate::export::json::MeadRecord;
+use crate::export::ExportFormat;
+use crate::format;
+use crate::import::{self, ImportReport};
+use crate::models::{
+    abv_histogram, batches_by_month, duplicate_ingredient_groups, is_valid_gravity, GravityReading, GravityTrend, LogEntry, LogEntryType,
+    Mead, mead_comparison, MeadStatus, Reminder, Unit, MAX_GRAVITY, MIN_GRAVITY, shopping_list, status_durations,
+};
+use crate::nav;
+use crate::theme::{self, ColorSupport};
+use crate::views::new_mead::NewMeadField;
+use crate::views::mead_detail::{
+    self, DetailField, DetailForm, DetailPane, FinishForm, FinishStep, IngredientSort, MeasurementForm, MeasurementStep, NO_VOLUME,
+};
+use crate::views::main_menu::{CopyPicker, ImportPreview, MenuItem, ProfilePicker};
+use crate::views::mead_list::ListColumn;
+use crate::views::integrity::IntegrityChoice;
+use crate::views::welcome::{self, WelcomeChoice};
+use crate::widgets::input_field::set_cursor_off;
+use crate::widgets::{ConfirmDialog, QuickCalculator};
+use crate::views::recent_activity::PAGE_SIZE;
+use crate::views::{IntegrityView, MainMenuView, MeadDetailView, MeadListView, NewMeadView, RecentActivityView, ReferenceView, StatsView, WelcomeView};
+
+const NORD_RED: Color = Color::Rgb(191, 97, 106);       // #BF616A
+const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+
+/// How often the screen is redrawn while the session timer runs
+const TIMER_TICK: Duration = Duration::from_millis(250);
+
+/// How long a blinking cursor stays shown, then hidden
+const CURSOR_BLINK: Duration = Duration::from_millis(530);
+
+/// Badge drawn in the top-right corner in read-only mode
+const READ_ONLY_BADGE: &str = " READ-ONLY ";
 
 /// The current view/screen being displayed
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +60,37 @@ pub enum View {
     MeadList,
     NewMead,
     MeadDetail(i64), // mead id
+    Welcome,
+    RecentActivity,
+    Stats,
+    Integrity,
+    Reference,
+}
+
+/// An action waiting on the user's answer to a confirmation dialog
+#[derive(Debug, Clone, PartialEq)]
+enum PendingAction {
+    ExportCalendar,
+    ExportMeadSheet,
+    /// Export only the meads with these ids, or every mead for None
+    ExportJson { ids: Option<Vec<i64>> },
+    ExportListCsv,
+    MergeIngredients,
+    CopyIngredients { from_id: i64, name: String },
+    DiscardIngredient,
+    DiscardNewMead,
+    ExportShoppingList,
+    ExportActivity,
+    ExportComparison { format: ComparisonFormat },
+    ExportSql,
+    UnfinishStatus,
+    ImportReplace,
+    DeleteMead { id: i64, name: String },
+    /// Merge the mead `source` (named `name`) into `target`
+    MergeMeads { target: i64, source: i64, name: String },
+    DeleteReading { id: i64, gravity: f64 },
+    SetAutoReminders { id: i64, enabled: bool },
+    Quit,
 }
 
 /// The main application state
@@ -22,6 +99,8 @@ pub struct App {
     pub current_view: View,
     /// Database connection
     pub db: Database,
+    /// Directory holding the databases, config file and exports
+    data_dir: PathBuf,
     /// Whether the app should exit
     pub should_exit: bool,
     /// Main menu view state
@@ -32,70 +111,432 @@ pub struct App {
     pub new_mead: NewMeadView,
     /// Mead detail view state
     pub mead_detail: MeadDetailView,
+    /// First-run welcome screen state
+    pub welcome: WelcomeView,
+    /// Recent activity feed state
+    pub recent_activity: RecentActivityView,
+    pub stats: StatsView,
+    /// Yeast and honey reference state
+    pub reference: ReferenceView,
+    /// Startup integrity warning state
+    pub integrity: IntegrityView,
     /// Status message to display
     pub status_message: Option<String>,
+    /// Meads counted for the status bar; recounted after meads are added
+    /// or removed rather than on every draw
+    batch_total: Option<usize>,
+    /// Confirmation dialog shown over the current view, and the action it
+    /// guards
+    confirm: Option<(ConfirmDialog, PendingAction)>,
+    /// Conversion calculator opened over any view with Ctrl+K
+    calculator: Option<QuickCalculator>,
+    /// Records of a previewed import waiting on the replace confirmation
+    staged_import: Vec<MeadRecord>,
+    /// Overwrite existing export files without asking
+    overwrite_exports: bool,
+    /// Ask before deleting or overwriting anything
+    confirm_destructive: bool,
+    /// Ask before quitting
+    confirm_quit: bool,
+    /// Whether the text cursor blinks
+    cursor_blink: bool,
+    /// Field the new mead form opens on
+    new_mead_start_field: NewMeadField,
+    /// Text of the log entry added with `L`
+    quick_log_text: String,
+    /// How often the shown mead list reloads from the database, if at all
+    list_refresh: Option<Duration>,
+    /// When the mead list was last loaded
+    list_loaded_at: Instant,
+    /// Last key press; the blink restarts from here so the cursor stays
+    /// visible while typing
+    last_key_at: Instant,
+    /// Ignore every key that would change data (`--read-only`)
+    pub read_only: bool,
+    /// System clipboard, opened on first use and kept alive so copied text
+    /// stays available on platforms where the owner must serve it
+    clipboard: Option<arboard::Clipboard>,
 }
 
 impl App {
     /// Create a new app instance
-    pub fn new() -> io::Result<Self> {
-        let db = Database::new().map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
-        
-        Ok(Self {
-            current_view: View::MainMenu,
+    pub fn new(read_only: bool, profile: Option<String>, image_picker: Option<Picker>) -> io::Result<Self> {
+        let data_dir = data_dir();
+        let mut config = Config::load(&data_dir);
+        if let Some(profile) = profile {
+            if !is_valid_profile_name(&profile) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Invalid profile name \"{}\": use letters, digits, - and _", profile),
+                ));
+            }
+            config.profile = profile;
+        }
+        config.color_support.get_or_insert_with(|| {
+            let colorterm = std::env::var("COLORTERM").ok();
+            let term = std::env::var("TERM").ok();
+            ColorSupport::detect(colorterm.as_deref(), term.as_deref())
+        });
+        let db = if read_only {
+            Database::open_read_only(&config, &data_dir)
+        } else {
+            Database::new(&config, &data_dir)
+        };
+        let db = db.map_err(|e| io::Error::other(e.to_string()))?;
+        Ok(Self::with_database(config, db, data_dir, read_only, image_picker))
+    }
+
+    /// Build the app around an already opened database, keeping config and
+    /// exports in `data_dir`
+    fn with_database(
+        config: Config,
+        db: Database,
+        data_dir: PathBuf,
+        read_only: bool,
+        image_picker: Option<Picker>,
+    ) -> Self {
+        let mut main_menu = MainMenuView::new();
+        main_menu.last_export = config.last_export;
+        main_menu.set_items(&config.menu_items);
+
+        let mut mead_list = MeadListView::new();
+        mead_list.hidden_columns = config
+            .hidden_columns
+            .iter()
+            .filter_map(|key| ListColumn::from_key(key))
+            .collect();
+        mead_list.zebra_rows = config.list_zebra_rows;
+        mead_list.tall_rows = config.list_tall_rows;
+        let mut mead_detail = MeadDetailView::new();
+        mead_detail.start_field = DetailField::from_key(&config.detail_start_field).unwrap_or(DetailField::Name);
+        let new_mead_start_field = NewMeadField::from_key(&config.new_mead_start_field).unwrap_or(NewMeadField::Base);
+        mead_detail.yan_overdose_margin = config.yan_overdose_margin;
+        mead_detail.log_newest_first = config.log_newest_first;
+        mead_detail.image_picker = image_picker;
+        mead_detail.show_color_estimate = config.show_color_estimate;
+        mead_detail.ingredient_glyphs = config.ingredient_glyphs;
+        mead_detail.notes_autosave =
+            (config.notes_autosave_secs > 0).then(|| Duration::from_secs(config.notes_autosave_secs));
+        format::set_precision(config.precision);
+        format::set_date_format(&config.date_format, &config.time_format);
+        format::set_temp_unit(config.temperature_unit);
+        nav::set_wrap(config.wrap_navigation);
+        theme::set_theme(config.theme);
+        theme::set_color_support(config.color_support.unwrap_or(ColorSupport::TrueColor));
+
+        let first_run = !config.onboarding_done
+            && db.get_all_meads().map(|m| m.is_empty()).unwrap_or(false);
+        let problems = if config.skip_integrity_check {
+            Vec::new()
+        } else {
+            db.integrity_problems().unwrap_or_else(|e| vec![e.to_string()])
+        };
+
+        // SQLite can corrupt a file shared over a network, so say so up front
+        let status_message = db
+            .file_path()
+            .filter(|path| looks_non_local(Path::new(path)))
+            .map(|_| "Warning: database may be on a network or removable drive; keep it on a local disk".to_string());
+
+        let current_view = if !problems.is_empty() {
+            View::Integrity
+        } else if first_run && !read_only {
+            View::Welcome
+        } else {
+            View::MainMenu
+        };
+
+        let batch_total = db.count_meads().ok();
+        Self {
+            current_view,
             db,
+            data_dir,
             should_exit: false,
-            main_menu: MainMenuView::new(),
-            mead_list: MeadListView::new(),
+            main_menu,
+            mead_list,
             new_mead: NewMeadView::new(),
-            mead_detail: MeadDetailView::new(),
-            status_message: None,
-        })
+            mead_detail,
+            welcome: WelcomeView::new(),
+            recent_activity: RecentActivityView::new(),
+            stats: StatsView::new(),
+            reference: ReferenceView::new(),
+            integrity: IntegrityView::new(problems),
+            status_message,
+            batch_total,
+            confirm: None,
+            calculator: None,
+            staged_import: Vec::new(),
+            overwrite_exports: config.overwrite_exports,
+            confirm_destructive: config.confirm_destructive,
+            confirm_quit: config.confirm_quit,
+            cursor_blink: config.cursor_blink,
+            new_mead_start_field,
+            quick_log_text: config.quick_log_text.clone(),
+            list_refresh: (config.list_refresh_secs > 0).then(|| Duration::from_secs(config.list_refresh_secs)),
+            list_loaded_at: Instant::now(),
+            last_key_at: Instant::now(),
+            read_only,
+            clipboard: None,
+        }
     }
 
     /// Main application loop
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
         while !self.should_exit {
+            if self.notes_autosave_due_in() == Some(Duration::ZERO) {
+                self.autosave_notes();
+            }
+            if self.list_refresh_due_in() == Some(Duration::ZERO) {
+                self.mead_list.needs_refresh = true;
+            }
+            let phase = self.last_key_at.elapsed().as_millis() / CURSOR_BLINK.as_millis();
+            set_cursor_off(self.cursor_blink && phase % 2 == 1);
             terminal.draw(|frame| self.draw(frame))?;
-            self.handle_events()?;
+            self.handle_events(terminal)?;
+        }
+        // Don't lose notes typed just before quitting
+        if self.notes_autosave_due_in().is_some() {
+            self.autosave_notes();
         }
         Ok(())
     }
 
+    /// Time left before typed notes are autosaved, counted from the last
+    /// keypress; None when autosave is off or there is nothing to save
+    fn notes_autosave_due_in(&self) -> Option<Duration> {
+        if !matches!(self.current_view, View::MeadDetail(_)) {
+            return None;
+        }
+        let delay = self.mead_detail.notes_autosave?;
+        self.mead_detail.unsaved_notes()?;
+        Some(delay.saturating_sub(self.last_key_at.elapsed()))
+    }
+
+    /// Time until the shown mead list reloads to pick up changes made by
+    /// another instance, if auto-refresh is on
+    fn list_refresh_due_in(&self) -> Option<Duration> {
+        if self.current_view != View::MeadList {
+            return None;
+        }
+        let interval = self.list_refresh?;
+        Some(interval.saturating_sub(self.list_loaded_at.elapsed()))
+    }
+
+    /// Save the notes typed in the detail view, leaving other unsaved edits
+    /// in their fields
+    fn autosave_notes(&mut self) {
+        let (Some(id), Some(notes)) = (self.mead_detail.mead.as_ref().map(|m| m.id), self.mead_detail.unsaved_notes())
+        else {
+            return;
+        };
+        match self.db.set_notes(id, &notes) {
+            Ok(()) => {
+                self.mead_detail.notes_saved(notes);
+                self.mead_list.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error saving notes: {}", e)),
+        }
+    }
+
     /// Render the current view
     fn draw(&mut self, frame: &mut Frame) {
         match &self.current_view {
-            View::MainMenu => self.main_menu.render(frame, &self.status_message),
+            View::MainMenu => {
+                // Counts are cached until the menu is next entered
+                if self.main_menu.needs_refresh {
+                    if let Ok(counts) = self.db.count_due_reminders() {
+                        self.main_menu.due_reminders = counts;
+                    }
+                    if let Ok(counts) = self.db.count_active_meads() {
+                        self.main_menu.batch_counts = counts;
+                    }
+                    self.main_menu.needs_refresh = false;
+                }
+                self.main_menu.render(frame, &self.status_message);
+            }
             View::MeadList => {
                 // Load meads if needed
                 if self.mead_list.needs_refresh {
-                    if let Ok(meads) = self.db.get_all_meads() {
-                        self.mead_list.set_meads(meads);
-                    }
+                    self.refresh_mead_list();
                 }
-                self.mead_list.render(frame);
+                self.mead_list.render(frame, &self.status_message);
             }
             View::NewMead => self.new_mead.render(frame),
+            View::Welcome => self.welcome.render(frame),
+            View::Integrity => self.integrity.render(frame),
+            View::Reference => self.reference.render(frame),
+            View::RecentActivity => {
+                if self.recent_activity.needs_refresh {
+                    self.refresh_activity();
+                }
+                self.recent_activity.render(frame);
+            }
+            View::Stats => {
+                if self.stats.needs_refresh {
+                    let since = self.stats.since();
+                    let usage = self.db.get_honey_usage(since.as_deref());
+                    let abvs = self.db.get_finished_abvs(since.as_deref());
+                    let changes = self.db.get_all_status_changes();
+                    let meads = self.db.get_all_meads();
+                    if let (Ok(usage), Ok(abvs), Ok(changes), Ok(meads)) = (usage, abvs, changes, meads) {
+                        let durations = status_durations(&changes, since.as_deref());
+                        let months = batches_by_month(&meads, Local::now().date_naive());
+                        self.stats.set_stats(usage, abv_histogram(&abvs), durations, months);
+                    }
+                }
+                self.stats.render(frame);
+            }
             View::MeadDetail(id) => {
                 // Load mead data if needed
                 if self.mead_detail.needs_refresh {
                     if let Ok(Some(mead)) = self.db.get_mead(*id) {
                         let ingredients = self.db.get_ingredients(*id).unwrap_or_default();
-                        let log_entries = self.db.get_log_entries(*id).unwrap_or_default();
-                        self.mead_detail.set_mead(mead, ingredients, log_entries);
+                        let log_entries = self
+                            .db
+                            .get_log_entries(*id, self.mead_detail.log_newest_first)
+                            .unwrap_or_default();
+                        let reminders = self.db.get_reminders(*id).unwrap_or_default();
+                        let readings = self.db.get_gravity_readings(*id).unwrap_or_default();
+                        self.mead_detail.set_mead(mead, ingredients, log_entries, reminders, readings);
+                        self.mead_detail.status_changes = self.db.get_status_changes(*id).unwrap_or_default();
                     }
                 }
-                self.mead_detail.render(frame);
+                self.mead_detail.render(frame, &self.status_message);
+            }
+        }
+
+        self.render_status_bar(frame);
+
+        if let Some(calculator) = &self.calculator {
+            frame.render_widget(calculator, frame.area());
+        }
+
+        if let Some((dialog, _)) = &self.confirm {
+            frame.render_widget(dialog, frame.area());
+        }
+
+        if self.read_only {
+            let area = frame.area();
+            let width = (READ_ONLY_BADGE.len() as u16).min(area.width);
+            let badge = Rect { x: area.right() - width, y: area.y, width, height: 1 };
+            frame.render_widget(
+                Paragraph::new(READ_ONLY_BADGE)
+                    .style(Style::default().fg(NORD_BG).bg(NORD_RED).add_modifier(Modifier::BOLD)),
+                badge,
+            );
+        }
+
+        theme::adapt_colors(frame.buffer_mut());
+    }
+
+    /// Draw the profile, database path and batch count along the bottom
+    /// row, which every view leaves free as margin
+    fn render_status_bar(&self, frame: &mut Frame) {
+        let area = frame.area();
+        if area.height == 0 {
+            return;
+        }
+        let count = match self.batch_total {
+            Some(1) => " · 1 batch ".to_string(),
+            Some(total) => format!(" · {} batches ", total),
+            None => " ".to_string(),
+        };
+        let profile = format!("{} · ", self.db.profile());
+        let home = std::env::var("HOME").ok();
+        let room = (area.width as usize).saturating_sub(profile.chars().count() + count.chars().count() + 1);
+        let path = abbreviate_path(self.db.file_path().unwrap_or("(in memory)"), home.as_deref(), room);
+        let bar = Rect { x: area.x, y: area.bottom() - 1, width: area.width, height: 1 };
+        frame.render_widget(
+            Paragraph::new(format!(" {}{}{}", profile, path, count)).style(Style::default().fg(NORD_GRAY)),
+            bar,
+        );
+    }
+
+    /// Whether a key would change data in the current view. Checked before
+    /// dispatch in read-only mode. The detail view lists the keys that are
+    /// safe rather than the ones that aren't, so new edit keys stay blocked.
+    fn is_mutation(&self, key: KeyEvent) -> bool {
+        match &self.current_view {
+            View::MainMenu => {
+                !self.main_menu.searching
+                    && key.code == KeyCode::Enter
+                    && self.main_menu.selected_item().is_some_and(|item| item.is_mutation())
             }
+            View::MeadList if self.mead_list.column_picker.is_some()
+                || self.mead_list.shopping_list.is_some()
+                || self.mead_list.type_ahead_active(Instant::now()) =>
+            {
+                false
+            }
+            View::MeadList => {
+                let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+                let safe = match key.code {
+                    KeyCode::Esc | KeyCode::Up | KeyCode::Down | KeyCode::Enter => true,
+                    KeyCode::Char('k' | 'j' | '?' | ' ' | '!' | '*' | 'r' | 'c' | 'Z' | 'H' | 's' | 'x' | 'X' | '/') => !ctrl,
+                    _ => false,
+                };
+                !safe
+            }
+            View::NewMead | View::Welcome => true,
+            View::MeadDetail(_)
+                if self.mead_detail.filtering_ingredients
+                    || matches!(self.mead_detail.form, Some(DetailForm::Priming(_)))
+                    || self.mead_detail.snapshot_viewer.is_some() =>
+            {
+                false
+            }
+            View::MeadDetail(_) => {
+                let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+                let safe = match key.code {
+                    KeyCode::Esc | KeyCode::Tab | KeyCode::BackTab | KeyCode::PageUp | KeyCode::PageDown => true,
+                    KeyCode::Up | KeyCode::Down => !ctrl,
+                    KeyCode::Left | KeyCode::Right => !self.mead_detail.rating_focused(),
+                    KeyCode::Home | KeyCode::End => true,
+                    KeyCode::Char('b' | 'c' | 'e' | 'f' | 'o' | 'p' | 'C' | 'T' | '+' | '=' | '-' | '<' | '>') => !ctrl,
+                    KeyCode::Char(_) if key.modifiers.contains(KeyModifiers::ALT) => true,
+                    KeyCode::Char('g' | 'y' | '/') => self.mead_detail.pane == DetailPane::Ingredients,
+                    _ => false,
+                };
+                !safe
+            }
+            View::Integrity => {
+                key.code == KeyCode::Enter
+                    && self.integrity.report.is_none()
+                    && self.integrity.get_choice() == IntegrityChoice::BackupAndRecover
+            }
+            View::RecentActivity | View::Reference | View::Stats => false,
         }
     }
 
     /// Handle input events
-    fn handle_events(&mut self) -> io::Result<()> {
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
-                self.handle_key_event(key);
+    fn handle_events(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        // Wake up now and then to redraw a running timer or blink the
+        // cursor; otherwise block until there is input
+        let tick = if self.mead_detail.timer_running() {
+            Some(TIMER_TICK)
+        } else {
+            self.cursor_blink.then_some(CURSOR_BLINK)
+        };
+        // Also wake up when typed notes are due to be autosaved or the
+        // list to be reloaded
+        let tick = [tick, self.notes_autosave_due_in(), self.list_refresh_due_in()]
+            .into_iter()
+            .flatten()
+            .min();
+        if let Some(tick) = tick {
+            if !event::poll(tick)? {
+                return Ok(());
+            }
+        }
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => self.handle_key_event(key),
+            Event::Resize(_, _) => {
+                self.mead_list.clamp_selection();
+                self.mead_detail.clamp_to_size();
+                // Clear so nothing from the old layout is left behind
+                terminal.clear()?;
             }
+            _ => {}
         }
         Ok(())
     }
@@ -104,167 +545,1001 @@ impl App {
     fn handle_key_event(&mut self, key: KeyEvent) {
         // Clear status message on any key press
         self.status_message = None;
+        self.last_key_at = Instant::now();
+
+        // Raw mode delivers Ctrl+C as a key rather than SIGINT, so exit
+        // through the normal path that restores the terminal
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_exit = true;
+            return;
+        }
+
+        if self.confirm.is_some() {
+            self.handle_confirm_key(key);
+            return;
+        }
+
+        // Ctrl+Q quits from any view
+        if key.code == KeyCode::Char('q') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.request_quit();
+            return;
+        }
+
+        // Ctrl+K opens or closes the calculator from any view
+        if key.code == KeyCode::Char('k') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.calculator = match self.calculator {
+                Some(_) => None,
+                None => Some(QuickCalculator::new()),
+            };
+            return;
+        }
+        if self.calculator.is_some() {
+            self.handle_calculator_key(key);
+            return;
+        }
+
+        if self.read_only && self.is_mutation(key) {
+            self.status_message = Some("Read-only mode: changes are disabled".to_string());
+            return;
+        }
 
+        let was_on_menu = self.current_view == View::MainMenu;
         match &self.current_view {
             View::MainMenu => self.handle_main_menu_key(key),
             View::MeadList => self.handle_mead_list_key(key),
             View::NewMead => self.handle_new_mead_key(key),
             View::MeadDetail(_) => self.handle_mead_detail_key(key),
+            View::Welcome => self.handle_welcome_key(key),
+            View::RecentActivity => self.handle_recent_activity_key(key),
+            View::Stats => self.handle_stats_key(key),
+            View::Integrity => self.handle_integrity_key(key),
+            View::Reference => self.handle_reference_key(key),
+        }
+
+        // Batches may have changed anywhere else, so recount on return
+        if !was_on_menu && self.current_view == View::MainMenu {
+            self.main_menu.needs_refresh = true;
+        }
+    }
+
+    /// Handle keys in the calculator overlay
+    fn handle_calculator_key(&mut self, key: KeyEvent) {
+        let Some(calculator) = self.calculator.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.calculator = None,
+            KeyCode::Tab | KeyCode::Down | KeyCode::Enter => calculator.next_field(),
+            KeyCode::BackTab | KeyCode::Up => calculator.previous_field(),
+            KeyCode::Left | KeyCode::Right if calculator.field == 0 => {
+                calculator.cycle_mode(key.code == KeyCode::Right);
+            }
+            code => {
+                let Some(input) = calculator.focused_input() else {
+                    return;
+                };
+                match code {
+                    KeyCode::Char(c) => input.insert_char(c),
+                    KeyCode::Backspace => input.delete_char(),
+                    KeyCode::Delete => input.delete_char_forward(),
+                    KeyCode::Left => input.move_cursor_left(),
+                    KeyCode::Right => input.move_cursor_right(),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Handle keys while a confirmation dialog is open
+    fn handle_confirm_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                if let Some((_, action)) = self.confirm.take() {
+                    self.run_pending(action);
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.confirm = None;
+                self.status_message = Some("Cancelled".to_string());
+            }
+            _ => {}
+        }
+    }
+
+    /// Carry out an action the user confirmed
+    fn run_pending(&mut self, action: PendingAction) {
+        match action {
+            PendingAction::ExportCalendar => self.write_calendar_export(),
+            PendingAction::ExportMeadSheet => self.write_mead_sheet_export(),
+            PendingAction::MergeIngredients => self.merge_duplicate_ingredients(),
+            PendingAction::CopyIngredients { from_id, name } => self.copy_ingredients_from(from_id, &name),
+            PendingAction::DiscardIngredient => self.mead_detail.close_form(),
+            PendingAction::DiscardNewMead => self.current_view = View::MainMenu,
+            PendingAction::UnfinishStatus => self.mead_detail.toggle_edit(),
+            PendingAction::Quit => self.should_exit = true,
+            PendingAction::ExportShoppingList => self.write_shopping_list_export(),
+            PendingAction::ExportActivity => self.write_activity_export(),
+            PendingAction::ExportSql => self.write_sql_export(),
+            PendingAction::ExportComparison { format } => self.write_comparison_export(format),
+            PendingAction::ExportJson { ids } => self.write_json_export(ids.as_deref()),
+            PendingAction::ExportListCsv => self.write_list_csv_export(),
+            PendingAction::ImportReplace => {
+                let records = std::mem::take(&mut self.staged_import);
+                self.run_import(&records, ImportMode::Replace);
+            }
+            PendingAction::DeleteMead { id, name } => self.delete_mead(id, &name),
+            PendingAction::MergeMeads { target, source, name } => self.merge_meads(target, source, &name),
+            PendingAction::DeleteReading { id, gravity } => self.delete_gravity_reading(id, gravity),
+            PendingAction::SetAutoReminders { id, enabled } => self.set_auto_reminders(id, enabled),
+        }
+    }
+
+    /// Show a confirmation dialog for a destructive action, or run it
+    /// straight away when `confirm_destructive` is off
+    fn confirm_or_run(&mut self, message: String, action: PendingAction) {
+        if self.confirm_destructive {
+            self.confirm = Some((ConfirmDialog::new(message), action));
+        } else {
+            self.run_pending(action);
+        }
+    }
+
+    /// Start editing the focused detail field, or cycle the status. Asks
+    /// first when the status would wrap from Finished back to Planning.
+    fn toggle_detail_edit(&mut self) {
+        if self.mead_detail.would_unfinish() {
+            let name = self.mead_detail.mead.as_ref().map(|m| m.name.clone()).unwrap_or_default();
+            self.confirm_or_run(
+                format!("Move {} from Finished back to Planning?", name),
+                PendingAction::UnfinishStatus,
+            );
+        } else {
+            self.mead_detail.toggle_edit();
+        }
+    }
+
+    /// Leave "edit all" in the detail view without saving, warning when
+    /// edits are left unsaved
+    fn stop_edit_all(&mut self) {
+        self.mead_detail.stop_edit_all();
+        let unsaved = self.mead_detail.get_updated_mead().is_some_and(|m| self.mead_detail.has_changes(&m));
+        if unsaved {
+            self.status_message = Some("Stopped editing all fields; changes are not saved yet (s saves)".to_string());
+        }
+    }
+
+    /// Quit, first asking if the config says to
+    fn request_quit(&mut self) {
+        if self.confirm_quit {
+            self.confirm = Some((ConfirmDialog::new("Quit MeadTUI?"), PendingAction::Quit));
+        } else {
+            self.should_exit = true;
+        }
+    }
+
+    /// Ask before overwriting an existing export file. Returns true if the
+    /// export can go ahead now.
+    fn confirm_overwrite(&mut self, path: &Path, action: PendingAction) -> bool {
+        if self.overwrite_exports || !self.confirm_destructive || !path.exists() {
+            return true;
+        }
+        let name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+        self.confirm = Some((ConfirmDialog::new(format!("Overwrite {}?", name)), action));
+        false
+    }
+
+    /// Handle keys on the startup integrity warning
+    fn handle_integrity_key(&mut self, key: KeyEvent) {
+        let choice = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.integrity.previous();
+                return;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.integrity.next();
+                return;
+            }
+            KeyCode::Enter => self.integrity.get_choice(),
+            KeyCode::Esc => IntegrityChoice::Continue,
+            _ => return,
+        };
+
+        match choice {
+            IntegrityChoice::BackupAndRecover if self.integrity.report.is_none() => {
+                self.integrity.report = Some(self.backup_and_recover());
+            }
+            _ => self.current_view = View::MainMenu,
+        }
+    }
+
+    /// Back up the database file and copy what can be read into a fresh
+    /// database, describing the outcome line by line
+    fn backup_and_recover(&self) -> Vec<String> {
+        let mut report = match self.db.backup_file() {
+            Ok(path) => vec![format!("Backup saved to {}", path.display())],
+            Err(e) => return vec![format!("Backup failed: {}", e), "Recovery was not attempted.".to_string()],
+        };
+
+        match self.db.recover(&self.data_dir) {
+            Ok(recovery) => {
+                report.push(format!("Recovered data written to {}", recovery.path.display()));
+                report.push(String::new());
+                report.extend(
+                    recovery
+                        .recovered
+                        .iter()
+                        .map(|(table, rows)| format!("  {}: {} rows recovered", table, rows)),
+                );
+                report.extend(
+                    recovery
+                        .failed
+                        .iter()
+                        .map(|(table, error)| format!("  {}: could not be read ({})", table, error)),
+                );
+                report.push(String::new());
+                report.push(
+                    "To use the recovered data, quit and replace mead_tracker.db with the recovered file."
+                        .to_string(),
+                );
+            }
+            Err(e) => report.push(format!("Recovery failed: {}", e)),
+        }
+        report
+    }
+
+    /// Handle keys on the first-run welcome screen
+    fn handle_welcome_key(&mut self, key: KeyEvent) {
+        let choice = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.welcome.previous();
+                return;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.welcome.next();
+                return;
+            }
+            KeyCode::Enter => self.welcome.get_choice(),
+            KeyCode::Esc => WelcomeChoice::Skip,
+            _ => return,
+        };
+
+        // Don't show the welcome screen again, whichever way it was left
+        if let Err(e) = Config::store(&self.data_dir, "onboarding_done", "true") {
+            self.status_message = Some(format!("Error saving config: {}", e));
+        }
+
+        match choice {
+            WelcomeChoice::SampleMead => {
+                let mead = welcome::sample_mead();
+                match self.db.create_mead(&mead) {
+                    Ok(id) => {
+                        self.count_batches();
+                        let (ingredient, entry) = welcome::sample_extras(id);
+                        if let Err(e) = self
+                            .db
+                            .create_ingredient(&ingredient)
+                            .and_then(|_| self.db.create_log_entry(&entry))
+                        {
+                            self.status_message = Some(format!("Error: {}", e));
+                        }
+                        self.mead_detail.needs_refresh = true;
+                        self.current_view = View::MeadDetail(id);
+                    }
+                    Err(e) => {
+                        self.status_message = Some(format!("Error: {}", e));
+                        self.current_view = View::MainMenu;
+                    }
+                }
+            }
+            WelcomeChoice::NewMead => self.open_new_mead(),
+            WelcomeChoice::Skip => self.current_view = View::MainMenu,
         }
     }
 
+    /// Show an empty new mead form, focused on the configured field
+    fn open_new_mead(&mut self) {
+        self.new_mead = NewMeadView::new();
+        self.new_mead.focus_field(self.new_mead_start_field);
+        self.current_view = View::NewMead;
+    }
+
     /// Handle keys in main menu
     fn handle_main_menu_key(&mut self, key: KeyEvent) {
+        if self.main_menu.searching {
+            self.handle_main_menu_search_key(key);
+            return;
+        }
+        if self.main_menu.import_preview.is_some() {
+            self.handle_import_prompt_key(key);
+            return;
+        }
+        if self.main_menu.profile_picker.is_some() {
+            self.handle_profile_picker_key(key);
+            return;
+        }
+
         match key.code {
-            KeyCode::Char('q') => self.should_exit = true,
+            KeyCode::Char('q') => self.request_quit(),
+            KeyCode::Char('/') => {
+                let meads = self.db.get_all_meads().unwrap_or_default();
+                self.main_menu.open_search(meads);
+            }
             KeyCode::Up | KeyCode::Char('k') => self.main_menu.previous(),
             KeyCode::Down | KeyCode::Char('j') => self.main_menu.next(),
+            KeyCode::Char('o') => self.copy_data_dir(),
+            KeyCode::Char('O') => self.open_data_dir(),
+            KeyCode::Char('e') => self.repeat_last_export(),
             KeyCode::Enter => {
-                match self.main_menu.selected {
-                    0 => {
+                let Some(item) = self.main_menu.selected_item() else {
+                    return;
+                };
+                match item {
+                    MenuItem::CurrentMeads => {
                         self.mead_list.needs_refresh = true;
                         self.current_view = View::MeadList;
                     }
-                    1 => {
-                        self.new_mead = NewMeadView::new();
-                        self.current_view = View::NewMead;
+                    MenuItem::NewMead => self.open_new_mead(),
+                    MenuItem::Search => {
+                        let meads = self.db.get_all_meads().unwrap_or_default();
+                        self.main_menu.open_search(meads);
+                    }
+                    MenuItem::RecentActivity => {
+                        self.recent_activity.needs_refresh = true;
+                        self.recent_activity.selected = 0;
+                        self.current_view = View::RecentActivity;
+                    }
+                    MenuItem::Reference => {
+                        self.reference = ReferenceView::new();
+                        self.current_view = View::Reference;
+                    }
+                    MenuItem::Statistics => {
+                        self.stats.needs_refresh = true;
+                        self.current_view = View::Stats;
+                    }
+                    MenuItem::ExportCalendar | MenuItem::ExportJson | MenuItem::ExportSql => {
+                        if let Some(format) = item.export() {
+                            self.run_export(format);
+                        }
+                    }
+                    MenuItem::ImportJson => self.start_import(),
+                    MenuItem::ImportRecipe => self.import_recipe(),
+                    MenuItem::SwitchProfile => {
+                        self.main_menu.profile_picker = Some(ProfilePicker::new(list_profiles(&self.data_dir), self.db.profile()));
                     }
-                    _ => {}
                 }
             }
             _ => {}
         }
     }
 
-    /// Handle keys in mead list
-    fn handle_mead_list_key(&mut self, key: KeyEvent) {
+    /// Handle keys while the main menu search box is open
+    fn handle_main_menu_search_key(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Esc => self.current_view = View::MainMenu,
-            KeyCode::Up | KeyCode::Char('k') => self.mead_list.previous(),
-            KeyCode::Down | KeyCode::Char('j') => self.mead_list.next(),
+            KeyCode::Esc => self.main_menu.close_search(),
+            KeyCode::Up => self.main_menu.previous_result(),
+            KeyCode::Down => self.main_menu.next_result(),
             KeyCode::Enter => {
-                if let Some(mead) = self.mead_list.get_selected() {
-                    let mead_id = mead.id;
+                if let Some(mead_id) = self.main_menu.selected_result_id() {
+                    self.main_menu.close_search();
                     self.mead_detail.needs_refresh = true;
                     self.current_view = View::MeadDetail(mead_id);
                 }
             }
-            KeyCode::Char('d') => {
-                if let Some(mead) = self.mead_list.get_selected() {
-                    let mead_id = mead.id;
-                    let mead_name = mead.name.clone();
-                    if self.db.delete_mead(mead_id).is_ok() {
-                        self.mead_list.needs_refresh = true;
-                        self.status_message = Some(format!("Deleted mead: {}", mead_name));
-                    }
-                }
+            KeyCode::Char(c) => {
+                self.main_menu.search_input.insert_char(c);
+                self.main_menu.update_results();
+            }
+            KeyCode::Backspace => {
+                self.main_menu.search_input.delete_char();
+                self.main_menu.update_results();
             }
+            KeyCode::Left => self.main_menu.search_input.move_cursor_left(),
+            KeyCode::Right => self.main_menu.search_input.move_cursor_right(),
             _ => {}
         }
     }
 
-    /// Handle keys in new mead form
-    fn handle_new_mead_key(&mut self, key: KeyEvent) {
+    /// Handle keys in the reference tables; typing searches
+    fn handle_reference_key(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Esc => {
-                if self.new_mead.is_editing() {
-                    self.new_mead.cancel_edit();
-                } else {
-                    self.current_view = View::MainMenu;
-                }
-            }
-            KeyCode::Tab => {
-                if key.modifiers.contains(KeyModifiers::SHIFT) {
-                    self.new_mead.previous_field();
-                } else {
-                    self.new_mead.next_field();
-                }
+            KeyCode::Esc => self.current_view = View::MainMenu,
+            KeyCode::Tab | KeyCode::BackTab => self.reference.toggle_tab(),
+            KeyCode::Up => self.reference.previous(),
+            KeyCode::Down => self.reference.next(),
+            KeyCode::Char(c) => {
+                self.reference.search_input.insert_char(c);
+                self.reference.search_changed();
             }
-            KeyCode::Up if !self.new_mead.is_editing() => {
-                self.new_mead.previous_field();
+            KeyCode::Backspace => {
+                self.reference.search_input.delete_char();
+                self.reference.search_changed();
             }
-            KeyCode::Down if !self.new_mead.is_editing() => {
-                self.new_mead.next_field();
+            KeyCode::Left => self.reference.search_input.move_cursor_left(),
+            KeyCode::Right => self.reference.search_input.move_cursor_right(),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the statistics view
+    fn handle_stats_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.current_view = View::MainMenu,
+            KeyCode::Char('y') => self.stats.toggle_period(),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the recent activity feed
+    fn handle_recent_activity_key(&mut self, key: KeyEvent) {
+        if self.recent_activity.editing_dates {
+            self.handle_activity_dates_key(key);
+            return;
+        }
+        if self.recent_activity.searching {
+            self.handle_activity_search_key(key);
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.current_view = View::MainMenu,
+            KeyCode::Up | KeyCode::Char('k') => self.recent_activity.previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.recent_activity.next(),
+            KeyCode::PageDown | KeyCode::Char(']') => self.recent_activity.next_page(),
+            KeyCode::PageUp | KeyCode::Char('[') => self.recent_activity.previous_page(),
+            KeyCode::Char('/') => self.recent_activity.open_search(),
+            KeyCode::Char('t') => self.recent_activity.cycle_type(),
+            KeyCode::Char('m') => self.recent_activity.toggle_mead_filter(),
+            KeyCode::Char('r') => self.recent_activity.open_dates(),
+            KeyCode::Char('c') => self.recent_activity.clear_filter(),
+            KeyCode::Char('x') => {
+                let path = export::export_path(&self.data_dir, export::activity::FILE_NAME);
+                if self.confirm_overwrite(&path, PendingAction::ExportActivity) {
+                    self.write_activity_export();
+                }
             }
             KeyCode::Enter => {
-                if self.new_mead.is_on_submit() {
-                    // Save the mead
-                    let mead = self.new_mead.build_mead();
-                    match self.db.create_mead(&mead) {
-                        Ok(_) => {
-                            self.status_message = Some(format!("Created mead: {}", mead.name));
-                            self.current_view = View::MainMenu;
-                        }
-                        Err(e) => {
-                            self.status_message = Some(format!("Error: {}", e));
-                        }
-                    }
-                } else if self.new_mead.is_editing() {
-                    // Stop editing and move to next field
-                    self.new_mead.next_field();
-                } else {
-                    self.new_mead.next_field();
+                if let Some(item) = self.recent_activity.get_selected() {
+                    self.mead_detail.needs_refresh = true;
+                    self.current_view = View::MeadDetail(item.mead_id);
                 }
             }
+            _ => {}
+        }
+    }
+
+    /// Handle keys while the activity search box has focus; Esc clears the
+    /// search, Enter keeps it
+    fn handle_activity_search_key(&mut self, key: KeyEvent) {
+        let view = &mut self.recent_activity;
+        match key.code {
+            KeyCode::Esc => view.close_search(true),
+            KeyCode::Enter => view.close_search(false),
             KeyCode::Char(c) => {
-                // Start editing automatically and insert the character
-                if !self.new_mead.is_on_submit() {
-                    if !self.new_mead.is_editing() {
-                        self.new_mead.toggle_edit();
-                    }
-                    self.new_mead.insert_char(c);
-                }
+                view.search_input.insert_char(c);
+                view.search_changed();
             }
             KeyCode::Backspace => {
-                if !self.new_mead.is_on_submit() {
-                    if !self.new_mead.is_editing() {
-                        self.new_mead.toggle_edit();
-                    }
-                    self.new_mead.delete_char();
-                }
-            }
-            KeyCode::Delete => {
-                if self.new_mead.is_editing() {
-                    self.new_mead.delete_char_forward();
-                }
-            }
-            KeyCode::Left => {
-                if self.new_mead.is_editing() {
-                    self.new_mead.move_cursor_left();
-                }
+                view.search_input.delete_char();
+                view.search_changed();
             }
-            KeyCode::Right => {
-                if self.new_mead.is_editing() {
-                    self.new_mead.move_cursor_right();
-                }
-            }
-            KeyCode::Home => {
-                if self.new_mead.is_editing() {
-                    self.new_mead.move_cursor_start();
+            KeyCode::Left => view.search_input.move_cursor_left(),
+            KeyCode::Right => view.search_input.move_cursor_right(),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the activity date range form
+    fn handle_activity_dates_key(&mut self, key: KeyEvent) {
+        let view = &mut self.recent_activity;
+        match key.code {
+            KeyCode::Esc => view.editing_dates = false,
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => view.next_date_field(),
+            KeyCode::Enter => {
+                if let Err(e) = view.apply_dates() {
+                    self.status_message = Some(e);
                 }
             }
-            KeyCode::End => {
-                if self.new_mead.is_editing() {
-                    self.new_mead.move_cursor_end();
-                }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                view.focused_date_input().set_value(format::fmt_date(Local::now().date_naive()));
             }
+            KeyCode::Char(c) => view.focused_date_input().insert_char(c),
+            KeyCode::Backspace => view.focused_date_input().delete_char(),
+            KeyCode::Left => view.focused_date_input().move_cursor_left(),
+            KeyCode::Right => view.focused_date_input().move_cursor_right(),
             _ => {}
         }
     }
 
-    /// Handle keys in mead detail view
-    fn handle_mead_detail_key(&mut self, key: KeyEvent) {
-        let in_input_mode = self.mead_detail.is_editing() 
-            || self.mead_detail.show_log_input 
-            || self.mead_detail.show_ingredient_input;
-
-        match key.code {
+    /// Load the activity page the view is on, under its filter
+    fn refresh_activity(&mut self) {
+        let view = &mut self.recent_activity;
+        let page = self
+            .db
+            .count_activity(&view.filter)
+            .and_then(|total| Ok((self.db.get_activity(&view.filter, PAGE_SIZE, view.offset())?, total)));
+        match page {
+            Ok((items, total)) => view.set_items(items, total),
+            Err(e) => {
+                view.needs_refresh = false;
+                self.status_message = Some(format!("Error: {}", e));
+            }
+        }
+    }
+
+    /// Write every event passing the activity filter, across all pages
+    fn write_activity_export(&mut self) {
+        let filter = &self.recent_activity.filter;
+        let items = self
+            .db
+            .count_activity(filter)
+            .and_then(|total| self.db.get_activity(filter, total, 0));
+        let path = export::export_path(&self.data_dir, export::activity::FILE_NAME);
+        self.status_message = Some(match items {
+            Ok(items) => match export::activity::write_activity_csv(&path, &items) {
+                Ok(()) => format!("Exported {} events to {}", items.len(), path.display()),
+                Err(e) => format!("Export failed: {}", e),
+            },
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Handle keys in the mead list's inline gravity editor
+    fn handle_list_gravity_key(&mut self, key: KeyEvent) {
+        let input = &mut self.mead_list.gravity_input;
+        match key.code {
+            KeyCode::Esc => self.mead_list.close_gravity_input(),
+            KeyCode::Enter => self.save_list_gravity(),
+            KeyCode::Char(c) => input.insert_char(c),
+            KeyCode::Backspace => input.delete_char(),
+            KeyCode::Delete => input.delete_char_forward(),
+            KeyCode::Left => input.move_cursor_left(),
+            KeyCode::Right => input.move_cursor_right(),
+            KeyCode::Home => input.move_cursor_start(),
+            KeyCode::End => input.move_cursor_end(),
+            _ => {}
+        }
+    }
+
+    /// Note added to a save message when the new gravity sits next to a
+    /// reading already taken today; with `same_day_readings = replace` it
+    /// takes that reading's place instead
+    fn same_day_warning(&self, mead_id: i64) -> &'static str {
+        if self.db.adds_second_reading_today(mead_id).unwrap_or(false) {
+            " (today already had a reading)"
+        } else {
+            ""
+        }
+    }
+
+    /// Save the inline gravity editor's value to the selected mead
+    fn save_list_gravity(&mut self) {
+        let gravity = self.mead_list.gravity_input.get_f64();
+        let Some(gravity) = gravity.filter(|g| is_valid_gravity(*g)) else {
+            self.status_message = Some(gravity_range_message());
+            return;
+        };
+        let Some(mut mead) = self.mead_list.get_selected().cloned() else {
+            return;
+        };
+
+        mead.current_gravity = gravity;
+        let warning = self.same_day_warning(mead.id);
+        match self.db.update_mead(&mead) {
+            Ok(()) => {
+                self.status_message =
+                    Some(format!("{}: gravity {}{}", mead.name, format::fmt_gravity(gravity), warning));
+                self.mead_list.close_gravity_input();
+                self.mead_list.needs_refresh = true;
+                self.mead_list.select_after_refresh = Some(mead.id);
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Handle keys in the mead list's column picker, saving each change
+    fn handle_column_picker_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('c') => self.mead_list.toggle_column_picker(),
+            KeyCode::Up | KeyCode::Char('k') => self.mead_list.previous_picker_column(),
+            KeyCode::Down | KeyCode::Char('j') => self.mead_list.next_picker_column(),
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                self.mead_list.toggle_picker_column();
+                if let Err(e) = Config::store(&self.data_dir, "hidden_columns", &self.mead_list.hidden_columns_value()) {
+                    self.status_message = Some(format!("Error saving config: {}", e));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the duplicate checklist; Enter creates the copy
+    fn handle_duplicate_picker_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.mead_list.duplicate_picker = None,
+            KeyCode::Up | KeyCode::Char('k') => self.mead_list.previous_duplicate_part(),
+            KeyCode::Down | KeyCode::Char('j') => self.mead_list.next_duplicate_part(),
+            KeyCode::Char(' ') => self.mead_list.toggle_duplicate_part(),
+            KeyCode::Enter => {
+                let Some((_, parts)) = self.mead_list.duplicate_picker.take() else {
+                    return;
+                };
+                let Some(mead) = self.mead_list.get_selected() else {
+                    return;
+                };
+                let name = mead.name.clone();
+                match self.db.duplicate_mead(mead.id, &parts) {
+                    Ok(id) => {
+                        self.count_batches();
+                        self.status_message = Some(format!("Duplicated {}", name));
+                        self.mead_list.needs_refresh = true;
+                        self.mead_list.select_after_refresh = Some(id);
+                    }
+                    Err(e) => self.status_message = Some(format!("Error: {}", e)),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Combine the ingredients of the marked meads, or the selected one,
+    /// into a shopping list
+    fn open_shopping_list(&mut self) {
+        let ids = self.mead_list.action_targets();
+        if ids.is_empty() {
+            return;
+        }
+        let mut ingredients = Vec::new();
+        for id in &ids {
+            match self.db.get_ingredients(*id) {
+                Ok(found) => ingredients.extend(found),
+                Err(e) => {
+                    self.status_message = Some(format!("Error: {}", e));
+                    return;
+                }
+            }
+        }
+        let batches = self
+            .mead_list
+            .meads
+            .iter()
+            .filter(|m| ids.contains(&m.id))
+            .map(|m| m.name.clone())
+            .collect();
+        self.mead_list.open_shopping_list(batches, shopping_list(&ingredients));
+    }
+
+    fn handle_shopping_list_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => self.mead_list.shopping_list = None,
+            KeyCode::Up | KeyCode::Char('k') => self.mead_list.previous_shopping_item(),
+            KeyCode::Down | KeyCode::Char('j') => self.mead_list.next_shopping_item(),
+            KeyCode::Char('x') => {
+                let path = export::export_path(&self.data_dir, export::shopping::FILE_NAME);
+                if self.confirm_overwrite(&path, PendingAction::ExportShoppingList) {
+                    self.write_shopping_list_export();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn write_shopping_list_export(&mut self) {
+        let Some(list) = &self.mead_list.shopping_list else {
+            return;
+        };
+        let path = export::export_path(&self.data_dir, export::shopping::FILE_NAME);
+        self.status_message = Some(match export::shopping::write_shopping_list(&path, &list.batches, &list.items) {
+            Ok(()) => format!("Exported shopping list to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Reload the mead list, working out which meads need attention
+    fn refresh_mead_list(&mut self) {
+        self.list_loaded_at = Instant::now();
+        let Ok(meads) = self.db.get_all_meads() else {
+            return;
+        };
+        let reminders = self.db.get_all_reminders().unwrap_or_default();
+        let readings = self.db.get_all_gravity_readings().unwrap_or_default();
+
+        let mut attention = HashSet::new();
+        let mut readings_due = HashSet::new();
+        let mut trends = HashMap::new();
+        for mead in &meads {
+            let mead_reminders: Vec<Reminder> =
+                reminders.iter().filter(|r| r.mead_id == mead.id).cloned().collect();
+            let mead_readings: Vec<GravityReading> =
+                readings.iter().filter(|r| r.mead_id == mead.id).cloned().collect();
+            if mead.needs_attention(&mead_reminders, &mead_readings) {
+                attention.insert(mead.id);
+            }
+            if mead.reading_due(&mead_readings) {
+                readings_due.insert(mead.id);
+            }
+            if let Some(trend) = GravityTrend::from_readings(&mead_readings) {
+                trends.insert(mead.id, trend);
+            }
+        }
+        self.mead_list.set_meads(meads, attention, readings_due, trends);
+    }
+
+    /// Handle keys in mead list
+    fn handle_mead_list_key(&mut self, key: KeyEvent) {
+        if self.mead_list.column_picker.is_some() {
+            self.handle_column_picker_key(key);
+            return;
+        }
+        if self.mead_list.editing_gravity {
+            self.handle_list_gravity_key(key);
+            return;
+        }
+        if self.mead_list.duplicate_picker.is_some() {
+            self.handle_duplicate_picker_key(key);
+            return;
+        }
+        if self.mead_list.shopping_list.is_some() {
+            self.handle_shopping_list_key(key);
+            return;
+        }
+
+        // After `/`, every character goes to type-ahead until it times out
+        let now = Instant::now();
+        if self.mead_list.type_ahead_active(now) {
+            match key.code {
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.mead_list.type_ahead(c, now);
+                    return;
+                }
+                KeyCode::Esc => {
+                    self.mead_list.cancel_type_ahead();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        match key.code {
+            KeyCode::Esc => self.current_view = View::MainMenu,
+            KeyCode::Up | KeyCode::Char('k') => self.mead_list.previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.mead_list.next(),
+            KeyCode::Char('?') => self.mead_list.show_legend = !self.mead_list.show_legend,
+            KeyCode::Enter => {
+                if let Some(mead) = self.mead_list.get_selected() {
+                    let mead_id = mead.id;
+                    self.mead_detail.needs_refresh = true;
+                    self.current_view = View::MeadDetail(mead_id);
+                }
+            }
+            KeyCode::Char('p') => {
+                if let Some(mead) = self.mead_list.get_selected() {
+                    let mead_id = mead.id;
+                    let pinned = !mead.pinned;
+                    let mead_name = mead.name.clone();
+                    match self.db.set_pinned(mead_id, pinned) {
+                        Ok(()) => {
+                            self.mead_list.needs_refresh = true;
+                            self.mead_list.select_after_refresh = Some(mead_id);
+                            let action = if pinned { "Pinned" } else { "Unpinned" };
+                            self.status_message = Some(format!("{}: {}", action, mead_name));
+                        }
+                        Err(e) => self.status_message = Some(format!("Error: {}", e)),
+                    }
+                }
+            }
+            KeyCode::Char(' ') => self.mead_list.toggle_marked(),
+            KeyCode::Char('!') => self.mead_list.toggle_attention_only(),
+            KeyCode::Char('*') => self.mead_list.toggle_competition_only(),
+            KeyCode::Char('r') => self.mead_list.toggle_rating_sort(),
+            KeyCode::Char('c') => self.mead_list.toggle_column_picker(),
+            KeyCode::Char('D') => self.mead_list.open_duplicate_picker(),
+            KeyCode::Char('M') => self.request_merge(),
+            KeyCode::Char('Z') => {
+                self.mead_list.zebra_rows = !self.mead_list.zebra_rows;
+                if let Err(e) = Config::store(&self.data_dir, "list_zebra_rows", &self.mead_list.zebra_rows.to_string()) {
+                    self.status_message = Some(format!("Error saving config: {}", e));
+                }
+            }
+            KeyCode::Char('H') => {
+                self.mead_list.tall_rows = !self.mead_list.tall_rows;
+                if let Err(e) = Config::store(&self.data_dir, "list_tall_rows", &self.mead_list.tall_rows.to_string()) {
+                    self.status_message = Some(format!("Error saving config: {}", e));
+                }
+            }
+            KeyCode::Char('g') => self.mead_list.open_gravity_input(),
+            KeyCode::Char('s') => self.open_shopping_list(),
+            // Export what the list shows, in the order shown
+            KeyCode::Char('x') => self.export_json(Some(self.mead_list.meads.iter().map(|m| m.id).collect())),
+            KeyCode::Char('X') => self.export_list_csv(),
+            KeyCode::Char('a') => {
+                let ids = self.mead_list.action_targets();
+                let bottled = self
+                    .mead_list
+                    .meads
+                    .iter()
+                    .filter(|m| ids.contains(&m.id) && m.status == MeadStatus::Bottled)
+                    .count();
+                match self.db.advance_statuses(&ids) {
+                    Ok(advanced) => {
+                        self.mead_list.marked.clear();
+                        self.mead_list.needs_refresh = true;
+                        self.mead_list.select_after_refresh =
+                            self.mead_list.get_selected().map(|m| m.id);
+                        let noun = if advanced == 1 { "batch" } else { "batches" };
+                        let mut message = format!("Advanced {} {}", advanced, noun);
+                        if bottled > 0 {
+                            message.push_str("; finish bottled batches from their detail view");
+                        }
+                        self.status_message = Some(message);
+                    }
+                    Err(e) => self.status_message = Some(format!("Error: {}", e)),
+                }
+            }
+            KeyCode::Char('d') => {
+                if let Some(mead) = self.mead_list.get_selected() {
+                    let action = PendingAction::DeleteMead {
+                        id: mead.id,
+                        name: mead.name.clone(),
+                    };
+                    let message = format!("Delete {} and all its logs, ingredients and readings?", mead.name);
+                    self.confirm_or_run(message, action);
+                }
+            }
+            KeyCode::Char('/') => self.mead_list.start_type_ahead(now),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in new mead form
+    fn handle_new_mead_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                if self.new_mead.is_editing() {
+                    self.new_mead.cancel_edit();
+                } else if self.new_mead.has_entries() {
+                    self.confirm_or_run("Discard this new mead?".to_string(), PendingAction::DiscardNewMead);
+                } else {
+                    self.current_view = View::MainMenu;
+                }
+            }
+            KeyCode::Tab => {
+                if key.modifiers.contains(KeyModifiers::SHIFT) {
+                    self.new_mead.previous_field();
+                } else {
+                    self.new_mead.next_field();
+                }
+            }
+            KeyCode::Up if !self.new_mead.is_editing() => {
+                self.new_mead.previous_field();
+            }
+            KeyCode::Down if !self.new_mead.is_editing() => {
+                self.new_mead.next_field();
+            }
+            KeyCode::Enter => {
+                if self.new_mead.is_on_submit() {
+                    if let Some(error) = self.new_mead.validation_error() {
+                        self.status_message = Some(format!("Error: {}", error));
+                        return;
+                    }
+                    // Save the mead
+                    let mead = self.new_mead.build_mead();
+                    match self.db.create_mead(&mead) {
+                        Ok(_) => {
+                            self.count_batches();
+                            self.status_message = Some(match self.new_mead.start_date_warning() {
+                                Some(warning) => format!("Created mead: {} ({})", mead.name, warning),
+                                None => format!("Created mead: {}", mead.name),
+                            });
+                            self.current_view = View::MainMenu;
+                        }
+                        Err(e) => {
+                            self.status_message = Some(format!("Error: {}", e));
+                        }
+                    }
+                } else if self.new_mead.is_editing() {
+                    // Stop editing and move to next field
+                    self.new_mead.next_field();
+                } else {
+                    self.new_mead.next_field();
+                }
+            }
+            KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.new_mead.undo();
+            }
+            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.new_mead.redo();
+            }
+            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.new_mead.fill_suggested_yan();
+            }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.new_mead.fill_today();
+            }
+            KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.new_mead.jump_to_hotkey(c);
+            }
+            KeyCode::Char(c) if !self.new_mead.is_on_submit() && !self.new_mead.is_on_select() => {
+                // Start editing automatically and insert the character
+                if !self.new_mead.is_editing() {
+                    self.new_mead.toggle_edit();
+                }
+                self.new_mead.insert_char(c);
+            }
+            KeyCode::Char(c) if self.new_mead.is_on_select() => self.new_mead.type_select_char(c),
+            KeyCode::Backspace if self.new_mead.is_on_select() => self.new_mead.delete_select_char(),
+            KeyCode::Backspace if !self.new_mead.is_on_submit() && !self.new_mead.is_on_select() => {
+                if !self.new_mead.is_editing() {
+                    self.new_mead.toggle_edit();
+                }
+                self.new_mead.delete_char();
+            }
+            KeyCode::Delete if self.new_mead.is_editing() => {
+                self.new_mead.delete_char_forward();
+            }
+            KeyCode::Left if self.new_mead.is_editing() || self.new_mead.is_on_select() => {
+                self.new_mead.move_cursor_left();
+            }
+            KeyCode::Right if self.new_mead.is_editing() || self.new_mead.is_on_select() => {
+                self.new_mead.move_cursor_right();
+            }
+            KeyCode::Left => self.new_mead.move_to_column(false),
+            KeyCode::Right => self.new_mead.move_to_column(true),
+            KeyCode::Home if self.new_mead.is_editing() => {
+                self.new_mead.move_cursor_start();
+            }
+            KeyCode::End if self.new_mead.is_editing() => {
+                self.new_mead.move_cursor_end();
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keys in mead detail view
+    fn handle_mead_detail_key(&mut self, key: KeyEvent) {
+        if self.mead_detail.notes_editor.is_some() {
+            self.handle_notes_editor_key(key);
+            return;
+        }
+        if self.mead_detail.log_copy.is_some() {
+            self.handle_log_copy_key(key);
+            return;
+        }
+        if self.mead_detail.ingredient_source.is_some() {
+            self.handle_ingredient_source_key(key);
+            return;
+        }
+        if self.mead_detail.snapshot_viewer.is_some() {
+            self.handle_snapshot_viewer_key(key);
+            return;
+        }
+        if self.mead_detail.filtering_ingredients {
+            self.handle_ingredient_filter_key(key);
+            return;
+        }
+        if let KeyCode::Char(c) = key.code {
+            if key.modifiers.contains(KeyModifiers::ALT) && self.mead_detail.jump_to_hotkey(c) {
+                return;
+            }
+        }
+
+        let in_input_mode = self.mead_detail.is_editing() || self.mead_detail.form_open() || self.mead_detail.edit_all;
+        let on_fields = self.mead_detail.pane == DetailPane::Fields;
+
+        match key.code {
+            KeyCode::Esc if self.mead_detail.edit_all => self.stop_edit_all(),
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if self.mead_detail.edit_all {
+                    self.stop_edit_all();
+                } else if !self.mead_detail.form_open() {
+                    self.mead_detail.start_edit_all();
+                }
+            }
             KeyCode::Esc => {
                 if self.mead_detail.is_editing() {
                     self.mead_detail.cancel_edit();
-                } else if self.mead_detail.show_log_input || self.mead_detail.show_ingredient_input {
-                    self.mead_detail.show_log_input = false;
-                    self.mead_detail.show_ingredient_input = false;
+                } else if self.mead_detail.ingredient_input_dirty() {
+                    self.confirm_or_run("Discard this ingredient?".to_string(), PendingAction::DiscardIngredient);
+                } else if self.mead_detail.form_open() {
+                    self.mead_detail.close_form();
                 } else {
                     self.mead_list.needs_refresh = true;
                     self.current_view = View::MeadList;
@@ -277,76 +1552,269 @@ impl App {
                     self.mead_detail.next_field();
                 }
             }
+            KeyCode::BackTab => self.mead_detail.previous_field(),
+            KeyCode::Left | KeyCode::Right if self.mead_detail.measurement_slider_active() => {
+                let fine = key.modifiers.contains(KeyModifiers::SHIFT);
+                self.mead_detail.nudge_measurement_slider(key.code == KeyCode::Right, fine);
+            }
+            KeyCode::Up | KeyCode::Down if self.mead_detail.amount_stepper_active() => {
+                let coarse = key.modifiers.contains(KeyModifiers::SHIFT);
+                self.mead_detail.step_ingredient_amount(key.code == KeyCode::Up, coarse);
+            }
+            KeyCode::Up if matches!(self.mead_detail.form, Some(DetailForm::Log(_))) => {
+                self.mead_detail.recall_previous_log();
+            }
+            KeyCode::Down if matches!(self.mead_detail.form, Some(DetailForm::Log(_))) => {
+                self.mead_detail.recall_next_log();
+            }
+            KeyCode::Up | KeyCode::Down
+                if !in_input_mode
+                    && key.modifiers.contains(KeyModifiers::CONTROL)
+                    && self.mead_detail.pane == DetailPane::Ingredients =>
+            {
+                if self.mead_detail.ingredient_sort != IngredientSort::Manual {
+                    self.status_message = Some("Switch to manual order (o) to move ingredients".to_string());
+                } else if self.mead_detail.ingredient_filter_active() {
+                    self.status_message = Some("Clear the filter (/ then Esc) to move ingredients".to_string());
+                } else if let Some(order) = self.mead_detail.move_selected_ingredient(key.code == KeyCode::Up) {
+                    if let Err(e) = self.db.set_ingredient_order(&order) {
+                        self.status_message = Some(format!("Error: {}", e));
+                        self.mead_detail.needs_refresh = true;
+                    }
+                }
+            }
             KeyCode::Up if !in_input_mode => {
-                self.mead_detail.previous_field();
+                if on_fields {
+                    self.mead_detail.previous_field();
+                } else {
+                    self.mead_detail.select_previous();
+                }
             }
             KeyCode::Down if !in_input_mode => {
-                self.mead_detail.next_field();
+                if on_fields {
+                    self.mead_detail.next_field();
+                } else {
+                    self.mead_detail.select_next();
+                }
+            }
+            KeyCode::Home | KeyCode::End
+                if !in_input_mode && matches!(self.mead_detail.pane, DetailPane::Readings | DetailPane::Logs) =>
+            {
+                self.mead_detail.jump_to_end(key.code == KeyCode::Home);
+            }
+            KeyCode::Left if !in_input_mode && key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.mead_detail.pan_chart(false);
+            }
+            KeyCode::Right if !in_input_mode && key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.mead_detail.pan_chart(true);
+            }
+            KeyCode::Left if self.mead_detail.edit_all_on_status() => self.mead_detail.previous_status(),
+            KeyCode::Right if self.mead_detail.edit_all_on_status() => self.toggle_detail_edit(),
+            KeyCode::Left | KeyCode::Right if self.mead_detail.rating_focused() => {
+                self.mead_detail.step_rating(key.code == KeyCode::Right);
+            }
+            KeyCode::Char(c @ '0'..='5') if self.mead_detail.rating_focused() => {
+                self.mead_detail.set_rating(c as u8 - b'0');
+            }
+            KeyCode::Left if !in_input_mode => {
+                self.mead_detail.previous_pane();
+            }
+            KeyCode::Right if !in_input_mode => {
+                self.mead_detail.next_pane();
+            }
+            KeyCode::Char('+' | '=') if !in_input_mode => self.mead_detail.zoom_chart(true),
+            KeyCode::Char('-') if !in_input_mode => self.mead_detail.zoom_chart(false),
+            KeyCode::Char('b') if !in_input_mode => self.mead_detail.toggle_abv_overlay(),
+            KeyCode::Char('<') if !in_input_mode => self.mead_detail.pan_chart(false),
+            KeyCode::Char('>') if !in_input_mode => self.mead_detail.pan_chart(true),
+            KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.mead_detail.undo();
+            }
+            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.mead_detail.redo();
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(label) = self.mead_detail.revert_current_field() {
+                    self.status_message = Some(format!("Reverted {} to saved value", label));
+                }
             }
             KeyCode::Char('l') if !in_input_mode => {
-                self.mead_detail.show_log_input = true;
-                self.mead_detail.log_input.set_focused(true);
+                self.mead_detail.open_log_input();
             }
-            KeyCode::Char('i') if !in_input_mode => {
-                self.mead_detail.show_ingredient_input = true;
-                self.mead_detail.ingredient_name_input.set_focused(true);
+            // A modifier so typing "a" still starts editing a field
+            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) && !in_input_mode => {
+                if let Some(mead) = &self.mead_detail.mead {
+                    let enabled = !mead.auto_reminders;
+                    let state = if enabled { "on" } else { "off" };
+                    let message = format!("Turn {} overdue and stall warnings for {}?", state, mead.name);
+                    self.confirm_or_run(message, PendingAction::SetAutoReminders { id: mead.id, enabled });
+                }
             }
-            KeyCode::Char('s') if !in_input_mode => {
-                // Save changes
-                if let Some(mead) = self.mead_detail.get_updated_mead() {
-                    if self.db.update_mead(&mead).is_ok() {
-                        self.status_message = Some("Mead updated!".to_string());
-                        self.mead_detail.needs_refresh = true;
-                    }
+            KeyCode::Char('t') if !in_input_mode && self.mead_detail.pane == DetailPane::Logs => {
+                match self.db.get_all_meads() {
+                    Ok(meads) => self.mead_detail.open_log_copy(&meads),
+                    Err(e) => self.status_message = Some(format!("Error: {}", e)),
                 }
             }
-            KeyCode::Enter => {
-                if self.mead_detail.show_log_input {
-                    // Save log entry
-                    if let Some(mead) = &self.mead_detail.mead {
-                        let entry = LogEntry {
-                            mead_id: mead.id,
-                            entry_text: self.mead_detail.log_input.get_value().to_string(),
-                            ..Default::default()
-                        };
-                        if !entry.entry_text.is_empty() {
-                            if self.db.create_log_entry(&entry).is_ok() {
-                                self.mead_detail.log_input.clear();
-                                self.mead_detail.show_log_input = false;
-                                self.mead_detail.needs_refresh = true;
-                            }
-                        }
-                    }
-                } else if self.mead_detail.show_ingredient_input {
-                    // Save ingredient
-                    if let Some(mead) = &self.mead_detail.mead {
-                        let ingredient = Ingredient {
-                            mead_id: mead.id,
-                            name: self.mead_detail.ingredient_name_input.get_value().to_string(),
-                            amount: self.mead_detail.ingredient_amount_input.get_f64().unwrap_or(0.0),
-                            unit: self.mead_detail.ingredient_unit_input.get_value().to_string(),
-                            ingredient_type: self.mead_detail.selected_ingredient_type.clone(),
-                            ..Default::default()
-                        };
-                        if !ingredient.name.is_empty() {
-                            if self.db.create_ingredient(&ingredient).is_ok() {
-                                self.mead_detail.clear_ingredient_inputs();
-                                self.mead_detail.show_ingredient_input = false;
-                                self.mead_detail.needs_refresh = true;
-                            }
-                        }
+            KeyCode::Char('i') if !in_input_mode => self.mead_detail.open_ingredient_input(),
+            KeyCode::Char('I') if !in_input_mode => match self.db.get_all_meads() {
+                Ok(meads) => self.mead_detail.open_ingredient_source(&meads),
+                Err(e) => self.status_message = Some(format!("Error: {}", e)),
+            },
+            KeyCode::Char('/') if !in_input_mode && self.mead_detail.pane == DetailPane::Ingredients => {
+                self.mead_detail.open_ingredient_filter();
+            }
+            KeyCode::Char('g') if !in_input_mode && self.mead_detail.pane == DetailPane::Ingredients => {
+                self.mead_detail.group_ingredients = !self.mead_detail.group_ingredients;
+            }
+            KeyCode::Char('y') if !in_input_mode && self.mead_detail.pane == DetailPane::Ingredients => {
+                self.mead_detail.ingredient_glyphs = self.mead_detail.ingredient_glyphs.next();
+                let glyphs = self.mead_detail.ingredient_glyphs.as_str();
+                self.status_message = Some(format!("Ingredient glyphs: {}", glyphs));
+                if let Err(e) = Config::store(&self.data_dir, "ingredient_glyphs", glyphs) {
+                    self.status_message = Some(format!("Error saving config: {}", e));
+                }
+            }
+            KeyCode::Char('u') if !in_input_mode && self.mead_detail.pane == DetailPane::Ingredients => {
+                let groups = duplicate_ingredient_groups(&self.mead_detail.ingredients);
+                if groups.is_empty() {
+                    self.status_message = Some("No duplicate ingredients".to_string());
+                } else {
+                    let extra: usize = groups.iter().map(|g| g.len() - 1).sum();
+                    let message = format!(
+                        "Merge {} duplicate ingredient row(s) into {}?",
+                        extra,
+                        groups.len()
+                    );
+                    self.confirm_or_run(message, PendingAction::MergeIngredients);
+                }
+            }
+            KeyCode::Char('m') if !in_input_mode => {
+                self.mead_detail.open_measurement();
+            }
+            KeyCode::Char('n') if !in_input_mode => {
+                self.mead_detail.open_nutrient_input();
+            }
+            KeyCode::Char('r') if !in_input_mode => {
+                self.mead_detail.open_reminder_input();
+            }
+            KeyCode::Char('x') if !in_input_mode && self.mead_detail.pane == DetailPane::Reminders => {
+                if let Some(reminder) = self.mead_detail.selected_reminder() {
+                    let done = !reminder.done;
+                    match self.db.set_reminder_done(reminder.id, done) {
+                        Ok(()) => self.mead_detail.needs_refresh = true,
+                        Err(e) => self.status_message = Some(format!("Error: {}", e)),
                     }
+                }
+            }
+            KeyCode::Char('c') if !in_input_mode => {
+                if let Some(mead) = self.mead_detail.get_updated_mead() {
+                    self.status_message = Some(match self.copy_to_clipboard(mead.summary()) {
+                        Ok(()) => "Copied to clipboard".to_string(),
+                        Err(e) => format!("Clipboard unavailable: {}", e),
+                    });
+                }
+            }
+            KeyCode::Char('e') if !in_input_mode => self.export_mead_sheet(),
+            KeyCode::Char('T') if !in_input_mode => {
+                self.status_message = Some(match self.mead_detail.toggle_timer() {
+                    Some(elapsed) => format!("Timer stopped at {}", format::fmt_elapsed(elapsed)),
+                    None => "Timer started".to_string(),
+                });
+            }
+            KeyCode::Char('w') if !in_input_mode => self.mead_detail.open_notes_editor(),
+            KeyCode::Char('v') if !in_input_mode => self.mead_detail.open_scale_input(),
+            KeyCode::Char('U') if !in_input_mode => self.mead_detail.open_top_up_input(),
+            KeyCode::Char('S') if !in_input_mode => self.mead_detail.open_snapshot_input(),
+            KeyCode::Char('C') if !in_input_mode => self.open_snapshot_viewer(),
+            KeyCode::Char('R') if !in_input_mode => self.mead_detail.open_reading_interval_input(),
+            KeyCode::Char('G') if !in_input_mode => self.sync_gravity_to_latest_reading(),
+            KeyCode::Char('A') if !in_input_mode => self.mead_detail.open_acidity_input(),
+            KeyCode::Char('E') if !in_input_mode => self.toggle_competition_entry(),
+            KeyCode::Char('J') if !in_input_mode => {
+                if self.mead_detail.mead.as_ref().is_some_and(|m| m.competition) {
+                    self.mead_detail.open_competition_input();
+                } else {
+                    self.status_message = Some("Mark the batch as a competition entry first (E)".to_string());
+                }
+            }
+            KeyCode::Char('L') if !in_input_mode => self.add_quick_log(),
+            KeyCode::Char('p') if !in_input_mode => {
+                let bottled = self.mead_detail.mead.as_ref().is_some_and(|m| m.status == MeadStatus::Bottled);
+                if bottled {
+                    self.mead_detail.open_priming_input();
                 } else {
-                    // Cycle status if on status field, otherwise toggle edit
-                    self.mead_detail.toggle_edit();
+                    self.status_message = Some("Priming sugar is for bottled meads".to_string());
+                }
+            }
+            KeyCode::Char('d') if !in_input_mode && self.mead_detail.pane == DetailPane::Readings => {
+                if let Some(reading) = self.mead_detail.selected_reading() {
+                    let message = format!(
+                        "Delete the {} reading from {}?",
+                        format::fmt_gravity(reading.gravity),
+                        format::fmt_date(reading.recorded_at.date_naive())
+                    );
+                    let action = PendingAction::DeleteReading {
+                        id: reading.id,
+                        gravity: reading.gravity,
+                    };
+                    self.confirm_or_run(message, action);
+                }
+            }
+            KeyCode::Char('f') if !in_input_mode => {
+                self.mead_detail.cycle_log_filter();
+            }
+            KeyCode::Char('o') if !in_input_mode && self.mead_detail.pane == DetailPane::Ingredients => {
+                self.mead_detail.cycle_ingredient_sort();
+            }
+            KeyCode::Char('o') if !in_input_mode => {
+                self.mead_detail.log_newest_first = !self.mead_detail.log_newest_first;
+                self.mead_detail.needs_refresh = true;
+                let value = self.mead_detail.log_newest_first.to_string();
+                if let Err(e) = Config::store(&self.data_dir, "log_newest_first", &value) {
+                    self.status_message = Some(format!("Error saving config: {}", e));
                 }
             }
+            KeyCode::PageDown => self.mead_detail.scroll_notes_down(),
+            KeyCode::PageUp => self.mead_detail.scroll_notes_up(),
+            KeyCode::Char('s') if !in_input_mode => self.save_detail_fields(),
+            KeyCode::Enter => match self.mead_detail.form {
+                Some(DetailForm::Measurement(MeasurementForm { step, .. })) => self.advance_measurement(step),
+                Some(DetailForm::Finish(FinishForm { step, .. })) => self.advance_finish(step),
+                Some(DetailForm::Reading(_)) => self.save_reading_correction(),
+                Some(DetailForm::Priming(_)) => self.mead_detail.close_form(),
+                Some(DetailForm::Snapshot(_)) => self.save_snapshot(),
+                Some(DetailForm::ReadingInterval(_)) => self.save_reading_interval(),
+                Some(DetailForm::Acidity(_)) => self.save_acidity(),
+                Some(DetailForm::TopUp(_)) => self.apply_top_up(),
+                Some(DetailForm::Competition(_)) => self.save_competition_details(),
+                Some(DetailForm::Scale(_)) => self.apply_scaling(),
+                Some(DetailForm::Nutrient(_)) => self.save_nutrient_addition(),
+                Some(DetailForm::Log(_)) => self.save_log_entry(),
+                Some(DetailForm::Ingredient(_)) => self.save_ingredient(),
+                Some(DetailForm::Reminder(_)) => self.save_reminder(),
+                None if !in_input_mode && self.mead_detail.pane == DetailPane::Readings => {
+                    self.mead_detail.open_reading_input();
+                }
+                None if self.mead_detail.edit_all => {
+                    let more_fields = self.mead_detail.advance_edit_all();
+                    if !more_fields {
+                        self.save_detail_fields();
+                    }
+                }
+                // Cycle status if on status field, otherwise toggle edit
+                None if on_fields => self.toggle_detail_edit(),
+                None => {}
+            },
             KeyCode::Char(c) => {
-                if self.mead_detail.show_log_input || self.mead_detail.show_ingredient_input {
+                if self.mead_detail.form_open() {
                     self.mead_detail.insert_char(c);
                 } else if !in_input_mode {
+                    if !on_fields {
+                        return;
+                    }
                     // Start editing automatically
-                    self.mead_detail.toggle_edit();
+                    self.toggle_detail_edit();
                     if self.mead_detail.is_editing() {
                         self.mead_detail.insert_char(c);
                     }
@@ -355,10 +1823,13 @@ impl App {
                 }
             }
             KeyCode::Backspace => {
-                if self.mead_detail.show_log_input || self.mead_detail.show_ingredient_input {
+                if self.mead_detail.form_open() {
                     self.mead_detail.delete_char();
                 } else if !self.mead_detail.is_editing() {
-                    self.mead_detail.toggle_edit();
+                    if !on_fields {
+                        return;
+                    }
+                    self.toggle_detail_edit();
                     if self.mead_detail.is_editing() {
                         self.mead_detail.delete_char();
                     }
@@ -378,5 +1849,2341 @@ impl App {
             _ => {}
         }
     }
-}
 
+    /// Save the detail view's field edits; moving to Finished asks for
+    /// the finishing details first
+    fn save_detail_fields(&mut self) {
+        let was_finished = self
+            .mead_detail
+            .mead
+            .as_ref()
+            .is_some_and(|m| m.status == MeadStatus::Finished);
+        match self.mead_detail.validated_mead() {
+            Ok(Some(mead)) if !self.mead_detail.has_changes(&mead) => {
+                self.status_message = Some("No changes".to_string());
+            }
+            Ok(Some(mead)) if mead.status == MeadStatus::Finished && !was_finished => {
+                self.mead_detail.open_finish(&mead);
+            }
+            Ok(Some(mead)) => {
+                let gravity_changed = self
+                    .mead_detail
+                    .mead
+                    .as_ref()
+                    .is_some_and(|saved| saved.current_gravity != mead.current_gravity);
+                let warning = if gravity_changed { self.same_day_warning(mead.id) } else { "" };
+                match self.db.update_mead(&mead) {
+                    Ok(()) => {
+                        self.status_message = Some(format!("Mead updated!{}", warning));
+                        self.mead_detail.needs_refresh = true;
+                    }
+                    Err(e) => self.status_message = Some(format!("Error: {}", e)),
+                }
+            }
+            Ok(None) => {}
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Save the log entry form's entry against the open mead
+    fn save_log_entry(&mut self) {
+        let (Some(mead), Some(DetailForm::Log(form))) = (&self.mead_detail.mead, &self.mead_detail.form) else {
+            return;
+        };
+        let entry = LogEntry {
+            mead_id: mead.id,
+            entry_text: form.input.get_value().to_string(),
+            entry_type: form.entry_type(),
+            ..Default::default()
+        };
+        if entry.entry_text.is_empty() {
+            return;
+        }
+        match self.db.create_log_entry(&entry) {
+            Ok(_) => {
+                self.mead_detail.push_log_history(entry.entry_text);
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Save the add ingredient form's ingredient to the open mead
+    fn save_ingredient(&mut self) {
+        let Some(ingredient) = self.mead_detail.typed_ingredient() else {
+            return;
+        };
+        if ingredient.name.is_empty() {
+            return;
+        }
+        if Unit::parse(&ingredient.unit).is_none() {
+            self.status_message = Some(format!(
+                "Unknown unit \"{}\" - try one of: {}",
+                ingredient.unit.trim(),
+                Unit::KNOWN
+            ));
+            return;
+        }
+        match self.db.create_ingredient(&ingredient) {
+            Ok(_) => {
+                // Saved either way; the warning is only advice
+                if let Some(warning) = ingredient.unit_warning() {
+                    self.status_message = Some(format!("Added {}; {}", ingredient.name, warning));
+                }
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Save the reminder form's reminder for the open mead
+    fn save_reminder(&mut self) {
+        let (Some(mead), Some(DetailForm::Reminder(form))) = (&self.mead_detail.mead, &self.mead_detail.form) else {
+            return;
+        };
+        let due = format::parse_date(form.date_input.get_value());
+        let reminder = Reminder {
+            mead_id: mead.id,
+            text: form.text_input.get_value().to_string(),
+            due_date: due.map(|date| date.format(format::ISO_DATE).to_string()).unwrap_or_default(),
+            ..Default::default()
+        };
+        if reminder.text.trim().is_empty() {
+            self.status_message = Some("Enter what the reminder is for".to_string());
+        } else if due.is_none() {
+            self.status_message = Some("Due date must be a real date, e.g. 2025-06-10".to_string());
+        } else {
+            match self.db.create_reminder(&reminder) {
+                Ok(_) => {
+                    self.mead_detail.close_form();
+                    self.mead_detail.needs_refresh = true;
+                }
+                Err(e) => self.status_message = Some(format!("Error: {}", e)),
+            }
+        }
+    }
+
+    /// Move the measurement flow on from `step`, recording it after the
+    /// temperature step
+    fn advance_measurement(&mut self, step: MeasurementStep) {
+        let Some(DetailForm::Measurement(form)) = &self.mead_detail.form else {
+            return;
+        };
+        let temp = form.temp_input.get_value().trim().to_string();
+        let Some(gravity) = form.gravity_input.get_f64().filter(|g| is_valid_gravity(*g)) else {
+            self.status_message = Some(gravity_range_message());
+            return;
+        };
+        if step == MeasurementStep::Gravity {
+            self.mead_detail.set_measurement_step(MeasurementStep::Temperature);
+            return;
+        }
+
+        let unit = format::temp_unit();
+        let temperature = match temp.parse::<f64>() {
+            Ok(value) => Some(unit.stored(value)),
+            Err(_) if temp.is_empty() => None,
+            Err(_) => {
+                self.status_message = Some("Temperature must be a number, or left empty".to_string());
+                return;
+            }
+        };
+        let Some(mut mead) = self.mead_detail.mead.clone() else {
+            return;
+        };
+
+        let delta = gravity - mead.current_gravity;
+        mead.current_gravity = gravity;
+        let entry_text = if temp.is_empty() {
+            format!("Gravity {}", format::fmt_gravity(gravity))
+        } else {
+            format!("Gravity {} at {}{}", format::fmt_gravity(gravity), temp, unit.symbol())
+        };
+        let entry = LogEntry {
+            mead_id: mead.id,
+            entry_text,
+            entry_type: LogEntryType::Measurement,
+            ..Default::default()
+        };
+
+        let warning = self.same_day_warning(mead.id);
+        match self.db.record_measurement(&mead, temperature, &entry) {
+            Ok(()) => {
+                let sign = if delta < 0.0 { "-" } else { "+" };
+                self.status_message = Some(format!(
+                    "Recorded {} ({}{}) - ABV {}%{}",
+                    format::fmt_gravity(gravity),
+                    sign,
+                    format::fmt_gravity(delta.abs()),
+                    format::fmt_abv(mead.current_abv().max(0.0)),
+                    warning
+                ));
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Move the finish flow on a step, or on the last step save the mead as
+    /// Finished with its finishing details and a log entry
+    fn advance_finish(&mut self, step: FinishStep) {
+        let Some(DetailForm::Finish(form)) = &self.mead_detail.form else {
+            return;
+        };
+        let date = format::parse_date(form.date_input.get_value());
+        let bottles = form.bottles_input.get_value().trim().parse::<u32>();
+        let Some(gravity) = form.gravity_input.get_f64().filter(|g| is_valid_gravity(*g)) else {
+            self.status_message = Some(gravity_range_message());
+            return;
+        };
+        if step == FinishStep::Gravity {
+            self.mead_detail.set_finish_step(FinishStep::BottledDate);
+            return;
+        }
+
+        let Some(date) = date else {
+            self.status_message = Some("Bottling date must be a real date, e.g. 2025-06-10".to_string());
+            return;
+        };
+        if step == FinishStep::BottledDate {
+            self.mead_detail.set_finish_step(FinishStep::BottleCount);
+            return;
+        }
+
+        let Ok(bottles) = bottles else {
+            self.status_message = Some("Bottles must be a whole number".to_string());
+            return;
+        };
+        let mut mead = match self.mead_detail.validated_mead() {
+            Ok(Some(mead)) => mead,
+            Ok(None) => return,
+            Err(e) => {
+                self.status_message = Some(format!("Error: {}", e));
+                return;
+            }
+        };
+
+        mead.status = MeadStatus::Finished;
+        mead.current_gravity = gravity;
+        mead.final_gravity = Some(gravity);
+        mead.final_abv = Some(mead.current_abv());
+        mead.bottled_date = date.format(format::ISO_DATE).to_string();
+        mead.bottle_count = Some(bottles);
+        let entry = LogEntry {
+            mead_id: mead.id,
+            entry_text: format!(
+                "Finished: FG {}, {}% ABV, {} bottles on {}",
+                format::fmt_gravity(gravity),
+                format::fmt_abv(mead.current_abv().max(0.0)),
+                bottles,
+                mead.bottled_date
+            ),
+            entry_type: LogEntryType::Action,
+            ..Default::default()
+        };
+
+        match self.db.finish_mead(&mead, &entry) {
+            Ok(()) => {
+                self.status_message = Some(format!("{} marked as finished", mead.name));
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Save the corrected gravity for the selected reading
+    fn save_reading_correction(&mut self) {
+        let Some(DetailForm::Reading(input)) = &self.mead_detail.form else {
+            return;
+        };
+        let Some(gravity) = input.get_f64().filter(|g| is_valid_gravity(*g)) else {
+            self.status_message = Some(gravity_range_message());
+            return;
+        };
+        let Some(id) = self.mead_detail.selected_reading().map(|r| r.id) else {
+            return;
+        };
+        match self.db.update_gravity_reading(id, gravity) {
+            Ok(()) => {
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Handle keys in the log copy picker; Enter writes the copies
+    fn handle_log_copy_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.mead_detail.log_copy = None,
+            KeyCode::Up | KeyCode::Char('k') => self.mead_detail.previous_log_copy_mead(),
+            KeyCode::Down | KeyCode::Char('j') => self.mead_detail.next_log_copy_mead(),
+            KeyCode::Char(' ') => self.mead_detail.toggle_log_copy_mead(),
+            KeyCode::Enter => {
+                let Some(picker) = self.mead_detail.log_copy.take() else {
+                    return;
+                };
+                // Keep the list order rather than the order they were picked
+                let ids: Vec<i64> = picker
+                    .meads
+                    .iter()
+                    .map(|(id, _)| *id)
+                    .filter(|id| picker.picked.contains(id))
+                    .collect();
+                if ids.is_empty() {
+                    self.status_message = Some("No meads picked".to_string());
+                    return;
+                }
+                self.status_message = Some(match self.db.copy_log_entry(&picker.entry, &ids) {
+                    Ok(count) => format!("Copied log entry to {} meads", count),
+                    Err(e) => format!("Error: {}", e),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the ingredient source picker; Enter asks before
+    /// copying the picked mead's ingredients in
+    fn handle_ingredient_source_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.mead_detail.ingredient_source = None,
+            KeyCode::Up | KeyCode::Char('k') => self.mead_detail.previous_ingredient_source(),
+            KeyCode::Down | KeyCode::Char('j') => self.mead_detail.next_ingredient_source(),
+            KeyCode::Enter => {
+                let Some(picker) = self.mead_detail.ingredient_source.take() else {
+                    return;
+                };
+                let Some((from_id, name)) = picker.meads.into_iter().nth(picker.selected) else {
+                    return;
+                };
+                match self.db.get_ingredients(from_id) {
+                    Ok(ingredients) if ingredients.is_empty() => {
+                        self.status_message = Some(format!("{} has no ingredients", name));
+                    }
+                    Ok(ingredients) => {
+                        // Always ask: copying adds rows rather than replacing
+                        // any, so it isn't covered by confirm_destructive
+                        let message = format!("Add {} ingredient(s) from {}?", ingredients.len(), name);
+                        let action = PendingAction::CopyIngredients { from_id, name };
+                        self.confirm = Some((ConfirmDialog::new(message), action));
+                    }
+                    Err(e) => self.status_message = Some(format!("Error: {}", e)),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Copy another mead's ingredients into the open one
+    fn copy_ingredients_from(&mut self, from_id: i64, name: &str) {
+        let Some(mead) = &self.mead_detail.mead else {
+            return;
+        };
+        match self.db.copy_ingredients(from_id, mead.id) {
+            Ok(count) => {
+                self.status_message = Some(format!("Added {} ingredient(s) from {}", count, name));
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Snapshot the saved mead under the typed label
+    fn save_snapshot(&mut self) {
+        let Some(id) = self.mead_detail.mead.as_ref().map(|m| m.id) else {
+            return;
+        };
+        let Some(DetailForm::Snapshot(input)) = &self.mead_detail.form else {
+            return;
+        };
+        let label = input.get_value().trim().to_string();
+        if label.is_empty() {
+            self.status_message = Some("Error: Snapshot needs a label".to_string());
+            return;
+        }
+        let result = self
+            .db
+            .get_mead(id)
+            .and_then(|saved| saved.map(|mead| self.db.create_snapshot(&mead, &label)).transpose());
+        match result {
+            Ok(_) => {
+                self.mead_detail.close_form();
+                self.status_message = Some(format!("Snapshot \"{}\" saved", label));
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Save the typed gravity reading schedule for the mead
+    fn save_reading_interval(&mut self) {
+        let Some(id) = self.mead_detail.mead.as_ref().map(|m| m.id) else {
+            return;
+        };
+        let Some(DetailForm::ReadingInterval(input)) = &self.mead_detail.form else {
+            return;
+        };
+        let days = match mead_detail::typed_reading_interval(input) {
+            Ok(days) => days,
+            Err(e) => {
+                self.status_message = Some(format!("Error: {}", e));
+                return;
+            }
+        };
+        match self.db.set_gravity_check_days(id, days) {
+            Ok(()) => {
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+                self.status_message = Some(match days {
+                    Some(days) => format!("Gravity reading due every {} days", days),
+                    None => "Reading schedule cleared".to_string(),
+                });
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Save the typed pH and TA for the mead
+    fn save_acidity(&mut self) {
+        let Some(id) = self.mead_detail.mead.as_ref().map(|m| m.id) else {
+            return;
+        };
+        let Some(DetailForm::Acidity(form)) = &self.mead_detail.form else {
+            return;
+        };
+        let (ph, ta) = match form.typed() {
+            Ok(acidity) => acidity,
+            Err(e) => {
+                self.status_message = Some(format!("Error: {}", e));
+                return;
+            }
+        };
+        match self.db.set_acidity(id, ph, ta) {
+            Ok(()) => {
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+                self.status_message = Some(if ph.is_none() && ta.is_none() {
+                    "Acidity cleared".to_string()
+                } else {
+                    "Acidity saved".to_string()
+                });
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Mark the open mead as a competition entry, or stop treating it as one.
+    /// Its category, score and notes are kept either way.
+    fn toggle_competition_entry(&mut self) {
+        let Some(mead) = &self.mead_detail.mead else {
+            return;
+        };
+        let competition = !mead.competition;
+        match self.db.set_competition(mead.id, competition) {
+            Ok(()) => {
+                self.mead_detail.needs_refresh = true;
+                self.mead_list.needs_refresh = true;
+                self.status_message = Some(if competition {
+                    "Marked as a competition entry (J for details)".to_string()
+                } else {
+                    "No longer a competition entry".to_string()
+                });
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Save the typed competition category, score and judges' notes
+    fn save_competition_details(&mut self) {
+        let Some(id) = self.mead_detail.mead.as_ref().map(|m| m.id) else {
+            return;
+        };
+        let Some(DetailForm::Competition(form)) = &self.mead_detail.form else {
+            return;
+        };
+        let score = match form.typed_score() {
+            Ok(score) => score,
+            Err(e) => {
+                self.status_message = Some(format!("Error: {}", e));
+                return;
+            }
+        };
+        let category = form.category_input.get_value().trim().to_string();
+        let notes = form.notes_input.get_value().trim().to_string();
+        match self.db.set_competition_details(id, &category, score, &notes) {
+            Ok(()) => {
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+                self.status_message = Some("Competition entry saved".to_string());
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Log the configured quick entry against the open mead, timestamped now
+    fn add_quick_log(&mut self) {
+        let Some(mead) = &self.mead_detail.mead else {
+            return;
+        };
+        let entry = LogEntry {
+            mead_id: mead.id,
+            entry_text: self.quick_log_text.clone(),
+            entry_type: LogEntryType::Observation,
+            ..Default::default()
+        };
+        match self.db.create_log_entry(&entry) {
+            Ok(_) => {
+                self.mead_detail.needs_refresh = true;
+                self.status_message = Some(format!("Logged \"{}\"", entry.entry_text));
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Set the current gravity back to the latest gravity reading, e.g.
+    /// after it was edited by hand
+    fn sync_gravity_to_latest_reading(&mut self) {
+        let Some(mead) = &self.mead_detail.mead else {
+            return;
+        };
+        let before = mead.current_gravity;
+        let Some(latest) = self.mead_detail.gravity_readings.iter().max_by_key(|r| (r.recorded_at, r.id)) else {
+            self.status_message = Some("No gravity readings to sync from".to_string());
+            return;
+        };
+        if (latest.gravity - before).abs() < f64::EPSILON {
+            self.status_message = Some("Current gravity already matches the latest reading".to_string());
+            return;
+        }
+        let after = latest.gravity;
+        match self.db.sync_current_gravity(mead.id) {
+            Ok(()) => {
+                self.mead_detail.needs_refresh = true;
+                self.status_message = Some(format!(
+                    "Current gravity {} → {} (latest reading)",
+                    format::fmt_gravity(before),
+                    format::fmt_gravity(after)
+                ));
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Load the mead's snapshots and open the comparison popup
+    fn open_snapshot_viewer(&mut self) {
+        let Some(id) = self.mead_detail.mead.as_ref().map(|m| m.id) else {
+            return;
+        };
+        match (self.db.get_snapshots(id), self.db.get_mead(id)) {
+            (Ok(snapshots), Ok(Some(current))) => self.mead_detail.open_snapshot_viewer(snapshots, current),
+            (Err(e), _) | (_, Err(e)) => self.status_message = Some(format!("Error: {}", e)),
+            (_, Ok(None)) => {}
+        }
+    }
+
+    /// Handle keys in the snapshot viewer
+    fn handle_snapshot_viewer_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => self.mead_detail.snapshot_viewer = None,
+            KeyCode::Up | KeyCode::Char('k') => self.mead_detail.previous_snapshot(),
+            KeyCode::Down | KeyCode::Char('j') => self.mead_detail.next_snapshot(),
+            KeyCode::Char('c') => self.export_comparison(ComparisonFormat::Csv),
+            KeyCode::Char('m') => self.export_comparison(ComparisonFormat::Markdown),
+            _ => {}
+        }
+    }
+
+    /// Export the highlighted snapshot next to the mead as it is now
+    fn export_comparison(&mut self, format: ComparisonFormat) {
+        let path = export::export_path(&self.data_dir, &format.file_name());
+        if self.confirm_overwrite(&path, PendingAction::ExportComparison { format }) {
+            self.write_comparison_export(format);
+        }
+    }
+
+    fn write_comparison_export(&mut self, format: ComparisonFormat) {
+        let Some(viewer) = &self.mead_detail.snapshot_viewer else {
+            return;
+        };
+        let Some(snapshot) = viewer.snapshots.get(viewer.selected) else {
+            return;
+        };
+        let rows = mead_comparison(&snapshot.mead, &viewer.current);
+        let path = export::export_path(&self.data_dir, &format.file_name());
+        let labels = (snapshot.label.as_str(), "Now");
+        self.status_message = Some(match export::comparison::write_comparison(&path, format, labels, &rows) {
+            Ok(()) => format!("Exported comparison to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Handle keys while the ingredient filter box has focus; Esc clears
+    /// the filter, Enter keeps it
+    fn handle_ingredient_filter_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.mead_detail.close_ingredient_filter(true),
+            KeyCode::Enter => self.mead_detail.close_ingredient_filter(false),
+            KeyCode::Up => self.mead_detail.select_previous(),
+            KeyCode::Down => self.mead_detail.select_next(),
+            KeyCode::Char(c) => {
+                self.mead_detail.ingredient_filter_input.insert_char(c);
+                self.mead_detail.ingredient_filter_changed();
+            }
+            KeyCode::Backspace => {
+                self.mead_detail.ingredient_filter_input.delete_char();
+                self.mead_detail.ingredient_filter_changed();
+            }
+            KeyCode::Left => self.mead_detail.ingredient_filter_input.move_cursor_left(),
+            KeyCode::Right => self.mead_detail.ingredient_filter_input.move_cursor_right(),
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the full-screen notes editor; Esc saves and closes
+    fn handle_notes_editor_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc {
+            let Some(notes) = self.mead_detail.close_notes_editor() else {
+                return;
+            };
+            if let Some(mut mead) = self.mead_detail.mead.clone() {
+                mead.notes = notes;
+                match self.db.update_mead(&mead) {
+                    Ok(()) => {
+                        self.status_message = Some("Notes saved".to_string());
+                        self.mead_detail.needs_refresh = true;
+                    }
+                    Err(e) => self.status_message = Some(format!("Error: {}", e)),
+                }
+            }
+            return;
+        }
+
+        let Some(editor) = self.mead_detail.notes_editor.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(c) => editor.insert_char(c),
+            KeyCode::Enter => editor.insert_newline(),
+            KeyCode::Backspace => editor.delete_char(),
+            KeyCode::Delete => editor.delete_char_forward(),
+            KeyCode::Left => editor.move_cursor_left(),
+            KeyCode::Right => editor.move_cursor_right(),
+            KeyCode::Up => editor.move_cursor_up(),
+            KeyCode::Down => editor.move_cursor_down(),
+            KeyCode::Home => editor.move_cursor_start(),
+            KeyCode::End => editor.move_cursor_end(),
+            _ => {}
+        }
+    }
+
+    /// Combine the open mead's duplicate ingredients
+    fn merge_duplicate_ingredients(&mut self) {
+        let Some(mead) = &self.mead_detail.mead else {
+            return;
+        };
+        match self.db.merge_duplicate_ingredients(mead.id) {
+            Ok(removed) => {
+                self.status_message = Some(format!("Merged {} duplicate ingredient row(s)", removed));
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    fn set_auto_reminders(&mut self, id: i64, enabled: bool) {
+        match self.db.set_auto_reminders(id, enabled) {
+            Ok(()) => {
+                let state = if enabled { "on" } else { "off" };
+                self.status_message = Some(format!("Overdue and stall warnings {}", state));
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Scale the open mead to the scaling form's target volume. A mead with
+    /// no volume has nothing to scale from, so it just gets the volume.
+    fn apply_scaling(&mut self) {
+        if let Some(mead) = self.mead_detail.mead.clone().filter(|m| !m.has_volume()) {
+            let Some(DetailForm::Scale(input)) = &self.mead_detail.form else {
+                return;
+            };
+            let Some(target) = input.get_f64().filter(|v| *v > 0.0) else {
+                self.status_message = Some("Enter a target volume above zero".to_string());
+                return;
+            };
+            let mead = Mead { volume_gallons: target, ..mead };
+            match self.db.update_mead(&mead) {
+                Ok(()) => {
+                    self.status_message = Some(format!("Set volume to {:.1} gal", target));
+                    self.mead_detail.close_form();
+                    self.mead_detail.needs_refresh = true;
+                }
+                Err(e) => self.status_message = Some(format!("Error: {}", e)),
+            }
+            return;
+        }
+        let Some(factor) = self.mead_detail.scale_factor() else {
+            self.status_message = Some("Enter a target volume above zero".to_string());
+            return;
+        };
+        let Some(mead) = &self.mead_detail.mead else {
+            return;
+        };
+        let volume = mead.volume_gallons * factor;
+        match self.db.scale_mead(mead.id, factor) {
+            Ok(()) => {
+                self.status_message = Some(format!("Scaled to {:.1} gal", volume));
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Save the top-up form's blended gravity and new volume for the mead,
+    /// recording the gravity as a reading and the top-up in the log
+    fn apply_top_up(&mut self) {
+        if self.mead_detail.mead.as_ref().is_some_and(|m| !m.has_volume()) {
+            self.status_message = Some(NO_VOLUME.to_string());
+            return;
+        }
+        let (Some(mead), Some(topped)) = (self.mead_detail.mead.clone(), self.mead_detail.topped_up_mead()) else {
+            self.status_message = Some("Enter the volume added and its gravity, e.g. 1.000 for water".to_string());
+            return;
+        };
+        let Some(DetailForm::TopUp(form)) = &self.mead_detail.form else {
+            return;
+        };
+        let added_gravity = form.gravity_input.get_f64().map(format::fmt_gravity).unwrap_or_default();
+        let entry = LogEntry {
+            mead_id: mead.id,
+            entry_text: format!(
+                "Topped up {:.1} gal at {}: gravity {} → {}",
+                topped.volume_gallons - mead.volume_gallons,
+                added_gravity,
+                format::fmt_gravity(mead.current_gravity),
+                format::fmt_gravity(topped.current_gravity)
+            ),
+            entry_type: LogEntryType::Addition,
+            ..Default::default()
+        };
+        match self.db.record_measurement(&topped, None, &entry) {
+            Ok(()) => {
+                self.status_message = Some(format!(
+                    "Topped up to {:.1} gal - gravity {}, ABV {}%",
+                    topped.volume_gallons,
+                    format::fmt_gravity(topped.current_gravity),
+                    format::fmt_abv(topped.current_abv().max(0.0))
+                ));
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Log the nutrient form's addition and add its YAN to the saved mead
+    fn save_nutrient_addition(&mut self) {
+        if self.mead_detail.mead.as_ref().is_some_and(|m| !m.has_volume()) {
+            self.status_message = Some(NO_VOLUME.to_string());
+            return;
+        }
+        let Some(ppm) = self.mead_detail.nutrient_yan_ppm() else {
+            self.status_message = Some("Enter the grams of nutrient added".to_string());
+            return;
+        };
+        let Some(mut mead) = self.mead_detail.mead.clone() else {
+            return;
+        };
+        let Some(DetailForm::Nutrient(form)) = &self.mead_detail.form else {
+            return;
+        };
+        let nutrient = form.nutrient();
+        let grams = form.amount_input.get_f64().unwrap_or(0.0);
+
+        mead.yan_added += ppm;
+        let entry = LogEntry {
+            mead_id: mead.id,
+            entry_text: format!(
+                "Added {:.1} g {} (+{} ppm YAN)",
+                grams,
+                nutrient.as_str(),
+                format::fmt_yan(ppm)
+            ),
+            entry_type: LogEntryType::Addition,
+            ..Default::default()
+        };
+
+        match self.db.update_mead(&mead).and_then(|_| self.db.create_log_entry(&entry)) {
+            Ok(_) => {
+                self.status_message = Some(entry.entry_text);
+                self.mead_detail.close_form();
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Export the open mead as a printable HTML brew sheet
+    fn export_mead_sheet(&mut self) {
+        let Some(mead) = &self.mead_detail.mead else {
+            return;
+        };
+        let path = export::export_path(&self.data_dir, &export::html::file_name(mead));
+        if self.confirm_overwrite(&path, PendingAction::ExportMeadSheet) {
+            self.write_mead_sheet_export();
+        }
+    }
+
+    fn write_mead_sheet_export(&mut self) {
+        let Some(mead) = &self.mead_detail.mead else {
+            return;
+        };
+        let path = export::export_path(&self.data_dir, &export::html::file_name(mead));
+        // Export the full log, not just the entries passing the log filter
+        let result = export::html::write_mead_sheet(
+            &path,
+            mead,
+            &self.mead_detail.ingredients,
+            &self.mead_detail.log_entries,
+        );
+        self.status_message = Some(match result {
+            Ok(()) => format!("Exported brew sheet to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Run one of the main menu exports and remember it for `e`
+    fn run_export(&mut self, format: ExportFormat) {
+        if self.main_menu.last_export != Some(format) {
+            self.main_menu.last_export = Some(format);
+            if let Err(e) = Config::store(&self.data_dir, "last_export", format.key()) {
+                self.status_message = Some(format!("Error saving config: {}", e));
+            }
+        }
+        match format {
+            ExportFormat::Calendar => self.export_calendar(),
+            ExportFormat::Json => self.export_json(None),
+            ExportFormat::Sql => self.export_sql(),
+        }
+    }
+
+    /// Run the main menu export used last again
+    fn repeat_last_export(&mut self) {
+        match self.main_menu.last_export {
+            Some(format) => self.run_export(format),
+            None => self.status_message = Some("Nothing exported yet; pick an export from the menu".to_string()),
+        }
+    }
+
+    /// Export reminders and status changes as an iCalendar file
+    fn export_calendar(&mut self) {
+        let path = export::export_path(&self.data_dir, export::ical::FILE_NAME);
+        if self.confirm_overwrite(&path, PendingAction::ExportCalendar) {
+            self.write_calendar_export();
+        }
+    }
+
+    fn write_calendar_export(&mut self) {
+        let path = export::export_path(&self.data_dir, export::ical::FILE_NAME);
+        let result = self.db.get_all_meads().and_then(|meads| {
+            let reminders = self.db.get_all_reminders()?;
+            let changes = self.db.get_all_status_changes()?;
+            Ok((meads, reminders, changes))
+        });
+        self.status_message = Some(match result {
+            Ok((meads, reminders, changes)) => {
+                match export::ical::write_calendar(&path, &meads, &reminders, &changes) {
+                    Ok(()) => format!("Exported calendar to {}", path.display()),
+                    Err(e) => format!("Export failed: {}", e),
+                }
+            }
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Ask to merge the two marked meads. The selected one is kept when it
+    /// is one of them, otherwise the one listed first.
+    fn request_merge(&mut self) {
+        let marked: Vec<&Mead> = self
+            .mead_list
+            .meads
+            .iter()
+            .filter(|m| self.mead_list.marked.contains(&m.id))
+            .collect();
+        let [first, second] = marked[..] else {
+            self.status_message = Some("Mark exactly two meads to merge".to_string());
+            return;
+        };
+        let selected = self.mead_list.get_selected().map(|m| m.id);
+        let (target, source) = if selected == Some(second.id) { (second, first) } else { (first, second) };
+        let message = format!(
+            "Merge {} into {}? Its ingredients, logs and readings move across and it is deleted.",
+            source.name, target.name
+        );
+        let action = PendingAction::MergeMeads { target: target.id, source: source.id, name: source.name.clone() };
+        self.confirm_or_run(message, action);
+    }
+
+    fn merge_meads(&mut self, target: i64, source: i64, name: &str) {
+        match self.db.merge_meads(target, source) {
+            Ok(()) => {
+                self.count_batches();
+                self.mead_list.marked.clear();
+                self.mead_list.needs_refresh = true;
+                self.mead_list.select_after_refresh = Some(target);
+                self.status_message = Some(format!("Merged {}", name));
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    fn delete_mead(&mut self, id: i64, name: &str) {
+        match self.db.delete_mead(id) {
+            Ok(()) => {
+                self.count_batches();
+                self.mead_list.needs_refresh = true;
+                self.status_message = Some(format!("Deleted mead: {}", name));
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    fn delete_gravity_reading(&mut self, id: i64, gravity: f64) {
+        match self.db.delete_gravity_reading(id) {
+            Ok(()) => {
+                self.status_message = Some(format!("Deleted reading {}", format::fmt_gravity(gravity)));
+                self.mead_detail.needs_refresh = true;
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Export meads as JSON: the ones in `ids`, or every mead for None
+    fn export_json(&mut self, ids: Option<Vec<i64>>) {
+        let path = self.json_export_path(ids.is_some());
+        if self.confirm_overwrite(&path, PendingAction::ExportJson { ids: ids.clone() }) {
+            self.write_json_export(ids.as_deref());
+        }
+    }
+
+    /// Where a JSON export goes. Only the full export is written where the
+    /// import reads, so a replacing import can't silently drop the meads a
+    /// filtered export left out.
+    fn json_export_path(&self, filtered: bool) -> PathBuf {
+        if filtered {
+            export::export_path(&self.data_dir, &export::json::filtered_file_name(Local::now().date_naive()))
+        } else {
+            export::export_path(&self.data_dir, export::json::FILE_NAME)
+        }
+    }
+
+    fn write_json_export(&mut self, ids: Option<&[i64]>) {
+        let path = self.json_export_path(ids.is_some());
+        self.status_message = Some(match self.db.get_records(ids) {
+            Ok(records) => match export::json::write_json(&path, &records) {
+                Ok(()) => format!("Exported {} meads to {}", records.len(), path.display()),
+                Err(e) => format!("Export failed: {}", e),
+            },
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Export the meads the list shows as CSV, in the order shown
+    fn export_list_csv(&mut self) {
+        let path = export::export_path(&self.data_dir, export::list::FILE_NAME);
+        if self.confirm_overwrite(&path, PendingAction::ExportListCsv) {
+            self.write_list_csv_export();
+        }
+    }
+
+    fn write_list_csv_export(&mut self) {
+        let path = export::export_path(&self.data_dir, export::list::FILE_NAME);
+        let meads = &self.mead_list.meads;
+        self.status_message = Some(match export::list::write_list_csv(&path, meads) {
+            Ok(()) => format!("Exported {} meads to {}", meads.len(), path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    fn export_sql(&mut self) {
+        let path = export::export_path(&self.data_dir, SQL_FILE_NAME);
+        if self.confirm_overwrite(&path, PendingAction::ExportSql) {
+            self.write_sql_export();
+        }
+    }
+
+    fn write_sql_export(&mut self) {
+        let path = export::export_path(&self.data_dir, SQL_FILE_NAME);
+        self.status_message = Some(match self.db.export_sql(&path) {
+            Ok(rows) => format!("Exported {} rows to {}", rows, path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Read the JSON export file and show what importing it would do.
+    /// Replacing is only offered when there are meads to replace.
+    fn start_import(&mut self) {
+        let path = export::export_path(&self.data_dir, export::json::FILE_NAME);
+        if !path.exists() {
+            self.status_message = Some(format!("Nothing to import: {} not found", path.display()));
+            return;
+        }
+        let records = match export::json::read_json(&path) {
+            Ok(records) => records,
+            Err(e) => {
+                self.status_message = Some(format!("Import failed: {}", e));
+                return;
+            }
+        };
+        let existing = match self.db.count_meads() {
+            Ok(count) => count,
+            Err(e) => {
+                self.status_message = Some(format!("Error: {}", e));
+                return;
+            }
+        };
+        self.main_menu.import_preview = Some(ImportPreview {
+            title: "Import JSON",
+            report: ImportReport::dry_run(&records),
+            records,
+            unmapped: Vec::new(),
+            can_replace: existing > 0,
+        });
+    }
+
+    /// Read the recipe import file and show the mead it would add, listing
+    /// anything that couldn't be mapped
+    fn import_recipe(&mut self) {
+        let path = export::export_path(&self.data_dir, import::recipe::FILE_NAME);
+        if !path.exists() {
+            self.status_message = Some(format!("Nothing to import: {} not found", path.display()));
+            return;
+        }
+        let recipe = match import::recipe::read_recipe(&path) {
+            Ok(recipe) => recipe,
+            Err(e) => {
+                self.status_message = Some(format!("Import failed: {}", e));
+                return;
+            }
+        };
+        let records = vec![recipe.record];
+        self.main_menu.import_preview = Some(ImportPreview {
+            title: "Import Recipe",
+            report: ImportReport::dry_run(&records),
+            records,
+            unmapped: recipe.unmapped,
+            can_replace: false,
+        });
+    }
+
+    /// Handle keys in the import preview. Nothing can be imported while the
+    /// report lists problems; Replace asks again, saying how many existing
+    /// meads would be deleted.
+    fn handle_import_prompt_key(&mut self, key: KeyEvent) {
+        let Some(preview) = &self.main_menu.import_preview else {
+            return;
+        };
+        let importable = preview.report.errors.is_empty();
+        match key.code {
+            KeyCode::Char('a') | KeyCode::Enter if importable => {
+                if let Some(preview) = self.main_menu.import_preview.take() {
+                    self.run_import(&preview.records, ImportMode::Append);
+                }
+            }
+            KeyCode::Char('r') if importable && preview.can_replace => {
+                if let Some(preview) = self.main_menu.import_preview.take() {
+                    self.staged_import = preview.records;
+                }
+                let existing = self.db.count_meads().unwrap_or(0);
+                self.confirm_or_run(
+                    format!(
+                        "Replace all {} existing meads? Their logs, ingredients and reminders are deleted too.",
+                        existing
+                    ),
+                    PendingAction::ImportReplace,
+                );
+            }
+            KeyCode::Esc => self.main_menu.import_preview = None,
+            _ => {}
+        }
+    }
+
+    /// Handle keys in the profile switcher; `n` names a new profile, which
+    /// is created when switched to
+    fn handle_profile_picker_key(&mut self, key: KeyEvent) {
+        let Some(picker) = &mut self.main_menu.profile_picker else {
+            return;
+        };
+        if let Some(copy) = &mut picker.copy_from {
+            match key.code {
+                KeyCode::Esc => picker.copy_from = None,
+                KeyCode::Up | KeyCode::Char('k') => copy.previous(),
+                KeyCode::Down | KeyCode::Char('j') => copy.next(),
+                KeyCode::Char(' ') => copy.toggle_selected(),
+                KeyCode::Enter if copy.marked.is_empty() => {
+                    self.status_message = Some("Mark batches to copy with Space".to_string());
+                }
+                KeyCode::Enter => {
+                    let (profile, ids) = (copy.profile.clone(), copy.marked_ids());
+                    self.copy_from_profile(&profile, &ids);
+                }
+                _ => {}
+            }
+            return;
+        }
+        if picker.naming {
+            match key.code {
+                KeyCode::Esc => picker.naming = false,
+                KeyCode::Enter => {
+                    let name = picker.name_input.get_value().trim().to_string();
+                    if is_valid_profile_name(&name) {
+                        self.switch_profile(&name);
+                    } else {
+                        self.status_message = Some("Profile names use letters, digits, - and _".to_string());
+                    }
+                }
+                KeyCode::Char(c) => picker.name_input.insert_char(c),
+                KeyCode::Backspace => picker.name_input.delete_char(),
+                KeyCode::Left => picker.name_input.move_cursor_left(),
+                KeyCode::Right => picker.name_input.move_cursor_right(),
+                _ => {}
+            }
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.main_menu.profile_picker = None,
+            KeyCode::Up | KeyCode::Char('k') => picker.previous(),
+            KeyCode::Down | KeyCode::Char('j') => picker.next(),
+            KeyCode::Char('n') if self.read_only => {
+                self.status_message = Some("Read-only mode: changes are disabled".to_string());
+            }
+            KeyCode::Char('n') => picker.start_naming(),
+            KeyCode::Char('c') if self.read_only => {
+                self.status_message = Some("Read-only mode: changes are disabled".to_string());
+            }
+            KeyCode::Char('c') => {
+                let Some(name) = picker.selected_profile().map(str::to_string) else {
+                    return;
+                };
+                if name == picker.active {
+                    self.status_message = Some("Pick another profile to copy batches from".to_string());
+                    return;
+                }
+                match Self::open_profile(&self.data_dir, &name).and_then(|db| db.get_all_meads()) {
+                    Ok(meads) => picker.copy_from = Some(CopyPicker::new(&name, meads)),
+                    Err(e) => self.status_message = Some(format!("Error: {}", e)),
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(name) = picker.selected_profile().map(str::to_string) {
+                    self.switch_profile(&name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Open another profile's database to read from, without a startup
+    /// backup
+    fn open_profile(data_dir: &Path, profile: &str) -> rusqlite::Result<Database> {
+        let mut config = Config::load(data_dir);
+        config.profile = profile.to_string();
+        config.backup_on_startup = false;
+        Database::new(&config, data_dir)
+    }
+
+    /// Copy batches from another profile into the open one
+    fn copy_from_profile(&mut self, profile: &str, ids: &[i64]) {
+        self.main_menu.profile_picker = None;
+        let result = Self::open_profile(&self.data_dir, profile).and_then(|other| self.db.copy_meads_from(&other, ids));
+        self.status_message = Some(match result {
+            Ok(1) => format!("Copied 1 batch from \"{}\"", profile),
+            Ok(count) => format!("Copied {} batches from \"{}\"", count, profile),
+            Err(e) => format!("Error: {}", e),
+        });
+        self.count_batches();
+        self.mead_list.needs_refresh = true;
+        self.main_menu.needs_refresh = true;
+        self.recent_activity.needs_refresh = true;
+        self.stats.needs_refresh = true;
+    }
+
+    /// Reopen the app on another profile's database, creating it if new,
+    /// and remember it for the next launch
+    fn switch_profile(&mut self, profile: &str) {
+        self.main_menu.profile_picker = None;
+        if profile == self.db.profile() {
+            self.status_message = Some(format!("Already using profile \"{}\"", profile));
+            return;
+        }
+        let mut config = Config::load(&self.data_dir);
+        config.profile = profile.to_string();
+        let db = if self.read_only {
+            Database::open_read_only(&config, &self.data_dir)
+        } else {
+            Database::new(&config, &self.data_dir)
+        };
+        match db {
+            Ok(db) => {
+                self.db = db;
+                self.count_batches();
+                self.mead_detail.forget_meads();
+                // Marks and selection are ids in the old profile's database
+                self.mead_list.marked.clear();
+                self.mead_list.select_after_refresh = None;
+                self.mead_list.needs_refresh = true;
+                self.main_menu.needs_refresh = true;
+                self.recent_activity.needs_refresh = true;
+                self.stats.needs_refresh = true;
+                // A read-only session leaves the config as it found it
+                let stored = if self.read_only { Ok(()) } else { Config::store(&self.data_dir, "profile", profile) };
+                self.status_message = Some(match stored {
+                    Ok(()) => format!("Switched to profile \"{}\"", profile),
+                    Err(e) => format!("Switched to profile \"{}\" (error saving config: {})", profile, e),
+                });
+            }
+            Err(e) => self.status_message = Some(format!("Error: {}", e)),
+        }
+    }
+
+    /// Write previewed import records to the database
+    fn run_import(&mut self, records: &[MeadRecord], mode: ImportMode) {
+        self.status_message = Some(match self.db.import_records(records, mode) {
+            Ok(1) => format!("Imported \"{}\"", records[0].mead.name),
+            Ok(count) => format!("Imported {} meads", count),
+            Err(e) => format!("Import failed: {}", e),
+        });
+        self.count_batches();
+        self.mead_list.needs_refresh = true;
+        self.main_menu.needs_refresh = true;
+    }
+
+    /// Recount the meads for the status bar
+    fn count_batches(&mut self) {
+        self.batch_total = self.db.count_meads().ok();
+    }
+
+    /// Show the data directory, copying it to the clipboard when there is
+    /// one
+    fn copy_data_dir(&mut self) {
+        let path = self.data_dir.display().to_string();
+        self.status_message = Some(match self.copy_to_clipboard(path.clone()) {
+            Ok(()) => format!("Data directory (copied): {}", path),
+            Err(_) => format!("Data directory: {}", path),
+        });
+    }
+
+    /// Open the data directory in the desktop's file manager, falling back
+    /// to showing its path
+    fn open_data_dir(&mut self) {
+        let path = self.data_dir.clone();
+        let opened = file_manager(std::env::consts::OS).is_some_and(|command| {
+            std::process::Command::new(command)
+                .arg(&path)
+                .stdin(std::process::Stdio::null())
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .spawn()
+                .is_ok()
+        });
+        self.status_message = Some(if opened {
+            format!("Opened {}", path.display())
+        } else {
+            format!("No file manager to open; data directory: {}", path.display())
+        });
+    }
+
+    /// Place text on the system clipboard
+    fn copy_to_clipboard(&mut self, text: String) -> Result<(), arboard::Error> {
+        let clipboard = match self.clipboard.take() {
+            Some(clipboard) => clipboard,
+            None => arboard::Clipboard::new()?,
+        };
+        self.clipboard.insert(clipboard).set_text(text)
+    }
+}
+
+/// Status shown when a typed gravity is missing or out of range
+fn gravity_range_message() -> String {
+    format!(
+        "Gravity must be between {} and {}",
+        format::fmt_gravity(MIN_GRAVITY),
+        format::fmt_gravity(MAX_GRAVITY)
+    )
+}
+
+/// Command that opens a folder in the file manager on `os`, if it has one
+fn file_manager(os: &str) -> Option<&'static str> {
+    match os {
+        "macos" => Some("open"),
+        "windows" => Some("explorer"),
+        "linux" | "freebsd" | "netbsd" | "openbsd" | "dragonfly" => Some("xdg-open"),
+        _ => None,
+    }
+}
+
+/// `path` with the home directory shown as "~", then cut from the front
+/// to at most `max` characters so the file name stays visible
+fn abbreviate_path(path: &str, home: Option<&str>, max: usize) -> String {
+    let path = match home.filter(|h| !h.is_empty()).and_then(|h| path.strip_prefix(h)) {
+        Some(rest) if rest.starts_with(['/', '\\']) => format!("~{}", rest),
+        _ => path.to_string(),
+    };
+    let len = path.chars().count();
+    if len <= max {
+        return path;
+    }
+    if max == 0 {
+        return String::new();
+    }
+    let tail: String = path.chars().skip(len - (max - 1)).collect();
+    format!("…{}", tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use crate::models::{rating_stars, Ingredient};
+    use crate::test_support::{detail_form, screen_rows, screen_text, TempDir};
+
+    /// An app whose config and exports go to a temp dir removed with it
+    struct TestApp {
+        app: App,
+        _data_dir: TempDir,
+    }
+
+    impl std::ops::Deref for TestApp {
+        type Target = App;
+
+        fn deref(&self) -> &App {
+            &self.app
+        }
+    }
+
+    impl std::ops::DerefMut for TestApp {
+        fn deref_mut(&mut self) -> &mut App {
+            &mut self.app
+        }
+    }
+
+    /// App around `db` with a data dir of its own
+    fn test_app(config: Config, db: Database, read_only: bool) -> TestApp {
+        let data_dir = TempDir::new();
+        let app = App::with_database(config, db, data_dir.path().to_path_buf(), read_only, None);
+        TestApp { app, _data_dir: data_dir }
+    }
+
+    /// App on an in-memory database showing the detail view of `mead`
+    fn app_showing(mut mead: Mead) -> TestApp {
+        let config = Config {
+            onboarding_done: true,
+            ..Config::default()
+        };
+        let db = Database::open_in_memory(&config).unwrap();
+        mead.id = db.create_mead(&mead).unwrap();
+
+        let mut app = test_app(config, db, false);
+        app.current_view = View::MeadDetail(mead.id);
+        app.mead_detail.set_mead(mead, Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        app
+    }
+
+    /// Like `app_showing`, with every write after setup failing
+    fn app_with_failing_db() -> TestApp {
+        let app = app_showing(Mead {
+            name: "Test Batch".to_string(),
+            ..Default::default()
+        });
+        app.db.reject_writes().unwrap();
+        app
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn data_folder_opens_with_the_platform_file_manager() {
+        assert_eq!(file_manager("linux"), Some("xdg-open"));
+        assert_eq!(file_manager("macos"), Some("open"));
+        assert_eq!(file_manager("windows"), Some("explorer"));
+        assert_eq!(file_manager("android"), None);
+    }
+
+    #[test]
+    fn status_bar_shows_the_database_and_batch_count() {
+        assert_eq!(abbreviate_path("/home/bee/.local/share/mead_tracker/mead_tracker.db", Some("/home/bee"), 80),
+            "~/.local/share/mead_tracker/mead_tracker.db");
+        assert_eq!(abbreviate_path("/home/beekeeper/m.db", Some("/home/bee"), 80), "/home/beekeeper/m.db");
+        assert_eq!(abbreviate_path("/data/mead_tracker.db", None, 10), "…racker.db");
+
+        let mut app = app_showing(Mead::default());
+        app.current_view = View::MainMenu;
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let row = &screen_rows(&terminal)[29];
+        assert!(row.contains(" default · (in memory) · 1 batch"), "{}", row);
+
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        app.delete_mead(id, "Test");
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let row = &screen_rows(&terminal)[29];
+        assert!(row.contains(" · 0 batches"), "{}", row);
+    }
+
+    #[test]
+    fn exports_ask_before_overwriting_a_file() {
+        let mut app = app_showing(Mead { name: "Cyser".to_string(), ..Default::default() });
+        let sheet = export::html::file_name(app.mead_detail.mead.as_ref().unwrap());
+        let exports = [(App::export_mead_sheet as fn(&mut App), sheet.as_str()), (App::export_calendar, export::ical::FILE_NAME)];
+        for (export, file_name) in exports {
+            let path = export::export_path(&app.data_dir, file_name);
+            std::fs::write(&path, "kept").unwrap();
+            export(&mut app);
+            let name = path.file_name().unwrap().to_string_lossy();
+            assert_eq!(app.confirm.as_ref().map(|(dialog, _)| dialog.message.clone()), Some(format!("Overwrite {}?", name)));
+            press(&mut app, KeyCode::Char('n'));
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), "kept");
+
+            export(&mut app);
+            press(&mut app, KeyCode::Char('y'));
+            assert_ne!(std::fs::read_to_string(&path).unwrap(), "kept");
+        }
+    }
+
+    #[test]
+    fn e_repeats_the_last_menu_export() {
+        let mut app = app_showing(Mead::default());
+        app.current_view = View::MainMenu;
+        app.overwrite_exports = true;
+        press(&mut app, KeyCode::Char('e'));
+        assert_eq!(app.status_message.as_deref(), Some("Nothing exported yet; pick an export from the menu"));
+
+        app.run_export(ExportFormat::Sql);
+        assert_eq!(app.main_menu.last_export, Some(ExportFormat::Sql));
+        app.status_message = None;
+        press(&mut app, KeyCode::Char('e'));
+        let message = app.status_message.clone().unwrap_or_default();
+        assert!(message.starts_with("Exported ") && message.ends_with(SQL_FILE_NAME), "{}", message);
+        assert_eq!(app.main_menu.last_export, Some(ExportFormat::Sql));
+    }
+
+    #[test]
+    fn exporting_the_filtered_list_keeps_the_full_export_for_a_replacing_import() {
+        let config = Config::parse("onboarding_done = true");
+        let db = Database::open_in_memory(&config).unwrap();
+        for (name, competition) in [("Cyser", false), ("Pyment", true), ("Show Mead", false)] {
+            db.create_mead(&Mead { name: name.to_string(), competition, ..Default::default() }).unwrap();
+        }
+        let mut app = test_app(config, db, false);
+        app.export_json(None);
+
+        app.current_view = View::MeadList;
+        app.refresh_mead_list();
+        press(&mut app, KeyCode::Char('*'));
+        press(&mut app, KeyCode::Char('x'));
+        let message = app.status_message.clone().unwrap_or_default();
+        assert!(message.starts_with("Exported 1 meads") && message.contains("meads-filtered-"), "{}", message);
+        press(&mut app, KeyCode::Char('X'));
+        let csv = std::fs::read_to_string(export::export_path(&app.data_dir, export::list::FILE_NAME)).unwrap();
+        assert_eq!(csv.lines().count(), 2);
+        assert!(csv.lines().nth(1).unwrap().starts_with("Pyment,"));
+
+        // Replacing from the import file brings back every mead, not just
+        // the filtered one
+        app.current_view = View::MainMenu;
+        app.start_import();
+        press(&mut app, KeyCode::Char('r'));
+        press(&mut app, KeyCode::Char('y'));
+        assert_eq!(app.db.count_meads().unwrap(), 3);
+    }
+
+    #[test]
+    fn esc_asks_before_discarding_a_typed_ingredient() {
+        let mut app = app_showing(Mead::default());
+
+        // An untouched form closes straight away
+        press(&mut app, KeyCode::Char('i'));
+        press(&mut app, KeyCode::Esc);
+        assert!(app.mead_detail.form.is_none());
+        assert!(app.confirm.is_none());
+
+        press(&mut app, KeyCode::Char('i'));
+        for c in "Honey".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Esc);
+        assert!(app.confirm.is_some());
+        press(&mut app, KeyCode::Char('n'));
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::Ingredient(_))));
+        assert_eq!(detail_form!(app.mead_detail, Ingredient).name_input.get_value(), "Honey");
+
+        press(&mut app, KeyCode::Esc);
+        press(&mut app, KeyCode::Char('y'));
+        assert!(app.mead_detail.form.is_none());
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(detail_form!(app.mead_detail, Ingredient).name_input.get_value(), "");
+    }
+
+    #[test]
+    fn up_and_down_step_the_ingredient_amount() {
+        let mut app = app_showing(Mead::default());
+        press(&mut app, KeyCode::Char('i'));
+        press(&mut app, KeyCode::Tab);
+        press(&mut app, KeyCode::Char('2'));
+        press(&mut app, KeyCode::Up);
+        app.handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT));
+        assert_eq!(detail_form!(app.mead_detail, Ingredient).amount_input.get_value(), "3.1");
+        for _ in 0..5 {
+            app.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT));
+        }
+        assert_eq!(detail_form!(app.mead_detail, Ingredient).amount_input.get_value(), "0.0");
+        assert_eq!(detail_form!(app.mead_detail, Ingredient).field, 1);
+    }
+
+    #[test]
+    fn suspicious_ingredient_units_warn_but_still_save() {
+        let mut app = app_showing(Mead::default());
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('i'));
+        detail_form!(app.mead_detail, Ingredient).name_input.set_value("Honey");
+        detail_form!(app.mead_detail, Ingredient).amount_input.set_value("48");
+        detail_form!(app.mead_detail, Ingredient).unit_input.set_value("oz");
+        assert_eq!(app.mead_detail.typed_ingredient().unwrap().unit_warning().as_deref(), Some("48 oz is 3.0 lbs - did you mean lbs?"));
+
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.status_message.as_deref(), Some("Added Honey; 48 oz is 3.0 lbs - did you mean lbs?"));
+        assert_eq!(app.db.get_ingredients(id).unwrap().len(), 1);
+
+        let pinch = |amount: f64, unit: &str| Ingredient { amount, unit: unit.to_string(), ..Default::default() }.unit_warning();
+        assert!(pinch(0.05, "lbs").unwrap().contains("did you mean oz?"));
+        assert!(pinch(60.0, "kg").is_some());
+        assert_eq!(pinch(12.0, "oz"), None);
+        assert_eq!(pinch(3.0, "lbs"), None);
+    }
+
+    #[test]
+    fn ingredient_notes_are_saved_with_the_ingredient() {
+        let mut app = app_showing(Mead::default());
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('i'));
+        detail_form!(app.mead_detail, Ingredient).name_input.set_value("Marionberries");
+        for _ in 0..4 {
+            press(&mut app, KeyCode::Tab);
+        }
+        for c in "Oregon, frozen".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.db.get_ingredients(id).unwrap()[0].notes, "Oregon, frozen");
+        assert!(app.mead_detail.form.is_none());
+    }
+
+    #[test]
+    fn list_auto_refresh_picks_up_new_meads_and_keeps_the_selection() {
+        let mut app = app_showing(Mead { name: "Cyser".to_string(), ..Default::default() });
+        app.current_view = View::MeadList;
+        app.refresh_mead_list();
+        assert_eq!(app.list_refresh_due_in(), None, "off by default");
+
+        app.list_refresh = Some(Duration::ZERO);
+        app.db.create_mead(&Mead { name: "Melomel".to_string(), ..Default::default() }).unwrap();
+        assert_eq!(app.list_refresh_due_in(), Some(Duration::ZERO));
+        app.refresh_mead_list();
+        assert_eq!(app.mead_list.meads.len(), 2);
+        assert_eq!(app.mead_list.get_selected().unwrap().name, "Cyser");
+    }
+
+    #[test]
+    fn rated_batches_save_and_sort_first_in_the_list() {
+        let mut app = app_showing(Mead { name: "Cyser".to_string(), ..Default::default() });
+        app.db.create_mead(&Mead { name: "Melomel".to_string(), ..Default::default() }).unwrap();
+        app.mead_detail.jump_to_hotkey('r');
+        press(&mut app, KeyCode::Char('4'));
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.mead_detail.current_rating, Some(4));
+        assert_eq!(app.mead_detail.pane, DetailPane::Fields, "arrows rate rather than change pane");
+        press(&mut app, KeyCode::Char('s'));
+
+        app.current_view = View::MeadList;
+        app.refresh_mead_list();
+        assert_eq!(app.mead_list.meads[0].name, "Melomel");
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(app.mead_list.meads[0].name, "Cyser");
+        assert_eq!(app.mead_list.meads[0].rating, Some(4));
+        assert_eq!(rating_stars(app.mead_list.meads[0].rating), "★★★★☆");
+    }
+
+    #[test]
+    fn edit_all_steps_through_every_field_and_saves_after_the_last() {
+        let mut app = app_showing(Mead { name: "Cyser".to_string(), status: MeadStatus::Primary, ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        let ctrl_e = KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL);
+        app.handle_key_event(ctrl_e);
+        assert!(app.mead_detail.is_editing(), "starts typing in the name");
+        press(&mut app, KeyCode::Char('!'));
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Char('3'));
+        press(&mut app, KeyCode::Enter);
+        "rack".chars().for_each(|c| press(&mut app, KeyCode::Char(c)));
+        for _ in 0..3 {
+            press(&mut app, KeyCode::Enter);
+        }
+        "okay".chars().for_each(|c| press(&mut app, KeyCode::Char(c)));
+        press(&mut app, KeyCode::Enter);
+        assert!(app.db.get_mead(id).unwrap().unwrap().notes.is_empty(), "nothing saved before the last field");
+        press(&mut app, KeyCode::Enter);
+
+        assert!(!app.mead_detail.edit_all);
+        let saved = app.db.get_mead(id).unwrap().unwrap();
+        assert_eq!(saved.name, "Cyser!");
+        assert_eq!(saved.status, MeadStatus::Secondary);
+        assert_eq!(saved.rating, Some(3));
+        assert_eq!(saved.next_action, "rack");
+        assert_eq!(saved.notes, "okay");
+
+        app.handle_key_event(ctrl_e);
+        press(&mut app, KeyCode::Char('?'));
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.mead_detail.edit_all);
+        assert!(app.status_message.as_deref().unwrap().contains("not saved"));
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().name, "Cyser!");
+    }
+
+    #[test]
+    fn merging_needs_two_marked_meads_and_keeps_the_selected_one() {
+        let mut app = app_showing(Mead { name: "Cyser".to_string(), ..Default::default() });
+        app.db.create_mead(&Mead { name: "Cyser again".to_string(), ..Default::default() }).unwrap();
+        app.current_view = View::MeadList;
+        app.refresh_mead_list();
+        press(&mut app, KeyCode::Char(' '));
+        press(&mut app, KeyCode::Char('M'));
+        assert_eq!(app.status_message.as_deref(), Some("Mark exactly two meads to merge"));
+
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Char(' '));
+        press(&mut app, KeyCode::Char('M'));
+        press(&mut app, KeyCode::Char('y'));
+        assert_eq!(app.status_message.as_deref(), Some("Merged Cyser again"));
+        app.refresh_mead_list();
+        let names: Vec<&str> = app.mead_list.meads.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["Cyser"]);
+    }
+
+    #[test]
+    fn failed_mead_save_reports_the_error() {
+        let mut app = app_with_failing_db();
+        app.mead_detail.name_input.set_value("Renamed");
+        press(&mut app, KeyCode::Char('s'));
+        let message = app.status_message.clone().unwrap_or_default();
+        assert!(message.starts_with("Error:"), "got {:?}", message);
+    }
+
+    #[test]
+    fn failed_log_entry_reports_the_error_and_keeps_the_input() {
+        let mut app = app_with_failing_db();
+        app.mead_detail.open_log_input();
+        detail_form!(app.mead_detail, Log).input.set_value("Pitched yeast");
+        press(&mut app, KeyCode::Enter);
+        let message = app.status_message.clone().unwrap_or_default();
+        assert!(message.starts_with("Error:"), "got {:?}", message);
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::Log(_))));
+        assert_eq!(detail_form!(app.mead_detail, Log).input.get_value(), "Pitched yeast");
+    }
+
+    #[test]
+    fn empty_reminders_are_reported_and_not_saved() {
+        let mut app = app_showing(Mead::default());
+        app.mead_detail.open_reminder_input();
+        detail_form!(app.mead_detail, Reminder).date_input.set_value("2025-06-10");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.status_message.as_deref(), Some("Enter what the reminder is for"));
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::Reminder(_))));
+        assert!(app.db.get_all_reminders().unwrap().is_empty());
+    }
+
+    #[test]
+    fn reading_corrections_must_be_plausible_gravities() {
+        let mut app = app_showing(Mead { current_gravity: 1.0506, ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        let readings = app.db.get_gravity_readings(id).unwrap();
+        let mead = app.db.get_mead(id).unwrap().unwrap();
+        app.mead_detail.set_mead(mead, vec![], vec![], vec![], readings);
+        app.mead_detail.open_reading_input();
+        assert_eq!(detail_form!(app.mead_detail, Reading).get_value(), "1.0506");
+
+        detail_form!(app.mead_detail, Reading).set_value("10.5");
+        app.save_reading_correction();
+        assert!(app.status_message.as_deref().unwrap_or_default().starts_with("Gravity must be between"));
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::Reading(_))));
+        assert_eq!(app.db.get_gravity_readings(id).unwrap()[0].gravity, 1.0506);
+    }
+
+    #[test]
+    fn measurements_out_of_the_gravity_range_are_not_recorded() {
+        let mut app = app_showing(Mead { status: MeadStatus::Primary, current_gravity: 1.050, ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('m'));
+        for c in "10.40".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(app.status_message.as_deref().unwrap_or_default().starts_with("Gravity must be between"));
+        assert_eq!(app.mead_detail.measurement_step(), Some(MeasurementStep::Gravity));
+
+        detail_form!(app.mead_detail, Measurement).gravity_input.set_value("1.040");
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mead_detail.measurement_step(), None);
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().current_gravity, 1.040);
+        assert_eq!(app.db.get_gravity_readings(id).unwrap().last().map(|r| r.gravity), Some(1.040));
+    }
+
+    #[test]
+    fn list_gravity_editor_saves_an_untouched_value_unchanged() {
+        let mut app = app_showing(Mead { name: "Cyser".to_string(), current_gravity: 1.0506, ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        app.current_view = View::MeadList;
+        app.refresh_mead_list();
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(app.mead_list.gravity_input.get_value(), "1.0506");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().current_gravity, 1.0506);
+        assert_eq!(app.db.get_gravity_readings(id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn read_only_list_allows_only_keys_that_change_nothing() {
+        let config = Config { onboarding_done: true, ..Config::default() };
+        let db = Database::open_in_memory(&config).unwrap();
+        let id = db.create_mead(&Mead { name: "Braggot".to_string(), ..Default::default() }).unwrap();
+        db.create_mead(&Mead { name: "Cyser".to_string(), ..Default::default() }).unwrap();
+        let mut app = test_app(config, db, true);
+        app.current_view = View::MeadList;
+        app.refresh_mead_list();
+
+        for key in ['p', 'a', 'u'] {
+            app.status_message = None;
+            press(&mut app, KeyCode::Char(key));
+            assert_eq!(app.status_message.as_deref(), Some("Read-only mode: changes are disabled"), "{}", key);
+        }
+        assert!(!app.db.get_mead(id).unwrap().unwrap().pinned);
+
+        app.status_message = None;
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.status_message, None);
+        assert_eq!(app.mead_list.selected, 1);
+    }
+
+    #[test]
+    fn typing_a_edits_the_field_and_ctrl_w_asks_before_toggling_warnings() {
+        let mut app = app_showing(Mead { name: "Sack".to_string(), ..Default::default() });
+        app.mead_detail.name_input.set_value("");
+        press(&mut app, KeyCode::Char('a'));
+        press(&mut app, KeyCode::Char('a'));
+        assert!(app.mead_detail.is_editing());
+        assert!(app.mead_detail.name_input.get_value().ends_with("aa"), "{}", app.mead_detail.name_input.get_value());
+        press(&mut app, KeyCode::Esc);
+
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        assert!(app.confirm.is_some());
+        press(&mut app, KeyCode::Char('y'));
+        assert!(!app.db.get_mead(id).unwrap().unwrap().auto_reminders);
+    }
+
+    #[test]
+    fn shopping_list_sums_ingredients_across_marked_meads() {
+        let mut app = app_showing(Mead { name: "Cyser".to_string(), ..Default::default() });
+        let cyser = app.mead_detail.mead.as_ref().unwrap().id;
+        let melomel = app.db.create_mead(&Mead { name: "Melomel".to_string(), ..Default::default() }).unwrap();
+        for (mead_id, name, amount, unit) in [
+            (cyser, "Clover honey", 1.0, "lb"),
+            (melomel, "Clover honey", 8.0, "oz"),
+            (melomel, "Fermaid O", 2.0, "sachets"),
+            (cyser, "Fermaid O", 1.0, "sachet"),
+        ] {
+            let ingredient = Ingredient { mead_id, name: name.to_string(), amount, unit: unit.to_string(), ..Default::default() };
+            app.db.create_ingredient(&ingredient).unwrap();
+        }
+        app.current_view = View::MeadList;
+        app.refresh_mead_list();
+        app.mead_list.marked = HashSet::from([cyser, melomel]);
+
+        press(&mut app, KeyCode::Char('s'));
+        let list = app.mead_list.shopping_list.as_ref().unwrap();
+        assert_eq!(list.batches.len(), 2);
+        let lines: Vec<(&str, String, usize)> =
+            list.items.iter().map(|i| (i.name.as_str(), i.amount_text(), i.batches)).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], ("Clover honey", "680 g".to_string(), 2));
+        assert_eq!(lines[1].0, "Fermaid O");
+        assert!(lines[1].1.starts_with("3.0 sachet"), "{}", lines[1].1);
+
+        press(&mut app, KeyCode::Esc);
+        assert!(app.mead_list.shopping_list.is_none());
+    }
+
+    #[test]
+    fn zero_volume_is_refused_and_can_be_set_by_scaling() {
+        let mut app = app_showing(Mead { volume_gallons: 0.0, ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+
+        // The nutrient calculator asks for a volume instead of dividing by it
+        press(&mut app, KeyCode::Char('n'));
+        detail_form!(app.mead_detail, Nutrient).amount_input.set_value("2");
+        assert_eq!(app.mead_detail.nutrient_yan_ppm(), None);
+        app.save_nutrient_addition();
+        assert_eq!(app.status_message.as_deref(), Some(NO_VOLUME));
+        app.mead_detail.close_form();
+
+        press(&mut app, KeyCode::Char('s'));
+        assert!(app.status_message.as_deref().unwrap().contains("Volume"));
+
+        press(&mut app, KeyCode::Char('v'));
+        detail_form!(app.mead_detail, Scale).set_value("5");
+        app.apply_scaling();
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().volume_gallons, 5.0);
+
+        app.current_view = View::NewMead;
+        app.new_mead.volume_gallons.set_value("0");
+        app.new_mead.current_field = NewMeadField::Submit as usize;
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.db.count_meads().unwrap(), 1);
+        assert!(app.status_message.as_deref().unwrap().contains("Volume must be above 0"));
+    }
+
+    #[test]
+    fn quitting_asks_first_when_configured() {
+        let mut app = app_showing(Mead::default());
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        app.handle_key_event(ctrl_q);
+        assert!(app.should_exit);
+
+        let mut app = app_showing(Mead::default());
+        app.confirm_quit = true;
+        app.handle_key_event(ctrl_q);
+        assert!(!app.should_exit);
+        press(&mut app, KeyCode::Char('n'));
+        assert!(!app.should_exit);
+
+        app.current_view = View::MainMenu;
+        press(&mut app, KeyCode::Char('q'));
+        press(&mut app, KeyCode::Char('y'));
+        assert!(app.should_exit);
+
+        // Ctrl+C exits straight away, even past an open prompt
+        let mut app = app_showing(Mead::default());
+        app.confirm_quit = true;
+        app.request_quit();
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(app.should_exit);
+    }
+
+    #[test]
+    fn leaving_a_filled_in_new_mead_form_asks_first() {
+        let mut app = app_showing(Mead::default());
+        app.open_new_mead();
+        press(&mut app, KeyCode::Esc);
+        assert!(matches!(app.current_view, View::MainMenu), "an untouched form closes straight away");
+
+        app.open_new_mead();
+        app.new_mead.name.set_value("Half-typed cyser");
+        press(&mut app, KeyCode::Esc);
+        assert!(app.confirm.is_some());
+        press(&mut app, KeyCode::Char('n'));
+        assert!(matches!(app.current_view, View::NewMead));
+        assert_eq!(app.new_mead.name.get_value(), "Half-typed cyser");
+
+        press(&mut app, KeyCode::Esc);
+        press(&mut app, KeyCode::Char('y'));
+        assert!(matches!(app.current_view, View::MainMenu));
+    }
+
+    #[test]
+    fn cycling_a_finished_status_asks_first() {
+        let mut app = app_showing(Mead {
+            status: MeadStatus::Finished,
+            ..Default::default()
+        });
+        app.mead_detail.next_field();
+        press(&mut app, KeyCode::Enter);
+        assert!(app.confirm.is_some());
+        assert_eq!(app.mead_detail.current_status, MeadStatus::Finished);
+
+        press(&mut app, KeyCode::Char('y'));
+        assert!(app.confirm.is_none());
+        assert_eq!(app.mead_detail.current_status, MeadStatus::Planning);
+
+        // Cycling on from Planning needs no confirmation
+        press(&mut app, KeyCode::Enter);
+        assert!(app.confirm.is_none());
+        assert_eq!(app.mead_detail.current_status, MeadStatus::Primary);
+    }
+
+    #[test]
+    fn editing_a_finished_batch_warns_once() {
+        let mut app = app_showing(Mead {
+            name: "Cyser".to_string(),
+            status: MeadStatus::Finished,
+            ..Default::default()
+        });
+        press(&mut app, KeyCode::Enter);
+        assert!(app.mead_detail.editing);
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("Editing a finished batch"), "{}", text);
+
+        // Saving goes ahead, and later edits aren't warned about again
+        press(&mut app, KeyCode::Enter);
+        assert!(!app.mead_detail.editing);
+        press(&mut app, KeyCode::Enter);
+        assert!(app.mead_detail.editing);
+        assert!(!app.mead_detail.finished_warning);
+    }
+
+    #[test]
+    fn measurement_gravity_can_be_set_with_the_slider() {
+        let mut app = app_showing(Mead {
+            name: "Traditional".to_string(),
+            status: MeadStatus::Primary,
+            current_gravity: 1.050,
+            ..Default::default()
+        });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('m'));
+        press(&mut app, KeyCode::Tab);
+        assert!(app.mead_detail.measurement_slider_active());
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Left);
+        app.handle_key_event(KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT));
+        press(&mut app, KeyCode::Char('9'));
+        assert_eq!(detail_form!(app.mead_detail, Measurement).gravity_input.get_value(), "1.041");
+
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mead_detail.measurement_step(), None);
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().current_gravity, 1.041);
+
+        press(&mut app, KeyCode::Char('m'));
+        assert!(!app.mead_detail.measurement_slider_active());
+    }
+
+    #[test]
+    fn saving_a_clean_form_skips_the_write() {
+        let mut app = app_showing(Mead { name: "Metheglin".to_string(), ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        let before = app.db.get_mead(id).unwrap().unwrap().updated_at;
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.status_message.as_deref(), Some("No changes"));
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().updated_at, before);
+
+        app.mead_detail.name_input.set_value("Spiced Metheglin");
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.status_message.as_deref(), Some("Mead updated!"));
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().name, "Spiced Metheglin");
+    }
+
+    #[test]
+    fn saving_untouched_fields_keeps_full_precision_values() {
+        let mut app = app_showing(Mead {
+            name: "Traditional".to_string(),
+            current_gravity: 1.0506,
+            yan_added: 123.45,
+            ..Default::default()
+        });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        let before = app.db.get_mead(id).unwrap().unwrap();
+        let readings = app.db.get_gravity_readings(id).unwrap().len();
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.status_message.as_deref(), Some("No changes"));
+
+        let after = app.db.get_mead(id).unwrap().unwrap();
+        assert_eq!(after.current_gravity, 1.0506);
+        assert_eq!(after.yan_added, 123.45);
+        assert_eq!(after.updated_at, before.updated_at);
+        assert_eq!(app.db.get_gravity_readings(id).unwrap().len(), readings);
+    }
+
+    #[test]
+    fn typed_notes_autosave_after_a_pause() {
+        let mut app = app_showing(Mead { name: "Bochet".to_string(), ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        assert_eq!(app.notes_autosave_due_in(), None);
+        press(&mut app, KeyCode::Char('w'));
+        for c in "Caramel".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        assert!(app.notes_autosave_due_in().is_some_and(|left| left > Duration::ZERO));
+
+        app.last_key_at -= Duration::from_secs(5);
+        assert_eq!(app.notes_autosave_due_in(), Some(Duration::ZERO));
+        app.autosave_notes();
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().notes, "Caramel");
+        assert!(app.mead_detail.notes_editor.is_some());
+        assert_eq!(app.notes_autosave_due_in(), None);
+    }
+
+    #[test]
+    fn competition_entries_record_category_score_and_notes() {
+        let mut app = app_showing(Mead { name: "Show Mead".to_string(), ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('J'));
+        assert!(!matches!(app.mead_detail.form, Some(DetailForm::Competition(_))));
+
+        press(&mut app, KeyCode::Char('E'));
+        assert!(app.db.get_mead(id).unwrap().unwrap().competition);
+        app.mead_detail.needs_refresh = true;
+        let mut terminal = Terminal::new(TestBackend::new(120, 50)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+
+        press(&mut app, KeyCode::Char('J'));
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::Competition(_))));
+        detail_form!(app.mead_detail, Competition).category_input.set_value("M1A Dry Traditional");
+        detail_form!(app.mead_detail, Competition).score_input.set_value("55");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.status_message.as_deref(), Some("Error: Score must be a number from 0 to 50"));
+
+        detail_form!(app.mead_detail, Competition).score_input.set_value("38.5");
+        detail_form!(app.mead_detail, Competition).notes_input.set_value("Clean, a little hot");
+        press(&mut app, KeyCode::Enter);
+        let mead = app.db.get_mead(id).unwrap().unwrap();
+        assert_eq!(mead.competition_label(), "M1A Dry Traditional, 38.5/50");
+        assert_eq!(mead.judge_notes, "Clean, a little hot");
+    }
+
+    #[test]
+    fn topping_up_dilutes_the_gravity_and_logs_it() {
+        let mut app = app_showing(Mead {
+            starting_gravity: 1.100,
+            current_gravity: 1.050,
+            volume_gallons: 4.0,
+            ..Default::default()
+        });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('U'));
+        press(&mut app, KeyCode::Enter);
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::TopUp(_))), "nothing to apply until the added volume is typed");
+
+        press(&mut app, KeyCode::Char('1'));
+        press(&mut app, KeyCode::Enter);
+        assert!(!matches!(app.mead_detail.form, Some(DetailForm::TopUp(_))));
+        let mead = app.db.get_mead(id).unwrap().unwrap();
+        assert!((mead.current_gravity - 1.040).abs() < 1e-9);
+        assert!((mead.starting_gravity - 1.080).abs() < 1e-9);
+        assert_eq!(mead.volume_gallons, 5.0);
+        let entries = app.db.get_log_entries(id, true).unwrap();
+        assert!(entries.iter().any(|e| e.entry_text == "Topped up 1.0 gal at 1.000: gravity 1.050 → 1.040"));
+        assert_eq!(app.db.get_gravity_readings(id).unwrap().last().map(|r| r.gravity), Some(mead.current_gravity));
+
+        // The added gravity is logged as a gravity, however it was typed
+        press(&mut app, KeyCode::Char('U'));
+        press(&mut app, KeyCode::Char('1'));
+        detail_form!(app.mead_detail, TopUp).gravity_input.set_value(" 0.99");
+        press(&mut app, KeyCode::Enter);
+        let entries = app.db.get_log_entries(id, true).unwrap();
+        assert!(entries.iter().any(|e| e.entry_text.starts_with("Topped up 1.0 gal at 0.990: ")));
+    }
+
+    #[test]
+    fn acidity_form_rejects_implausible_ph() {
+        let mut app = app_showing(Mead { name: "Melomel".to_string(), ..Default::default() });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('A'));
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::Acidity(_))));
+        for c in "5.2".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::Acidity(_))));
+        assert_eq!(app.status_message.as_deref(), Some("Error: pH 5.20 is outside 2.5-4.5"));
+
+        detail_form!(app.mead_detail, Acidity).ph_input.set_value("3.4".to_string());
+        press(&mut app, KeyCode::Tab);
+        for c in "6.5".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(!matches!(app.mead_detail.form, Some(DetailForm::Acidity(_))));
+        let mead = app.db.get_mead(id).unwrap().unwrap();
+        assert_eq!((mead.ph, mead.ta), (Some(3.4), Some(6.5)));
+        assert!(mead.summary().contains("Acidity: pH 3.40 · TA 6.5 g/L"));
+    }
+
+    #[test]
+    fn scheduled_gravity_readings_flag_when_due() {
+        let mut app = app_showing(Mead {
+            name: "Cyser".to_string(),
+            status: MeadStatus::Primary,
+            ..Default::default()
+        });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('R'));
+        assert!(matches!(app.mead_detail.form, Some(DetailForm::ReadingInterval(_))));
+        press(&mut app, KeyCode::Char('3'));
+        press(&mut app, KeyCode::Enter);
+        let mead = app.db.get_mead(id).unwrap().unwrap();
+        assert_eq!(mead.gravity_check_days, Some(3));
+
+        // Just created, so the first reading is today and the next is 3 days off
+        let readings = app.db.get_gravity_readings(id).unwrap();
+        assert_eq!(mead.reading_label(&readings).as_deref(), Some("in 3d"));
+        assert!(!mead.needs_attention(&[], &readings));
+
+        let stale = vec![GravityReading {
+            recorded_at: chrono::Utc::now() - chrono::Duration::days(5),
+            ..readings[0].clone()
+        }];
+        assert_eq!(mead.reading_label(&stale).as_deref(), Some("2d overdue"));
+        assert!(mead.needs_attention(&[], &stale));
+        let bottled = Mead { status: MeadStatus::Bottled, ..mead };
+        assert!(!bottled.reading_due(&stale));
+    }
+
+    #[test]
+    fn forms_open_on_the_configured_fields() {
+        let config = Config::parse("onboarding_done = true\nnew_mead_start_field = name\ndetail_start_field = current_gravity");
+        let db = Database::open_in_memory(&config).unwrap();
+        let id = db.create_mead(&Mead::default()).unwrap();
+        let mut app = test_app(config, db, false);
+
+        app.main_menu.selected = 1;
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.new_mead.current_field, NewMeadField::Name as usize);
+
+        let mead = app.db.get_mead(id).unwrap().unwrap();
+        app.mead_detail.set_mead(mead, vec![], vec![], vec![], vec![]);
+        assert_eq!(app.mead_detail.current_field, DetailField::CurrentGravity as usize);
+        assert!(app.mead_detail.current_gravity_input.focused);
+    }
+
+    #[test]
+    fn menu_items_follow_the_configured_order_and_hide_the_rest() {
+        let config = Config::parse("onboarding_done = true\nmenu_items = stats, search, bogus, stats, new_mead");
+        let db = Database::open_in_memory(&config).unwrap();
+        let mut app = test_app(config, db, true);
+        assert_eq!(app.main_menu.items, [MenuItem::Statistics, MenuItem::Search, MenuItem::NewMead]);
+
+        press(&mut app, KeyCode::Enter);
+        assert!(matches!(app.current_view, View::Stats));
+
+        app.current_view = View::MainMenu;
+        app.main_menu.selected = 2;
+        press(&mut app, KeyCode::Enter);
+        assert!(matches!(app.current_view, View::MainMenu), "read-only mode still blocks new meads");
+
+        app.main_menu.selected = 1;
+        press(&mut app, KeyCode::Enter);
+        assert!(app.main_menu.searching);
+    }
+
+    #[test]
+    fn quick_log_records_the_configured_text() {
+        let mut app = app_showing(Mead::default());
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        app.quick_log_text = "Stirred".to_string();
+        press(&mut app, KeyCode::Char('L'));
+        assert_eq!(app.status_message.as_deref(), Some("Logged \"Stirred\""));
+        let entries = app.db.get_log_entries(id, true).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].entry_text, "Stirred");
+        assert_eq!(entries[0].entry_type, LogEntryType::Observation);
+        assert!(!matches!(app.mead_detail.form, Some(DetailForm::Log(_))));
+    }
+
+    #[test]
+    fn current_gravity_resyncs_to_the_latest_reading() {
+        let mut app = app_showing(Mead {
+            name: "Cyser".to_string(),
+            current_gravity: 1.040,
+            ..Default::default()
+        });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(app.status_message.as_deref(), Some("No gravity readings to sync from"));
+
+        app.mead_detail.gravity_readings = app.db.get_gravity_readings(id).unwrap();
+        app.mead_detail.mead.as_mut().unwrap().current_gravity = 1.020;
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(app.status_message.as_deref(), Some("Current gravity 1.020 → 1.040 (latest reading)"));
+        assert!(app.mead_detail.needs_refresh);
+        assert_eq!(app.db.get_mead(id).unwrap().unwrap().current_gravity, 1.040);
+        assert_eq!(app.db.get_gravity_readings(id).unwrap().len(), 1);
+
+        app.mead_detail.mead.as_mut().unwrap().current_gravity = 1.040;
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(app.status_message.as_deref(), Some("Current gravity already matches the latest reading"));
+    }
+
+    #[test]
+    fn finishing_records_the_details_and_a_log_entry() {
+        let mut app = app_showing(Mead {
+            name: "Test Batch".to_string(),
+            status: MeadStatus::Bottled,
+            starting_gravity: 1.100,
+            current_gravity: 1.010,
+            volume_gallons: 1.0,
+            ..Default::default()
+        });
+        let id = app.mead_detail.mead.as_ref().unwrap().id;
+
+        // Cycle the status to Finished and save, which opens the flow
+        app.mead_detail.next_field();
+        app.mead_detail.toggle_edit();
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.mead_detail.finish_step(), Some(FinishStep::Gravity));
+        assert_eq!(detail_form!(app.mead_detail, Finish).bottles_input.get_value(), "5");
+
+        detail_form!(app.mead_detail, Finish).gravity_input.set_value("1.000");
+        press(&mut app, KeyCode::Enter);
+        detail_form!(app.mead_detail, Finish).date_input.set_value("2026-03-01");
+        press(&mut app, KeyCode::Enter);
+        detail_form!(app.mead_detail, Finish).bottles_input.set_value("4");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mead_detail.finish_step(), None);
+
+        let mead = app.db.get_mead(id).unwrap().unwrap();
+        assert_eq!(mead.status, MeadStatus::Finished);
+        assert_eq!(mead.final_gravity, Some(1.000));
+        assert_eq!(mead.bottled_date, "2026-03-01");
+        assert_eq!(mead.bottle_count, Some(4));
+        let logs = app.db.get_log_entries(id, true).unwrap();
+        assert!(logs.iter().any(|e| e.entry_text.starts_with("Finished: FG 1.000")));
+    }
+
+    #[test]
+    fn main_menu_recounts_batches_on_return() {
+        let mut app = app_showing(Mead {
+            name: "Test Batch".to_string(),
+            status: MeadStatus::Primary,
+            ..Default::default()
+        });
+        app.db.create_mead(&Mead::default()).unwrap();
+        app.main_menu.needs_refresh = false;
+        app.current_view = View::MeadList;
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.current_view, View::MainMenu);
+
+        assert!(app.main_menu.needs_refresh);
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        assert_eq!(app.main_menu.batch_counts, (1, 2));
+        assert!(!app.main_menu.needs_refresh);
+    }
+
+    #[test]
+    fn reminder_dates_are_typed_in_the_configured_format() {
+        let mut app = app_showing(Mead::default());
+        format::set_date_format("%d/%m/%Y", "%H:%M");
+        app.mead_detail.open_reminder_input();
+        assert_eq!(detail_form!(app.mead_detail, Reminder).date_input.get_value(), format::fmt_date(Local::now().date_naive()));
+
+        detail_form!(app.mead_detail, Reminder).text_input.set_value("Rack to secondary");
+        detail_form!(app.mead_detail, Reminder).date_input.set_value("10/06/2025");
+        press(&mut app, KeyCode::Enter);
+        let reminders = app.db.get_all_reminders().unwrap();
+        assert_eq!(reminders.len(), 1, "{:?}", app.status_message);
+        assert_eq!(reminders[0].due_date, "2025-06-10");
+    }
+
+    #[test]
+    fn slash_jumps_to_names_that_start_with_command_letters() {
+        let mut app = app_showing(Mead { name: "Acerola".to_string(), ..Default::default() });
+        app.db.create_mead(&Mead { name: "Dandelion".to_string(), ..Default::default() }).unwrap();
+        app.current_view = View::MeadList;
+        app.refresh_mead_list();
+        let name = |app: &App| app.mead_list.get_selected().map(|m| m.name.clone());
+        let first = name(&app);
+
+        // Without `/`, letters run their commands rather than jumping
+        press(&mut app, KeyCode::Char('u'));
+        assert_eq!(name(&app), first);
+        press(&mut app, KeyCode::Char('/'));
+        press(&mut app, KeyCode::Char('d'));
+        assert_eq!(name(&app).as_deref(), Some("Dandelion"));
+        assert!(app.confirm.is_none(), "d went to the jump, not delete");
+
+        press(&mut app, KeyCode::Esc);
+        assert!(matches!(app.current_view, View::MeadList), "Esc ends the jump first");
+        press(&mut app, KeyCode::Char('d'));
+        assert!(app.confirm.is_some());
+    }
+
+    #[test]
+    fn activity_log_filters_by_type_mead_and_date_and_exports_csv() {
+        let config = Config::parse("onboarding_done = true");
+        let db = Database::open_in_memory(&config).unwrap();
+        let cyser = db.create_mead(&Mead { name: "Cyser".to_string(), ..Default::default() }).unwrap();
+        let melomel = db.create_mead(&Mead { name: "Melomel".to_string(), ..Default::default() }).unwrap();
+        for (mead_id, text) in [(cyser, "Added juice, 1 gal"), (melomel, "Added cherries")] {
+            let entry = LogEntry { mead_id, entry_text: text.to_string(), entry_type: LogEntryType::Addition, ..Default::default() };
+            db.create_log_entry(&entry).unwrap();
+        }
+        let mut app = test_app(config, db, false);
+        app.current_view = View::RecentActivity;
+        let texts = |app: &mut App| -> Vec<String> {
+            app.refresh_activity();
+            app.recent_activity.items.iter().map(|a| a.text.clone()).collect()
+        };
+
+        for _ in 0..4 {
+            press(&mut app, KeyCode::Char('t'));
+        }
+        assert_eq!(app.recent_activity.filter.summary(), "Type: Addition");
+        assert_eq!(texts(&mut app), ["Added cherries", "Added juice, 1 gal"]);
+
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Char('m'));
+        assert_eq!(texts(&mut app), ["Added juice, 1 gal"]);
+        let csv = export::activity::build_csv(&app.recent_activity.items);
+        assert!(csv.starts_with("Timestamp,Mead,Kind,Entry Type,Text\n"), "{}", csv);
+        assert!(csv.contains(",Cyser,Log,Addition,\"Added juice, 1 gal\"\n"), "{}", csv);
+
+        press(&mut app, KeyCode::Char('r'));
+        for c in "2099-13-01".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(app.recent_activity.editing_dates, "a bad date keeps the form open");
+        app.recent_activity.since_input.set_value("2099-01-01");
+        press(&mut app, KeyCode::Enter);
+        assert!(!app.recent_activity.editing_dates);
+        assert!(texts(&mut app).is_empty());
+
+        press(&mut app, KeyCode::Char('c'));
+        assert!(app.recent_activity.filter.is_empty());
+        let shown = texts(&mut app).len();
+        assert_eq!(app.recent_activity.total, shown);
+        assert!(app.recent_activity.total > 2, "readings and status changes come back");
+    }
+
+    #[test]
+    fn comparison_export_diffs_each_field() {
+        let before = Mead { name: "Cyser".to_string(), volume_gallons: 5.0, target_abv: 12.0, ..Default::default() };
+        let after = Mead { volume_gallons: 5.25, target_abv: 13.5, start_date: "2026-02-01".to_string(), ..before.clone() };
+        let rows = mead_comparison(&before, &after);
+        let diff = |field: &str| rows.iter().find(|r| r.field == field).unwrap().diff();
+        assert_eq!(diff("Name"), "");
+        assert_eq!(diff("Volume"), "+0.25 gal");
+        assert_eq!(diff("Target ABV"), "+1.5%");
+        assert_eq!(diff("Start Date"), "changed");
+
+        let csv = export::comparison::build_csv(("as planned, v1", "Now"), &rows);
+        assert!(csv.starts_with("Field,\"as planned, v1\",Now,Diff\n"), "{}", csv);
+        assert!(csv.contains("Volume,5.00 gal,5.25 gal,+0.25 gal\n"));
+        let md = export::comparison::build_markdown(("v1", "Now"), &rows);
+        assert!(md.contains("| Target ABV | 12.0% | 13.5% | +1.5% |"), "{}", md);
+    }
+}
@@ -0,0 +1,348 @@
+//! Rough estimates derived from a batch's recipe.
+//!
+//! The water to honey ratio is the batch volume per pound of honey, with
+//! a warning outside the range most meads are made in.
+//!
+//! The bottle calculator counts the standard wine bottles a volume fills.
+//! The priming calculator gives the corn sugar (dextrose) that carbonates a
+//! batch to a target level in the bottle, allowing for the CO2 still in
+//! solution from fermentation.
+//!
+//! The quick conversions cover what comes up mid-brew: °Brix and specific
+//! gravity, ABV from two gravities, and the gravity a weight of honey gives
+//! at roughly 35 points per pound per gallon.
+//!
+//! Topping up blends two liquids, so the gravity that results is the
+//! average of their gravity points weighted by volume.
+//!
+//! The color model treats honey like malt: each variety gets a color in
+//! degrees Lovibond, scaled by pounds per gallon and converted to SRM with
+//! the Morey formula. Fruit adds its own depth and pulls the hue toward its
+//! juice color. Real color depends on much more than this, so the result is
+//! only a guide to what to expect in the glass.
+
+use crate::models::{BaseType, Ingredient, IngredientType, Measure, Mead};
+
+const GRAMS_PER_LB: f64 = 453.59237;
+
+const ML_PER_GALLON: f64 = 3785.41;
+
+/// Size of a standard wine bottle, in ml
+pub const BOTTLE_ML: f64 = 750.0;
+
+/// Lovibond assumed for a honey variety not in `HONEY_COLORS`
+const DEFAULT_HONEY_LOVIBOND: f64 = 3.0;
+
+/// Lovibond of honey caramelized for a bochet
+const BOCHET_LOVIBOND: f64 = 25.0;
+
+/// Honey varieties by keyword, lightest first, with their color in °L
+static HONEY_COLORS: &[(&str, f64)] = &[
+    ("acacia", 1.0),
+    ("fireweed", 1.0),
+    ("sage", 1.5),
+    ("clover", 2.0),
+    ("orange", 2.0),
+    ("tupelo", 2.5),
+    ("alfalfa", 2.5),
+    ("meadowfoam", 3.0),
+    ("wildflower", 4.0),
+    ("blueberry", 4.0),
+    ("tulip poplar", 6.0),
+    ("avocado", 8.0),
+    ("chestnut", 10.0),
+    ("buckwheat", 14.0),
+];
+
+/// A fruit's juice color and how much depth it adds, in °L per lb/gal
+struct FruitColor {
+    keyword: &'static str,
+    rgb: (u8, u8, u8),
+    lovibond: f64,
+}
+
+static FRUIT_COLORS: &[FruitColor] = &[
+    FruitColor { keyword: "black currant", rgb: (60, 10, 40), lovibond: 12.0 },
+    FruitColor { keyword: "blackberr", rgb: (70, 10, 50), lovibond: 10.0 },
+    FruitColor { keyword: "elderberr", rgb: (60, 10, 60), lovibond: 12.0 },
+    FruitColor { keyword: "blueberr", rgb: (80, 30, 90), lovibond: 8.0 },
+    FruitColor { keyword: "cherr", rgb: (130, 10, 30), lovibond: 7.0 },
+    FruitColor { keyword: "raspberr", rgb: (180, 30, 60), lovibond: 5.0 },
+    FruitColor { keyword: "cranberr", rgb: (170, 20, 40), lovibond: 5.0 },
+    FruitColor { keyword: "strawberr", rgb: (210, 60, 70), lovibond: 3.0 },
+    FruitColor { keyword: "plum", rgb: (120, 20, 60), lovibond: 6.0 },
+    FruitColor { keyword: "grape", rgb: (90, 20, 60), lovibond: 6.0 },
+    FruitColor { keyword: "pomegranate", rgb: (160, 20, 40), lovibond: 6.0 },
+    FruitColor { keyword: "peach", rgb: (240, 160, 70), lovibond: 1.0 },
+    FruitColor { keyword: "mango", rgb: (245, 170, 40), lovibond: 1.0 },
+    FruitColor { keyword: "apricot", rgb: (240, 150, 60), lovibond: 1.0 },
+];
+
+/// Carbonation most sparkling meads aim for, in volumes of CO2
+pub const DEFAULT_CO2_VOLUMES: f64 = 2.5;
+
+/// Warmest the batch is assumed to have been since fermentation finished,
+/// which sets how much CO2 is left in solution
+const CONDITIONING_TEMP_F: f64 = 68.0;
+
+const GRAMS_PER_OZ: f64 = 28.349523;
+
+/// Corn sugar needed to carbonate `volume_gallons` to `target_volumes` of
+/// CO2, in grams. Zero when the batch already holds that much.
+pub fn priming_sugar_grams(volume_gallons: f64, target_volumes: f64) -> f64 {
+    let t = CONDITIONING_TEMP_F;
+    let residual = 3.0378 - 0.050062 * t + 0.00026555 * t * t;
+    (15.195 * volume_gallons.max(0.0) * (target_volumes - residual)).max(0.0)
+}
+
+/// Convert grams to ounces
+pub fn grams_to_oz(grams: f64) -> f64 {
+    grams / GRAMS_PER_OZ
+}
+
+/// Fewest gallons per pound before a must is unusually concentrated
+/// (5 lbs of honey per gallon)
+const MIN_GALLONS_PER_LB: f64 = 0.2;
+
+/// Most gallons per pound before a must is unusually dilute (1.5 lbs of
+/// honey per gallon)
+const MAX_GALLONS_PER_LB: f64 = 1.0 / 1.5;
+
+/// Batch volume per pound of honey, or None for meads without both. Only
+/// honey bases count; ciders and wines take most of their sugar elsewhere.
+pub fn gallons_per_lb(mead: &Mead) -> Option<f64> {
+    let usable = mead.base_type == BaseType::Honey && mead.volume_gallons > 0.0 && mead.honey_amount_lbs > 0.0;
+    usable.then(|| mead.volume_gallons / mead.honey_amount_lbs)
+}
+
+/// Warning for a water to honey ratio outside the usual range
+pub fn ratio_warning(gallons_per_lb: f64) -> Option<&'static str> {
+    if gallons_per_lb > MAX_GALLONS_PER_LB {
+        Some("Very dilute: under 1.5 lbs of honey per gallon")
+    } else if gallons_per_lb < MIN_GALLONS_PER_LB {
+        Some("Very concentrated: over 5 lbs of honey per gallon, yeast may stall")
+    } else {
+        None
+    }
+}
+
+/// Full 750 ml bottles a batch of `volume_gallons` fills
+pub fn bottle_count(volume_gallons: f64) -> u32 {
+    (volume_gallons.max(0.0) * ML_PER_GALLON / BOTTLE_ML).floor() as u32
+}
+
+/// Gravity points one pound of honey adds to a gallon of must
+const HONEY_POINTS_PER_LB_GAL: f64 = 35.0;
+
+/// Specific gravity for a sugar content in °Brix; the inverse of
+/// `nutrients::brix`
+pub fn gravity_from_brix(brix: f64) -> Option<f64> {
+    (0.0..261.3).contains(&brix).then(|| 261.3 / (261.3 - brix))
+}
+
+/// ABV estimated from the original and final gravity
+pub fn abv_from_gravities(original: f64, final_gravity: f64) -> f64 {
+    (original - final_gravity) * 131.25
+}
+
+/// Original gravity of `honey_lbs` of honey made up to `volume_gallons`,
+/// or None without a volume
+pub fn honey_gravity(honey_lbs: f64, volume_gallons: f64) -> Option<f64> {
+    (volume_gallons > 0.0).then(|| 1.0 + honey_lbs.max(0.0) * HONEY_POINTS_PER_LB_GAL / volume_gallons / 1000.0)
+}
+
+/// Gravity after topping up `volume_gallons` at `gravity` with
+/// `added_gallons` at `added_gravity`, or None unless both volumes are above
+/// zero
+pub fn blended_gravity(gravity: f64, volume_gallons: f64, added_gravity: f64, added_gallons: f64) -> Option<f64> {
+    if volume_gallons <= 0.0 || added_gallons <= 0.0 {
+        return None;
+    }
+    let points = (gravity - 1.0) * volume_gallons + (added_gravity - 1.0) * added_gallons;
+    Some(1.0 + points / (volume_gallons + added_gallons))
+}
+
+/// An estimated color for a batch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorEstimate {
+    /// Depth of color on the SRM scale
+    pub srm: f64,
+    /// What it should look like, as RGB
+    pub rgb: (u8, u8, u8),
+}
+
+/// Estimate a batch's color from its honey and fruit additions, or None
+/// when there isn't enough to go on (no volume, or no honey or fruit).
+/// Ciders and wines take their color from the juice, so they get none.
+pub fn estimate_color(mead: &Mead, ingredients: &[Ingredient]) -> Option<ColorEstimate> {
+    if mead.base_type != BaseType::Honey || mead.volume_gallons <= 0.0 {
+        return None;
+    }
+
+    let honey_lbs = mead.honey_amount_lbs.max(0.0);
+    let mut mcu = honey_lovibond(mead) * honey_lbs / mead.volume_gallons;
+
+    // Fruit hue, weighted by pounds
+    let mut fruit_lbs = 0.0;
+    let mut fruit_rgb = (0.0, 0.0, 0.0);
+    for ingredient in ingredients.iter().filter(|i| i.ingredient_type == IngredientType::Fruit) {
+        let (Some(fruit), Some(lbs)) = (fruit_color(&ingredient.name), pounds(ingredient)) else {
+            continue;
+        };
+        mcu += fruit.lovibond * lbs / mead.volume_gallons;
+        fruit_lbs += lbs;
+        fruit_rgb.0 += fruit.rgb.0 as f64 * lbs;
+        fruit_rgb.1 += fruit.rgb.1 as f64 * lbs;
+        fruit_rgb.2 += fruit.rgb.2 as f64 * lbs;
+    }
+
+    if honey_lbs + fruit_lbs <= 0.0 {
+        return None;
+    }
+
+    let srm = 1.4922 * mcu.powf(0.6859);
+    let mut rgb = srm_to_rgb(srm);
+    if fruit_lbs > 0.0 {
+        // Fruit pulls the color toward its juice in proportion to its share
+        // of the fermentables
+        let share = fruit_lbs / (honey_lbs + fruit_lbs);
+        let mix = |base: u8, fruit: f64| {
+            (base as f64 * (1.0 - share) + fruit / fruit_lbs * share).round() as u8
+        };
+        rgb = (mix(rgb.0, fruit_rgb.0), mix(rgb.1, fruit_rgb.1), mix(rgb.2, fruit_rgb.2));
+    }
+
+    Some(ColorEstimate { srm, rgb })
+}
+
+/// Honey color in °L, darkest for a bochet
+fn honey_lovibond(mead: &Mead) -> f64 {
+    let honey = mead.honey_type.to_lowercase();
+    let is_bochet = honey.contains("bochet")
+        || honey.contains("caramel")
+        || mead.name.to_lowercase().contains("bochet");
+    if is_bochet {
+        return BOCHET_LOVIBOND;
+    }
+    HONEY_COLORS
+        .iter()
+        .find(|(keyword, _)| honey.contains(keyword))
+        .map(|(_, lovibond)| *lovibond)
+        .unwrap_or(DEFAULT_HONEY_LOVIBOND)
+}
+
+fn fruit_color(name: &str) -> Option<&'static FruitColor> {
+    let name = name.to_lowercase();
+    FRUIT_COLORS.iter().find(|fruit| name.contains(fruit.keyword))
+}
+
+/// An ingredient's weight in pounds, if it was given by weight
+fn pounds(ingredient: &Ingredient) -> Option<f64> {
+    match ingredient.canonical_amount()? {
+        (Measure::Mass, grams) => Some(grams / GRAMS_PER_LB),
+        _ => None,
+    }
+}
+
+/// Approximate RGB for a depth of color on the SRM scale
+fn srm_to_rgb(srm: f64) -> (u8, u8, u8) {
+    let channel = |falloff: f64| (255.0 * falloff.powf(srm)).round().clamp(0.0, 255.0) as u8;
+    (channel(0.975), channel(0.88), channel(0.7))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mead(honey_type: &str, lbs: f64) -> Mead {
+        Mead {
+            honey_type: honey_type.to_string(),
+            honey_amount_lbs: lbs,
+            volume_gallons: 1.0,
+            ..Default::default()
+        }
+    }
+
+    fn fruit(name: &str, amount: f64, unit: &str) -> Ingredient {
+        Ingredient {
+            ingredient_type: IngredientType::Fruit,
+            name: name.to_string(),
+            amount,
+            unit: unit.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn ratio_warns_outside_the_usual_range() {
+        let standard = gallons_per_lb(&mead("Clover", 3.0)).unwrap();
+        assert!((standard - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(ratio_warning(standard), None);
+        assert!(ratio_warning(gallons_per_lb(&mead("Clover", 1.0)).unwrap()).unwrap().starts_with("Very dilute"));
+        assert!(ratio_warning(gallons_per_lb(&mead("Clover", 6.0)).unwrap()).unwrap().starts_with("Very concentrated"));
+
+        assert_eq!(gallons_per_lb(&mead("Clover", 0.0)), None);
+        let cider = Mead {
+            base_type: BaseType::Apple,
+            ..mead("Clover", 1.0)
+        };
+        assert_eq!(gallons_per_lb(&cider), None);
+    }
+
+    #[test]
+    fn bottle_count_counts_only_full_bottles() {
+        assert_eq!(bottle_count(1.0), 5);
+        assert_eq!(bottle_count(5.0), 25);
+        assert_eq!(bottle_count(0.1), 0);
+        assert_eq!(bottle_count(-1.0), 0);
+    }
+
+    #[test]
+    fn priming_sugar_scales_with_volume_and_target() {
+        let five_gallons = priming_sugar_grams(5.0, DEFAULT_CO2_VOLUMES);
+        // About 4 oz of corn sugar for 5 gallons at 2.5 volumes
+        assert!((grams_to_oz(five_gallons) - 4.0).abs() < 0.5, "{}", five_gallons);
+        assert!((priming_sugar_grams(1.0, DEFAULT_CO2_VOLUMES) * 5.0 - five_gallons).abs() < 1e-9);
+        assert!(priming_sugar_grams(5.0, 3.0) > five_gallons);
+        assert_eq!(priming_sugar_grams(5.0, 0.5), 0.0);
+    }
+
+    #[test]
+    fn topping_up_averages_the_gravity_points_by_volume() {
+        let diluted = blended_gravity(1.100, 4.0, 1.000, 1.0).unwrap();
+        assert!((diluted - 1.080).abs() < 1e-9);
+        let with_juice = blended_gravity(1.000, 1.0, 1.050, 1.0).unwrap();
+        assert!((with_juice - 1.025).abs() < 1e-9);
+        assert_eq!(blended_gravity(1.100, 0.0, 1.000, 1.0), None);
+        assert_eq!(blended_gravity(1.100, 4.0, 1.000, 0.0), None);
+    }
+
+    #[test]
+    fn darker_honey_gives_a_darker_mead() {
+        let clover = estimate_color(&mead("Clover", 3.0), &[]).unwrap();
+        let buckwheat = estimate_color(&mead("Buckwheat", 3.0), &[]).unwrap();
+        let bochet = estimate_color(&mead("Caramelized wildflower", 3.0), &[]).unwrap();
+        assert!(clover.srm < buckwheat.srm);
+        assert!(buckwheat.srm < bochet.srm);
+    }
+
+    #[test]
+    fn fruit_by_weight_deepens_and_tints() {
+        let traditional = estimate_color(&mead("Clover", 3.0), &[]).unwrap();
+        let melomel = estimate_color(&mead("Clover", 3.0), &[fruit("Blackberries", 2.0, "lb")]).unwrap();
+        assert!(melomel.srm > traditional.srm);
+        assert!(melomel.rgb.1 < traditional.rgb.1);
+
+        // Fruit counted by the piece can't be weighed, so it is left out
+        let counted = estimate_color(&mead("Clover", 3.0), &[fruit("Cherries", 20.0, "each")]).unwrap();
+        assert_eq!(counted, traditional);
+    }
+
+    #[test]
+    fn no_estimate_without_volume_or_fermentables() {
+        assert!(estimate_color(&mead("Clover", 0.0), &[]).is_none());
+        let mut no_volume = mead("Clover", 3.0);
+        no_volume.volume_gallons = 0.0;
+        assert!(estimate_color(&no_volume, &[]).is_none());
+    }
+}
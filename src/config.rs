@@ -0,0 +1,366 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::db::SameDayReadings;
+use crate::export::ExportFormat;
+use crate::format::{self, Precision, TempUnit};
+use crate::models::IngredientGlyphs;
+use crate::theme::{self, ColorSupport, Theme, ThemeView};
+
+/// User settings, read from `mead_tracker.conf` in the data directory.
+///
+/// The file holds one `key = value` per line; blank lines and lines
+/// starting with `#` are ignored, as are unknown keys and bad values.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// How far (in percent) YAN added may exceed YAN required before warning
+    pub yan_overdose_margin: f64,
+    /// Decimal places shown for gravity, ABV and YAN
+    pub precision: Precision,
+    /// strftime format for displayed dates, e.g. "%d/%m/%Y"; dates are
+    /// still stored as ISO
+    pub date_format: String,
+    /// strftime format for displayed times, e.g. "%I:%M %p"
+    pub time_format: String,
+    /// Unit temperatures are shown and typed in ("F" or "C"); they are
+    /// still stored in °F
+    pub temperature_unit: TempUnit,
+    /// Whether the first-run welcome screen has been dismissed
+    pub onboarding_done: bool,
+    /// Overwrite existing export files without asking
+    pub overwrite_exports: bool,
+    /// Main menu export run last ("calendar", "json" or "sql"), which `e`
+    /// repeats; saved automatically
+    pub last_export: Option<ExportFormat>,
+    /// Ask before deletes, overwrites and other destructive actions; off
+    /// runs them straight away
+    pub confirm_destructive: bool,
+    /// Ask before quitting with `q` or Ctrl+Q
+    pub confirm_quit: bool,
+    /// Skip the database integrity check at startup
+    pub skip_integrity_check: bool,
+    /// Copy the database into `backups/` on each launch
+    pub backup_on_startup: bool,
+    /// Number of startup backups kept; older ones are deleted, and 0
+    /// keeps them all
+    pub backup_retention: usize,
+    /// Show log entries newest first (false for oldest first)
+    pub log_newest_first: bool,
+    /// Main menu items to show, by key and in order (e.g. "meads, new_mead,
+    /// stats"); items left out are hidden, and an empty list shows them all
+    pub menu_items: Vec<String>,
+    /// Mead list columns to hide, by key (e.g. "honey", "yeast")
+    pub hidden_columns: Vec<String>,
+    /// Shade every other row of the mead list
+    pub list_zebra_rows: bool,
+    /// Give each mead list row two lines instead of one
+    pub list_tall_rows: bool,
+    /// Whether moving past the end of a list, menu or form wraps around
+    pub wrap_navigation: bool,
+    /// Add a log entry such as "Status: Primary → Secondary" on each
+    /// status change
+    pub log_status_changes: bool,
+    /// What a gravity reading does on a day that already has one:
+    /// "append" keeps both and warns, "replace" keeps only the new one
+    pub same_day_readings: SameDayReadings,
+    /// Show an estimated color swatch in the mead detail view
+    pub show_color_estimate: bool,
+    /// Marks before ingredient types: "unicode" emoji, "ascii" for
+    /// terminals without them, or "off"
+    pub ingredient_glyphs: IngredientGlyphs,
+    /// Blink the cursor in text fields
+    pub cursor_blink: bool,
+    /// Seconds after the last keypress before typed notes are saved on
+    /// their own; 0 saves them only with `s`
+    pub notes_autosave_secs: u64,
+    /// Seconds between reloads of the mead list while it is shown, to pick
+    /// up changes made elsewhere; 0 turns this off
+    pub list_refresh_secs: u64,
+    /// Collection opened at startup, e.g. "club"; each profile has its own
+    /// database file in the data directory
+    pub profile: String,
+    /// Log entry added in one keypress with `L` in the detail view
+    pub quick_log_text: String,
+    /// Field the new mead form starts on, by key (e.g. "name")
+    pub new_mead_start_field: String,
+    /// Field the detail view starts on, by key (e.g. "current_gravity")
+    pub detail_start_field: String,
+    /// Title and border accents: `accent_color` for every view, and e.g.
+    /// `detail_accent = blue` or `new_mead_accent = #A3BE8C` for one
+    pub theme: Theme,
+    /// Colors the terminal can show ("truecolor", "256" or "16"); None,
+    /// or "auto" in the file, works it out from `$COLORTERM` and `$TERM`
+    pub color_support: Option<ColorSupport>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            yan_overdose_margin: 20.0,
+            precision: Precision {
+                gravity: 3,
+                abv: 1,
+                yan: 0,
+            },
+            date_format: format::ISO_DATE.to_string(),
+            time_format: format::ISO_TIME.to_string(),
+            temperature_unit: TempUnit::Fahrenheit,
+            onboarding_done: false,
+            overwrite_exports: false,
+            last_export: None,
+            confirm_destructive: true,
+            confirm_quit: false,
+            skip_integrity_check: false,
+            backup_on_startup: true,
+            backup_retention: 5,
+            menu_items: Vec::new(),
+            hidden_columns: Vec::new(),
+            log_newest_first: true,
+            list_zebra_rows: false,
+            list_tall_rows: false,
+            wrap_navigation: true,
+            log_status_changes: true,
+            same_day_readings: SameDayReadings::Append,
+            show_color_estimate: true,
+            ingredient_glyphs: IngredientGlyphs::Unicode,
+            cursor_blink: false,
+            notes_autosave_secs: 3,
+            list_refresh_secs: 0,
+            profile: crate::db::DEFAULT_PROFILE.to_string(),
+            quick_log_text: "Checked — no change".to_string(),
+            new_mead_start_field: "base".to_string(),
+            detail_start_field: "name".to_string(),
+            theme: Theme::default(),
+            color_support: None,
+        }
+    }
+}
+
+impl Config {
+    /// Load the config file from the data directory `dir`, falling back to
+    /// defaults if it is missing
+    pub fn load(dir: &Path) -> Self {
+        std::fs::read_to_string(Self::path(dir))
+            .map(|text| Self::parse(&text))
+            .unwrap_or_default()
+    }
+
+    /// Get the config file path in the data directory `dir`
+    pub fn path(dir: &Path) -> PathBuf {
+        dir.join("mead_tracker.conf")
+    }
+
+    /// Set one key in the config file, keeping the rest of the file as is
+    pub fn store(dir: &Path, key: &str, value: &str) -> io::Result<()> {
+        let path = Self::path(dir);
+        let text = std::fs::read_to_string(&path).unwrap_or_default();
+        std::fs::write(&path, Self::with_value(&text, key, value))
+    }
+
+    /// Config text with `key` set to `value`, replacing an existing line or
+    /// appending a new one
+    fn with_value(text: &str, key: &str, value: &str) -> String {
+        let entry = format!("{} = {}", key, value);
+        let mut found = false;
+        let mut lines: Vec<String> = text
+            .lines()
+            .map(|line| {
+                let is_key = !line.trim_start().starts_with('#')
+                    && line.split_once('=').is_some_and(|(k, _)| k.trim() == key);
+                if is_key && !found {
+                    found = true;
+                    entry.clone()
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect();
+        if !found {
+            lines.push(entry);
+        }
+        lines.join("\n") + "\n"
+    }
+
+    /// Parse config text, keeping defaults for anything missing or invalid
+    pub fn parse(text: &str) -> Self {
+        let mut config = Self::default();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "yan_overdose_margin" => {
+                    if let Ok(v) = value.parse() {
+                        config.yan_overdose_margin = v;
+                    }
+                }
+                "gravity_decimals" => {
+                    if let Ok(v) = value.parse() {
+                        config.precision.gravity = v;
+                    }
+                }
+                "abv_decimals" => {
+                    if let Ok(v) = value.parse() {
+                        config.precision.abv = v;
+                    }
+                }
+                "yan_decimals" => {
+                    if let Ok(v) = value.parse() {
+                        config.precision.yan = v;
+                    }
+                }
+                "date_format" if format::is_valid_date_format(value) => {
+                    config.date_format = value.to_string();
+                }
+                "time_format" if format::is_valid_time_format(value) => {
+                    config.time_format = value.to_string();
+                }
+                "temperature_unit" => {
+                    if let Some(unit) = TempUnit::parse(value) {
+                        config.temperature_unit = unit;
+                    }
+                }
+                "onboarding_done" => {
+                    if let Ok(v) = value.parse() {
+                        config.onboarding_done = v;
+                    }
+                }
+                "overwrite_exports" => {
+                    if let Ok(v) = value.parse() {
+                        config.overwrite_exports = v;
+                    }
+                }
+                "last_export" => {
+                    config.last_export = ExportFormat::parse(value);
+                }
+                "confirm_destructive" => {
+                    if let Ok(v) = value.parse() {
+                        config.confirm_destructive = v;
+                    }
+                }
+                "confirm_quit" => {
+                    if let Ok(v) = value.parse() {
+                        config.confirm_quit = v;
+                    }
+                }
+                "skip_integrity_check" => {
+                    if let Ok(v) = value.parse() {
+                        config.skip_integrity_check = v;
+                    }
+                }
+                "backup_on_startup" => {
+                    if let Ok(v) = value.parse() {
+                        config.backup_on_startup = v;
+                    }
+                }
+                "backup_retention" => {
+                    if let Ok(v) = value.parse() {
+                        config.backup_retention = v;
+                    }
+                }
+                "log_newest_first" => {
+                    if let Ok(v) = value.parse() {
+                        config.log_newest_first = v;
+                    }
+                }
+                "list_zebra_rows" => {
+                    if let Ok(v) = value.parse() {
+                        config.list_zebra_rows = v;
+                    }
+                }
+                "list_tall_rows" => {
+                    if let Ok(v) = value.parse() {
+                        config.list_tall_rows = v;
+                    }
+                }
+                "wrap_navigation" => {
+                    if let Ok(v) = value.parse() {
+                        config.wrap_navigation = v;
+                    }
+                }
+                "log_status_changes" => {
+                    if let Ok(v) = value.parse() {
+                        config.log_status_changes = v;
+                    }
+                }
+                "same_day_readings" => {
+                    if let Some(mode) = SameDayReadings::parse(value) {
+                        config.same_day_readings = mode;
+                    }
+                }
+                "show_color_estimate" => {
+                    if let Ok(v) = value.parse() {
+                        config.show_color_estimate = v;
+                    }
+                }
+                "ingredient_glyphs" => {
+                    if let Some(glyphs) = IngredientGlyphs::parse(value) {
+                        config.ingredient_glyphs = glyphs;
+                    }
+                }
+                "cursor_blink" => {
+                    if let Ok(v) = value.parse() {
+                        config.cursor_blink = v;
+                    }
+                }
+                "notes_autosave_secs" => {
+                    if let Ok(v) = value.parse() {
+                        config.notes_autosave_secs = v;
+                    }
+                }
+                "list_refresh_secs" => {
+                    if let Ok(v) = value.parse() {
+                        config.list_refresh_secs = v;
+                    }
+                }
+                "profile" if crate::db::is_valid_profile_name(value) => {
+                    config.profile = value.to_string();
+                }
+                "quick_log_text" if !value.is_empty() => {
+                    config.quick_log_text = value.to_string();
+                }
+                "new_mead_start_field" => {
+                    config.new_mead_start_field = value.to_string();
+                }
+                "detail_start_field" => {
+                    config.detail_start_field = value.to_string();
+                }
+                "accent_color" => {
+                    if let Some(color) = theme::parse_color(value) {
+                        config.theme.accent = Some(color);
+                    }
+                }
+                "color_support" => {
+                    config.color_support = ColorSupport::parse(value);
+                }
+                key if key.ends_with("_accent") => {
+                    let view = ThemeView::from_key(key.trim_end_matches("_accent"));
+                    if let (Some(view), Some(color)) = (view, theme::parse_color(value)) {
+                        *config.theme.view_mut(view) = Some(color);
+                    }
+                }
+                "menu_items" => {
+                    config.menu_items = value
+                        .split(',')
+                        .map(|key| key.trim().to_string())
+                        .filter(|key| !key.is_empty())
+                        .collect();
+                }
+                "hidden_columns" => {
+                    config.hidden_columns = value
+                        .split(',')
+                        .map(|key| key.trim().to_string())
+                        .filter(|key| !key.is_empty())
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+        config
+    }
+}
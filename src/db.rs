@@ -1,29 +1,194 @@
-use chrono::{DateTime, Utc};
-use rusqlite::{Connection, Result, params};
-use std::path::PathBuf;
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use rusqlite::{Connection, OpenFlags, Result, Row, params};
+use rusqlite::types::ValueRef;
+use std::collections::HashSet;
+use std::io;
+use std::path::{Path, PathBuf};
 
-use crate::models::{Ingredient, IngredientType, LogEntry, Mead, MeadStatus};
+use crate::config::Config;
+use crate::export::json::MeadRecord;
+
+use crate::format::{fmt_gravity, ISO_DATE};
+use crate::models::{
+    duplicate_ingredient_groups, Activity, ActivityFilter, ActivityKind, ActivityType, BaseType, CopyPart, GravityReading, HoneyUsage, Ingredient, IngredientType, LogEntry, LogEntryType, Mead, MeadSnapshot, MeadStatus, Reminder,
+    StatusChange,
+};
+
+/// Log entries, gravity readings and status changes as one table of
+/// (kind, mead_id, name, text, gravity, at, entry_type)
+const ACTIVITY_UNION: &str = "
+    SELECT 'log' AS kind, l.mead_id, m.name, l.entry_text AS text,
+        NULL AS gravity, l.timestamp AS at, l.entry_type
+    FROM log_entries l JOIN meads m ON m.id = l.mead_id
+    UNION ALL
+    SELECT 'gravity', g.mead_id, m.name, '', g.gravity, g.recorded_at, NULL
+    FROM gravity_readings g JOIN meads m ON m.id = g.mead_id
+    UNION ALL
+    SELECT 'status', s.mead_id, m.name, s.status, NULL, s.changed_at, NULL
+    FROM status_history s JOIN meads m ON m.id = s.mead_id";
+
+/// Filter on `ACTIVITY_UNION`, bound by `activity_params`; a NULL
+/// parameter lets everything through
+const ACTIVITY_WHERE: &str = "WHERE (?1 IS NULL OR mead_id = ?1)
+    AND (?2 IS NULL OR date(at, 'localtime') >= ?2)
+    AND (?3 IS NULL OR date(at, 'localtime') <= ?3)
+    AND (?4 IS NULL OR kind = ?4)
+    AND (?5 IS NULL OR entry_type = ?5)
+    AND (?6 IS NULL OR text LIKE ?6 ESCAPE '\\' OR name LIKE ?6 ESCAPE '\\')";
+
+/// Parameters for `ACTIVITY_WHERE`. Timestamps are stored as RFC 3339 in
+/// UTC, so days are compared on the local date each event happened on.
+fn activity_params(filter: &ActivityFilter) -> (Option<i64>, [Option<String>; 5]) {
+    let day = |date: NaiveDate| date.format(ISO_DATE).to_string();
+    let (kind, entry_type) = match &filter.activity_type {
+        None => (None, None),
+        Some(ActivityType::Gravity) => (Some("gravity"), None),
+        Some(ActivityType::Status) => (Some("status"), None),
+        Some(ActivityType::Log(entry_type)) => (Some("log"), Some(entry_type.as_str().to_string())),
+    };
+    let query = filter.query.trim();
+    let texts = [
+        filter.since.map(day),
+        filter.until.map(day),
+        kind.map(str::to_string),
+        entry_type,
+        (!query.is_empty()).then(|| {
+            let escaped = query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+            format!("%{}%", escaped)
+        }),
+    ];
+    (filter.mead.as_ref().map(|(id, _)| *id), texts)
+}
+
+/// Columns selected for a mead, in the order expected by `mead_from_row`
+const MEAD_COLUMNS: &str = "id, name, start_date, honey_type, honey_amount_lbs, yeast_strain,
+    target_abv, starting_gravity, current_gravity, yan_required, yan_added,
+    volume_gallons, status, notes, created_at, updated_at, pinned, target_date,
+    final_gravity, final_abv, auto_reminders, base_type, image_path, bottled_date, bottle_count,
+    gravity_check_days, ph, ta, competition, competition_category, competition_score, judge_notes, rating,
+    next_action";
+
+/// File the SQL dump is written to
+pub const SQL_FILE_NAME: &str = "mead_tracker.sql";
+
+/// How imported meads combine with the ones already in the database
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportMode {
+    /// Add the imported meads alongside the existing ones
+    Append,
+    /// Delete every existing mead first
+    Replace,
+}
+
+/// What happens to a gravity reading taken on a day that already has one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SameDayReadings {
+    /// Keep both readings; the app warns that the day already had one
+    Append,
+    /// The new reading takes the place of the day's earlier ones
+    Replace,
+}
+
+impl SameDayReadings {
+    /// Parse a config value: "append" or "replace"
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "append" => Some(SameDayReadings::Append),
+            "replace" => Some(SameDayReadings::Replace),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of `Database::recover`
+#[derive(Debug, Clone)]
+pub struct RecoveryReport {
+    /// The recovered database file
+    pub path: PathBuf,
+    /// Tables copied, with their row counts
+    pub recovered: Vec<(String, usize)>,
+    /// Tables that could not be copied, with the error
+    pub failed: Vec<(String, String)>,
+}
+
+/// Profile whose database is the original `mead_tracker.db`
+pub const DEFAULT_PROFILE: &str = "default";
 
 /// Database handler for mead tracking
 pub struct Database {
     conn: Connection,
+    /// Profile the database file belongs to
+    profile: String,
+    /// Add a log entry for each status change
+    log_status_changes: bool,
+    /// Whether a reading replaces the ones already taken that day
+    same_day_readings: SameDayReadings,
 }
 
 impl Database {
-    /// Create or open the database
-    pub fn new() -> Result<Self> {
-        let db_path = Self::get_db_path();
+    /// Create or open the database of the configured profile
+    pub fn new(config: &Config, dir: &Path) -> Result<Self> {
+        let db_path = Self::get_db_path(dir, &config.profile);
         let conn = Connection::open(&db_path)?;
-        let db = Self { conn };
+        if config.backup_on_startup {
+            // A failed backup shouldn't stop the app from starting
+            let _ = startup_backup(&db_path, &config.profile, config.backup_retention);
+        }
+        let db = Self {
+            conn,
+            profile: config.profile.clone(),
+            log_status_changes: config.log_status_changes,
+            same_day_readings: config.same_day_readings,
+        };
         db.init_tables()?;
         Ok(db)
     }
 
-    /// Get the database file path
-    fn get_db_path() -> PathBuf {
-        let mut path = dirs_next().unwrap_or_else(|| PathBuf::from("."));
-        path.push("mead_tracker.db");
-        path
+    /// Open the configured profile's database without writing to it: no
+    /// startup backup and no table creation or migration. The file must
+    /// already exist, written by this version of the app.
+    pub fn open_read_only(config: &Config, dir: &Path) -> Result<Self> {
+        let flags = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_URI | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+        Ok(Self {
+            conn: Connection::open_with_flags(Self::get_db_path(dir, &config.profile), flags)?,
+            profile: config.profile.clone(),
+            log_status_changes: config.log_status_changes,
+            same_day_readings: config.same_day_readings,
+        })
+    }
+
+    /// Path of the open database file, or None for an in-memory database
+    pub fn file_path(&self) -> Option<&str> {
+        self.conn.path().filter(|path| !path.is_empty())
+    }
+
+    /// Profile the open database belongs to
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+
+    /// Get the database file path for a profile in the data directory `dir`
+    fn get_db_path(dir: &Path, profile: &str) -> PathBuf {
+        dir.join(profile_file_name(profile))
+    }
+
+    /// Open a fresh database in memory, for tests
+    #[cfg(test)]
+    pub fn open_in_memory(config: &Config) -> Result<Self> {
+        let db = Self {
+            conn: Connection::open_in_memory()?,
+            profile: DEFAULT_PROFILE.to_string(),
+            log_status_changes: config.log_status_changes,
+            same_day_readings: config.same_day_readings,
+        };
+        db.init_tables()?;
+        Ok(db)
+    }
+
+    /// Make every later write fail, for tests of error handling
+    #[cfg(test)]
+    pub fn reject_writes(&self) -> Result<()> {
+        self.conn.execute_batch("PRAGMA query_only = ON")
     }
 
     /// Initialize database tables
@@ -75,18 +240,247 @@ impl Database {
             [],
         )?;
 
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS reminders (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                mead_id INTEGER NOT NULL,
+                due_date TEXT NOT NULL,
+                text TEXT NOT NULL,
+                done INTEGER NOT NULL DEFAULT 0,
+                FOREIGN KEY (mead_id) REFERENCES meads(id) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS status_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                mead_id INTEGER NOT NULL,
+                status TEXT NOT NULL,
+                changed_at TEXT NOT NULL,
+                FOREIGN KEY (mead_id) REFERENCES meads(id) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS gravity_readings (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                mead_id INTEGER NOT NULL,
+                gravity REAL NOT NULL,
+                status TEXT NOT NULL,
+                recorded_at TEXT NOT NULL,
+                FOREIGN KEY (mead_id) REFERENCES meads(id) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                mead_id INTEGER NOT NULL,
+                label TEXT NOT NULL,
+                taken_at TEXT NOT NULL,
+                data TEXT NOT NULL,
+                FOREIGN KEY (mead_id) REFERENCES meads(id) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+
+        self.migrate()?;
+
+        Ok(())
+    }
+
+    /// Bring tables created by older versions up to the current schema
+    fn migrate(&self) -> Result<()> {
+        self.add_column_if_missing("meads", "pinned", "INTEGER NOT NULL DEFAULT 0")?;
+        self.add_column_if_missing("meads", "target_date", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("log_entries", "entry_type", "TEXT NOT NULL DEFAULT 'Observation'")?;
+        self.add_column_if_missing("ingredients", "sort_order", "INTEGER NOT NULL DEFAULT 0")?;
+        self.add_column_if_missing("meads", "final_gravity", "REAL")?;
+        self.add_column_if_missing("meads", "final_abv", "REAL")?;
+        self.add_column_if_missing("meads", "auto_reminders", "INTEGER NOT NULL DEFAULT 1")?;
+        self.add_column_if_missing("meads", "base_type", "TEXT NOT NULL DEFAULT 'Honey'")?;
+        self.add_column_if_missing("meads", "image_path", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("meads", "bottled_date", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("meads", "bottle_count", "INTEGER")?;
+        self.add_column_if_missing("meads", "gravity_check_days", "INTEGER")?;
+        self.add_column_if_missing("meads", "ph", "REAL")?;
+        self.add_column_if_missing("meads", "ta", "REAL")?;
+        self.add_column_if_missing("meads", "competition", "INTEGER NOT NULL DEFAULT 0")?;
+        self.add_column_if_missing("meads", "competition_category", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("meads", "competition_score", "REAL")?;
+        self.add_column_if_missing("meads", "judge_notes", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("meads", "rating", "INTEGER")?;
+        self.add_column_if_missing("meads", "next_action", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("gravity_readings", "temperature", "REAL")?;
+        self.add_column_if_missing("ingredients", "notes", "TEXT NOT NULL DEFAULT ''")?;
+        // Batches bottled before the snapshot existed take their current
+        // gravity; values already recorded are kept
+        self.conn.execute(
+            "UPDATE meads SET final_gravity = current_gravity,
+                final_abv = COALESCE(final_abv, (starting_gravity - current_gravity) * 131.25)
+            WHERE status IN ('Bottled', 'Finished') AND final_gravity IS NULL",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Add a column to a table unless it already exists
+    fn add_column_if_missing(&self, table: &str, column: &str, definition: &str) -> Result<()> {
+        let mut stmt = self.conn.prepare(&format!("PRAGMA table_info({})", table))?;
+        let exists = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>>>()?
+            .iter()
+            .any(|name| name == column);
+
+        if !exists {
+            self.conn.execute(
+                &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+                [],
+            )?;
+        }
         Ok(())
     }
 
+    // ==================== INTEGRITY ====================
+
+    /// Run `PRAGMA integrity_check`, returning the problems it reports.
+    /// An empty list means the database is healthy.
+    pub fn integrity_problems(&self) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare("PRAGMA integrity_check")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        let problems: Vec<String> = rows.collect::<Result<_>>()?;
+        Ok(problems.into_iter().filter(|p| p != "ok").collect())
+    }
+
+    /// Copy the database file as it is on disk, returning the copy's path
+    pub fn backup_file(&self) -> std::io::Result<PathBuf> {
+        let path = self
+            .file_path()
+            .map(PathBuf::from)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "the database is not a file"))?;
+        let backup = path.with_extension(format!("db.bak-{}", Utc::now().format("%Y%m%d%H%M%S")));
+        std::fs::copy(&path, &backup)?;
+        Ok(backup)
+    }
+
+    /// Copy every readable row into a fresh database in the data directory
+    /// `dir`, like the sqlite3 shell's `.recover`. Tables that can't be read
+    /// are listed in the report rather than stopping the recovery.
+    pub fn recover(&self, dir: &Path) -> Result<RecoveryReport> {
+        let path = dir.join(format!("mead_tracker.recovered-{}.db", Utc::now().format("%Y%m%d%H%M%S")));
+        self.conn.execute("ATTACH DATABASE ?1 AS recovered", params![path.to_string_lossy()])?;
+        // Detach whether or not the copy worked, so the connection is left
+        // as it was
+        let copied = self.copy_to_recovered(path);
+        let detached = self.conn.execute("DETACH DATABASE recovered", []);
+        let report = copied?;
+        detached?;
+        Ok(report)
+    }
+
+    /// The copying half of `recover`, into the attached `recovered` database
+    fn copy_to_recovered(&self, path: PathBuf) -> Result<RecoveryReport> {
+        let tables: Vec<(String, String)> = {
+            let mut stmt = self.conn.prepare(
+                "SELECT name, sql FROM main.sqlite_master
+                WHERE type = 'table' AND name NOT LIKE 'sqlite_%'"
+            )?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_>>()?
+        };
+
+        let mut report = RecoveryReport {
+            path,
+            recovered: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (name, sql) in tables {
+            let copied = self
+                .conn
+                .execute_batch(&sql.replacen("CREATE TABLE ", "CREATE TABLE recovered.", 1))
+                .and_then(|_| {
+                    self.conn.execute(
+                        &format!("INSERT INTO recovered.\"{0}\" SELECT * FROM main.\"{0}\"", name),
+                        [],
+                    )
+                });
+            match copied {
+                Ok(rows) => report.recovered.push((name, rows)),
+                Err(e) => report.failed.push((name, e.to_string())),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Write every table as CREATE TABLE and INSERT statements, followed by
+    /// the indexes, in a plain `.sql` file the sqlite CLI can read back
+    /// with `.read`. Returns how many rows were written.
+    pub fn export_sql(&self, path: &Path) -> io::Result<usize> {
+        let (sql, rows) = self.dump_sql().map_err(io::Error::other)?;
+        std::fs::write(path, sql)?;
+        Ok(rows)
+    }
+
+    /// The SQL dump text and its row count
+    fn dump_sql(&self) -> Result<(String, usize)> {
+        let schema: Vec<(String, String, String)> = {
+            let mut stmt = self.conn.prepare(
+                "SELECT type, name, sql FROM sqlite_master
+                WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%'
+                ORDER BY type = 'index', rowid"
+            )?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+            rows.collect::<Result<_>>()?
+        };
+
+        let mut sql = String::from("PRAGMA foreign_keys=OFF;\nBEGIN TRANSACTION;\n");
+        let mut rows = 0;
+        for (kind, name, create) in schema {
+            sql.push_str(&create);
+            sql.push_str(";\n");
+            if kind != "table" {
+                continue;
+            }
+            let mut stmt = self.conn.prepare(&format!("SELECT * FROM \"{}\"", name))?;
+            let columns = stmt.column_count();
+            let mut query = stmt.query([])?;
+            while let Some(row) = query.next()? {
+                let values = (0..columns)
+                    .map(|i| row.get_ref(i).map(sql_literal))
+                    .collect::<Result<Vec<_>>>()?;
+                sql.push_str(&format!("INSERT INTO \"{}\" VALUES({});\n", name, values.join(",")));
+                rows += 1;
+            }
+        }
+        sql.push_str("COMMIT;\n");
+        Ok((sql, rows))
+    }
+
     // ==================== MEAD CRUD ====================
 
     /// Create a new mead
     pub fn create_mead(&self, mead: &Mead) -> Result<i64> {
+        let id = self.insert_mead(mead)?;
+        self.record_status_change(id, None, &mead.status)?;
+        self.record_gravity_reading(id, mead.current_gravity, None, &mead.status)?;
+        Ok(id)
+    }
+
+    /// Insert a mead row as given, without its first status and reading
+    fn insert_mead(&self, mead: &Mead) -> Result<i64> {
         self.conn.execute(
             "INSERT INTO meads (name, start_date, honey_type, honey_amount_lbs, yeast_strain,
                 target_abv, starting_gravity, current_gravity, yan_required, yan_added,
-                volume_gallons, status, notes, created_at, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+                volume_gallons, status, notes, created_at, updated_at, pinned, target_date,
+                auto_reminders, base_type, image_path, gravity_check_days, ph, ta, competition,
+                competition_category, competition_score, judge_notes, rating, next_action,
+                final_gravity, final_abv, bottled_date, bottle_count)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20,
+                ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32, ?33)",
             params![
                 mead.name,
                 mead.start_date,
@@ -103,96 +497,193 @@ impl Database {
                 mead.notes,
                 mead.created_at.to_rfc3339(),
                 mead.updated_at.to_rfc3339(),
+                mead.pinned,
+                mead.target_date,
+                mead.auto_reminders,
+                mead.base_type.as_str(),
+                mead.image_path,
+                mead.gravity_check_days,
+                mead.ph,
+                mead.ta,
+                mead.competition,
+                mead.competition_category,
+                mead.competition_score,
+                mead.judge_notes,
+                mead.rating,
+                mead.next_action,
+                mead.final_gravity,
+                mead.final_abv,
+                mead.bottled_date,
+                mead.bottle_count,
             ],
         )?;
         Ok(self.conn.last_insert_rowid())
     }
 
-    /// Get all meads
-    pub fn get_all_meads(&self) -> Result<Vec<Mead>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, name, start_date, honey_type, honey_amount_lbs, yeast_strain,
-                target_abv, starting_gravity, current_gravity, yan_required, yan_added,
-                volume_gallons, status, notes, created_at, updated_at
-            FROM meads ORDER BY created_at DESC"
-        )?;
+    /// Number of meads in the database
+    pub fn count_meads(&self) -> Result<usize> {
+        self.conn
+            .query_row("SELECT COUNT(*) FROM meads", [], |row| row.get::<_, i64>(0))
+            .map(|count| count as usize)
+    }
 
-        let meads = stmt.query_map([], |row| {
-            Ok(Mead {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                start_date: row.get(2)?,
-                honey_type: row.get(3)?,
-                honey_amount_lbs: row.get(4)?,
-                yeast_strain: row.get(5)?,
-                target_abv: row.get(6)?,
-                starting_gravity: row.get(7)?,
-                current_gravity: row.get(8)?,
-                yan_required: row.get(9)?,
-                yan_added: row.get(10)?,
-                volume_gallons: row.get(11)?,
-                status: MeadStatus::from_str(&row.get::<_, String>(12)?),
-                notes: row.get(13)?,
-                created_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(14)?)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now()),
-                updated_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(15)?)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now()),
+    /// Count meads as (active, total); active ones are in Primary,
+    /// Secondary or Aging
+    pub fn count_active_meads(&self) -> Result<(usize, usize)> {
+        self.conn.query_row(
+            "SELECT
+                COALESCE(SUM(status IN ('Primary', 'Secondary', 'Aging')), 0),
+                COUNT(*)
+            FROM meads",
+            [],
+            |row| Ok((row.get::<_, i64>(0)? as usize, row.get::<_, i64>(1)? as usize)),
+        )
+    }
+
+    /// Meads with their ingredients, log, reminders, readings, status
+    /// history and snapshots, for export: every mead, or only those in `ids`
+    /// and in that order
+    pub fn get_records(&self, ids: Option<&[i64]>) -> Result<Vec<MeadRecord>> {
+        let mut meads = self.get_all_meads()?;
+        if let Some(ids) = ids {
+            meads = ids
+                .iter()
+                .filter_map(|id| meads.iter().position(|m| m.id == *id))
+                .map(|index| meads[index].clone())
+                .collect();
+        }
+        meads
+            .into_iter()
+            .map(|mead| {
+                Ok(MeadRecord {
+                    ingredients: self.get_ingredients(mead.id)?,
+                    log_entries: self.get_log_entries(mead.id, false)?,
+                    reminders: self.get_reminders(mead.id)?,
+                    gravity_readings: self.get_gravity_readings(mead.id)?,
+                    status_history: self.get_status_changes(mead.id)?,
+                    snapshots: self.get_snapshots(mead.id)?,
+                    mead,
+                })
             })
-        })?;
+            .collect()
+    }
+
+    /// Add imported meads as new batches in a single transaction. Replace
+    /// deletes every existing mead and its records first, inside the same
+    /// transaction, so a failed import leaves the database as it was.
+    /// Returns how many meads were imported.
+    pub fn import_records(&self, records: &[MeadRecord], mode: ImportMode) -> Result<usize> {
+        let tx = self.conn.unchecked_transaction()?;
+        if mode == ImportMode::Replace {
+            for table in ["ingredients", "log_entries", "reminders", "status_history", "gravity_readings", "snapshots", "meads"] {
+                tx.execute(&format!("DELETE FROM {}", table), [])?;
+            }
+        }
+        for record in records {
+            let id = self.insert_mead(&record.mead)?;
+            // Records from older exports carry no history, so they start
+            // one the way a new mead does
+            if record.status_history.is_empty() {
+                self.record_status_change(id, None, &record.mead.status)?;
+            }
+            for change in &record.status_history {
+                tx.execute(
+                    "INSERT INTO status_history (mead_id, status, changed_at) VALUES (?1, ?2, ?3)",
+                    params![id, change.status.as_str(), change.changed_at.to_rfc3339()],
+                )?;
+            }
+            if record.gravity_readings.is_empty() {
+                self.record_gravity_reading(id, record.mead.current_gravity, None, &record.mead.status)?;
+            }
+            for reading in &record.gravity_readings {
+                tx.execute(
+                    "INSERT INTO gravity_readings (mead_id, gravity, temperature, status, recorded_at)
+                    VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![id, reading.gravity, reading.temperature, reading.status.as_str(), reading.recorded_at.to_rfc3339()],
+                )?;
+            }
+            for snapshot in &record.snapshots {
+                let mead = Mead { id, ..snapshot.mead.clone() };
+                let data = serde_json::to_string(&mead).map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+                tx.execute(
+                    "INSERT INTO snapshots (mead_id, label, taken_at, data) VALUES (?1, ?2, ?3, ?4)",
+                    params![id, snapshot.label, snapshot.taken_at.to_rfc3339(), data],
+                )?;
+            }
+            for (order, ingredient) in record.ingredients.iter().enumerate() {
+                self.insert_ingredient_at(id, ingredient, order)?;
+            }
+            for entry in &record.log_entries {
+                self.create_log_entry(&LogEntry { mead_id: id, ..entry.clone() })?;
+            }
+            for reminder in &record.reminders {
+                self.create_reminder(&Reminder { mead_id: id, ..reminder.clone() })?;
+            }
+        }
+        tx.commit()?;
+        Ok(records.len())
+    }
+
+    /// Copy meads in `ids` from another database, such as another profile's,
+    /// with everything recorded against them, in a single transaction.
+    /// Returns how many meads were copied.
+    pub fn copy_meads_from(&self, other: &Database, ids: &[i64]) -> Result<usize> {
+        self.import_records(&other.get_records(Some(ids))?, ImportMode::Append)
+    }
+
+    /// Get all meads, pinned meads first
+    pub fn get_all_meads(&self) -> Result<Vec<Mead>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM meads ORDER BY pinned DESC, created_at DESC",
+            MEAD_COLUMNS
+        ))?;
+
+        let meads = stmt.query_map([], mead_from_row)?;
 
         meads.collect()
     }
 
     /// Get a mead by ID
     pub fn get_mead(&self, id: i64) -> Result<Option<Mead>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, name, start_date, honey_type, honey_amount_lbs, yeast_strain,
-                target_abv, starting_gravity, current_gravity, yan_required, yan_added,
-                volume_gallons, status, notes, created_at, updated_at
-            FROM meads WHERE id = ?1"
-        )?;
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM meads WHERE id = ?1",
+            MEAD_COLUMNS
+        ))?;
 
         let mut rows = stmt.query(params![id])?;
         
         if let Some(row) = rows.next()? {
-            Ok(Some(Mead {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                start_date: row.get(2)?,
-                honey_type: row.get(3)?,
-                honey_amount_lbs: row.get(4)?,
-                yeast_strain: row.get(5)?,
-                target_abv: row.get(6)?,
-                starting_gravity: row.get(7)?,
-                current_gravity: row.get(8)?,
-                yan_required: row.get(9)?,
-                yan_added: row.get(10)?,
-                volume_gallons: row.get(11)?,
-                status: MeadStatus::from_str(&row.get::<_, String>(12)?),
-                notes: row.get(13)?,
-                created_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(14)?)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now()),
-                updated_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(15)?)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now()),
-            }))
+            Ok(Some(mead_from_row(row)?))
         } else {
             Ok(None)
         }
     }
 
-    /// Update a mead
+    /// Update a mead, recording a status change if the status differs
     pub fn update_mead(&self, mead: &Mead) -> Result<()> {
+        self.update_mead_measured(mead, None)
+    }
+
+    /// Update a mead, recording a reading if the gravity changed or a
+    /// sample temperature was measured with it
+    fn update_mead_measured(&self, mead: &Mead, temperature: Option<f64>) -> Result<()> {
+        let old: Option<(String, f64)> = self
+            .conn
+            .query_row(
+                "SELECT status, current_gravity FROM meads WHERE id = ?1",
+                params![mead.id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .ok();
+
         self.conn.execute(
             "UPDATE meads SET
                 name = ?1, start_date = ?2, honey_type = ?3, honey_amount_lbs = ?4,
                 yeast_strain = ?5, target_abv = ?6, starting_gravity = ?7, current_gravity = ?8,
                 yan_required = ?9, yan_added = ?10, volume_gallons = ?11, status = ?12,
-                notes = ?13, updated_at = ?14
-            WHERE id = ?15",
+                notes = ?13, updated_at = ?14, target_date = ?15, image_path = ?16, rating = ?17,
+                next_action = ?18, bottled_date = ?19, bottle_count = ?20
+            WHERE id = ?21",
             params![
                 mead.name,
                 mead.start_date,
@@ -208,28 +699,270 @@ impl Database {
                 mead.status.as_str(),
                 mead.notes,
                 Utc::now().to_rfc3339(),
+                mead.target_date,
+                mead.image_path,
+                mead.rating,
+                mead.next_action,
+                mead.bottled_date,
+                mead.bottle_count,
                 mead.id,
             ],
         )?;
+
+        if let Some((old_status, old_gravity)) = old {
+            let old_status = MeadStatus::from_str(&old_status);
+            if old_status != mead.status {
+                self.record_status_change(mead.id, Some(&old_status), &mead.status)?;
+            }
+            if (old_gravity - mead.current_gravity).abs() > f64::EPSILON || temperature.is_some() {
+                self.record_gravity_reading(mead.id, mead.current_gravity, temperature, &mead.status)?;
+            }
+        }
         Ok(())
     }
 
+    /// Save a mead moving to Finished along with its finishing details
+    /// (final gravity and ABV, bottling date and bottle count) and a log
+    /// entry summarising them, in a single transaction
+    pub fn finish_mead(&self, mead: &Mead, entry: &LogEntry) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        self.update_mead(mead)?;
+        // Entered values replace any snapshot taken on the status change
+        tx.execute(
+            "UPDATE meads SET final_gravity = ?1, final_abv = ?2 WHERE id = ?3",
+            params![mead.final_gravity, mead.final_abv, mead.id],
+        )?;
+        self.create_log_entry(entry)?;
+        tx.commit()
+    }
+
+    /// Create a new Planning batch from an existing mead, carrying over only
+    /// the chosen parts, in a single transaction. Returns the new id.
+    pub fn duplicate_mead(&self, id: i64, parts: &HashSet<CopyPart>) -> Result<i64> {
+        let source = self.get_mead(id)?.ok_or(rusqlite::Error::QueryReturnedNoRows)?;
+        let mut copy = Mead {
+            name: format!("{} (copy)", source.name),
+            ..Default::default()
+        };
+        if parts.contains(&CopyPart::Recipe) {
+            copy.base_type = source.base_type;
+            copy.honey_type = source.honey_type.clone();
+            copy.honey_amount_lbs = source.honey_amount_lbs;
+            copy.yeast_strain = source.yeast_strain.clone();
+            copy.volume_gallons = source.volume_gallons;
+            copy.yan_required = source.yan_required;
+        }
+        if parts.contains(&CopyPart::GravityTargets) {
+            copy.starting_gravity = source.starting_gravity;
+            copy.current_gravity = source.starting_gravity;
+            copy.target_abv = source.target_abv;
+        }
+        if parts.contains(&CopyPart::Notes) {
+            copy.notes = source.notes.clone();
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        // Copied readings are the copy's history, so it gets no reading of
+        // its own on top of them
+        let new_id = if parts.contains(&CopyPart::Readings) {
+            let new_id = self.insert_mead(&copy)?;
+            self.record_status_change(new_id, None, &copy.status)?;
+            new_id
+        } else {
+            self.create_mead(&copy)?
+        };
+        if parts.contains(&CopyPart::Ingredients) {
+            for (order, mut ingredient) in self.get_ingredients(id)?.into_iter().enumerate() {
+                // A fresh brew adds everything from its own start date
+                if !parts.contains(&CopyPart::IngredientDates) {
+                    ingredient.added_date = copy.start_date.clone();
+                }
+                self.insert_ingredient_at(new_id, &ingredient, order)?;
+            }
+        }
+        if parts.contains(&CopyPart::Logs) {
+            tx.execute(
+                "INSERT INTO log_entries (mead_id, timestamp, entry_text, entry_type)
+                SELECT ?1, timestamp, entry_text, entry_type FROM log_entries WHERE mead_id = ?2",
+                params![new_id, id],
+            )?;
+        }
+        if parts.contains(&CopyPart::Readings) {
+            tx.execute(
+                "INSERT INTO gravity_readings (mead_id, gravity, temperature, status, recorded_at)
+                SELECT ?1, gravity, temperature, status, recorded_at FROM gravity_readings WHERE mead_id = ?2",
+                params![new_id, id],
+            )?;
+        }
+        tx.commit()?;
+        Ok(new_id)
+    }
+
+    /// Pin or unpin a mead
+    pub fn set_pinned(&self, id: i64, pinned: bool) -> Result<()> {
+        self.conn.execute("UPDATE meads SET pinned = ?1 WHERE id = ?2", params![pinned, id])?;
+        Ok(())
+    }
+
+    /// Turn overdue and stalled-fermentation warnings on or off for a mead
+    pub fn set_auto_reminders(&self, id: i64, enabled: bool) -> Result<()> {
+        self.conn.execute(
+            "UPDATE meads SET auto_reminders = ?1 WHERE id = ?2",
+            params![enabled, id],
+        )?;
+        Ok(())
+    }
+
+    /// Replace a mead's notes, leaving its other fields as they are
+    pub fn set_notes(&self, id: i64, notes: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE meads SET notes = ?1, updated_at = ?2 WHERE id = ?3",
+            params![notes, Utc::now().to_rfc3339(), id],
+        )?;
+        Ok(())
+    }
+
+    /// Set how many days apart a mead's gravity readings are due, or None
+    /// for no schedule
+    pub fn set_gravity_check_days(&self, id: i64, days: Option<u32>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE meads SET gravity_check_days = ?1 WHERE id = ?2",
+            params![days, id],
+        )?;
+        Ok(())
+    }
+
+    /// Mark a mead as a competition entry, or not
+    pub fn set_competition(&self, id: i64, competition: bool) -> Result<()> {
+        self.conn.execute(
+            "UPDATE meads SET competition = ?1 WHERE id = ?2",
+            params![competition, id],
+        )?;
+        Ok(())
+    }
+
+    /// Set a competition entry's category, score and judges' notes
+    pub fn set_competition_details(&self, id: i64, category: &str, score: Option<f64>, judge_notes: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE meads SET competition_category = ?1, competition_score = ?2, judge_notes = ?3 WHERE id = ?4",
+            params![category, score, judge_notes, id],
+        )?;
+        Ok(())
+    }
+
+    /// Set a mead's measured pH and titratable acidity (g/L), None to clear
+    pub fn set_acidity(&self, id: i64, ph: Option<f64>, ta: Option<f64>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE meads SET ph = ?1, ta = ?2 WHERE id = ?3",
+            params![ph, ta, id],
+        )?;
+        Ok(())
+    }
+
+    /// Advance each mead one stage, in a single transaction. Finished meads
+    /// are skipped, and so are bottled ones, which finish through the
+    /// finishing flow so their details get asked for. Returns how many
+    /// meads moved.
+    pub fn advance_statuses(&self, ids: &[i64]) -> Result<usize> {
+        let tx = self.conn.unchecked_transaction()?;
+        let mut advanced = 0;
+        for &id in ids {
+            let status: String =
+                tx.query_row("SELECT status FROM meads WHERE id = ?1", params![id], |row| row.get(0))?;
+            let status = MeadStatus::from_str(&status);
+            if matches!(status, MeadStatus::Bottled | MeadStatus::Finished) {
+                continue;
+            }
+            let next = status.next();
+            tx.execute(
+                "UPDATE meads SET status = ?1, updated_at = ?2 WHERE id = ?3",
+                params![next.as_str(), Utc::now().to_rfc3339(), id],
+            )?;
+            self.record_status_change(id, Some(&status), &next)?;
+            advanced += 1;
+        }
+        tx.commit()?;
+        Ok(advanced)
+    }
+
+    /// Scale a batch to a new volume: honey and every ingredient amount are
+    /// multiplied by `factor` in a single transaction. Gravities and YAN
+    /// (a concentration) are unchanged.
+    pub fn scale_mead(&self, id: i64, factor: f64) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "UPDATE meads SET volume_gallons = volume_gallons * ?1,
+                honey_amount_lbs = honey_amount_lbs * ?1, updated_at = ?2
+            WHERE id = ?3",
+            params![factor, Utc::now().to_rfc3339(), id],
+        )?;
+        tx.execute(
+            "UPDATE ingredients SET amount = amount * ?1 WHERE mead_id = ?2",
+            params![factor, id],
+        )?;
+        tx.commit()
+    }
+
     /// Delete a mead
     pub fn delete_mead(&self, id: i64) -> Result<()> {
         // Delete related entries first
         self.conn.execute("DELETE FROM ingredients WHERE mead_id = ?1", params![id])?;
         self.conn.execute("DELETE FROM log_entries WHERE mead_id = ?1", params![id])?;
+        self.conn.execute("DELETE FROM reminders WHERE mead_id = ?1", params![id])?;
+        self.conn.execute("DELETE FROM status_history WHERE mead_id = ?1", params![id])?;
+        self.conn.execute("DELETE FROM gravity_readings WHERE mead_id = ?1", params![id])?;
+        self.conn.execute("DELETE FROM snapshots WHERE mead_id = ?1", params![id])?;
         self.conn.execute("DELETE FROM meads WHERE id = ?1", params![id])?;
         Ok(())
     }
 
+    /// Merge the mead `source` into `target` in a single transaction. Its
+    /// ingredients (after the target's own), log entries, reminders and
+    /// gravity readings move across, except the reading taken when the
+    /// source was created; then it is deleted along with its own status
+    /// history and snapshots. The target's fields are kept as they are,
+    /// apart from its current gravity, which follows the latest reading.
+    pub fn merge_meads(&self, target: i64, source: i64) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        let first: i64 = tx.query_row(
+            "SELECT COALESCE(MAX(sort_order), -1) + 1 FROM ingredients WHERE mead_id = ?1",
+            params![target],
+            |row| row.get(0),
+        )?;
+        let source_first: i64 = tx.query_row(
+            "SELECT COALESCE(MIN(sort_order), 0) FROM ingredients WHERE mead_id = ?1",
+            params![source],
+            |row| row.get(0),
+        )?;
+        tx.execute(
+            "UPDATE ingredients SET mead_id = ?1, sort_order = sort_order + ?3 WHERE mead_id = ?2",
+            params![target, source, first - source_first],
+        )?;
+        // The source's first reading was recorded when it was created and
+        // would only repeat the target's own starting point
+        tx.execute(
+            "DELETE FROM gravity_readings WHERE id = (SELECT MIN(id) FROM gravity_readings WHERE mead_id = ?1)",
+            params![source],
+        )?;
+        for table in ["log_entries", "reminders", "gravity_readings"] {
+            tx.execute(
+                &format!("UPDATE {} SET mead_id = ?1 WHERE mead_id = ?2", table),
+                params![target, source],
+            )?;
+        }
+        self.delete_mead(source)?;
+        self.sync_current_gravity(target)?;
+        tx.commit()
+    }
+
     // ==================== INGREDIENT CRUD ====================
 
-    /// Add an ingredient to a mead
+    /// Add an ingredient to a mead, placed at the top of its list
     pub fn create_ingredient(&self, ingredient: &Ingredient) -> Result<i64> {
         self.conn.execute(
-            "INSERT INTO ingredients (mead_id, ingredient_type, name, amount, unit, added_date)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            "INSERT INTO ingredients (mead_id, ingredient_type, name, amount, unit, added_date, notes, sort_order)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7,
+                (SELECT COALESCE(MIN(sort_order), 0) - 1 FROM ingredients WHERE mead_id = ?1))",
             params![
                 ingredient.mead_id,
                 ingredient.ingredient_type.as_str(),
@@ -237,16 +970,54 @@ impl Database {
                 ingredient.amount,
                 ingredient.unit,
                 ingredient.added_date,
+                ingredient.notes,
             ],
         )?;
         Ok(self.conn.last_insert_rowid())
     }
 
-    /// Get all ingredients for a mead
+    /// Copy an ingredient onto a mead at a given place in its list
+    fn insert_ingredient_at(&self, mead_id: i64, ingredient: &Ingredient, order: usize) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO ingredients (mead_id, ingredient_type, name, amount, unit, added_date, notes, sort_order)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                mead_id,
+                ingredient.ingredient_type.as_str(),
+                ingredient.name,
+                ingredient.amount,
+                ingredient.unit,
+                ingredient.added_date,
+                ingredient.notes,
+                order as i64,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Add copies of another mead's ingredients to the end of a mead's
+    /// list, in their order there. Returns the number added.
+    pub fn copy_ingredients(&self, from_id: i64, to_id: i64) -> Result<usize> {
+        let ingredients = self.get_ingredients(from_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        let first: i64 = tx.query_row(
+            "SELECT COALESCE(MAX(sort_order), -1) + 1 FROM ingredients WHERE mead_id = ?1",
+            params![to_id],
+            |row| row.get(0),
+        )?;
+        for (offset, ingredient) in ingredients.iter().enumerate() {
+            self.insert_ingredient_at(to_id, ingredient, first as usize + offset)?;
+        }
+        tx.commit()?;
+        Ok(ingredients.len())
+    }
+
+    /// Get all ingredients for a mead in their display order
     pub fn get_ingredients(&self, mead_id: i64) -> Result<Vec<Ingredient>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, mead_id, ingredient_type, name, amount, unit, added_date
-            FROM ingredients WHERE mead_id = ?1 ORDER BY added_date DESC"
+            "SELECT id, mead_id, ingredient_type, name, amount, unit, added_date, notes
+            FROM ingredients WHERE mead_id = ?1
+            ORDER BY sort_order, added_date DESC, id DESC"
         )?;
 
         let ingredients = stmt.query_map(params![mead_id], |row| {
@@ -258,16 +1029,45 @@ impl Database {
                 amount: row.get(4)?,
                 unit: row.get(5)?,
                 added_date: row.get(6)?,
+                notes: row.get(7)?,
             })
         })?;
 
         ingredients.collect()
     }
 
-    /// Delete an ingredient
-    pub fn delete_ingredient(&self, id: i64) -> Result<()> {
-        self.conn.execute("DELETE FROM ingredients WHERE id = ?1", params![id])?;
-        Ok(())
+    /// Combine duplicate ingredients of a mead into one row each, summing
+    /// their amounts, in a single transaction. Returns how many rows were
+    /// removed.
+    pub fn merge_duplicate_ingredients(&self, mead_id: i64) -> Result<usize> {
+        let ingredients = self.get_ingredients(mead_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        let mut removed = 0;
+        for group in duplicate_ingredient_groups(&ingredients) {
+            let total: f64 = group.iter().map(|i| i.amount).sum();
+            tx.execute(
+                "UPDATE ingredients SET amount = ?1 WHERE id = ?2",
+                params![total, group[0].id],
+            )?;
+            for extra in &group[1..] {
+                tx.execute("DELETE FROM ingredients WHERE id = ?1", params![extra.id])?;
+                removed += 1;
+            }
+        }
+        tx.commit()?;
+        Ok(removed)
+    }
+
+    /// Store the display order of ingredients, given their ids in order
+    pub fn set_ingredient_order(&self, ids: &[i64]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        for (position, id) in ids.iter().enumerate() {
+            tx.execute(
+                "UPDATE ingredients SET sort_order = ?1 WHERE id = ?2",
+                params![position as i64, id],
+            )?;
+        }
+        tx.commit()
     }
 
     // ==================== LOG ENTRY CRUD ====================
@@ -275,58 +1075,1277 @@ impl Database {
     /// Add a log entry to a mead
     pub fn create_log_entry(&self, entry: &LogEntry) -> Result<i64> {
         self.conn.execute(
-            "INSERT INTO log_entries (mead_id, timestamp, entry_text)
-            VALUES (?1, ?2, ?3)",
+            "INSERT INTO log_entries (mead_id, timestamp, entry_text, entry_type)
+            VALUES (?1, ?2, ?3, ?4)",
             params![
                 entry.mead_id,
                 entry.timestamp.to_rfc3339(),
                 entry.entry_text,
+                entry.entry_type.as_str(),
             ],
         )?;
         Ok(self.conn.last_insert_rowid())
     }
 
-    /// Get all log entries for a mead
-    pub fn get_log_entries(&self, mead_id: i64) -> Result<Vec<LogEntry>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, mead_id, timestamp, entry_text
-            FROM log_entries WHERE mead_id = ?1 ORDER BY timestamp DESC"
-        )?;
+    /// Write a copy of a log entry to each of the given meads in a single
+    /// transaction, stamped now. Returns how many copies were written.
+    pub fn copy_log_entry(&self, entry: &LogEntry, mead_ids: &[i64]) -> Result<usize> {
+        let tx = self.conn.unchecked_transaction()?;
+        let now = Utc::now().to_rfc3339();
+        for &mead_id in mead_ids {
+            tx.execute(
+                "INSERT INTO log_entries (mead_id, timestamp, entry_text, entry_type)
+                VALUES (?1, ?2, ?3, ?4)",
+                params![mead_id, now, entry.entry_text, entry.entry_type.as_str()],
+            )?;
+        }
+        tx.commit()?;
+        Ok(mead_ids.len())
+    }
+
+    /// Get all log entries for a mead, newest or oldest first
+    pub fn get_log_entries(&self, mead_id: i64, newest_first: bool) -> Result<Vec<LogEntry>> {
+        let order = if newest_first { "DESC" } else { "ASC" };
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT id, mead_id, timestamp, entry_text, entry_type
+            FROM log_entries WHERE mead_id = ?1 ORDER BY timestamp {0}, id {0}",
+            order
+        ))?;
 
         let entries = stmt.query_map(params![mead_id], |row| {
             Ok(LogEntry {
                 id: row.get(0)?,
                 mead_id: row.get(1)?,
-                timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(2)?)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now()),
+                timestamp: parse_timestamp(&row.get::<_, String>(2)?),
                 entry_text: row.get(3)?,
+                entry_type: LogEntryType::from_str(&row.get::<_, String>(4)?),
             })
         })?;
 
         entries.collect()
     }
 
-    /// Delete a log entry
-    pub fn delete_log_entry(&self, id: i64) -> Result<()> {
-        self.conn.execute("DELETE FROM log_entries WHERE id = ?1", params![id])?;
+    // ==================== REMINDER CRUD ====================
+
+    /// Add a reminder to a mead
+    pub fn create_reminder(&self, reminder: &Reminder) -> Result<i64> {
+        self.conn.execute(
+            "INSERT INTO reminders (mead_id, due_date, text, done)
+            VALUES (?1, ?2, ?3, ?4)",
+            params![
+                reminder.mead_id,
+                reminder.due_date,
+                reminder.text,
+                reminder.done,
+            ],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Get all reminders for a mead, open ones first by due date
+    pub fn get_reminders(&self, mead_id: i64) -> Result<Vec<Reminder>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, mead_id, due_date, text, done
+            FROM reminders WHERE mead_id = ?1 ORDER BY done, due_date"
+        )?;
+
+        let reminders = stmt.query_map(params![mead_id], |row| {
+            Ok(Reminder {
+                id: row.get(0)?,
+                mead_id: row.get(1)?,
+                due_date: row.get(2)?,
+                text: row.get(3)?,
+                done: row.get(4)?,
+            })
+        })?;
+
+        reminders.collect()
+    }
+
+    /// Mark a reminder done or not done
+    pub fn set_reminder_done(&self, id: i64, done: bool) -> Result<()> {
+        self.conn.execute("UPDATE reminders SET done = ?1 WHERE id = ?2", params![done, id])?;
+        Ok(())
+    }
+
+    /// Get open and completed reminders for every mead, by due date
+    pub fn get_all_reminders(&self) -> Result<Vec<Reminder>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, mead_id, due_date, text, done
+            FROM reminders ORDER BY due_date"
+        )?;
+
+        let reminders = stmt.query_map([], |row| {
+            Ok(Reminder {
+                id: row.get(0)?,
+                mead_id: row.get(1)?,
+                due_date: row.get(2)?,
+                text: row.get(3)?,
+                done: row.get(4)?,
+            })
+        })?;
+
+        reminders.collect()
+    }
+
+    /// Count open reminders across all meads as (due today, overdue)
+    pub fn count_due_reminders(&self) -> Result<(usize, usize)> {
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        self.conn.query_row(
+            "SELECT
+                COALESCE(SUM(due_date = ?1), 0),
+                COALESCE(SUM(due_date < ?1), 0)
+            FROM reminders WHERE done = 0",
+            params![today],
+            |row| Ok((row.get::<_, i64>(0)? as usize, row.get::<_, i64>(1)? as usize)),
+        )
+    }
+
+    // ==================== STATUS HISTORY ====================
+
+    /// Record that a mead entered a status now
+    fn record_status_change(&self, mead_id: i64, previous: Option<&MeadStatus>, status: &MeadStatus) -> Result<()> {
+        let now = Utc::now();
+        self.conn.execute(
+            "INSERT INTO status_history (mead_id, status, changed_at) VALUES (?1, ?2, ?3)",
+            params![mead_id, status.as_str(), now.to_rfc3339()],
+        )?;
+        if let (Some(previous), true) = (previous, self.log_status_changes) {
+            self.create_log_entry(&LogEntry {
+                id: 0,
+                mead_id,
+                timestamp: now,
+                entry_text: format!("Status: {} → {}", previous.as_str(), status.as_str()),
+                entry_type: LogEntryType::Action,
+            })?;
+        }
+        self.snapshot_bottling(mead_id, previous, status)
+    }
+
+    /// Record the mead's gravity and ABV on reaching Bottled. Finished
+    /// keeps the Bottled snapshot and only takes one if Bottled was skipped,
+    /// and a mead created as Bottled keeps any values it was given.
+    fn snapshot_bottling(&self, mead_id: i64, previous: Option<&MeadStatus>, status: &MeadStatus) -> Result<()> {
+        let only_if_missing = match status {
+            MeadStatus::Bottled => previous.is_none(),
+            MeadStatus::Finished => true,
+            _ => return Ok(()),
+        };
+        self.conn.execute(
+            "UPDATE meads SET final_gravity = current_gravity,
+                final_abv = (starting_gravity - current_gravity) * 131.25
+            WHERE id = ?1 AND (?2 = 0 OR final_gravity IS NULL)",
+            params![mead_id, only_if_missing],
+        )?;
+        Ok(())
+    }
+
+    /// Get every recorded status change, oldest first
+    pub fn get_all_status_changes(&self) -> Result<Vec<StatusChange>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, mead_id, status, changed_at
+            FROM status_history ORDER BY changed_at"
+        )?;
+
+        let changes = stmt.query_map([], status_change_from_row)?;
+
+        changes.collect()
+    }
+
+    /// Get the status changes for a mead, oldest first
+    pub fn get_status_changes(&self, mead_id: i64) -> Result<Vec<StatusChange>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, mead_id, status, changed_at
+            FROM status_history WHERE mead_id = ?1 ORDER BY changed_at"
+        )?;
+
+        let changes = stmt.query_map(params![mead_id], status_change_from_row)?;
+
+        changes.collect()
+    }
+
+    // ==================== GRAVITY READINGS ====================
+
+    /// Record a gravity reading, tagged with the stage it was taken in. In
+    /// replace mode the day's earlier readings are dropped first.
+    fn record_gravity_reading(&self, mead_id: i64, gravity: f64, temperature: Option<f64>, status: &MeadStatus) -> Result<()> {
+        let now = Utc::now().to_rfc3339();
+        if self.same_day_readings == SameDayReadings::Replace {
+            self.conn.execute(
+                "DELETE FROM gravity_readings
+                WHERE mead_id = ?1 AND date(recorded_at, 'localtime') = date(?2, 'localtime')",
+                params![mead_id, now],
+            )?;
+        }
+        self.conn.execute(
+            "INSERT INTO gravity_readings (mead_id, gravity, temperature, status, recorded_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![mead_id, gravity, temperature, status.as_str(), now],
+        )?;
         Ok(())
     }
+
+    /// Whether a new reading today would sit next to one already taken,
+    /// rather than replacing it
+    pub fn adds_second_reading_today(&self, mead_id: i64) -> Result<bool> {
+        if self.same_day_readings == SameDayReadings::Replace {
+            return Ok(false);
+        }
+        self.conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM gravity_readings
+                WHERE mead_id = ?1 AND date(recorded_at, 'localtime') = date('now', 'localtime'))",
+            params![mead_id],
+            |row| row.get(0),
+        )
+    }
+
+    /// Save a measured gravity with a log entry. A sample temperature, if
+    /// one was given, is kept on the reading even when the gravity is the
+    /// same as the last one.
+    pub fn record_measurement(&self, mead: &Mead, temperature: Option<f64>, entry: &LogEntry) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        self.update_mead_measured(mead, temperature)?;
+        self.create_log_entry(entry)?;
+        tx.commit()
+    }
+
+    /// Correct a gravity reading, then resync the mead's current gravity
+    pub fn update_gravity_reading(&self, id: i64, gravity: f64) -> Result<()> {
+        let mead_id = self.gravity_reading_mead(id)?;
+        self.conn.execute(
+            "UPDATE gravity_readings SET gravity = ?1 WHERE id = ?2",
+            params![gravity, id],
+        )?;
+        self.sync_current_gravity(mead_id)
+    }
+
+    /// Delete a gravity reading, then resync the mead's current gravity
+    pub fn delete_gravity_reading(&self, id: i64) -> Result<()> {
+        let mead_id = self.gravity_reading_mead(id)?;
+        self.conn.execute("DELETE FROM gravity_readings WHERE id = ?1", params![id])?;
+        self.sync_current_gravity(mead_id)
+    }
+
+    fn gravity_reading_mead(&self, id: i64) -> Result<i64> {
+        self.conn.query_row(
+            "SELECT mead_id FROM gravity_readings WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )
+    }
+
+    /// Set a mead's current gravity to its latest reading. With no readings
+    /// left the current gravity is kept as it is.
+    pub fn sync_current_gravity(&self, mead_id: i64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE meads SET current_gravity = (
+                SELECT gravity FROM gravity_readings WHERE mead_id = ?1
+                ORDER BY recorded_at DESC, id DESC LIMIT 1
+            ), updated_at = ?2
+            WHERE id = ?1 AND EXISTS (SELECT 1 FROM gravity_readings WHERE mead_id = ?1)",
+            params![mead_id, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Get a mead's gravity readings, oldest first
+    pub fn get_gravity_readings(&self, mead_id: i64) -> Result<Vec<GravityReading>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, mead_id, gravity, status, recorded_at, temperature
+            FROM gravity_readings WHERE mead_id = ?1 ORDER BY recorded_at, id"
+        )?;
+
+        let readings = stmt.query_map(params![mead_id], gravity_reading_from_row)?;
+        readings.collect()
+    }
+
+    /// Get every mead's gravity readings, oldest first
+    pub fn get_all_gravity_readings(&self) -> Result<Vec<GravityReading>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, mead_id, gravity, status, recorded_at, temperature
+            FROM gravity_readings ORDER BY recorded_at, id"
+        )?;
+
+        let readings = stmt.query_map([], gravity_reading_from_row)?;
+        readings.collect()
+    }
+
+    // ==================== ACTIVITY ====================
+
+    /// Get one page of log entries, gravity readings and status changes
+    /// across all meads that pass `filter`, newest first
+    pub fn get_activity(&self, filter: &ActivityFilter, limit: usize, offset: usize) -> Result<Vec<Activity>> {
+        let sql = format!(
+            "SELECT kind, mead_id, name, text, gravity, at, entry_type FROM ({}) {} ORDER BY at DESC LIMIT ?7 OFFSET ?8",
+            ACTIVITY_UNION, ACTIVITY_WHERE
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let (mead_id, [since, until, kind, entry_type, query]) = activity_params(filter);
+        let activity = stmt.query_map(
+            params![mead_id, since, until, kind, entry_type, query, limit as i64, offset as i64],
+            |row| {
+                let kind = match row.get::<_, String>(0)?.as_str() {
+                    "log" => ActivityKind::Log,
+                    "gravity" => ActivityKind::Gravity,
+                    _ => ActivityKind::Status,
+                };
+                let text = match row.get::<_, Option<f64>>(4)? {
+                    Some(gravity) => fmt_gravity(gravity),
+                    None => row.get(3)?,
+                };
+                Ok(Activity {
+                    mead_id: row.get(1)?,
+                    mead_name: row.get(2)?,
+                    kind,
+                    entry_type: row.get::<_, Option<String>>(6)?.map(|t| LogEntryType::from_str(&t)),
+                    text,
+                    at: parse_timestamp(&row.get::<_, String>(5)?),
+                })
+            },
+        )?;
+
+        activity.collect()
+    }
+
+    /// Count the events that pass `filter`, for paging through them
+    pub fn count_activity(&self, filter: &ActivityFilter) -> Result<usize> {
+        let sql = format!("SELECT COUNT(*) FROM ({}) {}", ACTIVITY_UNION, ACTIVITY_WHERE);
+        let (mead_id, [since, until, kind, entry_type, query]) = activity_params(filter);
+        let count: i64 = self.conn.query_row(
+            &sql,
+            params![mead_id, since, until, kind, entry_type, query],
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
+    // ==================== SNAPSHOTS ====================
+
+    /// Save the mead's current state under a label. Returns the snapshot id.
+    pub fn create_snapshot(&self, mead: &Mead, label: &str) -> Result<i64> {
+        let data = serde_json::to_string(mead).map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+        self.conn.execute(
+            "INSERT INTO snapshots (mead_id, label, taken_at, data) VALUES (?1, ?2, ?3, ?4)",
+            params![mead.id, label, Utc::now().to_rfc3339(), data],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Get a mead's snapshots, newest first
+    pub fn get_snapshots(&self, mead_id: i64) -> Result<Vec<MeadSnapshot>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, mead_id, label, taken_at, data
+            FROM snapshots WHERE mead_id = ?1 ORDER BY taken_at DESC, id DESC"
+        )?;
+
+        let snapshots = stmt.query_map(params![mead_id], snapshot_from_row)?;
+
+        snapshots.collect()
+    }
+
+    // ==================== STATS ====================
+
+    /// Honey used per variety by honey batches past planning, most first.
+    /// Varieties differing only in case or spacing are counted together.
+    /// With `since` (YYYY-MM-DD), only batches started on or after it count.
+    pub fn get_honey_usage(&self, since: Option<&str>) -> Result<Vec<HoneyUsage>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT MIN(TRIM(honey_type)), SUM(honey_amount_lbs), COUNT(*)
+            FROM meads
+            WHERE base_type = 'Honey' AND status != 'Planning'
+                AND (?1 IS NULL OR start_date >= ?1)
+            GROUP BY LOWER(TRIM(honey_type))
+            ORDER BY SUM(honey_amount_lbs) DESC, LOWER(TRIM(honey_type))"
+        )?;
+
+        let usage = stmt.query_map(params![since], |row| {
+            Ok(HoneyUsage {
+                honey_type: row.get(0)?,
+                lbs: row.get(1)?,
+                batches: row.get::<_, i64>(2)? as usize,
+            })
+        })?;
+        usage.collect()
+    }
+
+    /// ABV of each bottled or finished batch, as recorded at bottling when
+    /// it was. With `since` (YYYY-MM-DD), only batches started on or after
+    /// it count.
+    pub fn get_finished_abvs(&self, since: Option<&str>) -> Result<Vec<f64>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT COALESCE(final_abv, (starting_gravity - current_gravity) * 131.25)
+            FROM meads
+            WHERE status IN ('Bottled', 'Finished')
+                AND (?1 IS NULL OR start_date >= ?1)"
+        )?;
+
+        let abvs = stmt.query_map(params![since], |row| row.get(0))?;
+        abvs.collect()
+    }
+}
+
+fn gravity_reading_from_row(row: &Row) -> Result<GravityReading> {
+    Ok(GravityReading {
+        id: row.get(0)?,
+        mead_id: row.get(1)?,
+        gravity: row.get(2)?,
+        temperature: row.get(5)?,
+        status: MeadStatus::from_str(&row.get::<_, String>(3)?),
+        recorded_at: parse_timestamp(&row.get::<_, String>(4)?),
+    })
+}
+
+/// Build a status change from an `id, mead_id, status, changed_at` row
+fn status_change_from_row(row: &Row) -> Result<StatusChange> {
+    Ok(StatusChange {
+        id: row.get(0)?,
+        mead_id: row.get(1)?,
+        status: MeadStatus::from_str(&row.get::<_, String>(2)?),
+        changed_at: parse_timestamp(&row.get::<_, String>(3)?),
+    })
+}
+
+fn snapshot_from_row(row: &Row) -> Result<MeadSnapshot> {
+    let data: String = row.get(4)?;
+    let mead = serde_json::from_str(&data)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(4, rusqlite::types::Type::Text, Box::new(e)))?;
+    Ok(MeadSnapshot {
+        id: row.get(0)?,
+        mead_id: row.get(1)?,
+        label: row.get(2)?,
+        taken_at: parse_timestamp(&row.get::<_, String>(3)?),
+        mead,
+    })
+}
+
+/// Build a mead from a row selected with `MEAD_COLUMNS`
+fn mead_from_row(row: &Row) -> Result<Mead> {
+    Ok(Mead {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        start_date: row.get(2)?,
+        honey_type: row.get(3)?,
+        honey_amount_lbs: row.get(4)?,
+        yeast_strain: row.get(5)?,
+        target_abv: row.get(6)?,
+        starting_gravity: row.get(7)?,
+        current_gravity: row.get(8)?,
+        yan_required: row.get(9)?,
+        yan_added: row.get(10)?,
+        volume_gallons: row.get(11)?,
+        status: MeadStatus::from_str(&row.get::<_, String>(12)?),
+        notes: row.get(13)?,
+        created_at: parse_timestamp(&row.get::<_, String>(14)?),
+        updated_at: parse_timestamp(&row.get::<_, String>(15)?),
+        pinned: row.get(16)?,
+        target_date: row.get(17)?,
+        final_gravity: row.get(18)?,
+        final_abv: row.get(19)?,
+        auto_reminders: row.get(20)?,
+        base_type: BaseType::from_str(&row.get::<_, String>(21)?),
+        image_path: row.get(22)?,
+        bottled_date: row.get(23)?,
+        bottle_count: row.get(24)?,
+        gravity_check_days: row.get(25)?,
+        ph: row.get(26)?,
+        ta: row.get(27)?,
+        competition: row.get(28)?,
+        competition_category: row.get(29)?,
+        competition_score: row.get(30)?,
+        judge_notes: row.get(31)?,
+        rating: row.get(32)?,
+        next_action: row.get(33)?,
+    })
+}
+
+/// Parse a stored RFC 3339 timestamp, falling back to now if malformed
+fn parse_timestamp(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
+
+/// Prefix and extension of startup backup file names
+const BACKUP_PREFIX: &str = "mead_tracker-";
+const BACKUP_EXTENSION: &str = ".db";
+
+/// Back up a non-empty database into `backups/` (`backups/<profile>/` for
+/// other profiles), keeping only the newest `keep` copies; 0 keeps all
+fn startup_backup(db_path: &Path, profile: &str, keep: usize) -> std::io::Result<()> {
+    if std::fs::metadata(db_path)?.len() == 0 {
+        return Ok(());
+    }
+    let mut dir = db_path.with_file_name("backups");
+    if profile != DEFAULT_PROFILE {
+        dir.push(profile);
+    }
+    std::fs::create_dir_all(&dir)?;
+    let name = format!("{}{}{}", BACKUP_PREFIX, Utc::now().format("%Y%m%d-%H%M%S"), BACKUP_EXTENSION);
+    std::fs::copy(db_path, dir.join(name))?;
+
+    let names: Vec<String> = std::fs::read_dir(&dir)?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .collect();
+    for name in backups_to_prune(names, keep) {
+        std::fs::remove_file(dir.join(name))?;
+    }
+    Ok(())
+}
+
+/// Backup file names beyond the newest `keep`, or none when `keep` is 0.
+/// Timestamps sort by name, so the oldest names come first. Files that
+/// aren't backups are left alone.
+fn backups_to_prune(names: Vec<String>, keep: usize) -> Vec<String> {
+    if keep == 0 {
+        return Vec::new();
+    }
+    let mut backups: Vec<String> = names
+        .into_iter()
+        .filter(|n| n.starts_with(BACKUP_PREFIX) && n.ends_with(BACKUP_EXTENSION))
+        .collect();
+    backups.sort();
+    let excess = backups.len().saturating_sub(keep);
+    backups.truncate(excess);
+    backups
+}
+
+/// Whether `name` can be used as a profile: letters, digits, `-` and `_`
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Database file name for a profile: `mead_tracker.db` for the default,
+/// `mead_tracker-<profile>.db` for the rest
+pub fn profile_file_name(profile: &str) -> String {
+    if profile == DEFAULT_PROFILE {
+        "mead_tracker.db".to_string()
+    } else {
+        format!("mead_tracker-{}.db", profile)
+    }
+}
+
+/// Profiles with a database file in the data directory `dir`, default first
+pub fn list_profiles(dir: &Path) -> Vec<String> {
+    let names = std::fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|entry| entry.ok()?.file_name().into_string().ok()).collect())
+        .unwrap_or_default();
+    profiles_from_names(names)
+}
+
+/// Profile names among data directory file names, sorted after the default
+/// profile, which is always listed
+fn profiles_from_names(names: Vec<String>) -> Vec<String> {
+    let mut profiles: Vec<String> = names
+        .iter()
+        .filter_map(|name| name.strip_prefix("mead_tracker-")?.strip_suffix(".db"))
+        .filter(|profile| is_valid_profile_name(profile) && *profile != DEFAULT_PROFILE)
+        .map(str::to_string)
+        .collect();
+    profiles.sort();
+    profiles.insert(0, DEFAULT_PROFILE.to_string());
+    profiles
 }
 
-/// Get the data directory for the application
-fn dirs_next() -> Option<PathBuf> {
-    // Try to get the user's data directory, fall back to current directory
-    std::env::var("HOME")
-        .ok()
-        .map(|home| {
-            let mut path = PathBuf::from(home);
-            path.push(".local");
-            path.push("share");
-            path.push("mead_tracker");
-            // Create directory if it doesn't exist
-            let _ = std::fs::create_dir_all(&path);
-            path
+/// Get the data directory for the application, creating it if needed.
+///
+/// Uses the platform's usual location (XDG data dir on Linux, Application
+/// Support on macOS, APPDATA on Windows). A database already living in the
+/// old `~/.local/share/mead_tracker` path keeps being used from there.
+pub fn data_dir() -> PathBuf {
+    let env = |key: &str| std::env::var(key).ok().filter(|v| !v.is_empty());
+    let legacy = legacy_data_dir(&env);
+    let path = match &legacy {
+        Some(legacy) if legacy.join("mead_tracker.db").exists() => legacy.clone(),
+        _ => platform_data_dir(std::env::consts::OS, &env)
+            .or(legacy)
+            .unwrap_or_else(|| PathBuf::from(".")),
+    };
+    // Create directory if it doesn't exist
+    let _ = std::fs::create_dir_all(&path);
+    path
+}
+
+/// Resolve the platform data directory from environment variables
+fn platform_data_dir(os: &str, env: &dyn Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    let base = match os {
+        "windows" => env("APPDATA").map(PathBuf::from),
+        "macos" => env("HOME").map(|home| PathBuf::from(home).join("Library").join("Application Support")),
+        _ => env("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env("HOME").map(|home| PathBuf::from(home).join(".local").join("share"))),
+    }?;
+    Some(base.join("mead_tracker"))
+}
+
+/// The `$HOME/.local/share/mead_tracker` path used by earlier versions
+fn legacy_data_dir(env: &dyn Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    env("HOME").map(|home| PathBuf::from(home).join(".local").join("share").join("mead_tracker"))
+}
+
+/// Mount points usually holding network shares or removable drives
+const NON_LOCAL_PREFIXES: [&str; 6] = ["/mnt/", "/media/", "/run/media/", "/Volumes/", "/net/", "/nfs/"];
+/// Filesystem types in `/proc/mounts` that are reached over a network
+const NETWORK_FILESYSTEMS: [&str; 7] = ["nfs", "nfs4", "cifs", "smbfs", "smb3", "fuse.sshfs", "afs"];
+
+/// Whether `path` looks like it is on a network share or removable drive,
+/// where SQLite's file locking can't be relied on. A guess only: Windows
+/// UNC paths, the usual mount points, and on Linux anything `/proc/mounts`
+/// lists on a network filesystem.
+pub fn looks_non_local(path: &Path) -> bool {
+    let mounts = std::fs::read_to_string("/proc/mounts").unwrap_or_default();
+    non_local_path(&path.to_string_lossy(), &mounts)
+}
+
+/// `looks_non_local` on a path string and the text of `/proc/mounts`
+fn non_local_path(path: &str, mounts: &str) -> bool {
+    if path.starts_with("\\\\") || path.starts_with("//") || NON_LOCAL_PREFIXES.iter().any(|p| path.starts_with(p)) {
+        return true;
+    }
+    // The deepest mount containing the path decides
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace().skip(1);
+            Some((fields.next()?, fields.next()?))
         })
+        .filter(|(point, _)| {
+            path.strip_prefix(point)
+                .is_some_and(|rest| point.ends_with('/') || rest.is_empty() || rest.starts_with('/'))
+        })
+        .max_by_key(|(point, _)| point.len())
+        .is_some_and(|(_, kind)| NETWORK_FILESYSTEMS.contains(&kind))
+}
+
+/// A stored value written as an SQL literal, with quotes in text doubled
+fn sql_literal(value: ValueRef) -> String {
+    match value {
+        ValueRef::Null => "NULL".to_string(),
+        ValueRef::Integer(i) => i.to_string(),
+        // SQL has no literal for infinity; 9e999 overflows back to it
+        ValueRef::Real(f) if f.is_nan() => "NULL".to_string(),
+        ValueRef::Real(f) if f.is_infinite() => if f > 0.0 { "9e999" } else { "-9e999" }.to_string(),
+        ValueRef::Real(f) => format!("{:?}", f),
+        ValueRef::Text(text) => format!("'{}'", String::from_utf8_lossy(text).replace('\'', "''")),
+        ValueRef::Blob(bytes) => {
+            format!("X'{}'", bytes.iter().map(|b| format!("{:02X}", b)).collect::<String>())
+        }
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{abv_histogram, batches_by_month, mead_changes, status_durations};
+    use std::collections::HashMap;
+
+    #[test]
+    fn finished_abvs_fill_the_histogram() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let batches = [
+            (MeadStatus::Finished, 1.050),
+            (MeadStatus::Bottled, 1.100),
+            (MeadStatus::Finished, 1.110),
+            (MeadStatus::Primary, 1.150),
+        ];
+        for (status, starting_gravity) in batches {
+            let mead = Mead {
+                status,
+                starting_gravity,
+                current_gravity: 1.000,
+                ..Default::default()
+            };
+            db.create_mead(&mead).unwrap();
+        }
+
+        let abvs = db.get_finished_abvs(None).unwrap();
+        assert_eq!(abvs.len(), 3);
+        let counts: Vec<usize> = abv_histogram(&abvs).iter().map(|b| b.batches).collect();
+        assert_eq!(counts, vec![1, 0, 2, 0]);
+    }
+
+    #[test]
+    fn batches_are_counted_by_the_month_they_started() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        for start_date in ["2026-10-02", "2026-10-14", "2026-03-31", "2025-11-01", "2025-10-31", "", "someday"] {
+            db.create_mead(&Mead { start_date: start_date.to_string(), ..Default::default() }).unwrap();
+        }
+
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let months = batches_by_month(&db.get_all_meads().unwrap(), today);
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0].month, "2025-11");
+        assert_eq!(months[11].month, "2026-10");
+        let counts: Vec<usize> = months.iter().map(|m| m.batches).collect();
+        assert_eq!(counts, vec![1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2], "last October and bad dates are left out");
+    }
+
+    #[test]
+    fn same_day_readings_append_or_replace_as_configured() {
+        for (mode, readings) in [(SameDayReadings::Append, 3), (SameDayReadings::Replace, 2)] {
+            let db = Database::open_in_memory(&Config { same_day_readings: mode, ..Config::default() }).unwrap();
+            let mut mead = Mead { current_gravity: 1.100, ..Default::default() };
+            mead.id = db.create_mead(&mead).unwrap();
+            // A reading from yesterday is never replaced
+            let yesterday = (Utc::now() - chrono::Duration::days(1)).to_rfc3339();
+            db.conn
+                .execute("UPDATE gravity_readings SET recorded_at = ?1", params![yesterday])
+                .unwrap();
+            assert!(!db.adds_second_reading_today(mead.id).unwrap());
+
+            for gravity in [1.080, 1.070] {
+                db.update_mead(&Mead { current_gravity: gravity, ..mead.clone() }).unwrap();
+            }
+            assert_eq!(db.adds_second_reading_today(mead.id).unwrap(), mode == SameDayReadings::Append);
+            let gravities: Vec<f64> = db.get_gravity_readings(mead.id).unwrap().iter().map(|r| r.gravity).collect();
+            assert_eq!(gravities.len(), readings);
+            assert_eq!(gravities.last(), Some(&1.070));
+        }
+        assert_eq!(Config::parse("same_day_readings = Replace").same_day_readings, SameDayReadings::Replace);
+    }
+
+    #[test]
+    fn activity_is_filtered_by_mead_dates_type_and_text_a_page_at_a_time() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let traditional = db.create_mead(&Mead { name: "Traditional".to_string(), ..Default::default() }).unwrap();
+        let cyser = db.create_mead(&Mead { name: "Cyser".to_string(), ..Default::default() }).unwrap();
+        for (mead_id, day, entry_type, text) in [
+            (traditional, "2025-03-01", LogEntryType::Addition, "Added 2g Fermaid O"),
+            (traditional, "2025-03-10", LogEntryType::Observation, "Smells 50% banana"),
+            (cyser, "2025-04-01", LogEntryType::Addition, "Added apple juice"),
+        ] {
+            let timestamp = parse_timestamp(&format!("{}T12:00:00+00:00", day));
+            let entry = LogEntry { mead_id, timestamp, entry_type, entry_text: text.to_string(), ..Default::default() };
+            db.create_log_entry(&entry).unwrap();
+        }
+        let texts = |filter: &ActivityFilter| -> Vec<String> {
+            db.get_activity(filter, 10, 0).unwrap().into_iter().map(|a| a.text).collect()
+        };
+
+        let additions = ActivityFilter { activity_type: Some(ActivityType::Log(LogEntryType::Addition)), ..Default::default() };
+        assert_eq!(texts(&additions), ["Added apple juice", "Added 2g Fermaid O"]);
+        let traditional_additions = ActivityFilter { mead: Some((traditional, "Traditional".to_string())), ..additions.clone() };
+        assert_eq!(texts(&traditional_additions), ["Added 2g Fermaid O"]);
+
+        let early_march = ActivityFilter {
+            since: NaiveDate::from_ymd_opt(2025, 3, 2),
+            until: NaiveDate::from_ymd_opt(2025, 3, 10),
+            ..Default::default()
+        };
+        assert_eq!(texts(&early_march), ["Smells 50% banana"], "the last day counts in full");
+
+        let search = |query: &str| texts(&ActivityFilter { query: query.to_string(), ..Default::default() });
+        assert_eq!(search("fermaid"), ["Added 2g Fermaid O"]);
+        assert_eq!(search("%"), ["Smells 50% banana"], "% is matched literally");
+        assert!(search("cyser").contains(&"Added apple juice".to_string()), "mead names are searched too");
+
+        let logs: Vec<ActivityFilter> = LogEntryType::all()
+            .into_iter()
+            .map(|t| ActivityFilter { activity_type: Some(ActivityType::Log(t)), ..Default::default() })
+            .collect();
+        let log_count: usize = logs.iter().map(|f| db.count_activity(f).unwrap()).sum();
+        assert_eq!(log_count, 3);
+        let everything = ActivityFilter::default();
+        let total = db.count_activity(&everything).unwrap();
+        assert!(total > 3, "readings and status changes are included");
+        assert_eq!(db.get_activity(&everything, 2, total - 1).unwrap().len(), 1);
+        assert_eq!(db.get_activity(&additions, 10, 0).unwrap()[0].entry_type, Some(LogEntryType::Addition));
+    }
+
+    #[test]
+    fn status_durations_cover_stays_that_have_ended() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let ids = [db.create_mead(&Mead::default()).unwrap(), db.create_mead(&Mead::default()).unwrap()];
+        db.conn.execute("DELETE FROM status_history", []).unwrap();
+        let history = [
+            (ids[0], "Primary", "2025-03-01"),
+            (ids[0], "Secondary", "2025-03-15"),
+            (ids[0], "Aging", "2025-04-15"),
+            (ids[1], "Primary", "2026-01-01"),
+            (ids[1], "Secondary", "2026-01-21"),
+        ];
+        for (id, status, date) in history {
+            db.conn
+                .execute(
+                    "INSERT INTO status_history (mead_id, status, changed_at) VALUES (?1, ?2, ?3)",
+                    params![id, status, format!("{}T00:00:00+00:00", date)],
+                )
+                .unwrap();
+        }
+
+        let changes = db.get_all_status_changes().unwrap();
+        let durations = status_durations(&changes, None);
+        assert_eq!(durations.len(), 2, "statuses nobody has left are skipped");
+        assert_eq!(durations[0].status, MeadStatus::Primary);
+        assert_eq!(durations[0].stays, 2);
+        assert_eq!(durations[0].average_days, 17.0);
+        assert_eq!(durations[0].median_days, 17.0);
+        assert_eq!(durations[1].status, MeadStatus::Secondary);
+        assert_eq!(durations[1].average_days, 31.0);
+
+        let this_year = status_durations(&changes, Some("2026-01-01"));
+        assert_eq!(this_year.len(), 1);
+        assert_eq!(this_year[0].average_days, 20.0);
+    }
+
+    #[test]
+    fn meads_copy_across_databases_with_their_records() {
+        let club = Database::open_in_memory(&Config::default()).unwrap();
+        let mut mead = Mead { name: "Cyser".to_string(), ph: Some(3.4), rating: Some(4), ..Default::default() };
+        let cyser = club.create_mead(&mead).unwrap();
+        mead.id = cyser;
+        club.create_mead(&Mead { name: "Melomel".to_string(), ..Default::default() }).unwrap();
+        club.create_ingredient(&Ingredient { mead_id: cyser, name: "Apple Juice".to_string(), ..Default::default() })
+            .unwrap();
+        let primary = Mead { status: MeadStatus::Primary, current_gravity: 1.060, ..mead };
+        club.update_mead(&primary).unwrap();
+        club.finish_mead(
+            &Mead { status: MeadStatus::Finished, final_gravity: Some(1.002), bottle_count: Some(12), ..primary },
+            &LogEntry { mead_id: cyser, ..Default::default() },
+        )
+        .unwrap();
+
+        let home = Database::open_in_memory(&Config::default()).unwrap();
+        assert_eq!(home.copy_meads_from(&club, &[cyser]).unwrap(), 1);
+        let copied = home.get_all_meads().unwrap();
+        assert_eq!(copied.len(), 1);
+        assert_eq!(copied[0].name, "Cyser");
+        assert_eq!((copied[0].ph, copied[0].rating), (Some(3.4), Some(4)));
+        assert_eq!((copied[0].final_gravity, copied[0].bottle_count), (Some(1.002), Some(12)));
+        let ingredients = home.get_ingredients(copied[0].id).unwrap();
+        assert_eq!(ingredients[0].name, "Apple Juice");
+        let gravities: Vec<f64> = home.get_gravity_readings(copied[0].id).unwrap().into_iter().map(|r| r.gravity).collect();
+        assert_eq!(gravities, vec![1.100, 1.060]);
+        let statuses: Vec<MeadStatus> = home.get_status_changes(copied[0].id).unwrap().into_iter().map(|c| c.status).collect();
+        assert_eq!(statuses, vec![MeadStatus::Planning, MeadStatus::Primary, MeadStatus::Finished]);
+        assert_eq!(club.get_all_meads().unwrap().len(), 2, "the source keeps its batches");
+    }
+
+    #[test]
+    fn replace_import_round_trips_history_and_bottling() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let mut mead = Mead { name: "Sack Mead".to_string(), starting_gravity: 1.130, current_gravity: 1.130, ..Default::default() };
+        mead.id = db.create_mead(&mead).unwrap();
+        db.create_snapshot(&mead, "as planned").unwrap();
+        db.record_measurement(
+            &Mead { current_gravity: 1.020, ..mead.clone() },
+            Some(18.5),
+            &LogEntry { mead_id: mead.id, entry_text: "SG 1.020".to_string(), ..Default::default() },
+        )
+        .unwrap();
+        let bottled = Mead {
+            current_gravity: 1.020,
+            status: MeadStatus::Bottled,
+            final_gravity: Some(1.018),
+            final_abv: Some(14.4),
+            bottled_date: "2026-09-01".to_string(),
+            bottle_count: Some(24),
+            ..mead.clone()
+        };
+        db.finish_mead(&bottled, &LogEntry { mead_id: mead.id, ..Default::default() }).unwrap();
+
+        let json = serde_json::to_string(&db.get_records(None).unwrap()).unwrap();
+        let records: Vec<MeadRecord> = serde_json::from_str(&json).unwrap();
+        assert_eq!(db.import_records(&records, ImportMode::Replace).unwrap(), 1);
+
+        let restored = db.get_all_meads().unwrap().remove(0);
+        assert_ne!(restored.id, mead.id);
+        assert_eq!((restored.final_gravity, restored.final_abv), (Some(1.018), Some(14.4)));
+        assert_eq!((restored.bottled_date.as_str(), restored.bottle_count), ("2026-09-01", Some(24)));
+        let readings = db.get_gravity_readings(restored.id).unwrap();
+        assert_eq!(readings.len(), records[0].gravity_readings.len());
+        assert!(readings.iter().any(|r| r.temperature == Some(18.5)));
+        let statuses: Vec<MeadStatus> = db.get_status_changes(restored.id).unwrap().into_iter().map(|c| c.status).collect();
+        assert_eq!(statuses, vec![MeadStatus::Planning, MeadStatus::Bottled]);
+        let snapshots = db.get_snapshots(restored.id).unwrap();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!((snapshots[0].label.as_str(), snapshots[0].mead.starting_gravity), ("as planned", 1.130));
+    }
+
+    #[test]
+    fn importing_records_without_history_starts_one() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let record = MeadRecord {
+            mead: Mead { name: "Old Export".to_string(), status: MeadStatus::Secondary, current_gravity: 1.010, ..Default::default() },
+            ..Default::default()
+        };
+
+        db.import_records(&[record], ImportMode::Append).unwrap();
+        let id = db.get_all_meads().unwrap()[0].id;
+        let changes = db.get_status_changes(id).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].status, MeadStatus::Secondary);
+        let readings = db.get_gravity_readings(id).unwrap();
+        assert_eq!(readings.len(), 1);
+        assert_eq!(readings[0].gravity, 1.010);
+    }
+
+    #[test]
+    fn network_and_removable_paths_are_flagged() {
+        let mounts = "/dev/sda1 / ext4 rw 0 0\nserver:/export /home/sam/share nfs4 rw 0 0\n";
+        assert!(non_local_path("/home/sam/share/mead_tracker/mead_tracker.db", mounts));
+        assert!(!non_local_path("/home/sam/shared/mead_tracker.db", mounts));
+        assert!(!non_local_path("/home/sam/.local/share/mead_tracker/mead_tracker.db", mounts));
+        assert!(non_local_path("/media/usb/mead_tracker.db", ""));
+        assert!(non_local_path("\\\\nas\\brewing\\mead_tracker.db", ""));
+    }
+
+    #[test]
+    fn merging_moves_records_to_the_target_and_deletes_the_source() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let target = db.create_mead(&Mead { name: "Cyser".to_string(), ..Default::default() }).unwrap();
+        let source = db.create_mead(&Mead { name: "Cyser again".to_string(), ..Default::default() }).unwrap();
+        for (mead_id, name) in [(target, "Apple Juice"), (source, "Clover Honey")] {
+            db.create_ingredient(&Ingredient { mead_id, name: name.to_string(), ..Default::default() }).unwrap();
+            db.create_log_entry(&LogEntry { mead_id, entry_text: name.to_string(), ..Default::default() }).unwrap();
+        }
+
+        let measured = db.get_mead(source).unwrap().unwrap();
+        db.update_mead(&Mead { current_gravity: 1.020, ..measured }).unwrap();
+
+        db.merge_meads(target, source).unwrap();
+        assert!(db.get_mead(source).unwrap().is_none());
+        let mead = db.get_mead(target).unwrap().unwrap();
+        assert_eq!(mead.name, "Cyser");
+        assert_eq!(mead.current_gravity, 1.020, "the source's latest reading is now the target's");
+        let names: Vec<String> = db.get_ingredients(target).unwrap().into_iter().map(|i| i.name).collect();
+        assert_eq!(names, vec!["Apple Juice", "Clover Honey"]);
+        assert_eq!(db.get_log_entries(target, true).unwrap().len(), 2);
+        assert_eq!(db.get_gravity_readings(target).unwrap().len(), 2);
+        assert_eq!(db.get_status_changes(target).unwrap().len(), 1, "the source's history goes with it");
+    }
+
+    #[test]
+    fn copied_ingredients_follow_the_existing_ones() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let source = db.create_mead(&Mead::default()).unwrap();
+        let target = db.create_mead(&Mead::default()).unwrap();
+        for (mead_id, name) in [(source, "Blackberries"), (source, "Fermaid O"), (target, "Vanilla")] {
+            let ingredient = Ingredient {
+                mead_id,
+                name: name.to_string(),
+                ..Default::default()
+            };
+            db.create_ingredient(&ingredient).unwrap();
+        }
+
+        assert_eq!(db.copy_ingredients(source, target).unwrap(), 2);
+        let names: Vec<String> = db.get_ingredients(target).unwrap().into_iter().map(|i| i.name).collect();
+        assert_eq!(names, vec!["Vanilla", "Fermaid O", "Blackberries"]);
+        assert_eq!(db.get_ingredients(source).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn duplicated_ingredients_keep_their_dates_only_when_asked() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let source = db.create_mead(&Mead { start_date: "2025-03-01".to_string(), ..Default::default() }).unwrap();
+        for (name, added_date) in [("Blackberries", "2025-03-01"), ("Oak Cubes", "2025-05-20")] {
+            let ingredient = Ingredient {
+                mead_id: source,
+                name: name.to_string(),
+                added_date: added_date.to_string(),
+                ..Default::default()
+            };
+            db.create_ingredient(&ingredient).unwrap();
+        }
+        let dates = |parts: &[CopyPart]| -> Vec<(String, String)> {
+            let copy = db.duplicate_mead(source, &parts.iter().copied().collect()).unwrap();
+            let start = db.get_mead(copy).unwrap().unwrap().start_date;
+            assert_ne!(start, "2025-03-01");
+            db.get_ingredients(copy).unwrap().into_iter().map(|i| (i.name, i.added_date.replace(&start, "start"))).collect()
+        };
+
+        let reset = dates(&[CopyPart::Ingredients]);
+        assert_eq!(reset, vec![("Oak Cubes".to_string(), "start".to_string()), ("Blackberries".to_string(), "start".to_string())]);
+        let kept = dates(&[CopyPart::Ingredients, CopyPart::IngredientDates]);
+        assert_eq!(kept[0], ("Oak Cubes".to_string(), "2025-05-20".to_string()));
+    }
+
+    #[test]
+    fn non_finite_reals_dump_as_values_sqlite_reads() {
+        assert_eq!(sql_literal(ValueRef::Real(f64::NAN)), "NULL");
+        assert_eq!(sql_literal(ValueRef::Real(1.05)), "1.05");
+        let copy = Connection::open_in_memory().unwrap();
+        for value in [f64::INFINITY, f64::NEG_INFINITY] {
+            let read: f64 = copy.query_row(&format!("SELECT {}", sql_literal(ValueRef::Real(value))), [], |row| row.get(0)).unwrap();
+            assert_eq!(read, value);
+        }
+    }
+
+    #[test]
+    fn sql_dump_recreates_the_database() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let id = db.create_mead(&Mead { name: "Bee's Knees".to_string(), ..Default::default() }).unwrap();
+        db.create_ingredient(&Ingredient { mead_id: id, name: "Orange Zest".to_string(), ..Default::default() }).unwrap();
+
+        let (sql, rows) = db.dump_sql().unwrap();
+        assert!(sql.contains("'Bee''s Knees'"));
+        assert!(rows >= 3, "{}", rows);
+
+        let copy = Connection::open_in_memory().unwrap();
+        copy.execute_batch(&sql).unwrap();
+        let name: String = copy.query_row("SELECT name FROM meads WHERE id = ?1", params![id], |row| row.get(0)).unwrap();
+        assert_eq!(name, "Bee's Knees");
+        let gravity: f64 = copy.query_row("SELECT starting_gravity FROM meads", [], |row| row.get(0)).unwrap();
+        assert_eq!(gravity, 1.100);
+        let ingredients: i64 = copy.query_row("SELECT COUNT(*) FROM ingredients", [], |row| row.get(0)).unwrap();
+        assert_eq!(ingredients, 1);
+    }
+
+    #[test]
+    fn records_can_be_limited_to_chosen_meads() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let ids: Vec<i64> = ["Cyser", "Pyment", "Show Mead"]
+            .into_iter()
+            .map(|name| db.create_mead(&Mead { name: name.to_string(), ..Default::default() }).unwrap())
+            .collect();
+
+        assert_eq!(db.get_records(None).unwrap().len(), 3);
+        let chosen = db.get_records(Some(&[ids[2], ids[0]])).unwrap();
+        let names: Vec<&str> = chosen.iter().map(|r| r.mead.name.as_str()).collect();
+        assert_eq!(names, vec!["Show Mead", "Cyser"]);
+    }
+
+    #[test]
+    fn snapshots_keep_the_mead_as_it_was() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let mut mead = Mead {
+            name: "Orange Blossom".to_string(),
+            honey_amount_lbs: 3.0,
+            ..Default::default()
+        };
+        mead.id = db.create_mead(&mead).unwrap();
+        db.create_snapshot(&mead, "as planned").unwrap();
+
+        mead.honey_amount_lbs = 3.5;
+        mead.current_gravity = 1.020;
+        db.update_mead(&mead).unwrap();
+
+        let snapshots = db.get_snapshots(mead.id).unwrap();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].label, "as planned");
+        let changes = mead_changes(&snapshots[0].mead, &db.get_mead(mead.id).unwrap().unwrap());
+        let fields: Vec<&str> = changes.iter().map(|c| c.field).collect();
+        assert_eq!(fields, vec!["Honey Amount", "Current Gravity"]);
+        assert_eq!(changes[0].before, "3.00 lbs");
+    }
+
+    #[test]
+    fn prunes_oldest_backups_beyond_retention() {
+        let names = vec![
+            "mead_tracker-20240103-090000.db".to_string(),
+            "notes.txt".to_string(),
+            "mead_tracker-20240101-090000.db".to_string(),
+            "mead_tracker-20240102-090000.db".to_string(),
+        ];
+        assert_eq!(backups_to_prune(names.clone(), 2), vec!["mead_tracker-20240101-090000.db"]);
+        assert_eq!(backups_to_prune(names.clone(), 5), Vec::<String>::new());
+        assert_eq!(backups_to_prune(names.clone(), 1).len(), 2);
+        assert_eq!(backups_to_prune(names, 0), Vec::<String>::new(), "0 keeps every backup");
+    }
+
+    #[test]
+    fn profiles_are_found_by_database_file_name() {
+        let names = vec![
+            "mead_tracker.db".to_string(),
+            "mead_tracker-club.db".to_string(),
+            "mead_tracker.db.bak-20240101090000".to_string(),
+            "mead_tracker-bad name.db".to_string(),
+            "mead_tracker-comp_2024.db".to_string(),
+            "mead_tracker.conf".to_string(),
+        ];
+        assert_eq!(profiles_from_names(names), vec!["default", "club", "comp_2024"]);
+        assert_eq!(profiles_from_names(Vec::new()), vec!["default"]);
+        assert_eq!(profile_file_name("default"), "mead_tracker.db");
+        assert_eq!(profile_file_name("club"), "mead_tracker-club.db");
+        assert!(!is_valid_profile_name("../club"));
+    }
+
+    fn env_of(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> =
+            vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn linux_prefers_xdg_data_home() {
+        let env = env_of(&[("HOME", "/home/bee"), ("XDG_DATA_HOME", "/data")]);
+        assert_eq!(platform_data_dir("linux", &env), Some(PathBuf::from("/data/mead_tracker")));
+    }
+
+    #[test]
+    fn linux_falls_back_to_local_share() {
+        let env = env_of(&[("HOME", "/home/bee")]);
+        assert_eq!(
+            platform_data_dir("linux", &env),
+            Some(PathBuf::from("/home/bee/.local/share/mead_tracker"))
+        );
+    }
+
+    #[test]
+    fn macos_uses_application_support() {
+        let env = env_of(&[("HOME", "/Users/bee")]);
+        assert_eq!(
+            platform_data_dir("macos", &env),
+            Some(PathBuf::from("/Users/bee/Library/Application Support/mead_tracker"))
+        );
+    }
+
+    #[test]
+    fn windows_uses_appdata() {
+        let env = env_of(&[("APPDATA", "C:/Users/bee/AppData/Roaming"), ("HOME", "C:/Users/bee")]);
+        assert_eq!(
+            platform_data_dir("windows", &env),
+            Some(PathBuf::from("C:/Users/bee/AppData/Roaming").join("mead_tracker"))
+        );
+    }
+
+    #[test]
+    fn windows_without_appdata_has_no_platform_dir() {
+        let env = env_of(&[("HOME", "C:/Users/bee")]);
+        assert_eq!(platform_data_dir("windows", &env), None);
+        assert_eq!(
+            legacy_data_dir(&env),
+            Some(PathBuf::from("C:/Users/bee/.local/share/mead_tracker"))
+        );
+    }
+
+    #[test]
+    fn no_env_resolves_nothing() {
+        let env = env_of(&[]);
+        assert_eq!(platform_data_dir("linux", &env), None);
+        assert_eq!(legacy_data_dir(&env), None);
+    }
+
+    #[test]
+    fn correcting_or_deleting_a_reading_resyncs_the_current_gravity() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let mut mead = Mead { current_gravity: 1.100, ..Default::default() };
+        mead.id = db.create_mead(&mead).unwrap();
+        db.update_mead(&Mead { current_gravity: 1.080, ..mead.clone() }).unwrap();
+        let readings = db.get_gravity_readings(mead.id).unwrap();
+        assert_eq!(readings.len(), 2);
+
+        db.update_gravity_reading(readings[1].id, 1.0825).unwrap();
+        assert_eq!(db.get_gravity_readings(mead.id).unwrap()[1].gravity, 1.0825);
+        assert_eq!(db.get_mead(mead.id).unwrap().unwrap().current_gravity, 1.0825);
+
+        db.delete_gravity_reading(readings[1].id).unwrap();
+        assert_eq!(db.get_gravity_readings(mead.id).unwrap().len(), 1);
+        assert_eq!(db.get_mead(mead.id).unwrap().unwrap().current_gravity, 1.100);
+    }
+
+    #[test]
+    fn reopening_fills_in_only_missing_final_gravities() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let bottled = Mead { status: MeadStatus::Bottled, starting_gravity: 1.1, current_gravity: 1.0, ..Default::default() };
+        let entered = db.create_mead(&bottled).unwrap();
+        let missing = db.create_mead(&bottled).unwrap();
+        db.conn.execute("UPDATE meads SET final_gravity = 1.004, final_abv = 12.5 WHERE id = ?1", [entered]).unwrap();
+        db.conn.execute("UPDATE meads SET final_gravity = NULL, final_abv = NULL WHERE id = ?1", [missing]).unwrap();
+
+        db.migrate().unwrap();
+        let entered = db.get_mead(entered).unwrap().unwrap();
+        assert_eq!((entered.final_gravity, entered.final_abv), (Some(1.004), Some(12.5)));
+        assert_eq!(db.get_mead(missing).unwrap().unwrap().final_gravity, Some(1.0));
+    }
+
+    #[test]
+    fn duplicates_copy_only_the_chosen_parts() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let source = Mead {
+            name: "Traditional".to_string(),
+            honey_type: "Orange Blossom".to_string(),
+            yeast_strain: "71B".to_string(),
+            starting_gravity: 1.112,
+            current_gravity: 1.112,
+            notes: "Step-fed nutrients".to_string(),
+            ..Default::default()
+        };
+        let id = db.create_mead(&source).unwrap();
+        db.create_log_entry(&LogEntry { mead_id: id, entry_text: "Pitched".to_string(), ..Default::default() }).unwrap();
+        db.record_gravity_reading(id, 1.090, None, &MeadStatus::Primary).unwrap();
+
+        let recipe = db.duplicate_mead(id, &[CopyPart::Recipe].into_iter().collect()).unwrap();
+        let copy = db.get_mead(recipe).unwrap().unwrap();
+        assert_eq!(copy.name, "Traditional (copy)");
+        assert_eq!((copy.honey_type.as_str(), copy.yeast_strain.as_str()), ("Orange Blossom", "71B"));
+        assert_eq!(copy.starting_gravity, Mead::default().starting_gravity);
+        assert_eq!(copy.notes, "");
+        assert!(db.get_log_entries(recipe, true).unwrap().is_empty());
+        assert_eq!(db.get_gravity_readings(recipe).unwrap().len(), 1, "only its own creation reading");
+
+        let parts = [CopyPart::GravityTargets, CopyPart::Notes, CopyPart::Logs, CopyPart::Readings];
+        let full = db.duplicate_mead(id, &parts.into_iter().collect()).unwrap();
+        let copy = db.get_mead(full).unwrap().unwrap();
+        assert_eq!((copy.starting_gravity, copy.current_gravity), (1.112, 1.112));
+        assert_eq!(copy.notes, "Step-fed nutrients");
+        assert_eq!(copy.yeast_strain, Mead::default().yeast_strain);
+        assert_eq!(db.get_log_entries(full, true).unwrap()[0].entry_text, "Pitched");
+        assert_eq!(db.get_gravity_readings(id).unwrap().len(), 2, "the source keeps its readings");
+        assert_eq!(db.get_gravity_readings(full).unwrap().len(), 2, "the same readings, no creation reading");
+    }
+
+    #[test]
+    fn honey_usage_groups_varieties_and_skips_plans_and_other_bases() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let batches = [
+            ("Clover", 3.0, MeadStatus::Primary, BaseType::Honey, "2026-05-01"),
+            (" clover ", 2.5, MeadStatus::Finished, BaseType::Honey, "2025-01-10"),
+            ("Orange Blossom", 4.0, MeadStatus::Aging, BaseType::Honey, "2026-02-01"),
+            ("Buckwheat", 9.0, MeadStatus::Planning, BaseType::Honey, "2026-06-01"),
+            ("Clover", 6.0, MeadStatus::Primary, BaseType::Apple, "2026-03-01"),
+        ];
+        for (honey_type, honey_amount_lbs, status, base_type, start_date) in batches {
+            let mead = Mead {
+                honey_type: honey_type.to_string(),
+                honey_amount_lbs,
+                status,
+                base_type,
+                start_date: start_date.to_string(),
+                ..Default::default()
+            };
+            db.create_mead(&mead).unwrap();
+        }
+
+        let usage = db.get_honey_usage(None).unwrap();
+        let rows: Vec<(String, f64, usize)> = usage.iter().map(|u| (u.honey_type.clone(), u.lbs, u.batches)).collect();
+        assert_eq!(rows, vec![("Clover".to_string(), 5.5, 2), ("Orange Blossom".to_string(), 4.0, 1)]);
+
+        let this_year = db.get_honey_usage(Some("2026-01-01")).unwrap();
+        assert_eq!(this_year.iter().map(|u| u.lbs).collect::<Vec<_>>(), vec![4.0, 3.0]);
+    }
+
+    #[test]
+    fn advancing_stops_at_bottled_and_edits_keep_bottling_details() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let ids: Vec<i64> = [MeadStatus::Primary, MeadStatus::Bottled, MeadStatus::Finished]
+            .into_iter()
+            .map(|status| db.create_mead(&Mead { status, ..Default::default() }).unwrap())
+            .collect();
+
+        assert_eq!(db.advance_statuses(&ids).unwrap(), 1);
+        let statuses: Vec<MeadStatus> = ids.iter().map(|id| db.get_mead(*id).unwrap().unwrap().status).collect();
+        assert_eq!(statuses, vec![MeadStatus::Secondary, MeadStatus::Bottled, MeadStatus::Finished]);
+
+        let bottled = db.get_mead(ids[1]).unwrap().unwrap();
+        db.update_mead(&Mead { bottled_date: "2026-08-15".to_string(), bottle_count: Some(30), ..bottled }).unwrap();
+        let bottled = db.get_mead(ids[1]).unwrap().unwrap();
+        assert_eq!((bottled.bottled_date.as_str(), bottled.bottle_count), ("2026-08-15", Some(30)));
+    }
+
+    #[test]
+    fn measurements_keep_their_temperature_even_at_the_same_gravity() {
+        let db = Database::open_in_memory(&Config::default()).unwrap();
+        let mut mead = Mead { current_gravity: 1.050, ..Default::default() };
+        mead.id = db.create_mead(&mead).unwrap();
+        let entry = LogEntry { mead_id: mead.id, ..Default::default() };
+
+        db.record_measurement(&Mead { current_gravity: 1.040, ..mead.clone() }, Some(20.0), &entry).unwrap();
+        db.record_measurement(&Mead { current_gravity: 1.040, ..mead.clone() }, Some(16.5), &entry).unwrap();
+        db.record_measurement(&Mead { current_gravity: 1.040, ..mead.clone() }, None, &entry).unwrap();
+
+        let readings: Vec<(f64, Option<f64>)> =
+            db.get_gravity_readings(mead.id).unwrap().into_iter().map(|r| (r.gravity, r.temperature)).collect();
+        assert_eq!(readings, vec![(1.050, None), (1.040, Some(20.0)), (1.040, Some(16.5))]);
+    }
+}
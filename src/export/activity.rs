@@ -0,0 +1,31 @@
+use std::io;
+use std::path::Path;
+
+use crate::models::Activity;
+
+use super::csv_field;
+
+/// File the filtered activity log is written to
+pub const FILE_NAME: &str = "activity_log.csv";
+
+/// Write activity as CSV
+pub fn write_activity_csv(path: &Path, items: &[Activity]) -> io::Result<()> {
+    std::fs::write(path, build_csv(items))
+}
+
+/// CSV with a header row and one row per event: when it happened (RFC
+/// 3339), the mead, the kind of event, the log entry type and the text
+pub fn build_csv(items: &[Activity]) -> String {
+    let mut csv = String::from("Timestamp,Mead,Kind,Entry Type,Text\n");
+    for item in items {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            item.at.to_rfc3339(),
+            csv_field(&item.mead_name),
+            item.kind.as_str(),
+            item.entry_type.as_ref().map_or("", |t| t.as_str()),
+            csv_field(&item.text)
+        ));
+    }
+    csv
+}
@@ -0,0 +1,75 @@
+use std::io;
+use std::path::Path;
+
+use crate::models::FieldChange;
+
+use super::csv_field;
+
+/// File stem the comparison is written to, with ".csv" or ".md" added
+pub const FILE_STEM: &str = "comparison";
+
+/// Format a comparison is exported in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComparisonFormat {
+    Csv,
+    Markdown,
+}
+
+impl ComparisonFormat {
+    pub fn file_name(&self) -> String {
+        match self {
+            ComparisonFormat::Csv => format!("{}.csv", FILE_STEM),
+            ComparisonFormat::Markdown => format!("{}.md", FILE_STEM),
+        }
+    }
+}
+
+/// Write a comparison of two versions of a mead, one row per field
+pub fn write_comparison(
+    path: &Path,
+    format: ComparisonFormat,
+    labels: (&str, &str),
+    rows: &[FieldChange],
+) -> io::Result<()> {
+    let text = match format {
+        ComparisonFormat::Csv => build_csv(labels, rows),
+        ComparisonFormat::Markdown => build_markdown(labels, rows),
+    };
+    std::fs::write(path, text)
+}
+
+/// CSV with a header row: field, the two versions and the difference
+pub fn build_csv((before, after): (&str, &str), rows: &[FieldChange]) -> String {
+    let mut csv = format!("Field,{},{},Diff\n", csv_field(before), csv_field(after));
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            csv_field(row.field),
+            csv_field(&row.before),
+            csv_field(&row.after),
+            csv_field(&row.diff())
+        ));
+    }
+    csv
+}
+
+/// Markdown table: field, the two versions and the difference
+pub fn build_markdown((before, after): (&str, &str), rows: &[FieldChange]) -> String {
+    let mut md = format!("| Field | {} | {} | Diff |\n", md_cell(before), md_cell(after));
+    md.push_str("| --- | --- | --- | --- |\n");
+    for row in rows {
+        md.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            md_cell(row.field),
+            md_cell(&row.before),
+            md_cell(&row.after),
+            md_cell(&row.diff())
+        ));
+    }
+    md
+}
+
+/// Keep a Markdown table cell on one line and its pipes literal
+fn md_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
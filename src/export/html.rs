@@ -0,0 +1,135 @@
+use std::io;
+use std::path::Path;
+
+use crate::format::{fmt_abv, fmt_gravity, fmt_yan};
+use crate::models::{Ingredient, LogEntry, Mead};
+
+/// Print-friendly styles, inlined so the sheet is a single file
+const STYLE: &str = "
+body { font-family: Georgia, serif; max-width: 50em; margin: 2em auto; color: #222; }
+h1 { border-bottom: 2px solid #444; padding-bottom: 0.2em; }
+h2 { margin-top: 1.5em; font-size: 1.2em; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #999; padding: 0.3em 0.6em; text-align: left; vertical-align: top; }
+th { background: #eee; }
+.params th { width: 30%; }
+.notes { white-space: pre-wrap; }
+@media print { body { margin: 0; } h2 { page-break-after: avoid; } tr { page-break-inside: avoid; } }
+";
+
+/// Write a brew sheet for one mead as a self-contained HTML file
+pub fn write_mead_sheet(
+    path: &Path,
+    mead: &Mead,
+    ingredients: &[Ingredient],
+    log_entries: &[LogEntry],
+) -> io::Result<()> {
+    std::fs::write(path, build_mead_sheet(mead, ingredients, log_entries))
+}
+
+/// Build a brew sheet with the mead's parameters, ingredients and full log
+pub fn build_mead_sheet(mead: &Mead, ingredients: &[Ingredient], log_entries: &[LogEntry]) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!("<title>{}</title>\n", escape(&mead.name)));
+    html.push_str(&format!("<style>{}</style>\n</head>\n<body>\n", STYLE));
+    html.push_str(&format!("<h1>{}</h1>\n", escape(&mead.name)));
+
+    let mut params = vec![
+        ("Status", mead.status.as_str().to_string()),
+        ("Start date", mead.start_date.clone()),
+        ("Honey", format!("{} ({:.1} lbs)", mead.honey_type, mead.honey_amount_lbs)),
+        ("Yeast", mead.yeast_strain.clone()),
+        ("Volume", format!("{:.1} gal", mead.volume_gallons)),
+        ("Original gravity", fmt_gravity(mead.starting_gravity)),
+        ("Current gravity", fmt_gravity(mead.current_gravity)),
+        ("ABV", format!("{}% (target {}%)", fmt_abv(mead.current_abv()), fmt_abv(mead.target_abv))),
+        ("YAN", format!("{}/{} ppm", fmt_yan(mead.yan_added), fmt_yan(mead.yan_required))),
+    ];
+    if let Some((fg, abv)) = mead.bottling_snapshot() {
+        params.push(("As bottled", format!("FG {}, {}% ABV", fmt_gravity(fg), fmt_abv(abv))));
+    }
+    if !mead.bottled_date.is_empty() {
+        params.push(("Bottled", mead.bottled_date.clone()));
+    }
+    if let Some(count) = mead.bottle_count {
+        params.push(("Bottles", count.to_string()));
+    }
+    if !mead.target_date.is_empty() {
+        params.insert(2, ("Target date", mead.target_date.clone()));
+    }
+
+    html.push_str("<h2>Parameters</h2>\n<table class=\"params\">\n");
+    for (label, value) in params {
+        html.push_str(&format!("<tr><th>{}</th><td>{}</td></tr>\n", label, escape(&value)));
+    }
+    html.push_str("</table>\n");
+
+    if !mead.notes.is_empty() {
+        html.push_str(&format!("<h2>Notes</h2>\n<p class=\"notes\">{}</p>\n", escape(&mead.notes)));
+    }
+
+    html.push_str("<h2>Ingredients</h2>\n");
+    if ingredients.is_empty() {
+        html.push_str("<p>No ingredients recorded.</p>\n");
+    } else {
+        html.push_str("<table>\n<tr><th>Added</th><th>Type</th><th>Name</th><th>Amount</th><th>Notes</th></tr>\n");
+        for ing in ingredients {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:.1} {}</td><td>{}</td></tr>\n",
+                escape(&ing.added_date),
+                ing.ingredient_type.as_str(),
+                escape(&ing.name),
+                ing.amount,
+                escape(&ing.unit),
+                escape(&ing.notes)
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("<h2>Log</h2>\n");
+    if log_entries.is_empty() {
+        html.push_str("<p>No log entries.</p>\n");
+    } else {
+        html.push_str("<table>\n<tr><th>Date</th><th>Type</th><th>Entry</th></tr>\n");
+        // Oldest first reads naturally on paper
+        let mut entries: Vec<&LogEntry> = log_entries.iter().collect();
+        entries.sort_by_key(|entry| entry.timestamp);
+        for entry in entries {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td class=\"notes\">{}</td></tr>\n",
+                entry.timestamp.format("%Y-%m-%d %H:%M"),
+                entry.entry_type.as_str(),
+                escape(&entry.entry_text)
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// File name for a mead's sheet, keeping only filesystem-safe characters
+pub fn file_name(mead: &Mead) -> String {
+    let stem: String = mead
+        .name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    let stem = stem.trim_matches('_');
+    if stem.is_empty() {
+        format!("mead_{}.html", mead.id)
+    } else {
+        format!("{}.html", stem)
+    }
+}
+
+/// Escape text for HTML element content and attribute values
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
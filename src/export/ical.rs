@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+use chrono::{NaiveDate, Utc};
+
+use crate::models::{Mead, Reminder, StatusChange};
+
+/// File name used for the calendar export
+pub const FILE_NAME: &str = "mead_tracker.ics";
+
+/// Maximum octets per content line before folding (RFC 5545 section 3.1)
+const MAX_LINE_LEN: usize = 75;
+
+/// Write reminders and status changes as an iCalendar file
+pub fn write_calendar(
+    path: &Path,
+    meads: &[Mead],
+    reminders: &[Reminder],
+    status_changes: &[StatusChange],
+) -> io::Result<()> {
+    std::fs::write(path, build_calendar(meads, reminders, status_changes))
+}
+
+/// Build an iCalendar document with one all-day event per reminder and
+/// per status change
+pub fn build_calendar(meads: &[Mead], reminders: &[Reminder], status_changes: &[StatusChange]) -> String {
+    let names: HashMap<i64, &str> = meads.iter().map(|m| (m.id, m.name.as_str())).collect();
+    let name_of = |id: i64| names.get(&id).copied().unwrap_or("Unknown mead");
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//MeadTUI//Mead Tracker//EN".to_string(),
+    ];
+
+    for reminder in reminders {
+        let Ok(date) = NaiveDate::parse_from_str(reminder.due_date.trim(), "%Y-%m-%d") else {
+            continue;
+        };
+        let name = name_of(reminder.mead_id);
+        let summary = if reminder.done {
+            format!("{}: {} (done)", name, reminder.text)
+        } else {
+            format!("{}: {}", name, reminder.text)
+        };
+        push_event(
+            &mut lines,
+            &format!("reminder-{}@meadtui", reminder.id),
+            &stamp,
+            date,
+            &summary,
+            &format!("Reminder for {}: {}", name, reminder.text),
+        );
+    }
+
+    for change in status_changes {
+        let name = name_of(change.mead_id);
+        push_event(
+            &mut lines,
+            &format!("status-{}@meadtui", change.id),
+            &stamp,
+            change.changed_at.date_naive(),
+            &format!("{}: {}", name, change.status.as_str()),
+            &format!("{} moved to {}", name, change.status.as_str()),
+        );
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold_line(line)).collect::<Vec<_>>().join("\r\n") + "\r\n"
+}
+
+fn push_event(lines: &mut Vec<String>, uid: &str, stamp: &str, date: NaiveDate, summary: &str, description: &str) {
+    lines.push("BEGIN:VEVENT".to_string());
+    lines.push(format!("UID:{}", uid));
+    lines.push(format!("DTSTAMP:{}", stamp));
+    lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+    lines.push(format!("SUMMARY:{}", escape_text(summary)));
+    lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+    lines.push("END:VEVENT".to_string());
+}
+
+/// Escape a TEXT value
+fn escape_text(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line longer than 75 octets, continuing with a space
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > MAX_LINE_LEN {
+            folded.push_str("\r\n ");
+            len = 1;
+        }
+        folded.push(c);
+        len += c.len_utf8();
+    }
+    folded
+}
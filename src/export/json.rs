@@ -0,0 +1,47 @@
+use std::io;
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::format::ISO_DATE;
+use crate::models::{GravityReading, Ingredient, LogEntry, Mead, MeadSnapshot, Reminder, StatusChange};
+
+/// File the JSON export writes and the JSON import reads
+pub const FILE_NAME: &str = "mead_tracker.json";
+
+/// File an export of only some meads is written to, dated so it never
+/// takes the place of the full export the import reads
+pub fn filtered_file_name(date: NaiveDate) -> String {
+    format!("meads-filtered-{}.json", date.format(ISO_DATE))
+}
+
+/// One mead with everything recorded against it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MeadRecord {
+    pub mead: Mead,
+    #[serde(default)]
+    pub ingredients: Vec<Ingredient>,
+    #[serde(default)]
+    pub log_entries: Vec<LogEntry>,
+    #[serde(default)]
+    pub reminders: Vec<Reminder>,
+    #[serde(default)]
+    pub gravity_readings: Vec<GravityReading>,
+    #[serde(default)]
+    pub status_history: Vec<StatusChange>,
+    #[serde(default)]
+    pub snapshots: Vec<MeadSnapshot>,
+}
+
+/// Write meads and their records as pretty-printed JSON
+pub fn write_json(path: &Path, records: &[MeadRecord]) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(records)?;
+    std::fs::write(path, json + "\n")
+}
+
+/// Read meads and their records from a JSON export
+pub fn read_json(path: &Path) -> io::Result<Vec<MeadRecord>> {
+    let text = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&text)?)
+}
@@ -0,0 +1,42 @@
+use std::io;
+use std::path::Path;
+
+use crate::models::Mead;
+
+use super::csv_field;
+
+/// File the meads shown in the list are written to as CSV
+pub const FILE_NAME: &str = "mead_list.csv";
+
+/// Write meads as CSV
+pub fn write_list_csv(path: &Path, meads: &[Mead]) -> io::Result<()> {
+    std::fs::write(path, build_csv(meads))
+}
+
+/// CSV with a header row and one row per mead, in the order given. Dates
+/// are ISO and numbers unrounded so the file reads back exactly.
+pub fn build_csv(meads: &[Mead]) -> String {
+    let mut csv = String::from(
+        "Name,Base,Status,Start Date,Target Date,Honey,Honey (lbs),Yeast,Volume (gal),OG,Current,ABV,Target ABV\n",
+    );
+    for mead in meads {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{:.2},{}\n",
+            csv_field(&mead.name),
+            mead.base_type.as_str(),
+            mead.status.as_str(),
+            mead.start_date,
+            mead.target_date,
+            csv_field(&mead.honey_type),
+            mead.honey_amount_lbs,
+            csv_field(&mead.yeast_strain),
+            mead.volume_gallons,
+            mead.starting_gravity,
+            mead.current_gravity,
+            mead.current_abv(),
+            mead.target_abv
+        ));
+    }
+    csv
+}
+
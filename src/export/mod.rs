@@ -0,0 +1,72 @@
+pub mod activity;
+pub mod comparison;
+pub mod html;
+pub mod ical;
+pub mod json;
+pub mod list;
+pub mod shopping;
+
+use std::path::{Path, PathBuf};
+
+/// An export of the whole collection offered on the main menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Calendar,
+    Json,
+    Sql,
+}
+
+impl ExportFormat {
+    /// Config value, e.g. "json"
+    pub fn key(&self) -> &'static str {
+        match self {
+            ExportFormat::Calendar => "calendar",
+            ExportFormat::Json => "json",
+            ExportFormat::Sql => "sql",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "calendar" => Some(ExportFormat::Calendar),
+            "json" => Some(ExportFormat::Json),
+            "sql" => Some(ExportFormat::Sql),
+            _ => None,
+        }
+    }
+
+    /// Short name, e.g. "JSON"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Calendar => "Calendar",
+            ExportFormat::Json => "JSON",
+            ExportFormat::Sql => "SQL",
+        }
+    }
+
+    /// File the export is written to in the `exports` folder
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ExportFormat::Calendar => ical::FILE_NAME,
+            ExportFormat::Json => json::FILE_NAME,
+            ExportFormat::Sql => crate::db::SQL_FILE_NAME,
+        }
+    }
+}
+
+/// Quote a CSV field when it holds a comma, quote or line break
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Path for an export file inside the `exports` folder of the data
+/// directory `dir`
+pub fn export_path(dir: &Path, file_name: &str) -> PathBuf {
+    let path = dir.join("exports");
+    let _ = std::fs::create_dir_all(&path);
+    path.join(file_name)
+}
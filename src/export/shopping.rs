@@ -0,0 +1,30 @@
+use std::io;
+use std::path::Path;
+
+use crate::models::ShoppingItem;
+
+/// File the shopping list is written to
+pub const FILE_NAME: &str = "shopping_list.txt";
+
+/// Write a shopping list as plain text
+pub fn write_shopping_list(path: &Path, batches: &[String], items: &[ShoppingItem]) -> io::Result<()> {
+    std::fs::write(path, build_shopping_list(batches, items))
+}
+
+/// Build a plain-text shopping list: the batches it covers, then one line
+/// per ingredient with its total
+pub fn build_shopping_list(batches: &[String], items: &[ShoppingItem]) -> String {
+    let mut text = format!("Shopping list for {}\n\n", batches.join(", "));
+    let name_width = items.iter().map(|i| i.name.chars().count()).max().unwrap_or(0);
+    for item in items {
+        let used_in = if item.batches > 1 { format!("  ({} batches)", item.batches) } else { String::new() };
+        text.push_str(&format!(
+            "[ ] {:<width$}  {}{}\n",
+            item.name,
+            item.amount_text(),
+            used_in,
+            width = name_width
+        ));
+    }
+    text
+}
@@ -0,0 +1,352 @@
+//! Display formatting for brewing measurements and dates.
+//!
+//! Precision, date formats and the temperature unit are shared by the UI
+//! thread so every view formats a value the same way; `set_precision`,
+//! `set_date_format` and `set_temp_unit` are called once at startup from
+//! the config. They are kept per thread so tests, each on its own thread,
+//! can set them without affecting one another. Dates are always stored as
+//! ISO and temperatures in °F, only their display changes.
+
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Write};
+use std::thread::LocalKey;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// How dates are stored, and shown unless the config says otherwise
+pub const ISO_DATE: &str = "%Y-%m-%d";
+/// How times are shown unless the config says otherwise
+pub const ISO_TIME: &str = "%H:%M";
+
+thread_local! {
+    static GRAVITY_DECIMALS: Cell<usize> = const { Cell::new(3) };
+    static ABV_DECIMALS: Cell<usize> = const { Cell::new(1) };
+    static YAN_DECIMALS: Cell<usize> = const { Cell::new(0) };
+    /// Display formats for dates and times; empty means ISO
+    static DATE_FORMAT: RefCell<String> = const { RefCell::new(String::new()) };
+    static TIME_FORMAT: RefCell<String> = const { RefCell::new(String::new()) };
+    static CELSIUS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Unit temperatures are shown and typed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TempUnit {
+    Fahrenheit,
+    Celsius,
+}
+
+impl TempUnit {
+    /// Parse a config value: "F", "C" or the unit's full name
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "f" | "fahrenheit" => Some(TempUnit::Fahrenheit),
+            "c" | "celsius" => Some(TempUnit::Celsius),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TempUnit::Fahrenheit => "°F",
+            TempUnit::Celsius => "°C",
+        }
+    }
+
+    /// A stored °F temperature in this unit
+    pub fn display(self, fahrenheit: f64) -> f64 {
+        match self {
+            TempUnit::Fahrenheit => fahrenheit,
+            TempUnit::Celsius => fahrenheit_to_celsius(fahrenheit),
+        }
+    }
+
+    /// A temperature in this unit as °F, for storing
+    pub fn stored(self, value: f64) -> f64 {
+        match self {
+            TempUnit::Fahrenheit => value,
+            TempUnit::Celsius => celsius_to_fahrenheit(value),
+        }
+    }
+}
+
+/// Decimal places used for each kind of measurement
+#[derive(Debug, Clone, Copy)]
+pub struct Precision {
+    pub gravity: usize,
+    pub abv: usize,
+    pub yan: usize,
+}
+
+/// Set the decimal places used by the `fmt_*` helpers
+pub fn set_precision(precision: Precision) {
+    GRAVITY_DECIMALS.set(precision.gravity);
+    ABV_DECIMALS.set(precision.abv);
+    YAN_DECIMALS.set(precision.yan);
+}
+
+/// Set the strftime formats used by `fmt_date` and `fmt_datetime`. Either
+/// one that isn't a valid format falls back to ISO.
+pub fn set_date_format(date: &str, time: &str) {
+    let valid_or_empty = |format: &str, valid: bool| if valid { format.to_string() } else { String::new() };
+    DATE_FORMAT.set(valid_or_empty(date, is_valid_date_format(date)));
+    TIME_FORMAT.set(valid_or_empty(time, is_valid_time_format(time)));
+}
+
+/// Set the unit `fmt_temp` shows and temperature inputs are read in
+pub fn set_temp_unit(unit: TempUnit) {
+    CELSIUS.set(unit == TempUnit::Celsius);
+}
+
+/// The unit temperatures are shown and typed in
+pub fn temp_unit() -> TempUnit {
+    if CELSIUS.get() {
+        TempUnit::Celsius
+    } else {
+        TempUnit::Fahrenheit
+    }
+}
+
+pub fn fahrenheit_to_celsius(fahrenheit: f64) -> f64 {
+    (fahrenheit - 32.0) * 5.0 / 9.0
+}
+
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// Whether `format` is a non-empty strftime format chrono can show a date
+/// in. Time and zone fields like `%H` or `%z` have nothing to show for a
+/// date alone, so chrono fails on them just as on a bad specifier.
+pub fn is_valid_date_format(format: &str) -> bool {
+    renders(format, |out| write!(out, "{}", Utc::now().date_naive().format(format)))
+}
+
+/// Whether `format` is a non-empty strftime format chrono can show a
+/// timestamp's time in
+pub fn is_valid_time_format(format: &str) -> bool {
+    renders(format, |out| write!(out, "{}", Utc::now().format(format)))
+}
+
+/// Whether `format` is non-empty and writing a sample with it succeeds
+fn renders(format: &str, write: impl FnOnce(&mut String) -> fmt::Result) -> bool {
+    !format.trim().is_empty() && write(&mut String::new()).is_ok()
+}
+
+/// The configured format, or `default` if none is set
+fn format_or(format: &'static LocalKey<RefCell<String>>, default: &str) -> String {
+    format.with_borrow(|format| if format.is_empty() { default.to_string() } else { format.clone() })
+}
+
+/// Format a date for display
+pub fn fmt_date(date: NaiveDate) -> String {
+    date.format(&format_or(&DATE_FORMAT, ISO_DATE)).to_string()
+}
+
+/// Format a stored ISO date for display; anything that isn't a date is
+/// shown as it is
+pub fn fmt_date_str(date: &str) -> String {
+    match NaiveDate::parse_from_str(date.trim(), ISO_DATE) {
+        Ok(date) => fmt_date(date),
+        Err(_) => date.to_string(),
+    }
+}
+
+/// Parse a typed date, as ISO or in the configured display format
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    let text = text.trim();
+    NaiveDate::parse_from_str(text, ISO_DATE)
+        .or_else(|_| NaiveDate::parse_from_str(text, &format_or(&DATE_FORMAT, ISO_DATE)))
+        .ok()
+}
+
+/// Format a timestamp's date and time for display
+pub fn fmt_datetime(time: DateTime<Utc>) -> String {
+    let format = format!("{} {}", format_or(&DATE_FORMAT, ISO_DATE), format_or(&TIME_FORMAT, ISO_TIME));
+    time.format(&format).to_string()
+}
+
+/// Shown in place of a NaN or infinite value
+pub const NOT_A_NUMBER: &str = "—";
+
+/// Format to a number of decimals, or `NOT_A_NUMBER` if not finite
+fn fmt_decimals(value: f64, decimals: &'static LocalKey<Cell<usize>>) -> String {
+    if value.is_finite() {
+        format!("{:.*}", decimals.get(), value)
+    } else {
+        NOT_A_NUMBER.to_string()
+    }
+}
+
+/// Format a stored value to fill an edit field: to `decimals` places
+/// when that reads back as the same number, otherwise with every digit,
+/// so saving the field untouched keeps the value. Blank if not finite.
+pub fn fmt_edit(value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return String::new();
+    }
+    let shown = format!("{:.*}", decimals, value);
+    if shown.parse::<f64>() == Ok(value) {
+        shown
+    } else {
+        value.to_string()
+    }
+}
+
+/// A specific gravity to edit, as `fmt_gravity` shows it when exact
+pub fn edit_gravity(gravity: f64) -> String {
+    fmt_edit(gravity, GRAVITY_DECIMALS.get())
+}
+
+/// A YAN amount to edit, as `fmt_yan` shows it when exact
+pub fn edit_yan(yan: f64) -> String {
+    fmt_edit(yan, YAN_DECIMALS.get())
+}
+
+/// Format a specific gravity, e.g. "1.050"
+pub fn fmt_gravity(gravity: f64) -> String {
+    fmt_decimals(gravity, &GRAVITY_DECIMALS)
+}
+
+/// Format an ABV percentage without the sign, e.g. "12.5"
+pub fn fmt_abv(abv: f64) -> String {
+    fmt_decimals(abv, &ABV_DECIMALS)
+}
+
+/// Format a YAN amount in ppm without the unit, e.g. "250"
+pub fn fmt_yan(yan: f64) -> String {
+    fmt_decimals(yan, &YAN_DECIMALS)
+}
+
+/// Format a stored °F temperature in the display unit, e.g. "20°C"
+pub fn fmt_temp(fahrenheit: f64) -> String {
+    fmt_temp_in(fahrenheit, temp_unit())
+}
+
+fn fmt_temp_in(fahrenheit: f64, unit: TempUnit) -> String {
+    if !fahrenheit.is_finite() {
+        return NOT_A_NUMBER.to_string();
+    }
+    // Whole degrees unless a tenth was given
+    let value = (unit.display(fahrenheit) * 10.0).round() / 10.0;
+    format!("{}{}", value, unit.symbol())
+}
+
+/// Format how long ago a timestamp was, e.g. "just now", "5 minutes ago",
+/// "3 days ago"
+pub fn fmt_relative(time: DateTime<Utc>) -> String {
+    let elapsed = Utc::now() - time;
+    let (amount, unit) = if elapsed.num_minutes() < 1 {
+        return "just now".to_string();
+    } else if elapsed.num_hours() < 1 {
+        (elapsed.num_minutes(), "minute")
+    } else if elapsed.num_days() < 1 {
+        (elapsed.num_hours(), "hour")
+    } else if elapsed.num_days() < 30 {
+        (elapsed.num_days(), "day")
+    } else if elapsed.num_days() < 365 {
+        (elapsed.num_days() / 30, "month")
+    } else {
+        (elapsed.num_days() / 365, "year")
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+    format!("{} {}{} ago", amount, unit, plural)
+}
+
+/// Format a running time as "mm:ss", or "h:mm:ss" from an hour up
+pub fn fmt_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_formats_must_parse() {
+        assert!(is_valid_date_format("%d/%m/%Y"));
+        assert!(is_valid_time_format("%I:%M %p"));
+        assert!(is_valid_time_format("%H:%M %Z"));
+        assert!(!is_valid_date_format("%Q"));
+        assert!(!is_valid_time_format("%Q"));
+        assert!(!is_valid_date_format(""));
+    }
+
+    #[test]
+    fn date_formats_with_time_fields_are_rejected() {
+        for format in ["%H:%M", "%Y-%m-%d %Z", "%z"] {
+            assert!(!is_valid_date_format(format), "{}", format);
+        }
+    }
+
+    #[test]
+    fn stored_dates_that_do_not_parse_are_shown_as_is() {
+        assert_eq!(fmt_date_str("someday"), "someday");
+        assert_eq!(fmt_date_str(""), "");
+    }
+
+    #[test]
+    fn typed_dates_parse_only_when_real() {
+        assert_eq!(parse_date(" 2025-06-01 "), NaiveDate::from_ymd_opt(2025, 6, 1));
+        assert_eq!(parse_date("2025-02-30"), None);
+        assert_eq!(parse_date("next week"), None);
+    }
+
+    #[test]
+    fn elapsed_time_gains_hours_only_when_needed() {
+        assert_eq!(fmt_elapsed(Duration::from_secs(0)), "00:00");
+        assert_eq!(fmt_elapsed(Duration::from_secs(754)), "12:34");
+        assert_eq!(fmt_elapsed(Duration::from_secs(3725)), "1:02:05");
+    }
+
+    #[test]
+    fn non_finite_values_show_a_dash() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(fmt_gravity(value), NOT_A_NUMBER);
+            assert_eq!(fmt_abv(value), NOT_A_NUMBER);
+            assert_eq!(fmt_yan(value), NOT_A_NUMBER);
+        }
+    }
+
+    #[test]
+    fn temperatures_convert_both_ways() {
+        assert_eq!(fahrenheit_to_celsius(212.0), 100.0);
+        assert_eq!(celsius_to_fahrenheit(20.0), 68.0);
+        assert!((celsius_to_fahrenheit(fahrenheit_to_celsius(71.3)) - 71.3).abs() < 1e-9);
+        assert_eq!(TempUnit::Celsius.stored(TempUnit::Celsius.display(50.0)), 50.0);
+
+        assert_eq!(fmt_temp_in(68.0, TempUnit::Fahrenheit), "68°F");
+        assert_eq!(fmt_temp_in(68.0, TempUnit::Celsius), "20°C");
+        assert_eq!(fmt_temp_in(70.0, TempUnit::Celsius), "21.1°C");
+
+        assert_eq!(TempUnit::parse(" c "), Some(TempUnit::Celsius));
+        assert_eq!(TempUnit::parse("Fahrenheit"), Some(TempUnit::Fahrenheit));
+        assert_eq!(TempUnit::parse("K"), None);
+    }
+
+    #[test]
+    fn finite_values_use_default_precision() {
+        assert_eq!(fmt_gravity(1.05), "1.050");
+        assert_eq!(fmt_abv(12.46), "12.5");
+        assert_eq!(fmt_yan(250.4), "250");
+    }
+
+    #[test]
+    fn edit_values_keep_every_stored_digit() {
+        assert_eq!(edit_gravity(1.05), "1.050");
+        assert_eq!(edit_gravity(1.0506), "1.0506");
+        assert_eq!(edit_yan(250.4), "250.4");
+        assert_eq!(edit_yan(250.0), "250");
+        assert_eq!(fmt_edit(3.456, 2), "3.456");
+        assert_eq!(fmt_edit(f64::NAN, 3), "");
+        for value in [1.0837, 0.1 + 0.2, 1.0 / 3.0] {
+            assert_eq!(fmt_edit(value, 3).parse::<f64>(), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,45 @@
+/// Score how well `query` fuzzily matches `text`.
+///
+/// Every character of the query must appear in the text, in order, ignoring
+/// case. Returns None when that fails; otherwise higher scores are better
+/// matches: consecutive runs and matches at the start of words earn bonuses,
+/// skipped characters cost a little. An empty query matches everything.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query.to_lowercase().chars().filter(|c| !c.is_whitespace()).collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (ti, &c) in text.iter().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        if c != query[qi] {
+            continue;
+        }
+
+        score += 1;
+        let word_start = ti == 0 || !text[ti - 1].is_alphanumeric();
+        if word_start {
+            score += 8;
+        }
+        match last_match {
+            Some(last) if last + 1 == ti => score += 5,
+            Some(last) => score -= (ti - last - 1).min(5) as i32,
+            None => score -= ti.min(5) as i32,
+        }
+        last_match = Some(ti);
+        qi += 1;
+    }
+
+    if qi == query.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,97 @@
+pub mod recipe;
+
+use crate::export::json::MeadRecord;
+use crate::models::is_valid_gravity;
+
+/// What importing some records would add, worked out without touching the
+/// database
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportReport {
+    pub meads: usize,
+    pub ingredients: usize,
+    pub log_entries: usize,
+    pub reminders: usize,
+    /// Problems that stop the import, e.g. "Mead 2: no name"
+    pub errors: Vec<String>,
+}
+
+impl ImportReport {
+    /// Count and check records read from an import file
+    pub fn dry_run(records: &[MeadRecord]) -> Self {
+        let mut errors = Vec::new();
+        for (i, record) in records.iter().enumerate() {
+            let mead = &record.mead;
+            let label = if mead.name.trim().is_empty() {
+                errors.push(format!("Mead {}: no name", i + 1));
+                format!("Mead {}", i + 1)
+            } else {
+                mead.name.clone()
+            };
+            for (field, gravity) in [("starting", mead.starting_gravity), ("current", mead.current_gravity)] {
+                if !is_valid_gravity(gravity) {
+                    errors.push(format!("{}: {} gravity {:.3} is out of range", label, field, gravity));
+                }
+            }
+            for (n, ingredient) in record.ingredients.iter().enumerate() {
+                if ingredient.name.trim().is_empty() {
+                    errors.push(format!("{}: ingredient {} has no name", label, n + 1));
+                }
+                if ingredient.amount < 0.0 {
+                    errors.push(format!("{}: {} has a negative amount", label, ingredient.name));
+                }
+            }
+        }
+        Self {
+            meads: records.len(),
+            ingredients: records.iter().map(|r| r.ingredients.len()).sum(),
+            log_entries: records.iter().map(|r| r.log_entries.len()).sum(),
+            reminders: records.iter().map(|r| r.reminders.len()).sum(),
+            errors,
+        }
+    }
+
+    /// "2 meads, 5 ingredients, 12 log entries, 1 reminder"
+    pub fn summary(&self) -> String {
+        let count = |n: usize, one: &str, many: &str| format!("{} {}", n, if n == 1 { one } else { many });
+        [
+            count(self.meads, "mead", "meads"),
+            count(self.ingredients, "ingredient", "ingredients"),
+            count(self.log_entries, "log entry", "log entries"),
+            count(self.reminders, "reminder", "reminders"),
+        ]
+        .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Ingredient, Mead};
+
+    #[test]
+    fn dry_run_counts_records_and_lists_problems() {
+        let records = vec![
+            MeadRecord {
+                mead: Mead { name: "Cyser".to_string(), ..Default::default() },
+                ingredients: vec![Ingredient { name: "Apple Juice".to_string(), ..Default::default() }],
+                ..Default::default()
+            },
+            MeadRecord {
+                mead: Mead { name: " ".to_string(), starting_gravity: 2.5, ..Default::default() },
+                ingredients: vec![Ingredient::default()],
+                ..Default::default()
+            },
+        ];
+        let report = ImportReport::dry_run(&records);
+        assert_eq!(report.summary(), "2 meads, 2 ingredients, 0 log entries, 0 reminders");
+        assert_eq!(
+            report.errors,
+            vec![
+                "Mead 2: no name",
+                "Mead 2: starting gravity 2.500 is out of range",
+                "Mead 2: ingredient 1 has no name",
+            ]
+        );
+        assert!(ImportReport::dry_run(&records[..1]).errors.is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+//! Recipes exported from other brewing apps.
+//!
+//! Reads a single recipe in the JSON shape Brewfather and similar tools
+//! use: `name`, `og`, `fg`, `batchSize` in liters, `fermentables` with
+//! amounts in kg, `yeasts` and `notes`. Fermentables named as honey fill in
+//! the mead's honey; the rest become ingredients. Anything else is listed
+//! as unmapped rather than failing the import.
+
+use std::io;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::export::json::MeadRecord;
+use crate::models::{Ingredient, IngredientType, Mead, Measure, Unit};
+
+/// File the recipe import reads
+pub const FILE_NAME: &str = "recipe.json";
+
+/// Top-level keys that are read into the mead
+const MAPPED_KEYS: &[&str] = &[
+    "name", "og", "fg", "abv", "batchSize", "batch_size", "fermentables", "yeasts", "yeast", "notes",
+];
+
+/// A recipe turned into a mead, with what couldn't be brought across
+#[derive(Debug)]
+pub struct RecipeImport {
+    pub record: MeadRecord,
+    /// Keys and entries that were left out, e.g. "hops" or "fermentables[2]"
+    pub unmapped: Vec<String>,
+}
+
+/// Read a recipe file into a new mead
+pub fn read_recipe(path: &Path) -> io::Result<RecipeImport> {
+    let text = std::fs::read_to_string(path)?;
+    parse_recipe(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Map recipe JSON into a new mead. Fails only when the text isn't a JSON
+/// object; a recipe wrapped as `{"recipe": {...}}` is unwrapped first.
+pub fn parse_recipe(text: &str) -> Result<RecipeImport, String> {
+    let value: Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    let root = match value.get("recipe") {
+        Some(recipe) if recipe.is_object() => recipe,
+        _ => &value,
+    };
+    let Some(recipe) = root.as_object() else {
+        return Err("expected a recipe object".to_string());
+    };
+
+    let mut unmapped: Vec<String> = recipe
+        .iter()
+        .filter(|(key, value)| !MAPPED_KEYS.contains(&key.as_str()) && !is_blank(value))
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    let mut mead = Mead {
+        name: text_field(recipe, "name").unwrap_or_else(|| "Imported recipe".to_string()),
+        notes: text_field(recipe, "notes").unwrap_or_default(),
+        ..Default::default()
+    };
+    if let Some(og) = number(recipe.get("og")) {
+        mead.starting_gravity = og;
+        mead.current_gravity = og;
+    }
+    match (number(recipe.get("og")), number(recipe.get("fg")), number(recipe.get("abv"))) {
+        (Some(og), Some(fg), _) => mead.target_abv = (og - fg).max(0.0) * 131.25,
+        (_, _, Some(abv)) => mead.target_abv = abv,
+        _ => {}
+    }
+    if let Some(liters) = number(recipe.get("batchSize").or(recipe.get("batch_size"))) {
+        mead.volume_gallons = Unit::Liter.to_canonical(liters) / Unit::Gallon.to_canonical(1.0);
+    }
+
+    let mut ingredients = Vec::new();
+    let mut honeys = Vec::new();
+    let fermentables = recipe.get("fermentables").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+    for (i, fermentable) in fermentables.iter().enumerate() {
+        let Some(name) = fermentable.get("name").and_then(Value::as_str).filter(|n| !n.trim().is_empty()) else {
+            unmapped.push(format!("fermentables[{}]", i));
+            continue;
+        };
+        let amount = number(fermentable.get("amount")).unwrap_or(0.0);
+        let unit = fermentable.get("unit").and_then(Value::as_str).unwrap_or("kg");
+        let kind = fermentable.get("type").and_then(Value::as_str).unwrap_or("");
+        if contains_word(name, "honey") || kind.eq_ignore_ascii_case("honey") {
+            // Honey is tracked by weight; an amount in any other unit is
+            // reported rather than guessed at
+            match Unit::parse(unit).filter(|u| u.measure() == Measure::Mass) {
+                Some(unit) => mead.honey_amount_lbs += unit.to_canonical(amount) / Unit::Pound.to_canonical(1.0),
+                None => unmapped.push(format!("fermentables[{}].unit", i)),
+            }
+            honeys.push(name.trim().to_string());
+        } else {
+            ingredients.push(Ingredient {
+                ingredient_type: if kind.eq_ignore_ascii_case("fruit") {
+                    IngredientType::Fruit
+                } else {
+                    IngredientType::Adjunct
+                },
+                name: name.trim().to_string(),
+                amount,
+                unit: unit.to_string(),
+                added_date: mead.start_date.clone(),
+                ..Default::default()
+            });
+        }
+    }
+    mead.honey_type = honeys.join(", ");
+
+    let yeasts = match recipe.get("yeasts").or(recipe.get("yeast")) {
+        Some(Value::Array(yeasts)) => yeasts.clone(),
+        Some(yeast) => vec![yeast.clone()],
+        None => Vec::new(),
+    };
+    let strains: Vec<String> = yeasts.iter().filter_map(yeast_name).collect();
+    mead.yeast_strain = strains.join(", ");
+
+    Ok(RecipeImport {
+        record: MeadRecord { mead, ingredients, ..Default::default() },
+        unmapped,
+    })
+}
+
+/// "Lalvin 71B" from a yeast given as a name or as an object with a name
+/// and lab
+fn yeast_name(yeast: &Value) -> Option<String> {
+    if let Some(name) = yeast.as_str() {
+        return Some(name.trim().to_string()).filter(|n| !n.is_empty());
+    }
+    let name = yeast.get("name").and_then(Value::as_str)?.trim();
+    let lab = yeast.get("laboratory").and_then(Value::as_str).unwrap_or("").trim();
+    Some(if lab.is_empty() || name.starts_with(lab) { name.to_string() } else { format!("{} {}", lab, name) })
+}
+
+/// A number given as JSON number or numeric string
+fn number(value: Option<&Value>) -> Option<f64> {
+    match value? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .filter(|n: &f64| n.is_finite())
+}
+
+fn text_field(recipe: &Map<String, Value>, key: &str) -> Option<String> {
+    recipe.get(key).and_then(Value::as_str).map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// Whether `word` appears in `text` as a whole word, ignoring case
+fn contains_word(text: &str, word: &str) -> bool {
+    text.split(|c: char| !c.is_alphanumeric()).any(|w| w.eq_ignore_ascii_case(word))
+}
+
+/// Null, empty strings, arrays and objects carry nothing worth reporting
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        Value::Array(a) => a.is_empty(),
+        Value::Object(o) => o.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brewfather_recipes_map_to_a_mead_and_report_the_rest() {
+        let text = r#"{
+            "name": "Blackberry Melomel",
+            "og": 1.110,
+            "fg": "1.010",
+            "batchSize": 18.9,
+            "fermentables": [
+                {"name": "Orange Blossom Honey", "type": "Sugar", "amount": 2.0},
+                {"name": "Wildflower honey", "amount": 16, "unit": "oz"},
+                {"name": "Blackberries", "type": "Fruit", "amount": 1.5},
+                {"type": "Sugar", "amount": 1.0},
+                {"name": "Buckwheat Honey", "amount": 2, "unit": "jars"}
+            ],
+            "yeasts": [{"name": "71B", "laboratory": "Lalvin"}],
+            "hops": [{"name": "Cascade"}],
+            "style": {"name": "Melomel"},
+            "miscs": []
+        }"#;
+        let import = parse_recipe(text).unwrap();
+        let mead = &import.record.mead;
+        assert_eq!(mead.name, "Blackberry Melomel");
+        assert_eq!(mead.starting_gravity, 1.110);
+        assert!((mead.target_abv - 13.125).abs() < 1e-9, "{}", mead.target_abv);
+        assert!((mead.volume_gallons - 4.993).abs() < 0.001);
+        assert_eq!(mead.honey_type, "Orange Blossom Honey, Wildflower honey, Buckwheat Honey");
+        assert!((mead.honey_amount_lbs - 5.409).abs() < 0.001, "{}", mead.honey_amount_lbs);
+        assert_eq!(mead.yeast_strain, "Lalvin 71B");
+
+        let ingredients = &import.record.ingredients;
+        assert_eq!(ingredients.len(), 1);
+        assert_eq!(ingredients[0].ingredient_type, IngredientType::Fruit);
+        assert_eq!((ingredients[0].amount, ingredients[0].unit.as_str()), (1.5, "kg"));
+        assert_eq!(import.unmapped, ["hops", "style", "fermentables[3]", "fermentables[4].unit"]);
+
+        assert!(parse_recipe("[1, 2]").is_err());
+        assert_eq!(parse_recipe(r#"{"recipe": {"name": "Wrapped"}}"#).unwrap().record.mead.name, "Wrapped");
+    }
+}
@@ -1,11 +1,25 @@
 mod app;
+mod calc;
+mod config;
 mod db;
+mod export;
+mod format;
+mod fuzzy;
+mod import;
 mod models;
+mod nav;
+mod nutrients;
+mod reference;
+#[cfg(test)]
+mod test_support;
+mod theme;
 mod views;
 mod widgets;
 
 use std::io;
 
+use ratatui_image::picker::{Picker, ProtocolType};
+
 fn main() -> io::Result<()> {
     let mut terminal = ratatui::init();
     let result = run(&mut terminal);
@@ -14,6 +28,20 @@ fn main() -> io::Result<()> {
 }
 
 fn run(terminal: &mut ratatui::DefaultTerminal) -> io::Result<()> {
-    let mut app = app::App::new()?;
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let read_only = args.iter().any(|arg| arg == "--read-only");
+    // `--profile club` opens that profile's database instead of the
+    // configured one
+    let profile = args
+        .iter()
+        .position(|arg| arg == "--profile")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+    // Ask the terminal which image protocol it speaks; without sixel, kitty
+    // or iTerm2 graphics, photos are shown as a path only
+    let image_picker = Picker::from_query_stdio()
+        .ok()
+        .filter(|picker| picker.protocol_type() != ProtocolType::Halfblocks);
+    let mut app = app::App::new(read_only, profile, image_picker)?;
     app.run(terminal)
 }
@@ -1,7 +1,12 @@
-use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::format::{fmt_abv, fmt_date, fmt_date_str, fmt_gravity, fmt_yan, ISO_DATE};
 
 /// Status of a mead batch
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MeadStatus {
     Planning,
     Primary,
@@ -69,8 +74,112 @@ impl MeadStatus {
     }
 }
 
+/// Lowest specific gravity accepted for a reading
+pub const MIN_GRAVITY: f64 = 0.980;
+/// Highest specific gravity accepted for a reading
+pub const MAX_GRAVITY: f64 = 1.200;
+
+/// Whether a gravity reading is plausible for a mead
+pub fn is_valid_gravity(gravity: f64) -> bool {
+    (MIN_GRAVITY..=MAX_GRAVITY).contains(&gravity)
+}
+
+/// Highest score on a competition score sheet
+pub const MAX_COMPETITION_SCORE: f64 = 50.0;
+
+/// Most stars a batch can be rated
+pub const MAX_RATING: u8 = 5;
+
+/// Longest gravity reading schedule accepted, in days
+pub const MAX_READING_INTERVAL_DAYS: u32 = 365;
+
+/// A rating as filled and empty stars, e.g. "★★★☆☆"; empty when unrated
+pub fn rating_stars(rating: Option<u8>) -> String {
+    match rating {
+        Some(stars) => {
+            let stars = stars.min(MAX_RATING) as usize;
+            "★".repeat(stars) + &"☆".repeat(MAX_RATING as usize - stars)
+        }
+        None => String::new(),
+    }
+}
+
+/// Lowest pH accepted for a must or finished mead
+pub const MIN_PH: f64 = 2.5;
+/// Highest pH accepted for a must or finished mead
+pub const MAX_PH: f64 = 4.5;
+
+/// Whether a pH reading is plausible for a mead
+pub fn is_valid_ph(ph: f64) -> bool {
+    (MIN_PH..=MAX_PH).contains(&ph)
+}
+
+/// What a batch is fermented from. Non-honey bases cover ciders and wines
+/// tracked alongside meads; the honey fields then hold the juice or fruit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BaseType {
+    #[default]
+    Honey,
+    Apple,
+    Grape,
+    Other,
+}
+
+impl BaseType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BaseType::Honey => "Honey",
+            BaseType::Apple => "Apple",
+            BaseType::Grape => "Grape",
+            BaseType::Other => "Other",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "apple" => BaseType::Apple,
+            "grape" => BaseType::Grape,
+            "other" => BaseType::Other,
+            _ => BaseType::Honey,
+        }
+    }
+
+    pub fn all() -> Vec<BaseType> {
+        vec![BaseType::Honey, BaseType::Apple, BaseType::Grape, BaseType::Other]
+    }
+
+    /// Label for the fermentable fields: "Honey" for meads, "Base" otherwise
+    pub fn fermentable_label(&self) -> &'static str {
+        match self {
+            BaseType::Honey => "Honey",
+            _ => "Base",
+        }
+    }
+
+    /// Gravity a fully dry batch finishes at. Cider and wine drop below
+    /// 1.000 as the alcohol thins them out; meads usually stop near it.
+    pub fn dry_gravity(&self) -> f64 {
+        match self {
+            BaseType::Honey | BaseType::Other => 1.000,
+            BaseType::Apple => 0.998,
+            BaseType::Grape => 0.995,
+        }
+    }
+
+    /// Typical starting gravity for a new batch
+    pub fn default_gravity(&self) -> f64 {
+        match self {
+            BaseType::Honey => 1.100,
+            BaseType::Apple => 1.050,
+            BaseType::Grape => 1.090,
+            BaseType::Other => 1.060,
+        }
+    }
+}
+
 /// Main mead batch data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Mead {
     pub id: i64,
     pub name: String,
@@ -88,6 +197,46 @@ pub struct Mead {
     pub notes: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Pinned meads sort to the top of the list
+    pub pinned: bool,
+    /// Planned completion date (YYYY-MM-DD), empty for no target
+    pub target_date: String,
+    /// Gravity recorded when the batch moved to Bottled
+    pub final_gravity: Option<f64>,
+    /// ABV recorded when the batch moved to Bottled
+    pub final_abv: Option<f64>,
+    /// Whether overdue targets and stalled fermentation are flagged; off
+    /// for batches that are slow on purpose
+    pub auto_reminders: bool,
+    /// What the batch is fermented from
+    pub base_type: BaseType,
+    /// Path to a photo of the batch, empty for none. Only the path is
+    /// stored; the image stays where it is on disk.
+    pub image_path: String,
+    /// Date the batch was bottled (YYYY-MM-DD), empty if not recorded
+    pub bottled_date: String,
+    /// Bottles filled, if recorded
+    pub bottle_count: Option<u32>,
+    /// Days between gravity readings while fermenting, if the batch has a
+    /// reading schedule
+    pub gravity_check_days: Option<u32>,
+    /// Measured pH, if recorded
+    pub ph: Option<f64>,
+    /// Titratable acidity in g/L, if recorded
+    pub ta: Option<f64>,
+    /// Whether the batch is meant for entering in a competition
+    pub competition: bool,
+    /// Category the batch is entered in, e.g. "M1A Dry Traditional"
+    pub competition_category: String,
+    /// Score the judges gave, out of `MAX_COMPETITION_SCORE`
+    pub competition_score: Option<f64>,
+    /// Feedback from the judges' score sheets
+    pub judge_notes: String,
+    /// Stars given after tasting, 1 to `MAX_RATING`; None until rated
+    pub rating: Option<u8>,
+    /// What to do next with the batch, e.g. "rack on Saturday"; empty
+    /// when nothing is planned
+    pub next_action: String,
 }
 
 impl Default for Mead {
@@ -96,7 +245,7 @@ impl Default for Mead {
         Self {
             id: 0,
             name: String::new(),
-            start_date: now.format("%Y-%m-%d").to_string(),
+            start_date: Local::now().format("%Y-%m-%d").to_string(),
             honey_type: String::new(),
             honey_amount_lbs: 0.0,
             yeast_strain: String::new(),
@@ -110,12 +259,290 @@ impl Default for Mead {
             notes: String::new(),
             created_at: now,
             updated_at: now,
+            pinned: false,
+            target_date: String::new(),
+            final_gravity: None,
+            final_abv: None,
+            auto_reminders: true,
+            base_type: BaseType::Honey,
+            image_path: String::new(),
+            bottled_date: String::new(),
+            bottle_count: None,
+            gravity_check_days: None,
+            ph: None,
+            ta: None,
+            competition: false,
+            competition_category: String::new(),
+            competition_score: None,
+            judge_notes: String::new(),
+            rating: None,
+            next_action: String::new(),
         }
     }
 }
 
+impl Mead {
+    /// Days from today until the target date; negative once overdue.
+    /// Returns None when no (valid) target date is set.
+    pub fn days_until_target(&self) -> Option<i64> {
+        let target = NaiveDate::parse_from_str(self.target_date.trim(), "%Y-%m-%d").ok()?;
+        Some((target - Local::now().date_naive()).num_days())
+    }
+
+    /// Whether the batch has a volume the per-volume calculators can use
+    pub fn has_volume(&self) -> bool {
+        self.volume_gallons.is_finite() && self.volume_gallons > 0.0
+    }
+
+    /// Current ABV estimated from starting and current gravity
+    pub fn current_abv(&self) -> f64 {
+        self.abv_at(self.current_gravity)
+    }
+
+    /// ABV estimated for the mead once it has fermented down to `gravity`
+    pub fn abv_at(&self, gravity: f64) -> f64 {
+        (self.starting_gravity - gravity) * 131.25
+    }
+
+    /// Percent of the sugar fermented once the mead reaches `gravity`, or
+    /// None without a starting gravity above water
+    pub fn attenuation_at(&self, gravity: f64) -> Option<f64> {
+        (self.starting_gravity > 1.0)
+            .then(|| (self.starting_gravity - gravity) / (self.starting_gravity - 1.0) * 100.0)
+    }
+
+    /// Final gravity and ABV as recorded at bottling, for bottled and
+    /// finished batches. Later gravity edits don't change these.
+    pub fn bottling_snapshot(&self) -> Option<(f64, f64)> {
+        if !matches!(self.status, MeadStatus::Bottled | MeadStatus::Finished) {
+            return None;
+        }
+        Some((self.final_gravity?, self.final_abv?))
+    }
+
+    /// Highest ABV the starting gravity allows, fermenting fully dry
+    pub fn potential_abv(&self) -> f64 {
+        ((self.starting_gravity - self.base_type.dry_gravity()) * 131.25).max(0.0)
+    }
+
+    /// Final gravity that would give the target ABV
+    pub fn target_fg(&self) -> f64 {
+        self.starting_gravity - self.target_abv / 131.25
+    }
+
+    /// Project when the mead reaches its target FG by extrapolating the
+    /// gravity drop per day over the most recent readings
+    pub fn fg_estimate(&self, readings: &[GravityReading]) -> FgEstimate {
+        let target = self.target_fg();
+        if self.current_gravity <= target {
+            return FgEstimate::Reached;
+        }
+
+        let recent = &readings[readings.len().saturating_sub(FG_ESTIMATE_READINGS)..];
+        if recent.len() < 2 {
+            return FgEstimate::CannotEstimate;
+        }
+
+        // Least-squares slope of gravity against days since the first point
+        let start = recent[0].recorded_at;
+        let points: Vec<(f64, f64)> = recent
+            .iter()
+            .map(|r| ((r.recorded_at - start).num_seconds() as f64 / 86_400.0, r.gravity))
+            .collect();
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+        let var_x: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+        if var_x <= 0.0 {
+            return FgEstimate::CannotEstimate;
+        }
+        let slope = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum::<f64>() / var_x;
+        if slope >= 0.0 {
+            return FgEstimate::CannotEstimate;
+        }
+
+        let last = recent[recent.len() - 1].recorded_at;
+        let days_left = (self.current_gravity - target) / -slope;
+        match chrono::Duration::try_seconds((days_left * 86_400.0) as i64) {
+            Some(remaining) => match last.checked_add_signed(remaining) {
+                Some(date) => FgEstimate::By(date.date_naive()),
+                None => FgEstimate::CannotEstimate,
+            },
+            None => FgEstimate::CannotEstimate,
+        }
+    }
+
+    /// Whether fermentation looks stalled: still fermenting above the target
+    /// FG, with gravity dropping less than `STUCK_MIN_DROP` over the last
+    /// `STUCK_DAYS` days of readings
+    pub fn is_stuck(&self, readings: &[GravityReading]) -> bool {
+        if !self.auto_reminders
+            || !matches!(self.status, MeadStatus::Primary | MeadStatus::Secondary)
+            || self.current_gravity <= self.target_fg()
+        {
+            return false;
+        }
+        let Some(latest) = readings.last() else {
+            return false;
+        };
+        let cutoff = latest.recorded_at - chrono::Duration::days(STUCK_DAYS);
+        readings
+            .iter()
+            .rev()
+            .find(|r| r.recorded_at <= cutoff)
+            .is_some_and(|earlier| earlier.gravity - latest.gravity < STUCK_MIN_DROP)
+    }
+
+    /// Whole days since the latest gravity reading, or None without one
+    pub fn reading_age(readings: &[GravityReading]) -> Option<i64> {
+        let last = readings.iter().map(|r| r.recorded_at).max()?;
+        Some((Local::now().date_naive() - last.with_timezone(&Local).date_naive()).num_days())
+    }
+
+    /// Days until the next scheduled gravity reading, counted from the
+    /// latest reading (or the start date without one); negative once it is
+    /// overdue. None without a schedule, outside Primary and Secondary, or
+    /// when the due date would be past the last date chrono can hold.
+    pub fn days_until_reading(&self, readings: &[GravityReading]) -> Option<i64> {
+        let interval = self.gravity_check_days.filter(|days| *days > 0)?;
+        if !matches!(self.status, MeadStatus::Primary | MeadStatus::Secondary) {
+            return None;
+        }
+        let last = readings
+            .iter()
+            .map(|r| r.recorded_at.with_timezone(&Local).date_naive())
+            .max()
+            .or_else(|| NaiveDate::parse_from_str(self.start_date.trim(), "%Y-%m-%d").ok())?;
+        let due = last.checked_add_signed(chrono::Duration::days(interval as i64))?;
+        Some((due - Local::now().date_naive()).num_days())
+    }
+
+    /// Whether a scheduled gravity reading is due today or overdue
+    pub fn reading_due(&self, readings: &[GravityReading]) -> bool {
+        self.days_until_reading(readings).is_some_and(|days| days <= 0)
+    }
+
+    /// "due today", "in 2d" or "3d overdue" for the next scheduled reading
+    pub fn reading_label(&self, readings: &[GravityReading]) -> Option<String> {
+        self.days_until_reading(readings).map(|days| match days {
+            0 => "due today".to_string(),
+            d if d > 0 => format!("in {}d", d),
+            d => format!("{}d overdue", -d),
+        })
+    }
+
+    /// Whether a fermenting mead has been given less YAN than it needs
+    pub fn yan_incomplete(&self) -> bool {
+        matches!(self.status, MeadStatus::Primary | MeadStatus::Secondary)
+            && self.yan_added < self.yan_required
+    }
+
+    /// Whether the mead is past its target date before being bottled
+    pub fn target_overdue(&self) -> bool {
+        self.auto_reminders
+            && !matches!(self.status, MeadStatus::Bottled | MeadStatus::Finished)
+            && self.days_until_target().is_some_and(|days| days < 0)
+    }
+
+    /// Whether the batch is worth looking at today: an overdue reminder or
+    /// target date, stuck fermentation, or incomplete YAN. Takes this mead's
+    /// reminders and readings.
+    pub fn needs_attention(&self, reminders: &[Reminder], readings: &[GravityReading]) -> bool {
+        reminders.iter().any(Reminder::is_overdue)
+            || self.target_overdue()
+            || self.reading_due(readings)
+            || self.is_stuck(readings)
+            || self.yan_incomplete()
+    }
+
+    /// Fraction of the target ABV reached so far, clamped to 0.0..=1.0.
+    /// Bad gravities or targets that give no finite ratio count as 0.0.
+    pub fn abv_progress(&self) -> f64 {
+        if self.target_abv <= 0.0 {
+            return 0.0;
+        }
+        let progress = self.current_abv() / self.target_abv;
+        if progress.is_finite() { progress.clamp(0.0, 1.0) } else { 0.0 }
+    }
+
+    /// Percentage by which YAN added exceeds YAN required, if it does
+    pub fn yan_overage_percent(&self) -> Option<f64> {
+        if self.yan_required <= 0.0 || self.yan_added <= self.yan_required {
+            return None;
+        }
+        Some((self.yan_added / self.yan_required - 1.0) * 100.0)
+    }
+
+    /// Plain-text summary suitable for sharing outside the app
+    pub fn summary(&self) -> String {
+        let mut lines = vec![
+            format!("{} ({})", self.name, self.status.as_str()),
+            format!("Started: {}", self.start_date),
+            format!(
+                "{}: {} ({:.1} lbs)",
+                self.base_type.fermentable_label(),
+                self.honey_type,
+                self.honey_amount_lbs
+            ),
+            format!("Yeast: {}", self.yeast_strain),
+            format!("Volume: {:.1} gal", self.volume_gallons),
+            format!("OG: {}  Current: {}", fmt_gravity(self.starting_gravity), fmt_gravity(self.current_gravity)),
+            format!("ABV: {}% (target {}%)", fmt_abv(self.current_abv()), fmt_abv(self.target_abv)),
+            format!("YAN: {}/{} ppm", fmt_yan(self.yan_added), fmt_yan(self.yan_required)),
+        ];
+        if let Some(acidity) = self.acidity_label() {
+            lines.push(format!("Acidity: {}", acidity));
+        }
+        if self.competition {
+            lines.push(format!("Competition: {}", self.competition_label()));
+        }
+        if !self.next_action.is_empty() {
+            lines.push(format!("Next: {}", self.next_action));
+        }
+        if !self.notes.is_empty() {
+            lines.push(format!("Notes: {}", self.notes));
+        }
+        lines.join("\n")
+    }
+
+    /// "pH 3.40 · TA 6.5 g/L", or just the one recorded; None when neither is
+    pub fn acidity_label(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            self.ph.map(|ph| format!("pH {:.2}", ph)),
+            self.ta.map(|ta| format!("TA {:.1} g/L", ta)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
+
+    /// "M1A Dry Traditional, 38/50", with "no category" or "not scored" for
+    /// what isn't recorded yet
+    pub fn competition_label(&self) -> String {
+        let category = if self.competition_category.is_empty() {
+            "no category"
+        } else {
+            self.competition_category.as_str()
+        };
+        match self.competition_score {
+            Some(score) => format!("{}, {}/{}", category, score, MAX_COMPETITION_SCORE),
+            None => format!("{}, not scored", category),
+        }
+    }
+
+    /// Short description of the time left until the target date
+    pub fn target_label(&self) -> Option<String> {
+        self.days_until_target().map(|days| match days {
+            0 => "due today".to_string(),
+            d if d > 0 => format!("{}d left", d),
+            d => format!("{}d overdue", -d),
+        })
+    }
+}
+
 /// Type of ingredient added to mead
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IngredientType {
     Fruit,
     Spice,
@@ -145,6 +572,24 @@ impl IngredientType {
         }
     }
 
+    /// Mark shown before the type in ingredient lists, or "" when glyphs
+    /// are off
+    pub fn glyph(&self, glyphs: IngredientGlyphs) -> &'static str {
+        match (glyphs, self) {
+            (IngredientGlyphs::Off, _) => "",
+            (IngredientGlyphs::Unicode, IngredientType::Fruit) => "🍓",
+            (IngredientGlyphs::Unicode, IngredientType::Spice) => "🌿",
+            (IngredientGlyphs::Unicode, IngredientType::Nutrient) => "🧪",
+            (IngredientGlyphs::Unicode, IngredientType::Adjunct) => "🌰",
+            (IngredientGlyphs::Unicode, IngredientType::Other) => "📦",
+            (IngredientGlyphs::Ascii, IngredientType::Fruit) => "o",
+            (IngredientGlyphs::Ascii, IngredientType::Spice) => "~",
+            (IngredientGlyphs::Ascii, IngredientType::Nutrient) => "+",
+            (IngredientGlyphs::Ascii, IngredientType::Adjunct) => "#",
+            (IngredientGlyphs::Ascii, IngredientType::Other) => "*",
+        }
+    }
+
     pub fn all() -> Vec<IngredientType> {
         vec![
             IngredientType::Fruit,
@@ -156,8 +601,200 @@ impl IngredientType {
     }
 }
 
+/// How ingredient types are marked in ingredient lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IngredientGlyphs {
+    /// Emoji such as 🍓 for fruit
+    Unicode,
+    /// Plain characters for terminals without emoji
+    Ascii,
+    Off,
+}
+
+impl IngredientGlyphs {
+    /// Parse a config value: "unicode", "ascii" or "off"
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "unicode" | "emoji" => Some(IngredientGlyphs::Unicode),
+            "ascii" => Some(IngredientGlyphs::Ascii),
+            "off" | "none" => Some(IngredientGlyphs::Off),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IngredientGlyphs::Unicode => "unicode",
+            IngredientGlyphs::Ascii => "ascii",
+            IngredientGlyphs::Off => "off",
+        }
+    }
+
+    /// The next style round, for toggling from the keyboard
+    pub fn next(&self) -> Self {
+        match self {
+            IngredientGlyphs::Unicode => IngredientGlyphs::Ascii,
+            IngredientGlyphs::Ascii => IngredientGlyphs::Off,
+            IngredientGlyphs::Off => IngredientGlyphs::Unicode,
+        }
+    }
+}
+
+/// Yeast nutrient products with a known YAN contribution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nutrient {
+    FermaidO,
+    FermaidK,
+    Dap,
+    GoFerm,
+}
+
+impl Nutrient {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Nutrient::FermaidO => "Fermaid O",
+            Nutrient::FermaidK => "Fermaid K",
+            Nutrient::Dap => "DAP",
+            Nutrient::GoFerm => "Go-Ferm",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        Self::all()
+            .into_iter()
+            .find(|n| n.as_str().eq_ignore_ascii_case(s))
+            .unwrap_or(Nutrient::FermaidO)
+    }
+
+    pub fn all() -> Vec<Nutrient> {
+        vec![Nutrient::FermaidO, Nutrient::FermaidK, Nutrient::Dap, Nutrient::GoFerm]
+    }
+
+    /// YAN in ppm added by one gram of product per liter of must
+    pub fn ppm_per_gram_per_liter(&self) -> f64 {
+        match self {
+            Nutrient::FermaidO => 40.0,
+            Nutrient::FermaidK => 100.0,
+            Nutrient::Dap => 210.0,
+            Nutrient::GoFerm => 30.0,
+        }
+    }
+
+    /// YAN in ppm added by `grams` of product to a batch of `volume_gallons`
+    pub fn yan_ppm(&self, grams: f64, volume_gallons: f64) -> f64 {
+        let liters = volume_gallons * LITERS_PER_GALLON;
+        if liters <= 0.0 {
+            return 0.0;
+        }
+        grams / liters * self.ppm_per_gram_per_liter()
+    }
+}
+
+const LITERS_PER_GALLON: f64 = 3.78541;
+
+/// What a unit measures; amounts only add up within the same measure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Measure {
+    /// Canonical unit: grams
+    Mass,
+    /// Canonical unit: millilitres
+    Volume,
+    /// Whole items, e.g. cinnamon sticks
+    Count,
+}
+
+impl Measure {
+    /// A total in canonical units, in kg or L once it reaches a thousand
+    pub fn fmt_total(self, amount: f64) -> String {
+        match self {
+            Measure::Mass if amount >= 1000.0 => format!("{:.2} kg", amount / 1000.0),
+            Measure::Mass => format!("{:.0} g", amount),
+            Measure::Volume if amount >= 1000.0 => format!("{:.2} L", amount / 1000.0),
+            Measure::Volume => format!("{:.0} ml", amount),
+            Measure::Count => format!("{:.0} each", amount),
+        }
+    }
+}
+
+/// A unit an ingredient amount can be given in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Gram,
+    Kilogram,
+    Ounce,
+    Pound,
+    Milliliter,
+    Liter,
+    FluidOunce,
+    Teaspoon,
+    Tablespoon,
+    Cup,
+    Gallon,
+    Each,
+}
+
+impl Unit {
+    /// Units listed in the unknown-unit warning
+    pub const KNOWN: &'static str = "g, kg, oz, lb, ml, l, fl oz, tsp, tbsp, cup, gal, each";
+
+    /// Parse a free-form unit, ignoring case, plurals and a trailing dot
+    pub fn parse(s: &str) -> Option<Unit> {
+        let s = s.trim().trim_end_matches('.').to_lowercase();
+        let unit = match s.as_str() {
+            "g" | "gram" | "grams" => Unit::Gram,
+            "kg" | "kilo" | "kilos" | "kilogram" | "kilograms" => Unit::Kilogram,
+            "oz" | "ounce" | "ounces" => Unit::Ounce,
+            "lb" | "lbs" | "pound" | "pounds" => Unit::Pound,
+            "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" => Unit::Milliliter,
+            "l" | "liter" | "liters" | "litre" | "litres" => Unit::Liter,
+            "fl oz" | "floz" | "fluid ounce" | "fluid ounces" => Unit::FluidOunce,
+            "tsp" | "teaspoon" | "teaspoons" => Unit::Teaspoon,
+            "tbsp" | "tablespoon" | "tablespoons" => Unit::Tablespoon,
+            "cup" | "cups" => Unit::Cup,
+            "gal" | "gallon" | "gallons" => Unit::Gallon,
+            "" | "each" | "ea" | "pc" | "pcs" | "piece" | "pieces" | "whole" => Unit::Each,
+            _ => return None,
+        };
+        Some(unit)
+    }
+
+    pub fn measure(&self) -> Measure {
+        match self {
+            Unit::Gram | Unit::Kilogram | Unit::Ounce | Unit::Pound => Measure::Mass,
+            Unit::Milliliter
+            | Unit::Liter
+            | Unit::FluidOunce
+            | Unit::Teaspoon
+            | Unit::Tablespoon
+            | Unit::Cup
+            | Unit::Gallon => Measure::Volume,
+            Unit::Each => Measure::Count,
+        }
+    }
+
+    /// Convert an amount in this unit to grams, millilitres or a count
+    pub fn to_canonical(self, amount: f64) -> f64 {
+        let factor = match self {
+            Unit::Gram => 1.0,
+            Unit::Kilogram => 1000.0,
+            Unit::Ounce => 28.349523,
+            Unit::Pound => 453.59237,
+            Unit::Milliliter => 1.0,
+            Unit::Liter => 1000.0,
+            Unit::FluidOunce => 29.57353,
+            Unit::Teaspoon => 4.92892,
+            Unit::Tablespoon => 14.78676,
+            Unit::Cup => 236.58824,
+            Unit::Gallon => 3785.41178,
+            Unit::Each => 1.0,
+        };
+        amount * factor
+    }
+}
+
 /// Ingredient added to a mead batch
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Ingredient {
     pub id: i64,
     pub mead_id: i64,
@@ -166,6 +803,133 @@ pub struct Ingredient {
     pub amount: f64,
     pub unit: String,
     pub added_date: String,
+    /// Sourcing or prep details, e.g. "Oregon marionberries, frozen";
+    /// empty when there are none
+    pub notes: String,
+}
+
+impl Ingredient {
+    /// The amount in canonical units, or None if the unit isn't recognised
+    pub fn canonical_amount(&self) -> Option<(Measure, f64)> {
+        Unit::parse(&self.unit).map(|unit| (unit.measure(), unit.to_canonical(self.amount)))
+    }
+
+    /// A note when the amount looks like it was typed in the wrong unit,
+    /// e.g. 48 oz of honey that was meant as pounds. Only a hint; the
+    /// amount may well be right.
+    pub fn unit_warning(&self) -> Option<String> {
+        let unit = Unit::parse(&self.unit)?;
+        let grams = unit.to_canonical(self.amount);
+        let lbs = grams / Unit::Pound.to_canonical(1.0);
+        let oz = grams / Unit::Ounce.to_canonical(1.0);
+        match unit {
+            Unit::Ounce if self.amount >= 32.0 => {
+                Some(format!("{} oz is {:.1} lbs - did you mean lbs?", self.amount, lbs))
+            }
+            Unit::Pound if self.amount > 0.0 && self.amount < 0.1 => {
+                Some(format!("{} lbs is only {:.1} oz - did you mean oz?", self.amount, oz))
+            }
+            Unit::Kilogram if self.amount >= 50.0 => {
+                Some(format!("{} kg is {:.0} lbs - did you mean g?", self.amount, lbs))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether two ingredients are the same addition entered twice: same
+    /// name (ignoring case), type and unit
+    pub fn is_duplicate_of(&self, other: &Ingredient) -> bool {
+        self.ingredient_type == other.ingredient_type
+            && self.name.trim().eq_ignore_ascii_case(other.name.trim())
+            && self.unit.trim().eq_ignore_ascii_case(other.unit.trim())
+    }
+}
+
+/// Group duplicate ingredients, keeping their order. Only groups with more
+/// than one ingredient are returned; the first of each is the one to keep.
+pub fn duplicate_ingredient_groups(ingredients: &[Ingredient]) -> Vec<Vec<&Ingredient>> {
+    let mut groups: Vec<Vec<&Ingredient>> = Vec::new();
+    for ingredient in ingredients {
+        match groups.iter_mut().find(|group| group[0].is_duplicate_of(ingredient)) {
+            Some(group) => group.push(ingredient),
+            None => groups.push(vec![ingredient]),
+        }
+    }
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+/// One line of a shopping list: an ingredient's total over the batches
+/// that use it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShoppingItem {
+    pub name: String,
+    /// Total in canonical units, or in `unit` when it isn't recognised
+    pub amount: f64,
+    /// What the amount measures, or None for an unrecognised unit
+    pub measure: Option<Measure>,
+    /// The unit as typed, for an unrecognised unit
+    pub unit: String,
+    /// Number of batches needing it
+    pub batches: usize,
+}
+
+impl ShoppingItem {
+    /// The total with its unit, e.g. "1.36 kg" or "3.0 sachets"
+    pub fn amount_text(&self) -> String {
+        match self.measure {
+            Some(measure) => measure.fmt_total(self.amount),
+            None => format!("{:.1} {}", self.amount, self.unit.trim()),
+        }
+    }
+}
+
+/// Combine ingredients into a shopping list, alphabetical by name. Amounts
+/// of the same name (ignoring case) are summed where their units measure
+/// the same thing, so 8 oz and 1 lb of honey make one line; unrecognised
+/// units only combine with the same unit, ignoring case and a plural "s".
+pub fn shopping_list(ingredients: &[Ingredient]) -> Vec<ShoppingItem> {
+    let mut items: Vec<(ShoppingItem, HashSet<i64>)> = Vec::new();
+    for ingredient in ingredients {
+        let (measure, amount) = match ingredient.canonical_amount() {
+            Some((measure, amount)) => (Some(measure), amount),
+            None => (None, ingredient.amount),
+        };
+        let name = ingredient.name.trim();
+        let existing = items.iter_mut().find(|(item, _)| {
+            item.name.eq_ignore_ascii_case(name)
+                && item.measure == measure
+                && (measure.is_some() || unit_key(&item.unit) == unit_key(&ingredient.unit))
+        });
+        match existing {
+            Some((item, meads)) => {
+                item.amount += amount;
+                meads.insert(ingredient.mead_id);
+            }
+            None => items.push((
+                ShoppingItem {
+                    name: name.to_string(),
+                    amount,
+                    measure,
+                    unit: ingredient.unit.clone(),
+                    batches: 0,
+                },
+                HashSet::from([ingredient.mead_id]),
+            )),
+        }
+    }
+    let mut items: Vec<ShoppingItem> = items
+        .into_iter()
+        .map(|(item, meads)| ShoppingItem { batches: meads.len(), ..item })
+        .collect();
+    items.sort_by_key(|item| item.name.to_lowercase());
+    items
+}
+
+/// An unrecognised unit for comparing, so "Sachets" matches "sachet"
+fn unit_key(unit: &str) -> String {
+    let unit = unit.trim().to_lowercase();
+    unit.strip_suffix('s').map(str::to_string).unwrap_or(unit)
 }
 
 impl Default for Ingredient {
@@ -177,18 +941,59 @@ impl Default for Ingredient {
             name: String::new(),
             amount: 0.0,
             unit: String::from("oz"),
-            added_date: Utc::now().format("%Y-%m-%d").to_string(),
+            added_date: Local::now().format("%Y-%m-%d").to_string(),
+            notes: String::new(),
         }
     }
 }
 
+/// Category of a log entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogEntryType {
+    Measurement,
+    Addition,
+    Observation,
+    Action,
+}
+
+impl LogEntryType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogEntryType::Measurement => "Measurement",
+            LogEntryType::Addition => "Addition",
+            LogEntryType::Observation => "Observation",
+            LogEntryType::Action => "Action",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "measurement" => LogEntryType::Measurement,
+            "addition" => LogEntryType::Addition,
+            "action" => LogEntryType::Action,
+            _ => LogEntryType::Observation,
+        }
+    }
+
+    pub fn all() -> Vec<LogEntryType> {
+        vec![
+            LogEntryType::Measurement,
+            LogEntryType::Addition,
+            LogEntryType::Observation,
+            LogEntryType::Action,
+        ]
+    }
+}
+
 /// Log entry for tracking changes/events
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct LogEntry {
     pub id: i64,
     pub mead_id: i64,
     pub timestamp: DateTime<Utc>,
     pub entry_text: String,
+    pub entry_type: LogEntryType,
 }
 
 impl Default for LogEntry {
@@ -198,7 +1003,557 @@ impl Default for LogEntry {
             mead_id: 0,
             timestamp: Utc::now(),
             entry_text: String::new(),
+            entry_type: LogEntryType::Observation,
         }
     }
 }
 
+/// A dated to-do attached to a mead batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Reminder {
+    pub id: i64,
+    pub mead_id: i64,
+    /// Due date (YYYY-MM-DD)
+    pub due_date: String,
+    pub text: String,
+    pub done: bool,
+}
+
+impl Default for Reminder {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            mead_id: 0,
+            due_date: Local::now().format("%Y-%m-%d").to_string(),
+            text: String::new(),
+            done: false,
+        }
+    }
+}
+
+impl Reminder {
+    /// Whether the reminder is still open and its due date has passed
+    pub fn is_overdue(&self) -> bool {
+        !self.done
+            && NaiveDate::parse_from_str(self.due_date.trim(), "%Y-%m-%d")
+                .is_ok_and(|due| due < Local::now().date_naive())
+    }
+}
+
+/// A status a mead entered, and when
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub id: i64,
+    pub mead_id: i64,
+    pub status: MeadStatus,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Days of readings looked back over when checking for a stuck fermentation
+const STUCK_DAYS: i64 = 7;
+
+/// Smallest gravity drop over `STUCK_DAYS` that still counts as fermenting
+const STUCK_MIN_DROP: f64 = 0.002;
+
+/// Number of most recent gravity readings used to estimate the FG date
+const FG_ESTIMATE_READINGS: usize = 5;
+
+/// Projected date for reaching the target final gravity
+#[derive(Debug, Clone, PartialEq)]
+pub enum FgEstimate {
+    /// Current gravity is already at or below the target
+    Reached,
+    /// Expected to reach the target on this date
+    By(NaiveDate),
+    /// Too few readings, or gravity isn't dropping
+    CannotEstimate,
+}
+
+impl FgEstimate {
+    pub fn label(&self) -> String {
+        match self {
+            FgEstimate::Reached => "target FG reached".to_string(),
+            FgEstimate::By(date) => format!("Est. FG by {}", fmt_date(*date)),
+            FgEstimate::CannotEstimate => "cannot estimate".to_string(),
+        }
+    }
+}
+
+/// Gravity changes smaller than this count as holding steady
+const TREND_MIN_CHANGE: f64 = 0.0005;
+
+/// Which way gravity moved between the two most recent readings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GravityTrend {
+    Up,
+    Down,
+    Steady,
+}
+
+impl GravityTrend {
+    /// Trend from one mead's readings, oldest first. None with fewer than
+    /// two readings.
+    pub fn from_readings(readings: &[GravityReading]) -> Option<Self> {
+        let [previous, latest] = readings.get(readings.len().checked_sub(2)?..)? else {
+            return None;
+        };
+        let change = latest.gravity - previous.gravity;
+        Some(if change.abs() < TREND_MIN_CHANGE {
+            GravityTrend::Steady
+        } else if change < 0.0 {
+            GravityTrend::Down
+        } else {
+            GravityTrend::Up
+        })
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            GravityTrend::Up => "▲",
+            GravityTrend::Down => "▼",
+            GravityTrend::Steady => "—",
+        }
+    }
+}
+
+/// A gravity measurement and the stage the mead was in when it was taken
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GravityReading {
+    pub id: i64,
+    pub mead_id: i64,
+    pub gravity: f64,
+    /// Sample temperature, when one was given with the measurement
+    pub temperature: Option<f64>,
+    pub status: MeadStatus,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Part of a mead that can be carried over when duplicating it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CopyPart {
+    /// Honey, yeast, volume and YAN required
+    Recipe,
+    /// Starting gravity and target ABV
+    GravityTargets,
+    Ingredients,
+    /// Copied ingredients keep their added dates instead of taking the new
+    /// batch's start date
+    IngredientDates,
+    Notes,
+    Logs,
+    Readings,
+}
+
+impl CopyPart {
+    pub const ALL: [CopyPart; 7] = [
+        CopyPart::Recipe,
+        CopyPart::GravityTargets,
+        CopyPart::Ingredients,
+        CopyPart::IngredientDates,
+        CopyPart::Notes,
+        CopyPart::Logs,
+        CopyPart::Readings,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CopyPart::Recipe => "Recipe (honey, yeast, volume, YAN)",
+            CopyPart::GravityTargets => "Gravity targets (OG, target ABV)",
+            CopyPart::Ingredients => "Ingredients",
+            CopyPart::IngredientDates => "  ...keeping their added dates",
+            CopyPart::Notes => "Notes",
+            CopyPart::Logs => "Log entries",
+            CopyPart::Readings => "Gravity readings",
+        }
+    }
+
+    /// Whether the part is ticked when the duplicate dialog opens
+    pub fn copied_by_default(&self) -> bool {
+        matches!(self, CopyPart::Recipe | CopyPart::GravityTargets | CopyPart::Ingredients)
+    }
+}
+
+/// Kind of event shown in the recent activity feed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActivityKind {
+    Log,
+    Gravity,
+    Status,
+}
+
+impl ActivityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityKind::Log => "Log",
+            ActivityKind::Gravity => "Gravity",
+            ActivityKind::Status => "Status",
+        }
+    }
+}
+
+/// Something that happened to a mead: a log entry, gravity reading or
+/// status change
+#[derive(Debug, Clone)]
+pub struct Activity {
+    pub mead_id: i64,
+    pub mead_name: String,
+    pub kind: ActivityKind,
+    /// Category of a log entry; None for readings and status changes
+    pub entry_type: Option<LogEntryType>,
+    /// Log text, formatted gravity or new status
+    pub text: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Events the activity log can be narrowed to: gravity readings, status
+/// changes, or log entries of one type
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActivityType {
+    Gravity,
+    Status,
+    Log(LogEntryType),
+}
+
+impl ActivityType {
+    /// Every type, in the order the filter cycles through them
+    pub fn all() -> Vec<ActivityType> {
+        let mut types = vec![ActivityType::Gravity, ActivityType::Status];
+        types.extend(LogEntryType::all().into_iter().map(ActivityType::Log));
+        types
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityType::Gravity => ActivityKind::Gravity.as_str(),
+            ActivityType::Status => ActivityKind::Status.as_str(),
+            ActivityType::Log(entry_type) => entry_type.as_str(),
+        }
+    }
+}
+
+/// What the activity log shows; the default shows everything
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActivityFilter {
+    /// Only this mead's events, as (id, name)
+    pub mead: Option<(i64, String)>,
+    /// First day shown
+    pub since: Option<NaiveDate>,
+    /// Last day shown
+    pub until: Option<NaiveDate>,
+    pub activity_type: Option<ActivityType>,
+    /// Text the event or its mead's name must contain, any case
+    pub query: String,
+}
+
+impl ActivityFilter {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The filters in use, e.g. "Mead: Traditional · Type: Addition";
+    /// empty when nothing is filtered
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some((_, name)) = &self.mead {
+            parts.push(format!("Mead: {}", name));
+        }
+        if let Some(activity_type) = &self.activity_type {
+            parts.push(format!("Type: {}", activity_type.as_str()));
+        }
+        match (self.since, self.until) {
+            (Some(since), Some(until)) => parts.push(format!("{} to {}", fmt_date(since), fmt_date(until))),
+            (Some(since), None) => parts.push(format!("From {}", fmt_date(since))),
+            (None, Some(until)) => parts.push(format!("Until {}", fmt_date(until))),
+            (None, None) => {}
+        }
+        if !self.query.is_empty() {
+            parts.push(format!("Text: \"{}\"", self.query));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// Upper ABV bound and label of each histogram bucket, weakest first
+const ABV_BUCKETS: [(f64, &str); 4] = [(8.0, "<8%"), (12.0, "8-12%"), (16.0, "12-16%"), (f64::INFINITY, "16%+")];
+
+/// Finished batches in one ABV range
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbvBucket {
+    pub label: &'static str,
+    pub batches: usize,
+}
+
+/// Count batches into ABV ranges, keeping empty ranges so the histogram
+/// always has the same rows
+pub fn abv_histogram(abvs: &[f64]) -> Vec<AbvBucket> {
+    let mut buckets: Vec<AbvBucket> = ABV_BUCKETS
+        .iter()
+        .map(|(_, label)| AbvBucket { label, batches: 0 })
+        .collect();
+    for abv in abvs.iter().filter(|abv| abv.is_finite()) {
+        if let Some(index) = ABV_BUCKETS.iter().position(|(upper, _)| abv < upper) {
+            buckets[index].batches += 1;
+        }
+    }
+    buckets
+}
+
+/// Months shown in the batches-started trend, including the current one
+pub const TREND_MONTHS: i32 = 12;
+
+/// Batches started in one calendar month
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthCount {
+    /// The month as "YYYY-MM"
+    pub month: String,
+    pub batches: usize,
+}
+
+/// Batches started in each of the last `TREND_MONTHS` months up to
+/// `today`, oldest first. Months without a batch are kept so the chart
+/// has no gaps; start dates that are missing or don't parse are left out.
+pub fn batches_by_month(meads: &[Mead], today: NaiveDate) -> Vec<MonthCount> {
+    let month_index = |date: NaiveDate| date.year() * 12 + date.month0() as i32;
+    let last = month_index(today);
+    let first = last - (TREND_MONTHS - 1);
+    let mut counts = vec![0; TREND_MONTHS as usize];
+    for mead in meads {
+        let Ok(start) = NaiveDate::parse_from_str(mead.start_date.trim(), ISO_DATE) else {
+            continue;
+        };
+        let index = month_index(start);
+        if (first..=last).contains(&index) {
+            counts[(index - first) as usize] += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, batches)| {
+            let index = first + i as i32;
+            MonthCount { month: format!("{}-{:02}", index / 12, index % 12 + 1), batches }
+        })
+        .collect()
+}
+
+/// Honey of one variety used across batches
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoneyUsage {
+    /// Variety as entered; empty when none was given
+    pub honey_type: String,
+    pub lbs: f64,
+    pub batches: usize,
+}
+
+/// How long batches stayed in one status before moving on
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusDuration {
+    pub status: MeadStatus,
+    /// Stays that have ended, i.e. the batch has since changed status
+    pub stays: usize,
+    pub average_days: f64,
+    pub median_days: f64,
+}
+
+/// Average and median days spent in each status, from status changes in
+/// time order. A stay counts once the batch has left the status, and only
+/// if it began on or after `since` (an ISO date). Statuses with no ended
+/// stays are left out.
+pub fn status_durations(changes: &[StatusChange], since: Option<&str>) -> Vec<StatusDuration> {
+    let mut days: HashMap<&'static str, Vec<f64>> = HashMap::new();
+    let mut last: HashMap<i64, &StatusChange> = HashMap::new();
+    for change in changes {
+        if let Some(entered) = last.insert(change.mead_id, change) {
+            let began = entered.changed_at.format("%Y-%m-%d").to_string();
+            if since.is_none_or(|since| began.as_str() >= since) {
+                let stay = (change.changed_at - entered.changed_at).num_seconds() as f64 / 86_400.0;
+                days.entry(entered.status.as_str()).or_default().push(stay);
+            }
+        }
+    }
+    MeadStatus::all()
+        .into_iter()
+        .filter_map(|status| {
+            let mut stays = days.remove(status.as_str())?;
+            stays.sort_by(f64::total_cmp);
+            let middle = stays.len() / 2;
+            let median_days = if stays.len().is_multiple_of(2) {
+                (stays[middle - 1] + stays[middle]) / 2.0
+            } else {
+                stays[middle]
+            };
+            Some(StatusDuration {
+                status,
+                stays: stays.len(),
+                average_days: stays.iter().sum::<f64>() / stays.len() as f64,
+                median_days,
+            })
+        })
+        .collect()
+}
+
+/// A mead's full state saved under a label, e.g. "as planned"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeadSnapshot {
+    pub id: i64,
+    pub mead_id: i64,
+    pub label: String,
+    pub taken_at: DateTime<Utc>,
+    /// The mead as it was when the snapshot was taken
+    pub mead: Mead,
+}
+
+/// A field that differs between two versions of a mead, as displayed
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// Fields compared between versions of a mead, with how each is shown
+type MeadField = (&'static str, fn(&Mead) -> String);
+
+const COMPARED_FIELDS: &[MeadField] = &[
+    ("Name", |m| m.name.clone()),
+    ("Status", |m| m.status.as_str().to_string()),
+    ("Base", |m| m.base_type.as_str().to_string()),
+    ("Start Date", |m| fmt_date_str(&m.start_date)),
+    ("Target Date", |m| fmt_date_str(&m.target_date)),
+    ("Honey", |m| m.honey_type.clone()),
+    ("Honey Amount", |m| format!("{:.2} lbs", m.honey_amount_lbs)),
+    ("Yeast", |m| m.yeast_strain.clone()),
+    ("Volume", |m| format!("{:.2} gal", m.volume_gallons)),
+    ("Target ABV", |m| format!("{}%", fmt_abv(m.target_abv))),
+    ("Starting Gravity", |m| fmt_gravity(m.starting_gravity)),
+    ("Current Gravity", |m| fmt_gravity(m.current_gravity)),
+    ("YAN Required", |m| format!("{} ppm", fmt_yan(m.yan_required))),
+    ("YAN Added", |m| format!("{} ppm", fmt_yan(m.yan_added))),
+    ("Final Gravity", |m| m.final_gravity.map(fmt_gravity).unwrap_or_default()),
+    ("Bottled", |m| fmt_date_str(&m.bottled_date)),
+    ("Bottles", |m| m.bottle_count.map(|c| c.to_string()).unwrap_or_default()),
+    ("pH", |m| m.ph.map(|ph| format!("{:.2}", ph)).unwrap_or_default()),
+    ("TA", |m| m.ta.map(|ta| format!("{:.1} g/L", ta)).unwrap_or_default()),
+    ("Rating", |m| rating_stars(m.rating)),
+    ("Notes", |m| m.notes.clone()),
+];
+
+/// Every compared field of `before` and `after` as displayed, in form order
+pub fn mead_comparison(before: &Mead, after: &Mead) -> Vec<FieldChange> {
+    COMPARED_FIELDS
+        .iter()
+        .map(|(field, show)| FieldChange { field, before: show(before), after: show(after) })
+        .collect()
+}
+
+/// Fields whose displayed value differs from `before` to `after`, in form
+/// order
+pub fn mead_changes(before: &Mead, after: &Mead) -> Vec<FieldChange> {
+    mead_comparison(before, after)
+        .into_iter()
+        .filter(|change| change.before != change.after)
+        .collect()
+}
+
+impl FieldChange {
+    /// How the value moved: a signed difference such as "+0.25 gal" for
+    /// numbers, "changed" for other text and blank when it is the same
+    pub fn diff(&self) -> String {
+        if self.before == self.after {
+            return String::new();
+        }
+        match (split_number(&self.before), split_number(&self.after)) {
+            (Some((before, decimals_before, unit)), Some((after, decimals_after, after_unit))) if unit == after_unit => {
+                format!("{:+.*}{}", decimals_before.max(decimals_after), after - before, unit)
+            }
+            _ => "changed".to_string(),
+        }
+    }
+}
+
+/// A displayed value split into its leading number, the decimal places it
+/// was shown with and the unit after it, e.g. "5.00 gal" or "12.5%"
+fn split_number(text: &str) -> Option<(f64, usize, &str)> {
+    let end = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(end);
+    let value = number.parse().ok()?;
+    let decimals = number.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+    Some((value, decimals, unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reading `days` after the first of June 2025
+    fn reading(days: i64, gravity: f64) -> GravityReading {
+        let start = DateTime::parse_from_rfc3339("2025-06-01T12:00:00Z").unwrap().with_timezone(&Utc);
+        GravityReading {
+            id: 0,
+            mead_id: 1,
+            gravity,
+            temperature: None,
+            status: MeadStatus::Primary,
+            recorded_at: start + chrono::Duration::days(days),
+        }
+    }
+
+    fn fermenting(current_gravity: f64) -> Mead {
+        Mead {
+            status: MeadStatus::Primary,
+            starting_gravity: 1.100,
+            current_gravity,
+            target_abv: 14.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fg_estimate_extrapolates_the_recent_drop() {
+        // Target FG is 1.100 - 14 / 131.25, about 0.993
+        let mead = fermenting(1.080);
+        let readings = [reading(0, 1.100), reading(2, 1.090), reading(4, 1.080)];
+        let expected = NaiveDate::from_ymd_opt(2025, 6, 22).unwrap();
+        assert_eq!(mead.fg_estimate(&readings), FgEstimate::By(expected));
+
+        assert_eq!(fermenting(0.990).fg_estimate(&readings), FgEstimate::Reached);
+        assert_eq!(mead.fg_estimate(&readings[..1]), FgEstimate::CannotEstimate);
+    }
+
+    #[test]
+    fn fg_estimate_gives_up_on_flat_or_rising_gravity() {
+        let mead = fermenting(1.080);
+        let flat = [reading(0, 1.080), reading(3, 1.080)];
+        assert_eq!(mead.fg_estimate(&flat), FgEstimate::CannotEstimate);
+        let rising = [reading(0, 1.070), reading(3, 1.080)];
+        assert_eq!(mead.fg_estimate(&rising), FgEstimate::CannotEstimate);
+        let same_time = [reading(1, 1.090), reading(1, 1.080)];
+        assert_eq!(mead.fg_estimate(&same_time), FgEstimate::CannotEstimate);
+    }
+
+    #[test]
+    fn fg_estimate_past_the_last_date_cannot_estimate() {
+        // Dropping a ten-billionth a day would take longer than dates go
+        let mead = fermenting(1.100);
+        let readings = [reading(0, 1.100 + 1e-10), reading(1, 1.100)];
+        assert_eq!(mead.fg_estimate(&readings), FgEstimate::CannotEstimate);
+    }
+
+    #[test]
+    fn reading_schedules_count_from_the_latest_reading() {
+        let mead = Mead { gravity_check_days: Some(7), ..fermenting(1.080) };
+        let latest = GravityReading { recorded_at: Utc::now() - chrono::Duration::days(3), ..reading(0, 1.080) };
+        assert_eq!(mead.days_until_reading(&[reading(0, 1.100), latest]), Some(4));
+        assert_eq!(Mead { gravity_check_days: None, ..mead }.days_until_reading(&[]), None);
+    }
+
+    #[test]
+    fn reading_schedules_past_the_last_date_have_no_due_day() {
+        let mead = Mead { gravity_check_days: Some(MAX_READING_INTERVAL_DAYS), ..fermenting(1.080) };
+        let last = GravityReading { recorded_at: DateTime::<Utc>::MAX_UTC, ..reading(0, 1.080) };
+        assert_eq!(mead.days_until_reading(&[last]), None);
+    }
+}
@@ -0,0 +1,78 @@
+//! Moving a selection through lists, menus and form fields.
+//!
+//! Whether moving past either end wraps around is kept per thread, like
+//! the display formats, so every view behaves the same; `set_wrap` is
+//! called once at startup from the config.
+
+use std::cell::Cell;
+
+thread_local! {
+    static WRAP: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Set whether navigation wraps from the last item to the first and back
+pub fn set_wrap(wrap: bool) {
+    WRAP.set(wrap);
+}
+
+/// Index after `index` in a list of `len` items: wraps to the first item,
+/// or stays on the last when wrapping is off
+pub fn next_index(index: usize, len: usize) -> usize {
+    step_next(index, len, WRAP.get())
+}
+
+/// Index before `index` in a list of `len` items: wraps to the last item,
+/// or stays on the first when wrapping is off
+pub fn previous_index(index: usize, len: usize) -> usize {
+    step_previous(index, len, WRAP.get())
+}
+
+fn step_next(index: usize, len: usize, wrap: bool) -> usize {
+    if len == 0 {
+        0
+    } else if index + 1 < len {
+        index + 1
+    } else if wrap {
+        0
+    } else {
+        len - 1
+    }
+}
+
+fn step_previous(index: usize, len: usize, wrap: bool) -> usize {
+    if len == 0 {
+        0
+    } else if index > 0 {
+        index.min(len) - 1
+    } else if wrap {
+        len - 1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_at_both_ends() {
+        assert_eq!(step_next(2, 3, true), 0);
+        assert_eq!(step_previous(0, 3, true), 2);
+        assert_eq!(step_next(0, 3, true), 1);
+    }
+
+    #[test]
+    fn stops_at_the_ends_without_wrapping() {
+        assert_eq!(step_next(2, 3, false), 2);
+        assert_eq!(step_previous(0, 3, false), 0);
+        assert_eq!(step_previous(2, 3, false), 1);
+    }
+
+    #[test]
+    fn empty_and_out_of_range_lists_stay_in_bounds() {
+        assert_eq!(step_next(0, 0, true), 0);
+        assert_eq!(step_previous(0, 0, false), 0);
+        assert_eq!(step_previous(7, 3, true), 2);
+    }
+}
@@ -0,0 +1,81 @@
+//! Suggested yeast nutrient targets for a must.
+//!
+//! Yeast need more nitrogen the more sugar they have to ferment, so the
+//! YAN target follows TOSNA: ten times the sugar content in °Brix, scaled by
+//! how hungry the yeast strain is. The total to add is that concentration
+//! over the batch volume.
+
+const LITERS_PER_GALLON: f64 = 3.78541;
+
+/// How much nitrogen a yeast strain needs to ferment cleanly
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NitrogenDemand {
+    Low,
+    Medium,
+    High,
+}
+
+impl NitrogenDemand {
+    pub fn all() -> Vec<NitrogenDemand> {
+        vec![NitrogenDemand::Low, NitrogenDemand::Medium, NitrogenDemand::High]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NitrogenDemand::Low => "Low",
+            NitrogenDemand::Medium => "Medium",
+            NitrogenDemand::High => "High",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "Low" => NitrogenDemand::Low,
+            "High" => NitrogenDemand::High,
+            _ => NitrogenDemand::Medium,
+        }
+    }
+
+    /// Multiplier on the sugar-based YAN target
+    fn factor(&self) -> f64 {
+        match self {
+            NitrogenDemand::Low => 0.75,
+            NitrogenDemand::Medium => 0.9,
+            NitrogenDemand::High => 1.25,
+        }
+    }
+}
+
+/// Sugar content in °Brix for a specific gravity
+pub fn brix(gravity: f64) -> f64 {
+    if gravity <= 1.0 {
+        return 0.0;
+    }
+    261.3 * (1.0 - 1.0 / gravity)
+}
+
+/// Suggested YAN in ppm for a must of `starting_gravity`
+pub fn suggested_yan(starting_gravity: f64, demand: NitrogenDemand) -> f64 {
+    brix(starting_gravity) * 10.0 * demand.factor()
+}
+
+/// Total YAN in mg that brings `volume_gallons` of must to `ppm`
+pub fn total_yan_mg(ppm: f64, volume_gallons: f64) -> f64 {
+    ppm * volume_gallons.max(0.0) * LITERS_PER_GALLON
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hungrier_yeast_and_stronger_musts_need_more_nitrogen() {
+        let medium = suggested_yan(1.100, NitrogenDemand::Medium);
+        assert!((medium - 213.8).abs() < 0.1, "{}", medium);
+        assert!(suggested_yan(1.100, NitrogenDemand::Low) < medium);
+        assert!(suggested_yan(1.100, NitrogenDemand::High) > medium);
+        assert!(suggested_yan(1.130, NitrogenDemand::Medium) > medium);
+        assert_eq!(suggested_yan(1.000, NitrogenDemand::High), 0.0);
+        assert!((total_yan_mg(200.0, 1.0) - 757.1).abs() < 0.1);
+    }
+}
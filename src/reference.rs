@@ -0,0 +1,176 @@
+//! Built-in reference data for planning recipes: common yeast strains and
+//! honey varietals. Figures are the manufacturers' published ranges or
+//! typical values and vary with the must.
+
+/// A yeast strain commonly used for mead
+#[derive(Debug, Clone, Copy)]
+pub struct YeastInfo {
+    pub name: &'static str,
+    /// How completely it ferments the sugars, e.g. "Very high"
+    pub attenuation: &'static str,
+    /// Fermentation temperature range in °F
+    pub temp_range_f: (u8, u8),
+    /// Alcohol tolerance, % ABV
+    pub alcohol_tolerance: f64,
+    pub notes: &'static str,
+}
+
+/// A honey varietal and what it brings to a mead
+#[derive(Debug, Clone, Copy)]
+pub struct HoneyInfo {
+    pub name: &'static str,
+    pub flavor: &'static str,
+}
+
+pub static YEASTS: &[YeastInfo] = &[
+    YeastInfo {
+        name: "Lalvin 71B-1122",
+        attenuation: "High",
+        temp_range_f: (59, 86),
+        alcohol_tolerance: 14.0,
+        notes: "Softens acidity; fruity esters suit melomels",
+    },
+    YeastInfo {
+        name: "Lalvin D47",
+        attenuation: "High",
+        temp_range_f: (59, 68),
+        alcohol_tolerance: 14.0,
+        notes: "Rich mouthfeel; harsh fusels above 68°F",
+    },
+    YeastInfo {
+        name: "Lalvin EC-1118",
+        attenuation: "Very high",
+        temp_range_f: (50, 86),
+        alcohol_tolerance: 18.0,
+        notes: "Neutral, vigorous, ferments bone dry",
+    },
+    YeastInfo {
+        name: "Lalvin K1-V1116",
+        attenuation: "Very high",
+        temp_range_f: (50, 95),
+        alcohol_tolerance: 18.0,
+        notes: "Floral esters; tolerant of tough conditions",
+    },
+    YeastInfo {
+        name: "Lalvin QA23",
+        attenuation: "High",
+        temp_range_f: (59, 90),
+        alcohol_tolerance: 16.0,
+        notes: "Clean and fresh; brings out honey aromatics",
+    },
+    YeastInfo {
+        name: "Red Star Premier Blanc",
+        attenuation: "Very high",
+        temp_range_f: (59, 86),
+        alcohol_tolerance: 15.0,
+        notes: "Neutral; good for restarting stuck ferments",
+    },
+    YeastInfo {
+        name: "Red Star Premier Cuvée",
+        attenuation: "Very high",
+        temp_range_f: (45, 95),
+        alcohol_tolerance: 18.0,
+        notes: "Low foaming, low nutrient needs",
+    },
+    YeastInfo {
+        name: "Mangrove Jack's M05",
+        attenuation: "Very high",
+        temp_range_f: (59, 86),
+        alcohol_tolerance: 18.0,
+        notes: "Bred for mead; clean and dry",
+    },
+    YeastInfo {
+        name: "Wyeast 4184 Sweet Mead",
+        attenuation: "Medium",
+        temp_range_f: (65, 75),
+        alcohol_tolerance: 11.0,
+        notes: "Stops early and leaves residual sweetness",
+    },
+    YeastInfo {
+        name: "Wyeast 4632 Dry Mead",
+        attenuation: "Very high",
+        temp_range_f: (55, 75),
+        alcohol_tolerance: 18.0,
+        notes: "Dry, crisp finish with subtle fruit",
+    },
+    YeastInfo {
+        name: "White Labs WLP720",
+        attenuation: "Medium",
+        temp_range_f: (70, 75),
+        alcohol_tolerance: 15.0,
+        notes: "Sweet mead/wine strain, slightly fruity",
+    },
+    YeastInfo {
+        name: "SafAle US-05",
+        attenuation: "78-82%",
+        temp_range_f: (59, 75),
+        alcohol_tolerance: 11.0,
+        notes: "Clean ale yeast for braggots",
+    },
+    YeastInfo {
+        name: "Kveik (Voss)",
+        attenuation: "High",
+        temp_range_f: (77, 104),
+        alcohol_tolerance: 12.0,
+        notes: "Fast and hot; orange-peel esters",
+    },
+];
+
+pub static HONEYS: &[HoneyInfo] = &[
+    HoneyInfo {
+        name: "Wildflower",
+        flavor: "Varies by region and season; a balanced all-rounder",
+    },
+    HoneyInfo {
+        name: "Clover",
+        flavor: "Mild, sweet and clean; lets fruit and spice lead",
+    },
+    HoneyInfo {
+        name: "Orange Blossom",
+        flavor: "Floral with a light citrus note; a classic traditional",
+    },
+    HoneyInfo {
+        name: "Buckwheat",
+        flavor: "Dark, malty and earthy, like molasses; strong in small doses",
+    },
+    HoneyInfo {
+        name: "Tupelo",
+        flavor: "Buttery and floral with a hint of cinnamon; resists crystallising",
+    },
+    HoneyInfo {
+        name: "Meadowfoam",
+        flavor: "Toasted marshmallow and vanilla",
+    },
+    HoneyInfo {
+        name: "Sage",
+        flavor: "Light, delicate and herbal",
+    },
+    HoneyInfo {
+        name: "Acacia",
+        flavor: "Very pale and mild with a clean vanilla finish",
+    },
+    HoneyInfo {
+        name: "Avocado",
+        flavor: "Dark and rich with butterscotch notes",
+    },
+    HoneyInfo {
+        name: "Blueberry",
+        flavor: "Fruity with a tangy, slightly tart edge",
+    },
+    HoneyInfo {
+        name: "Mesquite",
+        flavor: "Warm and smoky with a woody sweetness",
+    },
+    HoneyInfo {
+        name: "Fireweed",
+        flavor: "Light, smooth and subtly fruity",
+    },
+    HoneyInfo {
+        name: "Heather",
+        flavor: "Bold, bittersweet and aromatic; thick and jelly-like",
+    },
+    HoneyInfo {
+        name: "Manuka",
+        flavor: "Earthy and medicinal; usually too costly to ferment",
+    },
+];
@@ -0,0 +1,58 @@
+//! Helpers shared by the app and view tests for reading what was drawn and
+//! for keeping files they write out of the user's data directory.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ratatui::{backend::TestBackend, Terminal};
+
+/// Everything on screen as one string, row after row
+pub fn screen_text(terminal: &Terminal<TestBackend>) -> String {
+    terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect()
+}
+
+/// Each screen row as text
+pub fn screen_rows(terminal: &Terminal<TestBackend>) -> Vec<String> {
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+        .collect()
+}
+
+/// A fresh directory under the system temp dir, removed with everything in
+/// it when dropped
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let name = format!("mead_tracker_test_{}_{}", std::process::id(), NEXT.fetch_add(1, Ordering::Relaxed));
+        let path = std::env::temp_dir().join(name);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// The detail view's open form of the given kind, failing the test when it
+/// isn't the one open
+macro_rules! detail_form {
+    ($view:expr, $kind:ident) => {
+        match &mut $view.form {
+            Some($crate::views::mead_detail::DetailForm::$kind(form)) => form,
+            _ => panic!("the {} form isn't open", stringify!($kind)),
+        }
+    };
+}
+pub(crate) use detail_form;
@@ -0,0 +1,272 @@
+//! Accent colors for view titles and their borders, and the color each
+//! status is shown in.
+//!
+//! Like the display formats, the theme is kept per thread so any view can
+//! look up its accent while rendering; `set_theme` is called once at
+//! startup from the config. A view without its own accent uses the global
+//! one, and without that the built-in frost blue.
+//!
+//! The views draw with fixed RGB colors. On terminals without truecolor
+//! each finished frame is passed through `adapt_colors`, which swaps those
+//! for the nearest of the 256 or 16 colors the terminal has.
+
+use std::cell::Cell;
+
+use ratatui::buffer::Buffer;
+use ratatui::style::Color;
+
+use crate::models::MeadStatus;
+
+/// Accent used when the config sets none
+pub const DEFAULT_ACCENT: Color = Color::Rgb(136, 192, 208); // #88C0D0
+
+thread_local! {
+    static THEME: Cell<Theme> = const { Cell::new(Theme::EMPTY) };
+    static COLOR_SUPPORT: Cell<ColorSupport> = const { Cell::new(ColorSupport::TrueColor) };
+}
+
+/// How many colors the terminal can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+impl ColorSupport {
+    /// Parse a config value: "truecolor", "256" or "16"
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "truecolor" | "24bit" => Some(ColorSupport::TrueColor),
+            "256" => Some(ColorSupport::Ansi256),
+            "16" => Some(ColorSupport::Ansi16),
+            _ => None,
+        }
+    }
+
+    /// Work out the support from `$COLORTERM` and `$TERM`. Consoles that
+    /// set neither, such as Windows Terminal, are taken to have truecolor.
+    pub fn detect(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if colorterm.is_some_and(|c| matches!(c, "truecolor" | "24bit")) {
+            return ColorSupport::TrueColor;
+        }
+        match term {
+            None if colorterm.is_none() => ColorSupport::TrueColor,
+            Some(term) if term.contains("256color") => ColorSupport::Ansi256,
+            _ => ColorSupport::Ansi16,
+        }
+    }
+
+    /// `color` as this terminal can show it
+    pub fn adapt(self, color: Color) -> Color {
+        let Color::Rgb(r, g, b) = color else {
+            return color;
+        };
+        match self {
+            ColorSupport::TrueColor => color,
+            ColorSupport::Ansi256 => Color::Indexed(nearest_256(r, g, b)),
+            ColorSupport::Ansi16 => nearest_16(r, g, b),
+        }
+    }
+}
+
+/// The 16 ANSI colors with their usual xterm values
+const ANSI_16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_16(r: u8, g: u8, b: u8) -> Color {
+    ANSI_16
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+/// Index of the nearest color in the 6×6×6 cube or the gray ramp of the
+/// 256-color palette
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let level = |v: u8| (0..6).min_by_key(|&i| (LEVELS[i] as i32 - v as i32).abs()).unwrap_or(0);
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (LEVELS[ri], LEVELS[gi], LEVELS[bi]);
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray = 8 + step * 10;
+    if distance((gray, gray, gray), (r, g, b)) < distance(cube, (r, g, b)) {
+        232 + step
+    } else {
+        16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8
+    }
+}
+
+/// A view that can be given its own accent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeView {
+    Menu,
+    List,
+    Detail,
+    NewMead,
+    Stats,
+    Activity,
+    Reference,
+}
+
+impl ThemeView {
+    /// The view named by a config key prefix, e.g. "detail" in
+    /// `detail_accent`
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "menu" => Some(ThemeView::Menu),
+            "list" => Some(ThemeView::List),
+            "detail" => Some(ThemeView::Detail),
+            "new_mead" => Some(ThemeView::NewMead),
+            "stats" => Some(ThemeView::Stats),
+            "activity" => Some(ThemeView::Activity),
+            "reference" => Some(ThemeView::Reference),
+            _ => None,
+        }
+    }
+}
+
+/// Accent colors from the config; None falls back as described above
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Theme {
+    /// Accent for every view without its own
+    pub accent: Option<Color>,
+    pub menu: Option<Color>,
+    pub list: Option<Color>,
+    pub detail: Option<Color>,
+    pub new_mead: Option<Color>,
+    pub stats: Option<Color>,
+    pub activity: Option<Color>,
+    pub reference: Option<Color>,
+}
+
+impl Theme {
+    const EMPTY: Theme = Theme {
+        accent: None,
+        menu: None,
+        list: None,
+        detail: None,
+        new_mead: None,
+        stats: None,
+        activity: None,
+        reference: None,
+    };
+
+    /// The override for one view
+    pub fn view_mut(&mut self, view: ThemeView) -> &mut Option<Color> {
+        match view {
+            ThemeView::Menu => &mut self.menu,
+            ThemeView::List => &mut self.list,
+            ThemeView::Detail => &mut self.detail,
+            ThemeView::NewMead => &mut self.new_mead,
+            ThemeView::Stats => &mut self.stats,
+            ThemeView::Activity => &mut self.activity,
+            ThemeView::Reference => &mut self.reference,
+        }
+    }
+
+    /// Accent a view is drawn with
+    pub fn accent_for(mut self, view: ThemeView) -> Color {
+        self.view_mut(view).or(self.accent).unwrap_or(DEFAULT_ACCENT)
+    }
+}
+
+/// Set the accents used by `accent`
+pub fn set_theme(theme: Theme) {
+    THEME.set(theme);
+}
+
+/// Set how many colors frames are drawn with
+pub fn set_color_support(support: ColorSupport) {
+    COLOR_SUPPORT.set(support);
+}
+
+/// Swap the RGB colors in a drawn frame for ones the terminal can show
+pub fn adapt_colors(buf: &mut Buffer) {
+    let support = COLOR_SUPPORT.get();
+    if support == ColorSupport::TrueColor {
+        return;
+    }
+    for cell in buf.content.iter_mut() {
+        cell.fg = support.adapt(cell.fg);
+        cell.bg = support.adapt(cell.bg);
+    }
+}
+
+/// Accent for a view's title and border
+pub fn accent(view: ThemeView) -> Color {
+    THEME.get().accent_for(view)
+}
+
+/// Color used for a status wherever it is shown
+pub fn status_color(status: &MeadStatus) -> Color {
+    match status {
+        MeadStatus::Planning => Color::Rgb(76, 86, 106),    // #4C566A gray
+        MeadStatus::Primary => Color::Rgb(163, 190, 140),   // #A3BE8C green
+        MeadStatus::Secondary => Color::Rgb(94, 129, 172),  // #5E81AC blue
+        MeadStatus::Aging => Color::Rgb(180, 142, 173),     // #B48EAD purple
+        MeadStatus::Bottled => Color::Rgb(235, 203, 139),   // #EBCB8B gold
+        MeadStatus::Finished => Color::Rgb(136, 192, 208),  // #88C0D0 frost
+    }
+}
+
+/// Parse a config color: a name such as "green" or "lightblue", or a hex
+/// value such as "#A3BE8C"
+pub fn parse_color(value: &str) -> Option<Color> {
+    value.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views_fall_back_to_the_global_accent_then_the_default() {
+        let mut theme = Theme::default();
+        assert_eq!(theme.accent_for(ThemeView::Detail), DEFAULT_ACCENT);
+
+        theme.accent = parse_color("#A3BE8C");
+        *theme.view_mut(ThemeView::Detail) = parse_color("blue");
+        assert_eq!(theme.accent_for(ThemeView::Detail), Color::Blue);
+        assert_eq!(theme.accent_for(ThemeView::NewMead), Color::Rgb(163, 190, 140));
+        assert_eq!(parse_color("not a color"), None);
+    }
+
+    #[test]
+    fn colors_fall_back_to_what_the_terminal_has() {
+        assert_eq!(ColorSupport::detect(Some("truecolor"), Some("xterm")), ColorSupport::TrueColor);
+        assert_eq!(ColorSupport::detect(None, Some("xterm-256color")), ColorSupport::Ansi256);
+        assert_eq!(ColorSupport::detect(None, Some("screen")), ColorSupport::Ansi16);
+        assert_eq!(ColorSupport::detect(None, None), ColorSupport::TrueColor);
+
+        let gray = Color::Rgb(76, 86, 106); // #4C566A
+        assert_eq!(ColorSupport::TrueColor.adapt(gray), gray);
+        assert_eq!(ColorSupport::Ansi16.adapt(gray), Color::DarkGray);
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Rgb(0, 255, 255)), Color::LightCyan);
+        assert_eq!(ColorSupport::Ansi256.adapt(Color::Rgb(0, 255, 255)), Color::Indexed(51));
+        assert_eq!(ColorSupport::Ansi256.adapt(Color::Rgb(46, 52, 64)), Color::Indexed(237));
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Yellow), Color::Yellow);
+    }
+}
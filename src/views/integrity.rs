@@ -0,0 +1,163 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::nav;
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+const NORD_RED: Color = Color::Rgb(191, 97, 106);       // #BF616A
+
+/// Most integrity problems listed before the rest are summarised
+const MAX_PROBLEMS_SHOWN: usize = 8;
+
+/// What the user picked on the integrity warning
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntegrityChoice {
+    BackupAndRecover,
+    Continue,
+}
+
+/// Warning shown at startup when the database fails its integrity check
+pub struct IntegrityView {
+    /// Problems reported by `PRAGMA integrity_check`
+    pub problems: Vec<String>,
+    /// Currently selected option
+    pub selected: usize,
+    /// Result of the backup and recovery, once attempted
+    pub report: Option<Vec<String>>,
+}
+
+impl IntegrityView {
+    const CHOICES: [(IntegrityChoice, &'static str); 2] = [
+        (IntegrityChoice::BackupAndRecover, "Back up the database and attempt recovery"),
+        (IntegrityChoice::Continue, "Continue to the main menu"),
+    ];
+
+    pub fn new(problems: Vec<String>) -> Self {
+        Self {
+            problems,
+            selected: 0,
+            report: None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = nav::next_index(self.selected, Self::CHOICES.len());
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = nav::previous_index(self.selected, Self::CHOICES.len());
+    }
+
+    pub fn get_choice(&self) -> IntegrityChoice {
+        Self::CHOICES[self.selected].0
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([
+                Constraint::Min(8),    // Problems or report
+                Constraint::Length(4), // Choices
+                Constraint::Length(3), // Controls
+            ])
+            .split(area);
+
+        let label = Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD);
+        let text = Style::default().fg(NORD_WHITE);
+
+        let mut lines = vec![Line::from(Span::styled(
+            " Database integrity check failed ",
+            Style::default().fg(NORD_RED).add_modifier(Modifier::BOLD),
+        ))
+        .alignment(Alignment::Center)];
+        lines.push(Line::from(""));
+        match &self.report {
+            Some(report) => {
+                lines.extend(report.iter().map(|line| Line::from(Span::styled(line.clone(), text))));
+            }
+            None => {
+                lines.push(Line::from(Span::styled(
+                    "SQLite reported these problems. Your data may be partly unreadable.",
+                    Style::default().fg(NORD_GRAY),
+                )));
+                lines.push(Line::from(""));
+                lines.extend(
+                    self.problems
+                        .iter()
+                        .take(MAX_PROBLEMS_SHOWN)
+                        .map(|problem| Line::from(Span::styled(format!("  {}", problem), text))),
+                );
+                if self.problems.len() > MAX_PROBLEMS_SHOWN {
+                    lines.push(Line::from(Span::styled(
+                        format!("  ...and {} more", self.problems.len() - MAX_PROBLEMS_SHOWN),
+                        Style::default().fg(NORD_GRAY),
+                    )));
+                }
+            }
+        }
+
+        let body = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_RED))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(body, chunks[0]);
+
+        let items: Vec<ListItem> = Self::CHOICES
+            .iter()
+            .enumerate()
+            .map(|(i, (_, label))| {
+                let style = if i == self.selected {
+                    Style::default()
+                        .fg(NORD_BG)
+                        .bg(NORD_CYAN)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(NORD_WHITE)
+                };
+                let prefix = if i == self.selected { "> " } else { "  " };
+                ListItem::new(Line::from(format!("{}{}", prefix, label))).style(style)
+            })
+            .collect();
+
+        let choices = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_BLUE))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(choices, chunks[1]);
+
+        let controls = Line::from(vec![
+            Span::styled("Up/Down", label),
+            Span::styled(" Navigate  ", text),
+            Span::styled("Enter", label),
+            Span::styled(" Select  ", text),
+            Span::styled("Esc", label),
+            Span::styled(" Continue", text),
+        ]);
+        let controls_widget = Paragraph::new(controls).alignment(Alignment::Center).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_FROST))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(controls_widget, chunks[2]);
+    }
+}
@@ -1,12 +1,23 @@
+use std::collections::HashSet;
+
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols::border,
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
+use crate::export::json::MeadRecord;
+use crate::export::ExportFormat;
+use crate::fuzzy::fuzzy_score;
+use crate::import::ImportReport;
+use crate::models::Mead;
+use crate::nav;
+use crate::theme::{self, ThemeView};
+use crate::widgets::InputField;
+
 // Nord-adjacent color palette
 const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
 const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
@@ -14,32 +25,359 @@ const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
 const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
 const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
 const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+const NORD_RED: Color = Color::Rgb(191, 97, 106);       // #BF616A
+const NORD_YELLOW: Color = Color::Rgb(235, 203, 139);   // #EBCB8B
+
+/// Most import problems listed before the rest are counted
+const MAX_LISTED_ERRORS: usize = 5;
+/// Most batches shown at once when picking ones to copy
+const MAX_COPY_ROWS: usize = 12;
+
+/// Records read from an import file, shown with their dry-run report until
+/// the user confirms or cancels
+#[derive(Debug)]
+pub struct ImportPreview {
+    /// Popup title, e.g. "Import JSON"
+    pub title: &'static str,
+    pub records: Vec<MeadRecord>,
+    pub report: ImportReport,
+    /// Parts of the file that won't be brought across
+    pub unmapped: Vec<String>,
+    /// Whether replacing the existing meads is offered
+    pub can_replace: bool,
+}
+
+/// Profiles to switch between, shown over the menu
+#[derive(Debug)]
+pub struct ProfilePicker {
+    /// Profiles with a database file, default first
+    pub profiles: Vec<String>,
+    /// Selected profile
+    pub selected: usize,
+    /// Profile currently open
+    pub active: String,
+    /// Name typed for a new profile
+    pub name_input: InputField,
+    /// Whether a new profile name is being typed
+    pub naming: bool,
+    /// Batches of another profile being picked to copy into the open one
+    pub copy_from: Option<CopyPicker>,
+}
+
+impl ProfilePicker {
+    pub fn new(profiles: Vec<String>, active: &str) -> Self {
+        let selected = profiles.iter().position(|p| p == active).unwrap_or(0);
+        Self {
+            profiles,
+            selected,
+            active: active.to_string(),
+            name_input: InputField::new("New profile").with_placeholder("letters, digits, - and _"),
+            naming: false,
+            copy_from: None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = nav::next_index(self.selected, self.profiles.len());
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = nav::previous_index(self.selected, self.profiles.len());
+    }
+
+    /// The selected profile
+    pub fn selected_profile(&self) -> Option<&str> {
+        self.profiles.get(self.selected).map(String::as_str)
+    }
+
+    /// Start typing a new profile name
+    pub fn start_naming(&mut self) {
+        self.name_input.clear();
+        self.name_input.set_focused(true);
+        self.naming = true;
+    }
+}
+
+/// Another profile's batches, marked one by one to copy into the open
+/// profile
+#[derive(Debug)]
+pub struct CopyPicker {
+    /// Profile the batches are copied from
+    pub profile: String,
+    pub meads: Vec<Mead>,
+    /// Ids of the batches to copy
+    pub marked: HashSet<i64>,
+    pub selected: usize,
+}
+
+impl CopyPicker {
+    pub fn new(profile: &str, meads: Vec<Mead>) -> Self {
+        Self {
+            profile: profile.to_string(),
+            meads,
+            marked: HashSet::new(),
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = nav::next_index(self.selected, self.meads.len());
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = nav::previous_index(self.selected, self.meads.len());
+    }
+
+    /// Mark or unmark the selected batch
+    pub fn toggle_selected(&mut self) {
+        if let Some(mead) = self.meads.get(self.selected) {
+            if !self.marked.remove(&mead.id) {
+                self.marked.insert(mead.id);
+            }
+        }
+    }
+
+    /// Ids of the marked batches, in list order
+    pub fn marked_ids(&self) -> Vec<i64> {
+        self.meads
+            .iter()
+            .map(|m| m.id)
+            .filter(|id| self.marked.contains(id))
+            .collect()
+    }
+}
+
+/// An action on the main menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuItem {
+    CurrentMeads,
+    NewMead,
+    Search,
+    RecentActivity,
+    Reference,
+    Statistics,
+    ExportCalendar,
+    ExportJson,
+    ExportSql,
+    ImportJson,
+    ImportRecipe,
+    SwitchProfile,
+}
+
+impl MenuItem {
+    /// Every item, in the order shown when the config doesn't set one
+    pub const ALL: [MenuItem; 12] = [
+        MenuItem::CurrentMeads,
+        MenuItem::NewMead,
+        MenuItem::Search,
+        MenuItem::RecentActivity,
+        MenuItem::Reference,
+        MenuItem::Statistics,
+        MenuItem::ExportCalendar,
+        MenuItem::ExportJson,
+        MenuItem::ExportSql,
+        MenuItem::ImportJson,
+        MenuItem::ImportRecipe,
+        MenuItem::SwitchProfile,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MenuItem::CurrentMeads => "Current Meads",
+            MenuItem::NewMead => "New Mead",
+            MenuItem::Search => "Search Batches",
+            MenuItem::RecentActivity => "Recent Activity",
+            MenuItem::Reference => "Reference",
+            MenuItem::Statistics => "Statistics",
+            MenuItem::ExportCalendar => "Export Calendar",
+            MenuItem::ExportJson => "Export JSON",
+            MenuItem::ExportSql => "Export SQL",
+            MenuItem::ImportJson => "Import JSON",
+            MenuItem::ImportRecipe => "Import Recipe",
+            MenuItem::SwitchProfile => "Switch Profile",
+        }
+    }
+
+    /// Name used for this item in the config file
+    pub fn key(&self) -> &'static str {
+        match self {
+            MenuItem::CurrentMeads => "meads",
+            MenuItem::NewMead => "new_mead",
+            MenuItem::Search => "search",
+            MenuItem::RecentActivity => "activity",
+            MenuItem::Reference => "reference",
+            MenuItem::Statistics => "stats",
+            MenuItem::ExportCalendar => "export_calendar",
+            MenuItem::ExportJson => "export_json",
+            MenuItem::ExportSql => "export_sql",
+            MenuItem::ImportJson => "import_json",
+            MenuItem::ImportRecipe => "import_recipe",
+            MenuItem::SwitchProfile => "profiles",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<MenuItem> {
+        Self::ALL.into_iter().find(|item| item.key() == key)
+    }
+
+    /// The export this item runs, if it is one
+    pub fn export(&self) -> Option<ExportFormat> {
+        match self {
+            MenuItem::ExportCalendar => Some(ExportFormat::Calendar),
+            MenuItem::ExportJson => Some(ExportFormat::Json),
+            MenuItem::ExportSql => Some(ExportFormat::Sql),
+            _ => None,
+        }
+    }
+
+    /// Whether choosing this item changes data, so read-only mode blocks it
+    pub fn is_mutation(&self) -> bool {
+        matches!(self, MenuItem::NewMead | MenuItem::ImportJson | MenuItem::ImportRecipe)
+    }
+}
 
 /// Main menu view state
 pub struct MainMenuView {
     /// Currently selected menu item
     pub selected: usize,
-    /// Menu options
-    options: Vec<&'static str>,
+    /// Items shown, in order
+    pub items: Vec<MenuItem>,
+    /// Open reminders across all meads as (due today, overdue)
+    pub due_reminders: (usize, usize),
+    /// Meads as (active, total), shown beside Current Meads
+    pub batch_counts: (usize, usize),
+    /// Whether the counts need to be reloaded from DB
+    pub needs_refresh: bool,
+    /// Batch search box
+    pub search_input: InputField,
+    /// Whether the search box is open
+    pub searching: bool,
+    /// Meads the search runs over, loaded when the search opens
+    search_pool: Vec<Mead>,
+    /// Indexes into the search pool, best match first
+    results: Vec<usize>,
+    /// Selected search result
+    result_selected: usize,
+    /// Import waiting to be confirmed, shown over the menu
+    pub import_preview: Option<ImportPreview>,
+    /// Profile switcher, shown over the menu
+    pub profile_picker: Option<ProfilePicker>,
+    /// Export `e` repeats, marked on its menu option
+    pub last_export: Option<ExportFormat>,
 }
 
 impl MainMenuView {
     pub fn new() -> Self {
         Self {
             selected: 0,
-            options: vec!["Current Meads", "New Mead"],
+            items: MenuItem::ALL.to_vec(),
+            due_reminders: (0, 0),
+            batch_counts: (0, 0),
+            needs_refresh: true,
+            search_input: InputField::new("Search").with_placeholder("Batch name, honey or yeast"),
+            searching: false,
+            search_pool: Vec::new(),
+            results: Vec::new(),
+            result_selected: 0,
+            import_preview: None,
+            profile_picker: None,
+            last_export: None,
+        }
+    }
+
+    /// Open the search box over the given meads
+    pub fn open_search(&mut self, meads: Vec<Mead>) {
+        self.search_pool = meads;
+        self.search_input.clear();
+        self.search_input.set_focused(true);
+        self.searching = true;
+        self.update_results();
+    }
+
+    /// Close the search box
+    pub fn close_search(&mut self) {
+        self.searching = false;
+        self.search_input.set_focused(false);
+        self.search_pool.clear();
+        self.results.clear();
+    }
+
+    /// Re-rank the search pool against the current query
+    pub fn update_results(&mut self) {
+        let query = self.search_input.get_value();
+        let mut scored: Vec<(i32, usize)> = self
+            .search_pool
+            .iter()
+            .enumerate()
+            .filter_map(|(i, mead)| {
+                let best = [&mead.name, &mead.honey_type, &mead.yeast_strain]
+                    .iter()
+                    .filter_map(|field| fuzzy_score(query, field))
+                    .max()?;
+                // Rank name matches above honey or yeast matches
+                let name_bonus = if fuzzy_score(query, &mead.name).is_some() { 10 } else { 0 };
+                Some((best + name_bonus, i))
+            })
+            .collect();
+        // Stable sort keeps the pinned-first list order for ties
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        self.results = scored.into_iter().map(|(_, i)| i).collect();
+        self.result_selected = 0;
+    }
+
+    pub fn next_result(&mut self) {
+        self.result_selected = nav::next_index(self.result_selected, self.results.len());
+    }
+
+    pub fn previous_result(&mut self) {
+        self.result_selected = nav::previous_index(self.result_selected, self.results.len());
+    }
+
+    /// Get the id of the selected search result
+    pub fn selected_result_id(&self) -> Option<i64> {
+        self.results
+            .get(self.result_selected)
+            .and_then(|&i| self.search_pool.get(i))
+            .map(|mead| mead.id)
+    }
+
+    /// Show the items named by config keys, in that order. Unknown and
+    /// repeated keys are skipped; if none are left every item is shown.
+    pub fn set_items(&mut self, keys: &[String]) {
+        let mut items: Vec<MenuItem> = Vec::new();
+        for item in keys.iter().filter_map(|key| MenuItem::from_key(key)) {
+            if !items.contains(&item) {
+                items.push(item);
+            }
         }
+        self.items = if items.is_empty() { MenuItem::ALL.to_vec() } else { items };
+        self.selected = self.selected.min(self.items.len() - 1);
+    }
+
+    /// The item under the cursor
+    pub fn selected_item(&self) -> Option<MenuItem> {
+        self.items.get(self.selected).copied()
     }
 
     pub fn next(&mut self) {
-        self.selected = (self.selected + 1) % self.options.len();
+        self.selected = nav::next_index(self.selected, self.items.len());
     }
 
     pub fn previous(&mut self) {
-        if self.selected == 0 {
-            self.selected = self.options.len() - 1;
-        } else {
-            self.selected -= 1;
+        self.selected = nav::previous_index(self.selected, self.items.len());
+    }
+
+    /// Menu text for an item, with live batch counts on Current Meads
+    fn option_label(&self, item: MenuItem) -> String {
+        match item {
+            MenuItem::CurrentMeads => {
+                let (active, total) = self.batch_counts;
+                format!("{} ({}, {} active)", item.label(), total, active)
+            }
+            _ if item.export().is_some() && item.export() == self.last_export => {
+                format!("{} (last used)", item.label())
+            }
+            _ => item.label().to_string(),
         }
     }
 
@@ -59,12 +397,25 @@ impl MainMenuView {
             .split(area);
 
         // Render title/logo
+        let (due_today, overdue) = self.due_reminders;
+        let mut reminder_line = vec![Span::styled(
+            format!("Due today: {}", due_today),
+            Style::default().fg(if due_today > 0 { NORD_YELLOW } else { NORD_GRAY }),
+        )];
+        if overdue > 0 {
+            reminder_line.push(Span::styled(
+                format!("  Overdue: {}", overdue),
+                Style::default().fg(NORD_RED).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        let accent = theme::accent(ThemeView::Menu);
         let title = vec![
             Line::from(""),
             Line::from(Span::styled(
                 " MEAD TRACKER ",
                 Style::default()
-                    .fg(NORD_FROST)
+                    .fg(accent)
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
@@ -72,11 +423,12 @@ impl MainMenuView {
                 "Track your mead brewing journey",
                 Style::default().fg(NORD_GRAY),
             )),
+            Line::from(reminder_line),
         ];
 
         let title_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(NORD_FROST))
+            .border_style(Style::default().fg(accent))
             .border_set(border::ROUNDED);
 
         let title_widget = Paragraph::new(title)
@@ -87,10 +439,10 @@ impl MainMenuView {
 
         // Render menu
         let items: Vec<ListItem> = self
-            .options
+            .items
             .iter()
             .enumerate()
-            .map(|(i, opt)| {
+            .map(|(i, &item)| {
                 let style = if i == self.selected {
                     Style::default()
                         .fg(NORD_BG)
@@ -101,7 +453,7 @@ impl MainMenuView {
                 };
 
                 let prefix = if i == self.selected { "> " } else { "  " };
-                ListItem::new(Line::from(format!("{}{}", prefix, opt))).style(style)
+                ListItem::new(Line::from(format!("{}{}", prefix, self.option_label(item)))).style(style)
             })
             .collect();
 
@@ -118,9 +470,13 @@ impl MainMenuView {
 
         let menu = List::new(items).block(menu_block);
 
-        // Center the menu horizontally
-        let menu_area = centered_rect(40, 100, chunks[1]);
-        frame.render_widget(menu, menu_area);
+        if self.searching {
+            self.render_search(frame, centered_rect(60, 100, chunks[1]));
+        } else {
+            // Center the menu horizontally
+            let menu_area = centered_rect(40, 100, chunks[1]);
+            frame.render_widget(menu, menu_area);
+        }
 
         // Render status message if any
         let status_text = status_message.as_ref().map(|s| s.as_str()).unwrap_or("");
@@ -138,29 +494,44 @@ impl MainMenuView {
         frame.render_widget(status, chunks[2]);
 
         // Render controls
-        let controls = Line::from(vec![
-            Span::styled(
-                "Up/Down",
-                Style::default()
-                    .fg(NORD_CYAN)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
-            Span::styled(
-                "Enter",
-                Style::default()
-                    .fg(NORD_CYAN)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(" Select  ", Style::default().fg(NORD_WHITE)),
-            Span::styled(
-                "q",
-                Style::default()
-                    .fg(NORD_CYAN)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(" Quit", Style::default().fg(NORD_WHITE)),
-        ]);
+        let key_style = Style::default()
+            .fg(NORD_CYAN)
+            .add_modifier(Modifier::BOLD);
+        let controls = if self.searching {
+            Line::from(vec![
+                Span::styled("Up/Down", key_style),
+                Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", key_style),
+                Span::styled(" Open  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", key_style),
+                Span::styled(" Close Search", Style::default().fg(NORD_WHITE)),
+            ])
+        } else {
+            let mut keys = vec![
+                Span::styled("Up/Down", key_style),
+                Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", key_style),
+                Span::styled(" Select  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("/", key_style),
+                Span::styled(" Search  ", Style::default().fg(NORD_WHITE)),
+            ];
+            if let Some(export) = self.last_export {
+                keys.push(Span::styled("e", key_style));
+                keys.push(Span::styled(
+                    format!(" Repeat {} Export ({})  ", export.as_str(), export.file_name()),
+                    Style::default().fg(NORD_WHITE),
+                ));
+            }
+            keys.extend([
+                Span::styled("o/O", key_style),
+                Span::styled(" Copy/Open Data Folder  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Ctrl+K", key_style),
+                Span::styled(" Calculator  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("q", key_style),
+                Span::styled(" Quit", Style::default().fg(NORD_WHITE)),
+            ]);
+            Line::from(keys)
+        };
 
         let controls_widget = Paragraph::new(controls).alignment(Alignment::Center).block(
             Block::default()
@@ -170,6 +541,71 @@ impl MainMenuView {
         );
 
         frame.render_widget(controls_widget, chunks[3]);
+
+        if let Some(preview) = &self.import_preview {
+            render_import_prompt(frame, preview);
+        }
+        if let Some(picker) = &self.profile_picker {
+            render_profile_picker(frame, picker);
+        }
+    }
+
+    /// Render the search box and its results
+    fn render_search(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        frame.render_widget(&self.search_input, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .results
+            .iter()
+            .filter_map(|&i| self.search_pool.get(i))
+            .map(|mead| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(mead.name.clone(), Style::default().fg(NORD_WHITE)),
+                    Span::styled(
+                        format!("  {}", mead.status.as_str()),
+                        Style::default().fg(theme::status_color(&mead.status)),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let title = if self.search_pool.is_empty() {
+            " No meads yet ".to_string()
+        } else {
+            format!(" Results ({}) ", self.results.len())
+        };
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        title,
+                        Style::default()
+                            .fg(NORD_CYAN)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_BLUE))
+                    .border_set(border::ROUNDED),
+            )
+            .highlight_style(
+                Style::default()
+                    .fg(NORD_BG)
+                    .bg(NORD_CYAN)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+
+        let mut state = ListState::default();
+        if !self.results.is_empty() {
+            state.select(Some(self.result_selected));
+        }
+        frame.render_stateful_widget(list, chunks[1], &mut state);
     }
 }
 
@@ -179,6 +615,145 @@ impl Default for MainMenuView {
     }
 }
 
+/// Ask whether a JSON import adds to the existing meads or replaces them
+fn render_import_prompt(frame: &mut Frame, preview: &ImportPreview) {
+    let key_style = Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD);
+    let report = &preview.report;
+    let mut text = vec![Line::from(Span::styled(
+        format!("Would add {}", report.summary()),
+        Style::default().fg(NORD_WHITE),
+    ))];
+    for error in report.errors.iter().take(MAX_LISTED_ERRORS) {
+        text.push(Line::from(Span::styled(error.clone(), Style::default().fg(NORD_RED))));
+    }
+    if report.errors.len() > MAX_LISTED_ERRORS {
+        text.push(Line::from(Span::styled(
+            format!("...and {} more problems", report.errors.len() - MAX_LISTED_ERRORS),
+            Style::default().fg(NORD_RED),
+        )));
+    }
+    if !preview.unmapped.is_empty() {
+        text.push(Line::from(Span::styled(
+            format!("Not mapped: {}", preview.unmapped.join(", ")),
+            Style::default().fg(NORD_YELLOW),
+        )));
+    }
+    text.push(Line::from(""));
+
+    // Nothing is written while the file has problems
+    let mut keys = Vec::new();
+    if report.errors.is_empty() {
+        if preview.can_replace {
+            keys.push(Span::styled("a", key_style));
+            keys.push(Span::styled(" Append  ", Style::default().fg(NORD_WHITE)));
+            keys.push(Span::styled("r", key_style));
+            keys.push(Span::styled(" Replace  ", Style::default().fg(NORD_WHITE)));
+        } else {
+            keys.push(Span::styled("Enter", key_style));
+            keys.push(Span::styled(" Import  ", Style::default().fg(NORD_WHITE)));
+        }
+    }
+    keys.push(Span::styled("Esc", key_style));
+    keys.push(Span::styled(" Cancel", Style::default().fg(NORD_WHITE)));
+    text.push(Line::from(keys));
+
+    let [popup] = Layout::horizontal([Constraint::Length(60)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [popup] = Layout::vertical([Constraint::Length(text.len() as u16 + 2)])
+        .flex(Flex::Center)
+        .areas(popup);
+
+    let prompt = Paragraph::new(text).alignment(Alignment::Center).block(
+        Block::default()
+            .title(Span::styled(format!(" {} ", preview.title), key_style))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_CYAN))
+            .border_set(border::ROUNDED)
+            .style(Style::default().bg(NORD_BG)),
+    );
+    frame.render_widget(Clear, popup);
+    frame.render_widget(prompt, popup);
+}
+
+/// List the profiles to switch to, with a box for naming a new one
+fn render_profile_picker(frame: &mut Frame, picker: &ProfilePicker) {
+    if let Some(copy) = &picker.copy_from {
+        render_copy_picker(frame, copy, &picker.active);
+        return;
+    }
+    let key_style = Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD);
+    let items: Vec<ListItem> = picker
+        .profiles
+        .iter()
+        .map(|profile| {
+            let mark = if *profile == picker.active { "● " } else { "  " };
+            ListItem::new(Line::from(vec![
+                Span::styled(mark, Style::default().fg(NORD_CYAN)),
+                Span::styled(profile.clone(), Style::default().fg(NORD_WHITE)),
+            ]))
+        })
+        .collect();
+
+    let keys = if picker.naming {
+        Line::from(vec![
+            Span::styled("Enter", key_style),
+            Span::styled(" Create  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("Esc", key_style),
+            Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled("Enter", key_style),
+            Span::styled(" Switch  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("n", key_style),
+            Span::styled(" New  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("c", key_style),
+            Span::styled(" Copy Batches  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("Esc", key_style),
+            Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+        ])
+    };
+
+    let input_height = if picker.naming { 3 } else { 0 };
+    let height = picker.profiles.len() as u16 + input_height + 3;
+    let [popup] = Layout::horizontal([Constraint::Length(56)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [popup] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(popup);
+
+    let block = Block::default()
+        .title(Span::styled(" Switch Profile ", key_style))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(NORD_CYAN))
+        .border_set(border::ROUNDED)
+        .style(Style::default().bg(NORD_BG));
+    let inner = block.inner(popup);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(block, popup);
+
+    let [list_area, input_area, keys_area] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(input_height),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+    let list = List::new(items).highlight_style(
+        Style::default().fg(NORD_BG).bg(NORD_CYAN).add_modifier(Modifier::BOLD),
+    );
+    let mut state = ListState::default();
+    if !picker.naming {
+        state.select(Some(picker.selected));
+    }
+    frame.render_stateful_widget(list, list_area, &mut state);
+    if picker.naming {
+        frame.render_widget(&picker.name_input, input_area);
+    }
+    frame.render_widget(Paragraph::new(keys).alignment(Alignment::Center), keys_area);
+}
+
 /// Helper function to create a centered rect
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -199,3 +774,109 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+/// Batches of another profile with marks, for picking ones to copy
+fn render_copy_picker(frame: &mut Frame, copy: &CopyPicker, active: &str) {
+    let key_style = Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD);
+    let items: Vec<ListItem> = copy
+        .meads
+        .iter()
+        .map(|mead| {
+            let mark = if copy.marked.contains(&mead.id) { "[x] " } else { "[ ] " };
+            ListItem::new(Line::from(vec![
+                Span::styled(mark, Style::default().fg(NORD_CYAN)),
+                Span::styled(mead.name.clone(), Style::default().fg(NORD_WHITE)),
+                Span::styled(format!("  {}", mead.status.as_str()), Style::default().fg(theme::status_color(&mead.status))),
+            ]))
+        })
+        .collect();
+
+    let keys = Line::from(vec![
+        Span::styled("Space", key_style),
+        Span::styled(" Mark  ", Style::default().fg(NORD_WHITE)),
+        Span::styled("Enter", key_style),
+        Span::styled(format!(" Copy {}  ", copy.marked.len()), Style::default().fg(NORD_WHITE)),
+        Span::styled("Esc", key_style),
+        Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+    ]);
+
+    let rows = copy.meads.len().clamp(1, MAX_COPY_ROWS) as u16;
+    let [popup] = Layout::horizontal([Constraint::Length(56)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [popup] = Layout::vertical([Constraint::Length(rows + 3)])
+        .flex(Flex::Center)
+        .areas(popup);
+
+    let block = Block::default()
+        .title(Span::styled(format!(" Copy from {} into {} ", copy.profile, active), key_style))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(NORD_CYAN))
+        .border_set(border::ROUNDED)
+        .style(Style::default().bg(NORD_BG));
+    let inner = block.inner(popup);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(block, popup);
+
+    let [list_area, keys_area] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+    if copy.meads.is_empty() {
+        let empty = Paragraph::new("This profile has no batches.")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(NORD_GRAY));
+        frame.render_widget(empty, list_area);
+    } else {
+        let list = List::new(items).highlight_style(
+            Style::default().fg(NORD_BG).bg(NORD_CYAN).add_modifier(Modifier::BOLD),
+        );
+        let mut state = ListState::default().with_selected(Some(copy.selected));
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+    frame.render_widget(Paragraph::new(keys).alignment(Alignment::Center), keys_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use crate::test_support::screen_rows;
+
+    fn render(view: &MainMenuView) -> Terminal<TestBackend> {
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        terminal
+    }
+
+    #[test]
+    fn items_render_with_counts_and_the_selection_highlighted() {
+        let mut view = MainMenuView::new();
+        view.batch_counts = (1, 3);
+        view.last_export = Some(ExportFormat::Json);
+        view.next();
+        view.next();
+        let terminal = render(&view);
+        let rows = screen_rows(&terminal);
+        let text = rows.concat();
+        assert!(text.contains("Current Meads (3, 1 active)"), "{}", text);
+        assert!(text.contains("Export JSON (last used)"));
+        assert!(text.contains("Repeat JSON Export"));
+
+        let y = rows.iter().position(|row| row.contains("> Search Batches")).unwrap();
+        let x = rows[y].chars().position(|c| c == '>').unwrap();
+        let cell = &terminal.backend().buffer()[(x as u16, y as u16)];
+        assert_eq!(cell.bg, NORD_CYAN, "the selected row is highlighted");
+        assert!(!rows.iter().any(|row| row.contains("> Current Meads")));
+    }
+
+    #[test]
+    fn configured_items_replace_the_default_list() {
+        let mut view = MainMenuView::new();
+        view.selected = 10;
+        view.set_items(&["stats".to_string(), "bogus".to_string(), "stats".to_string()]);
+        assert_eq!(view.items, [MenuItem::Statistics]);
+        assert_eq!(view.selected_item(), Some(MenuItem::Statistics), "the cursor stays on the list");
+
+        view.set_items(&[]);
+        assert_eq!(view.items, MenuItem::ALL);
+        assert!(MenuItem::ALL.iter().all(|item| MenuItem::from_key(item.key()) == Some(*item)));
+    }
+}
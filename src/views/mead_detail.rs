@@ -1,31 +1,67 @@
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols::border,
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    symbols::Marker,
+    widgets::{
+        Axis, Block, Borders, Chart, Clear, Dataset, Gauge, GraphType, LegendPosition, List, ListItem, ListState,
+        Paragraph, Row, Table, TableState, Wrap,
+    },
 };
+use ratatui_image::{picker::Picker, protocol::Protocol, Image, Resize};
 
-use crate::models::{Ingredient, IngredientType, LogEntry, Mead, MeadStatus};
-use crate::widgets::InputField;
+use crate::calc;
+use crate::format::{
+    self, fmt_abv, fmt_date, fmt_date_str, fmt_datetime, fmt_elapsed, fmt_gravity, fmt_relative, fmt_temp, fmt_yan,
+};
+use crate::fuzzy::fuzzy_score;
+use crate::models::{
+    is_valid_gravity, is_valid_ph, mead_changes, rating_stars, BaseType, GravityReading, Ingredient, IngredientGlyphs,
+    IngredientType, LogEntry, LogEntryType, Mead, MeadSnapshot, MeadStatus, Measure, Nutrient, Reminder, StatusChange,
+    MAX_COMPETITION_SCORE, MAX_GRAVITY, MAX_PH, MAX_RATING, MAX_READING_INTERVAL_DAYS, MIN_GRAVITY, MIN_PH,
+};
+use crate::nav;
+use crate::theme::{self, ThemeView};
+use crate::widgets::{hotkey_span, GravitySlider, InputField, SelectField, TextArea};
 
 // Nord-adjacent color palette
 const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
 const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
 const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
-const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
 const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
 const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+const NORD_RED: Color = Color::Rgb(191, 97, 106);       // #BF616A
+const NORD_GREEN: Color = Color::Rgb(163, 190, 140);    // #A3BE8C
+const NORD_YELLOW: Color = Color::Rgb(235, 203, 139);   // #EBCB8B
+const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+
+/// Maximum number of submitted log entries kept for Up/Down recall
+const LOG_HISTORY_LIMIT: usize = 50;
+
+/// Size of the photo preview in terminal cells, inside its border
+const PREVIEW_COLS: u16 = 24;
+const PREVIEW_ROWS: u16 = 8;
 
 /// Field indices for navigation in detail view
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DetailField {
     Name = 0,
     Status,
+    Rating,
+    NextAction,
     CurrentGravity,
     YanAdded,
     Notes,
+    Photo,
 }
 
 impl DetailField {
@@ -33,14 +69,684 @@ impl DetailField {
         match i {
             0 => DetailField::Name,
             1 => DetailField::Status,
-            2 => DetailField::CurrentGravity,
-            3 => DetailField::YanAdded,
-            _ => DetailField::Notes,
+            2 => DetailField::Rating,
+            3 => DetailField::NextAction,
+            4 => DetailField::CurrentGravity,
+            5 => DetailField::YanAdded,
+            6 => DetailField::Notes,
+            _ => DetailField::Photo,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            DetailField::Name => "Name",
+            DetailField::NextAction => "Next Action",
+            DetailField::Status => "Status",
+            DetailField::Rating => "Rating",
+            DetailField::CurrentGravity => "Current Gravity",
+            DetailField::YanAdded => "YAN Added",
+            DetailField::Notes => "Notes",
+            DetailField::Photo => "Photo",
         }
     }
 
     fn count() -> usize {
-        5
+        8
+    }
+
+    /// Name used for the field in the config file
+    pub fn key(&self) -> &'static str {
+        match self {
+            DetailField::Name => "name",
+            DetailField::NextAction => "next_action",
+            DetailField::Status => "status",
+            DetailField::Rating => "rating",
+            DetailField::CurrentGravity => "current_gravity",
+            DetailField::YanAdded => "yan_added",
+            DetailField::Notes => "notes",
+            DetailField::Photo => "photo",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<DetailField> {
+        (0..Self::count()).map(Self::from_index).find(|f| f.key() == key)
+    }
+
+    /// Letter that jumps to the field with Alt
+    fn hotkey(&self) -> char {
+        match self {
+            DetailField::Name => 'n',
+            DetailField::NextAction => 'a',
+            DetailField::Status => 's',
+            DetailField::Rating => 'r',
+            DetailField::CurrentGravity => 'g',
+            DetailField::YanAdded => 'y',
+            DetailField::Notes => 'o',
+            DetailField::Photo => 'p',
+        }
+    }
+}
+
+/// Steps of the quick measurement flow
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeasurementStep {
+    Gravity,
+    Temperature,
+}
+
+/// Steps of the mark-as-finished flow
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FinishStep {
+    Gravity,
+    BottledDate,
+    BottleCount,
+}
+
+/// Where the user was in a mead's detail view, kept for the session so
+/// going back to the mead returns there
+#[derive(Debug, Clone, Copy)]
+struct DetailPlace {
+    pane: DetailPane,
+    current_field: usize,
+    reading_selected: usize,
+    ingredient_selected: usize,
+    ingredient_offset: usize,
+    reminder_selected: usize,
+    log_selected: usize,
+    notes_scroll: u16,
+}
+
+/// Panes of the detail view that can hold keyboard focus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DetailPane {
+    Fields,
+    Readings,
+    Ingredients,
+    Reminders,
+    Logs,
+}
+
+impl DetailPane {
+    fn next(self) -> Self {
+        match self {
+            DetailPane::Fields => DetailPane::Readings,
+            DetailPane::Readings => DetailPane::Ingredients,
+            DetailPane::Ingredients => DetailPane::Reminders,
+            DetailPane::Reminders => DetailPane::Logs,
+            DetailPane::Logs => DetailPane::Fields,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            DetailPane::Fields => DetailPane::Logs,
+            DetailPane::Readings => DetailPane::Fields,
+            DetailPane::Ingredients => DetailPane::Readings,
+            DetailPane::Reminders => DetailPane::Ingredients,
+            DetailPane::Logs => DetailPane::Reminders,
+        }
+    }
+}
+
+/// Order of the ingredients pane
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IngredientSort {
+    /// The saved order, changed with Ctrl+Up/Down
+    Manual,
+    /// Oldest addition first
+    Date,
+    Name,
+}
+
+impl IngredientSort {
+    pub fn next(self) -> Self {
+        match self {
+            IngredientSort::Manual => IngredientSort::Date,
+            IngredientSort::Date => IngredientSort::Name,
+            IngredientSort::Name => IngredientSort::Manual,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IngredientSort::Manual => "manual",
+            IngredientSort::Date => "by date",
+            IngredientSort::Name => "by name",
+        }
+    }
+}
+
+/// Unit the add ingredient form starts with
+const DEFAULT_INGREDIENT_UNIT: &str = "oz";
+/// Amount added or taken by Up/Down in the ingredient amount field
+const AMOUNT_STEP: f64 = 0.1;
+/// Amount added or taken by Shift+Up/Down
+const AMOUNT_COARSE_STEP: f64 = 1.0;
+
+/// Picker for the meads a log entry is copied to
+pub struct LogCopyPicker {
+    /// The entry being copied
+    pub entry: LogEntry,
+    /// Other meads as (id, name)
+    pub meads: Vec<(i64, String)>,
+    /// Ids of the meads picked
+    pub picked: HashSet<i64>,
+    /// Highlighted row
+    pub selected: usize,
+}
+
+/// Picker for the mead whose ingredients are copied into this one
+pub struct IngredientSourcePicker {
+    /// Other meads as (id, name)
+    pub meads: Vec<(i64, String)>,
+    /// Highlighted row
+    pub selected: usize,
+}
+
+/// Viewer comparing saved snapshots with the mead as it is now
+pub struct SnapshotViewer {
+    /// Snapshots of the mead, newest first
+    pub snapshots: Vec<MeadSnapshot>,
+    /// The saved mead the snapshots are compared with
+    pub current: Mead,
+    /// Highlighted snapshot
+    pub selected: usize,
+}
+
+/// Form open over the detail view, holding what has been typed into it.
+/// Only one is open at a time, and closing it drops what was typed.
+pub enum DetailForm {
+    Log(LogForm),
+    Ingredient(Box<IngredientForm>),
+    Reminder(ReminderForm),
+    Measurement(MeasurementForm),
+    Finish(FinishForm),
+    /// Corrected gravity for the selected reading
+    Reading(InputField),
+    /// Target volume for scaling the recipe
+    Scale(InputField),
+    /// Target carbonation in the priming sugar calculator, in volumes of CO2
+    Priming(InputField),
+    /// Label for a new snapshot
+    Snapshot(InputField),
+    /// Days between scheduled gravity readings
+    ReadingInterval(InputField),
+    Acidity(AcidityForm),
+    TopUp(TopUpForm),
+    Competition(CompetitionForm),
+    Nutrient(NutrientForm),
+}
+
+impl DetailForm {
+    /// Focus the next (or previous) field of a form with several; forms
+    /// with a single input have nowhere to move
+    fn cycle_field(&mut self, forward: bool) {
+        let step = |field: &mut usize, count: usize| {
+            *field = if forward { nav::next_index(*field, count) } else { nav::previous_index(*field, count) };
+        };
+        match self {
+            DetailForm::Log(form) => {
+                step(&mut form.field, 2);
+                form.update_focus();
+            }
+            DetailForm::Ingredient(form) => {
+                step(&mut form.field, 5);
+                form.update_focus();
+            }
+            DetailForm::Reminder(form) => {
+                step(&mut form.field, 2);
+                form.update_focus();
+            }
+            DetailForm::Acidity(form) => {
+                step(&mut form.field, 2);
+                form.update_focus();
+            }
+            DetailForm::TopUp(form) => {
+                step(&mut form.field, 2);
+                form.update_focus();
+            }
+            DetailForm::Competition(form) => {
+                step(&mut form.field, 3);
+                form.update_focus();
+            }
+            DetailForm::Nutrient(form) => {
+                step(&mut form.field, 2);
+                form.update_focus();
+            }
+            _ => {}
+        }
+    }
+
+    /// The focused text input, None while a selector or the gravity slider
+    /// has focus
+    fn focused_input_mut(&mut self) -> Option<&mut InputField> {
+        match self {
+            DetailForm::Log(form) => (form.field == 0).then_some(&mut form.input),
+            DetailForm::Ingredient(form) => match form.field {
+                0 => Some(&mut form.name_input),
+                1 => Some(&mut form.amount_input),
+                2 => Some(&mut form.unit_input),
+                4 => Some(&mut form.notes_input),
+                _ => None,
+            },
+            DetailForm::Reminder(form) => Some(if form.field == 0 { &mut form.text_input } else { &mut form.date_input }),
+            DetailForm::Measurement(form) => match form.step {
+                MeasurementStep::Gravity if form.slider => None,
+                MeasurementStep::Gravity => Some(&mut form.gravity_input),
+                MeasurementStep::Temperature => Some(&mut form.temp_input),
+            },
+            DetailForm::Finish(form) => Some(match form.step {
+                FinishStep::Gravity => &mut form.gravity_input,
+                FinishStep::BottledDate => &mut form.date_input,
+                FinishStep::BottleCount => &mut form.bottles_input,
+            }),
+            DetailForm::Reading(input)
+            | DetailForm::Scale(input)
+            | DetailForm::Priming(input)
+            | DetailForm::Snapshot(input)
+            | DetailForm::ReadingInterval(input) => Some(input),
+            DetailForm::Acidity(form) => Some(if form.field == 0 { &mut form.ph_input } else { &mut form.ta_input }),
+            DetailForm::TopUp(form) => Some(if form.field == 0 { &mut form.volume_input } else { &mut form.gravity_input }),
+            DetailForm::Competition(form) => Some(match form.field {
+                0 => &mut form.category_input,
+                1 => &mut form.score_input,
+                _ => &mut form.notes_input,
+            }),
+            DetailForm::Nutrient(form) => (form.field == 1).then_some(&mut form.amount_input),
+        }
+    }
+
+    /// The focused selector, changed with Left/Right
+    fn focused_select_mut(&mut self) -> Option<&mut SelectField> {
+        match self {
+            DetailForm::Log(form) => (form.field == 1).then_some(&mut form.type_select),
+            DetailForm::Ingredient(form) => (form.field == 3).then_some(&mut form.type_select),
+            DetailForm::Nutrient(form) => (form.field == 0).then_some(&mut form.select),
+            _ => None,
+        }
+    }
+}
+
+/// Log entry form
+pub struct LogForm {
+    pub input: InputField,
+    pub type_select: SelectField,
+    /// Focused field (0 = text, 1 = type)
+    pub field: usize,
+    /// Position in the view's log history while recalling (None = editing
+    /// the draft)
+    history_index: Option<usize>,
+    /// Text that was being typed before recall started
+    draft: String,
+}
+
+impl LogForm {
+    fn new() -> Self {
+        let mut form = Self {
+            input: InputField::new("Log Entry"),
+            type_select: SelectField::new(
+                "Type (Left/Right to change)",
+                LogEntryType::all().iter().map(|t| t.as_str()).collect(),
+            )
+            .with_value(LogEntryType::Observation.as_str()),
+            field: 0,
+            history_index: None,
+            draft: String::new(),
+        };
+        form.update_focus();
+        form
+    }
+
+    fn update_focus(&mut self) {
+        self.input.set_focused(self.field == 0);
+        self.type_select.set_focused(self.field == 1);
+    }
+
+    /// Type chosen for the entry
+    pub fn entry_type(&self) -> LogEntryType {
+        LogEntryType::from_str(self.type_select.get_value())
+    }
+}
+
+/// Add ingredient form
+pub struct IngredientForm {
+    pub name_input: InputField,
+    pub amount_input: InputField,
+    pub unit_input: InputField,
+    pub type_select: SelectField,
+    pub notes_input: InputField,
+    /// Focused field (0-4)
+    pub field: usize,
+}
+
+impl IngredientForm {
+    fn new() -> Self {
+        let mut form = Self {
+            name_input: InputField::new("Ingredient Name"),
+            amount_input: InputField::new("Amount")
+                .numeric()
+                .with_hint(format!("Up/Down ±{}, Shift ±{}", AMOUNT_STEP, AMOUNT_COARSE_STEP)),
+            unit_input: InputField::new("Unit").with_value(DEFAULT_INGREDIENT_UNIT),
+            type_select: SelectField::new(
+                "Type (Left/Right to change)",
+                IngredientType::all().iter().map(|t| t.as_str()).collect(),
+            ),
+            notes_input: InputField::new("Notes").with_placeholder("e.g. Oregon marionberries, frozen"),
+            field: 0,
+        };
+        form.update_focus();
+        form
+    }
+
+    fn update_focus(&mut self) {
+        self.name_input.set_focused(self.field == 0);
+        self.amount_input.set_focused(self.field == 1);
+        self.unit_input.set_focused(self.field == 2);
+        self.type_select.set_focused(self.field == 3);
+        self.notes_input.set_focused(self.field == 4);
+    }
+
+    /// Type chosen for the ingredient
+    pub fn ingredient_type(&self) -> IngredientType {
+        IngredientType::from_str(self.type_select.get_value())
+    }
+
+    /// Whether anything has been typed that closing the form would lose
+    pub fn dirty(&self) -> bool {
+        !self.name_input.get_value().trim().is_empty()
+            || !self.amount_input.get_value().trim().is_empty()
+            || self.unit_input.get_value().trim() != DEFAULT_INGREDIENT_UNIT
+            || !self.notes_input.get_value().trim().is_empty()
+    }
+}
+
+/// Add reminder form
+pub struct ReminderForm {
+    pub text_input: InputField,
+    pub date_input: InputField,
+    /// Focused field (0 = text, 1 = due date)
+    pub field: usize,
+}
+
+impl ReminderForm {
+    /// The form due today, in the configured date format
+    fn new() -> Self {
+        let mut form = Self {
+            text_input: InputField::new("Reminder").with_placeholder("Add nutrients"),
+            date_input: InputField::new("Due Date").with_value(format::fmt_date(Local::now().date_naive())),
+            field: 0,
+        };
+        form.update_focus();
+        form
+    }
+
+    fn update_focus(&mut self) {
+        self.text_input.set_focused(self.field == 0);
+        self.date_input.set_focused(self.field == 1);
+    }
+}
+
+/// Quick measurement flow
+pub struct MeasurementForm {
+    pub step: MeasurementStep,
+    pub gravity_input: InputField,
+    pub temp_input: InputField,
+    /// Whether the gravity is set with a slider instead of typed; the
+    /// slider writes into `gravity_input`
+    pub slider: bool,
+}
+
+impl MeasurementForm {
+    /// The flow at the gravity step, with the temperature in the
+    /// configured unit
+    fn new() -> Self {
+        let unit = format::temp_unit();
+        let mut form = Self {
+            step: MeasurementStep::Gravity,
+            gravity_input: InputField::new("Gravity").gravity().with_placeholder("1.050"),
+            temp_input: InputField::new(format!("Temperature {} (optional)", unit.symbol()))
+                .numeric()
+                .with_placeholder(format!("{:.0}", unit.display(68.0))),
+            slider: false,
+        };
+        form.set_step(MeasurementStep::Gravity);
+        form
+    }
+
+    fn set_step(&mut self, step: MeasurementStep) {
+        self.step = step;
+        self.gravity_input.set_focused(step == MeasurementStep::Gravity);
+        self.temp_input.set_focused(step == MeasurementStep::Temperature);
+    }
+
+    /// Gravity the slider shows: the typed value when it is in range,
+    /// otherwise `current`
+    fn slider_value(&self, current: Option<f64>) -> f64 {
+        self.gravity_input
+            .get_f64()
+            .filter(|g| is_valid_gravity(*g))
+            .or(current)
+            .unwrap_or(MIN_GRAVITY)
+            .clamp(MIN_GRAVITY, MAX_GRAVITY)
+    }
+}
+
+/// Mark-as-finished flow
+pub struct FinishForm {
+    pub step: FinishStep,
+    /// Final gravity
+    pub gravity_input: InputField,
+    /// Bottling date
+    pub date_input: InputField,
+    /// Bottle count
+    pub bottles_input: InputField,
+}
+
+impl FinishForm {
+    /// The flow filled in with the current gravity, today's date and the
+    /// bottles the batch should fill
+    fn new(mead: &Mead) -> Self {
+        let mut form = Self {
+            step: FinishStep::Gravity,
+            gravity_input: InputField::new("Final Gravity")
+                .gravity()
+                .with_placeholder("1.000")
+                .with_value(format::edit_gravity(mead.current_gravity)),
+            date_input: InputField::new("Bottling Date").with_value(format::fmt_date(Local::now().date_naive())),
+            bottles_input: InputField::new("Bottles")
+                .numeric()
+                .with_value(calc::bottle_count(mead.volume_gallons).to_string()),
+        };
+        form.set_step(FinishStep::Gravity);
+        form
+    }
+
+    fn set_step(&mut self, step: FinishStep) {
+        self.step = step;
+        self.gravity_input.set_focused(step == FinishStep::Gravity);
+        self.date_input.set_focused(step == FinishStep::BottledDate);
+        self.bottles_input.set_focused(step == FinishStep::BottleCount);
+    }
+}
+
+/// pH and titratable acidity form
+pub struct AcidityForm {
+    pub ph_input: InputField,
+    /// Titratable acidity in g/L
+    pub ta_input: InputField,
+    /// Focused field (0 = pH, 1 = TA)
+    pub field: usize,
+}
+
+impl AcidityForm {
+    /// The form filled in with the mead's recorded pH and TA
+    fn new(mead: &Mead) -> Self {
+        let ph = mead.ph.map(|ph| format::fmt_edit(ph, 2)).unwrap_or_default();
+        let ta = mead.ta.map(|ta| format::fmt_edit(ta, 1)).unwrap_or_default();
+        let mut form = Self {
+            ph_input: InputField::new("pH").numeric().with_placeholder("3.4").with_value(ph),
+            ta_input: InputField::new("TA (g/L)").numeric().with_placeholder("6.5").with_value(ta),
+            field: 0,
+        };
+        form.update_focus();
+        form
+    }
+
+    fn update_focus(&mut self) {
+        self.ph_input.set_focused(self.field == 0);
+        self.ta_input.set_focused(self.field == 1);
+    }
+
+    /// pH and TA typed, None for blank fields; an error for a pH outside
+    /// 2.5-4.5 or a negative TA
+    pub fn typed(&self) -> Result<(Option<f64>, Option<f64>), String> {
+        let parse = |input: &InputField| {
+            let text = input.get_value().trim();
+            if text.is_empty() {
+                return Ok(None);
+            }
+            input.get_f64().map(Some).ok_or_else(|| format!("\"{}\" is not a number", text))
+        };
+        let ph = parse(&self.ph_input)?;
+        let ta = parse(&self.ta_input)?;
+        if let Some(ph) = ph.filter(|ph| !is_valid_ph(*ph)) {
+            return Err(format!("pH {:.2} is outside {}-{}", ph, MIN_PH, MAX_PH));
+        }
+        if ta.is_some_and(|ta| ta < 0.0) {
+            return Err("TA can't be negative".to_string());
+        }
+        Ok((ph, ta))
+    }
+}
+
+/// Top-up form
+pub struct TopUpForm {
+    /// Gallons added
+    pub volume_input: InputField,
+    /// Gravity of what was added
+    pub gravity_input: InputField,
+    /// Focused field (0 = volume, 1 = gravity)
+    pub field: usize,
+}
+
+impl TopUpForm {
+    /// The form assuming water until another gravity is typed
+    fn new() -> Self {
+        let mut form = Self {
+            volume_input: InputField::new("Added Volume (gallons)").numeric().with_placeholder("0.5"),
+            gravity_input: InputField::new("Added Gravity")
+                .gravity()
+                .with_placeholder("1.000 for water")
+                .with_value("1.000"),
+            field: 0,
+        };
+        form.update_focus();
+        form
+    }
+
+    fn update_focus(&mut self) {
+        self.volume_input.set_focused(self.field == 0);
+        self.gravity_input.set_focused(self.field == 1);
+    }
+}
+
+/// Competition entry form
+pub struct CompetitionForm {
+    pub category_input: InputField,
+    pub score_input: InputField,
+    /// Judges' notes
+    pub notes_input: InputField,
+    /// Focused field (0 = category, 1 = score, 2 = notes)
+    pub field: usize,
+}
+
+impl CompetitionForm {
+    /// The form filled in with what is recorded for the mead
+    fn new(mead: &Mead) -> Self {
+        let score = mead.competition_score.map(|s| s.to_string()).unwrap_or_default();
+        let mut form = Self {
+            category_input: InputField::new("Category")
+                .with_placeholder("M1A Dry Traditional")
+                .with_value(&mead.competition_category),
+            score_input: InputField::new("Score (out of 50)").numeric().with_value(score),
+            notes_input: InputField::new("Judge Notes")
+                .with_placeholder("Clean, a little hot")
+                .with_value(&mead.judge_notes),
+            field: 0,
+        };
+        form.update_focus();
+        form
+    }
+
+    fn update_focus(&mut self) {
+        self.category_input.set_focused(self.field == 0);
+        self.score_input.set_focused(self.field == 1);
+        self.notes_input.set_focused(self.field == 2);
+    }
+
+    /// Score typed: None when blank, an error for anything that isn't a
+    /// number from 0 to 50
+    pub fn typed_score(&self) -> Result<Option<f64>, String> {
+        let text = self.score_input.get_value().trim();
+        if text.is_empty() {
+            return Ok(None);
+        }
+        match self.score_input.get_f64() {
+            Some(score) if (0.0..=MAX_COMPETITION_SCORE).contains(&score) => Ok(Some(score)),
+            _ => Err(format!("Score must be a number from 0 to {}", MAX_COMPETITION_SCORE)),
+        }
+    }
+}
+
+/// Nutrient addition form
+pub struct NutrientForm {
+    /// Nutrient product
+    pub select: SelectField,
+    /// Grams of nutrient
+    pub amount_input: InputField,
+    /// Focused field (0 = product, 1 = grams)
+    pub field: usize,
+}
+
+impl NutrientForm {
+    fn new() -> Self {
+        let mut form = Self {
+            select: SelectField::new(
+                "Nutrient (Left/Right to change)",
+                Nutrient::all().iter().map(|n| n.as_str()).collect(),
+            ),
+            amount_input: InputField::new("Grams").numeric().with_placeholder("2.5"),
+            field: 0,
+        };
+        form.update_focus();
+        form
+    }
+
+    fn update_focus(&mut self) {
+        self.select.set_focused(self.field == 0);
+        self.amount_input.set_focused(self.field == 1);
+    }
+
+    /// Product chosen
+    pub fn nutrient(&self) -> Nutrient {
+        Nutrient::from_str(self.select.get_value())
+    }
+}
+
+/// Interval typed in the reading schedule form: None when blank or 0, an
+/// error for anything that isn't a whole number of days up to
+/// `MAX_READING_INTERVAL_DAYS`
+pub fn typed_reading_interval(input: &InputField) -> Result<Option<u32>, String> {
+    let text = input.get_value().trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    match text.parse::<u32>() {
+        Ok(0) => Ok(None),
+        Ok(days) if days <= MAX_READING_INTERVAL_DAYS => Ok(Some(days)),
+        Ok(_) => Err(format!("Check gravity at most {} days apart", MAX_READING_INTERVAL_DAYS)),
+        Err(_) => Err(format!("\"{}\" is not a whole number of days", text)),
     }
 }
 
@@ -52,32 +758,103 @@ pub struct MeadDetailView {
     pub ingredients: Vec<Ingredient>,
     /// Log entries for this mead
     pub log_entries: Vec<LogEntry>,
+    /// Whether log entries are listed newest first
+    pub log_newest_first: bool,
+    /// Reminders for this mead
+    pub reminders: Vec<Reminder>,
+    /// Gravity readings for the chart, oldest first
+    pub gravity_readings: Vec<GravityReading>,
+    /// Status changes for the timeline, oldest first
+    pub status_changes: Vec<StatusChange>,
+    /// Days since the first reading shown on the gravity chart, or None
+    /// for every reading
+    pub chart_window: Option<(f64, f64)>,
+    /// Whether the gravity chart also plots ABV against a second axis
+    pub show_abv_overlay: bool,
+    /// Mead picker for copying the selected log entry, while it is open
+    pub log_copy: Option<LogCopyPicker>,
+    /// Mead picker for copying in another batch's ingredients, while it is
+    /// open
+    pub ingredient_source: Option<IngredientSourcePicker>,
+    /// Snapshot comparison, while it is open
+    pub snapshot_viewer: Option<SnapshotViewer>,
+    /// Selected gravity reading
+    pub reading_selected: usize,
+    /// Form open over the view, if any
+    pub form: Option<DetailForm>,
+    /// Pane with keyboard focus
+    pub pane: DetailPane,
+    /// Selected row in each list pane
+    pub ingredient_selected: usize,
+    pub reminder_selected: usize,
+    pub log_selected: usize,
     /// Whether data needs refresh
     pub needs_refresh: bool,
     /// Current field being edited
     pub current_field: usize,
+    /// Field focused when a mead is first opened
+    pub start_field: DetailField,
     /// Whether currently editing
     pub editing: bool,
+    /// Whether "edit all" is stepping through every field in turn, Enter
+    /// moving on and saving after the last
+    pub edit_all: bool,
+    /// Whether the "editing a finished batch" banner is up for this edit
+    pub finished_warning: bool,
+    /// Whether the banner has been shown since this mead was opened
+    finished_warned: bool,
     /// Editable fields
     pub name_input: InputField,
+    pub next_action_input: InputField,
     pub current_gravity_input: InputField,
     pub yan_added_input: InputField,
     pub notes_input: InputField,
+    pub photo_input: InputField,
     /// Current status (for cycling)
     pub current_status: MeadStatus,
-    /// Log entry input
-    pub log_input: InputField,
-    /// Whether showing log input
-    pub show_log_input: bool,
-    /// Ingredient input fields
-    pub ingredient_name_input: InputField,
-    pub ingredient_amount_input: InputField,
-    pub ingredient_unit_input: InputField,
-    pub selected_ingredient_type: IngredientType,
-    /// Whether showing ingredient input
-    pub show_ingredient_input: bool,
-    /// Current ingredient input field (0-3)
-    pub ingredient_field: usize,
+    /// Stars given to the batch, None while unrated
+    pub current_rating: Option<u8>,
+    /// Only show log entries of this type (None = all)
+    pub log_filter: Option<LogEntryType>,
+    /// Log entries submitted this session, oldest first
+    pub log_history: Vec<String>,
+    /// Whether the ingredients pane shows totals grouped by type
+    pub group_ingredients: bool,
+    /// Order of the ingredients pane
+    pub ingredient_sort: IngredientSort,
+    /// Narrows the ingredients pane to names and types matching every word
+    pub ingredient_filter_input: InputField,
+    /// Whether the ingredient filter box has keyboard focus
+    pub filtering_ingredients: bool,
+    /// Carbonation the priming sugar is worked out for, in volumes of
+    /// CO2; the calculator reopens with the last target typed
+    pub priming_co2: f64,
+    /// Percent over YAN required that counts as an overdose
+    pub yan_overdose_margin: f64,
+    /// Full-screen notes editor, while it is open
+    pub notes_editor: Option<TextArea>,
+    /// How long after the last keypress typed notes are saved on their own;
+    /// None to save them only with `s`
+    pub notes_autosave: Option<Duration>,
+    /// Lines scrolled in the notes block
+    notes_scroll: u16,
+    /// Furthest the notes block could scroll at the last render
+    notes_max_scroll: Cell<u16>,
+    /// First ingredient row shown at the last render, so the pane keeps its
+    /// scroll while another pane has focus
+    ingredient_offset: Cell<usize>,
+    /// Last place in each mead opened this session, by mead id
+    places: HashMap<i64, DetailPlace>,
+    /// Draws photo previews; None when the terminal has no image protocol
+    pub image_picker: Option<Picker>,
+    /// Encoded preview of the photo at the given path
+    photo_preview: Option<(String, Protocol)>,
+    /// Whether to show the estimated color swatch
+    pub show_color_estimate: bool,
+    /// Marks shown before ingredient types
+    pub ingredient_glyphs: IngredientGlyphs,
+    /// When the session timer was started, if it is running
+    timer_started: Option<Instant>,
 }
 
 impl MeadDetailView {
@@ -86,480 +863,3080 @@ impl MeadDetailView {
             mead: None,
             ingredients: Vec::new(),
             log_entries: Vec::new(),
+            log_newest_first: true,
+            reminders: Vec::new(),
+            gravity_readings: Vec::new(),
+            status_changes: Vec::new(),
+            chart_window: None,
+            show_abv_overlay: false,
+            log_copy: None,
+            ingredient_source: None,
+            snapshot_viewer: None,
+            reading_selected: 0,
+            form: None,
+            pane: DetailPane::Fields,
+            ingredient_selected: 0,
+            reminder_selected: 0,
+            log_selected: 0,
             needs_refresh: true,
             current_field: 0,
+            start_field: DetailField::Name,
             editing: false,
-            name_input: InputField::new("Name"),
-            current_gravity_input: InputField::new("Current Gravity"),
-            yan_added_input: InputField::new("YAN Added"),
-            notes_input: InputField::new("Notes"),
+            edit_all: false,
+            finished_warning: false,
+            finished_warned: false,
+            name_input: InputField::new("Name").with_hotkey(DetailField::Name.hotkey()),
+            next_action_input: InputField::new("Next Action")
+                .with_placeholder("e.g. rack on Saturday")
+                .with_hotkey(DetailField::NextAction.hotkey()),
+            current_gravity_input: InputField::new("Current Gravity")
+                .gravity()
+                .with_hotkey(DetailField::CurrentGravity.hotkey()),
+            yan_added_input: InputField::new("YAN Added")
+                .numeric()
+                .with_hotkey(DetailField::YanAdded.hotkey()),
+            notes_input: InputField::new("Notes").with_hotkey(DetailField::Notes.hotkey()),
+            photo_input: InputField::new("Photo (file path)")
+                .with_placeholder("e.g. ~/Pictures/batch.jpg")
+                .with_hotkey(DetailField::Photo.hotkey()),
             current_status: MeadStatus::Planning,
-            log_input: InputField::new("Log Entry"),
-            show_log_input: false,
-            ingredient_name_input: InputField::new("Ingredient Name"),
-            ingredient_amount_input: InputField::new("Amount"),
-            ingredient_unit_input: InputField::new("Unit").with_value("oz"),
-            selected_ingredient_type: IngredientType::Fruit,
-            show_ingredient_input: false,
-            ingredient_field: 0,
-        }
-    }
-
-    pub fn set_mead(&mut self, mead: Mead, ingredients: Vec<Ingredient>, log_entries: Vec<LogEntry>) {
-        self.name_input.set_value(&mead.name);
-        self.current_gravity_input.set_value(format!("{:.3}", mead.current_gravity));
-        self.yan_added_input.set_value(format!("{:.0}", mead.yan_added));
-        self.notes_input.set_value(&mead.notes);
+            current_rating: None,
+            log_filter: None,
+            log_history: Vec::new(),
+            group_ingredients: false,
+            ingredient_sort: IngredientSort::Manual,
+            ingredient_filter_input: InputField::new("Filter").with_placeholder("Name or type"),
+            filtering_ingredients: false,
+            priming_co2: calc::DEFAULT_CO2_VOLUMES,
+            yan_overdose_margin: 20.0,
+            notes_editor: None,
+            notes_autosave: None,
+            notes_scroll: 0,
+            notes_max_scroll: Cell::new(0),
+            ingredient_offset: Cell::new(0),
+            places: HashMap::new(),
+            image_picker: None,
+            photo_preview: None,
+            show_color_estimate: true,
+            ingredient_glyphs: IngredientGlyphs::Unicode,
+            timer_started: None,
+        }
+    }
+
+    pub fn set_mead(
+        &mut self,
+        mead: Mead,
+        ingredients: Vec<Ingredient>,
+        log_entries: Vec<LogEntry>,
+        reminders: Vec<Reminder>,
+        gravity_readings: Vec<GravityReading>,
+    ) {
+        // A refresh of the same mead keeps navigation where it was; opening
+        // a different mead returns to where it was left, or the first field
+        let same_mead = self.mead.as_ref().is_some_and(|m| m.id == mead.id);
+        if !same_mead {
+            if let Some(previous) = &self.mead {
+                self.places.insert(previous.id, self.place());
+            }
+            self.set_field_focus(false);
+            self.current_field = self.start_field as usize;
+            self.editing = false;
+            self.edit_all = false;
+            self.finished_warning = false;
+            self.finished_warned = false;
+            self.pane = DetailPane::Fields;
+            self.close_form();
+            self.close_ingredient_filter(true);
+            self.reading_selected = 0;
+            self.ingredient_selected = 0;
+            self.ingredient_offset.set(0);
+            self.reminder_selected = 0;
+            self.log_selected = 0;
+            self.notes_scroll = 0;
+            self.chart_window = None;
+            if let Some(place) = self.places.get(&mead.id).copied() {
+                self.restore_place(place);
+            }
+        }
+
+        // Leave the field being edited alone so a refresh doesn't clobber it
+        let editing_field = self.editing.then(|| DetailField::from_index(self.current_field));
+        if editing_field != Some(DetailField::Name) {
+            self.name_input.set_value(&mead.name);
+        }
+        if editing_field != Some(DetailField::NextAction) {
+            self.next_action_input.set_value(&mead.next_action);
+        }
+        if editing_field != Some(DetailField::CurrentGravity) {
+            self.current_gravity_input.set_value(format::edit_gravity(mead.current_gravity));
+        }
+        if editing_field != Some(DetailField::YanAdded) {
+            self.yan_added_input.set_value(format::edit_yan(mead.yan_added));
+        }
+        if editing_field != Some(DetailField::Notes) {
+            self.notes_input.set_value(&mead.notes);
+        }
+        if editing_field != Some(DetailField::Photo) {
+            self.photo_input.set_value(&mead.image_path);
+        }
         self.current_status = mead.status.clone();
+        self.current_rating = mead.rating;
+        self.set_field_focus(self.pane == DetailPane::Fields);
         self.mead = Some(mead);
         self.ingredients = ingredients;
+        self.sort_ingredients();
         self.log_entries = log_entries;
+        self.reminders = reminders;
+        self.gravity_readings = gravity_readings;
+        self.clamp_selections();
+        self.load_photo_preview();
         self.needs_refresh = false;
     }
 
-    pub fn next_field(&mut self) {
-        if self.show_log_input {
-            return;
-        }
-        if self.show_ingredient_input {
-            self.ingredient_field = (self.ingredient_field + 1) % 4;
-            self.update_ingredient_focus();
-            return;
+    /// Current pane, field, selections and scroll offsets
+    fn place(&self) -> DetailPlace {
+        DetailPlace {
+            pane: self.pane,
+            current_field: self.current_field,
+            reading_selected: self.reading_selected,
+            ingredient_selected: self.ingredient_selected,
+            ingredient_offset: self.ingredient_offset.get(),
+            reminder_selected: self.reminder_selected,
+            log_selected: self.log_selected,
+            notes_scroll: self.notes_scroll,
         }
-        self.set_field_focus(false);
-        self.editing = false;
-        self.current_field = (self.current_field + 1) % DetailField::count();
-        self.set_field_focus(true);
     }
 
-    pub fn previous_field(&mut self) {
-        if self.show_log_input {
+    /// Go back to a saved place; selections past the end of the lists are
+    /// pulled back into range once the lists are loaded
+    fn restore_place(&mut self, place: DetailPlace) {
+        self.pane = place.pane;
+        self.current_field = place.current_field;
+        self.reading_selected = place.reading_selected;
+        self.ingredient_selected = place.ingredient_selected;
+        self.ingredient_offset.set(place.ingredient_offset);
+        self.reminder_selected = place.reminder_selected;
+        self.log_selected = place.log_selected;
+        self.notes_scroll = place.notes_scroll;
+    }
+
+    /// Decode the saved photo for the preview, keeping the current one if
+    /// the path hasn't changed. Files that can't be read get no preview.
+    fn load_photo_preview(&mut self) {
+        let Some(picker) = &self.image_picker else {
+            return;
+        };
+        let path = self.mead.as_ref().map(|m| m.image_path.trim()).unwrap_or("");
+        if path.is_empty() {
+            self.photo_preview = None;
             return;
         }
-        if self.show_ingredient_input {
-            if self.ingredient_field == 0 {
-                self.ingredient_field = 3;
-            } else {
-                self.ingredient_field -= 1;
-            }
-            self.update_ingredient_focus();
+        if self.photo_preview.as_ref().is_some_and(|(cached, _)| cached == path) {
             return;
         }
-        self.set_field_focus(false);
+        let size = Rect::new(0, 0, PREVIEW_COLS, PREVIEW_ROWS);
+        self.photo_preview = image::ImageReader::open(photo_file(path))
+            .ok()
+            .and_then(|reader| reader.with_guessed_format().ok())
+            .and_then(|reader| reader.decode().ok())
+            .and_then(|img| picker.new_protocol(img, size, Resize::Fit(None)).ok())
+            .map(|protocol| (path.to_string(), protocol));
+    }
+
+    /// Drop the open mead and every remembered place, e.g. after switching
+    /// to another database where the same ids are different meads
+    pub fn forget_meads(&mut self) {
+        self.mead = None;
+        self.places.clear();
+        self.needs_refresh = true;
+    }
+
+    /// Open the full-screen editor on the current notes
+    pub fn open_notes_editor(&mut self) {
         self.editing = false;
-        if self.current_field == 0 {
-            self.current_field = DetailField::count() - 1;
-        } else {
-            self.current_field -= 1;
-        }
-        self.set_field_focus(true);
+        self.notes_editor = Some(
+            TextArea::new("Notes (Esc to save and close)").with_value(self.notes_input.get_value()),
+        );
     }
 
-    fn update_ingredient_focus(&mut self) {
-        self.ingredient_name_input.set_focused(self.ingredient_field == 0);
-        self.ingredient_amount_input.set_focused(self.ingredient_field == 1);
-        self.ingredient_unit_input.set_focused(self.ingredient_field == 2);
-        // Field 3 is type selector
+    /// Close the full-screen editor, copying its text into the notes field.
+    /// Returns the new notes.
+    pub fn close_notes_editor(&mut self) -> Option<String> {
+        let notes = self.notes_editor.take()?.get_value();
+        self.notes_input.set_value(&notes);
+        Some(notes)
     }
 
-    fn set_field_focus(&mut self, focused: bool) {
-        match DetailField::from_index(self.current_field) {
-            DetailField::Name => self.name_input.set_focused(focused),
-            DetailField::Status => {}
-            DetailField::CurrentGravity => self.current_gravity_input.set_focused(focused),
-            DetailField::YanAdded => self.yan_added_input.set_focused(focused),
-            DetailField::Notes => self.notes_input.set_focused(focused),
-        }
+    /// Notes typed in the notes field or editor that differ from the saved
+    /// mead, if any
+    pub fn unsaved_notes(&self) -> Option<String> {
+        let mead = self.mead.as_ref()?;
+        let notes = match &self.notes_editor {
+            Some(editor) => editor.get_value(),
+            None => self.notes_input.get_value().to_string(),
+        };
+        (notes != mead.notes).then_some(notes)
     }
 
-    fn get_current_field_mut(&mut self) -> Option<&mut InputField> {
-        if self.show_log_input {
-            return Some(&mut self.log_input);
-        }
-        if self.show_ingredient_input {
-            return match self.ingredient_field {
-                0 => Some(&mut self.ingredient_name_input),
-                1 => Some(&mut self.ingredient_amount_input),
-                2 => Some(&mut self.ingredient_unit_input),
-                _ => None,
-            };
-        }
-        match DetailField::from_index(self.current_field) {
-            DetailField::Name => Some(&mut self.name_input),
-            DetailField::Status => None,
-            DetailField::CurrentGravity => Some(&mut self.current_gravity_input),
-            DetailField::YanAdded => Some(&mut self.yan_added_input),
-            DetailField::Notes => Some(&mut self.notes_input),
+    /// Record that `notes` were saved without reloading the mead, so other
+    /// unsaved edits stay in their fields
+    pub fn notes_saved(&mut self, notes: String) {
+        if let Some(mead) = &mut self.mead {
+            mead.notes = notes;
         }
     }
 
-    pub fn is_editing(&self) -> bool {
-        self.editing
+    /// "saving…" while typed notes wait for their autosave
+    fn autosave_indicator(&self) -> Option<Line<'static>> {
+        self.notes_autosave?;
+        self.unsaved_notes()?;
+        Some(Line::from(Span::styled(" saving… ", Style::default().fg(NORD_GRAY))).right_aligned())
     }
 
-    pub fn toggle_edit(&mut self) {
-        let field = DetailField::from_index(self.current_field);
-        if field == DetailField::Status {
-            // Cycle status instead of editing
-            self.current_status = self.current_status.next();
-        } else {
-            self.editing = !self.editing;
-        }
+    /// Scroll the notes block down a line
+    pub fn scroll_notes_down(&mut self) {
+        self.notes_scroll = (self.notes_scroll + 1).min(self.notes_max_scroll.get());
     }
 
-    pub fn cancel_edit(&mut self) {
-        self.editing = false;
+    /// Scroll the notes block up a line
+    pub fn scroll_notes_up(&mut self) {
+        self.notes_scroll = self.notes_scroll.min(self.notes_max_scroll.get()).saturating_sub(1);
     }
 
-    pub fn insert_char(&mut self, c: char) {
-        if self.show_ingredient_input && self.ingredient_field == 3 {
-            // Type selector - ignore char input
-            return;
-        }
-        if let Some(field) = self.get_current_field_mut() {
-            field.insert_char(c);
-        }
+    /// Pull selections and the notes scroll back into range after the
+    /// terminal is resized
+    pub fn clamp_to_size(&mut self) {
+        self.clamp_selections();
+        self.notes_scroll = self.notes_scroll.min(self.notes_max_scroll.get());
     }
 
-    pub fn delete_char(&mut self) {
-        if let Some(field) = self.get_current_field_mut() {
-            field.delete_char();
-        }
+    /// Whether one of the forms (log, ingredient, reminder, ...) is open
+    pub fn form_open(&self) -> bool {
+        self.form.is_some()
     }
 
-    pub fn delete_char_forward(&mut self) {
-        if let Some(field) = self.get_current_field_mut() {
-            field.delete_char_forward();
+    /// Close the open form, dropping what was typed in it
+    pub fn close_form(&mut self) {
+        if matches!(self.form, Some(DetailForm::Priming(_))) {
+            self.priming_co2 = self.priming_target();
         }
+        self.form = None;
     }
 
-    pub fn move_cursor_left(&mut self) {
-        if self.show_ingredient_input && self.ingredient_field == 3 {
-            // Cycle ingredient type
-            self.selected_ingredient_type = match self.selected_ingredient_type {
-                IngredientType::Fruit => IngredientType::Other,
-                IngredientType::Spice => IngredientType::Fruit,
-                IngredientType::Nutrient => IngredientType::Spice,
-                IngredientType::Adjunct => IngredientType::Nutrient,
-                IngredientType::Other => IngredientType::Adjunct,
-            };
-            return;
-        }
+    /// Open `form` in place of any other
+    fn open_form(&mut self, form: DetailForm) {
+        self.close_form();
+        self.form = Some(form);
+    }
+
+    /// Move keyboard focus to the next pane
+    pub fn next_pane(&mut self) {
+        self.focus_pane(self.pane.next());
+    }
+
+    /// Move keyboard focus to the previous pane
+    pub fn previous_pane(&mut self) {
+        self.focus_pane(self.pane.previous());
+    }
+
+    fn focus_pane(&mut self, pane: DetailPane) {
+        self.editing = false;
+        self.set_field_focus(pane == DetailPane::Fields);
+        self.pane = pane;
+    }
+
+    /// Move the selection down in the focused list pane
+    pub fn select_next(&mut self) {
+        let len = self.pane_len();
+        if let Some(selected) = self.pane_selection_mut() {
+            *selected = nav::next_index(*selected, len);
+        }
+    }
+
+    /// Move the selection up in the focused list pane
+    pub fn select_previous(&mut self) {
+        let len = self.pane_len();
+        if let Some(selected) = self.pane_selection_mut() {
+            *selected = nav::previous_index(*selected, len);
+        }
+    }
+
+    /// Select the newest (or oldest) entry in the readings or log pane,
+    /// whichever end of the list it sits at
+    pub fn jump_to_end(&mut self, newest: bool) {
+        let last = self.pane_len().saturating_sub(1);
+        match self.pane {
+            // Readings are listed oldest first
+            DetailPane::Readings => self.reading_selected = if newest { last } else { 0 },
+            DetailPane::Logs => self.log_selected = if newest == self.log_newest_first { 0 } else { last },
+            _ => {}
+        }
+    }
+
+    fn pane_len(&self) -> usize {
+        match self.pane {
+            DetailPane::Fields => 0,
+            DetailPane::Readings => self.gravity_readings.len(),
+            DetailPane::Ingredients => self.visible_ingredients().len(),
+            DetailPane::Reminders => self.reminders.len(),
+            DetailPane::Logs => self.visible_log_entries().count(),
+        }
+    }
+
+    fn pane_selection_mut(&mut self) -> Option<&mut usize> {
+        match self.pane {
+            DetailPane::Fields => None,
+            DetailPane::Readings => Some(&mut self.reading_selected),
+            DetailPane::Ingredients => Some(&mut self.ingredient_selected),
+            DetailPane::Reminders => Some(&mut self.reminder_selected),
+            DetailPane::Logs => Some(&mut self.log_selected),
+        }
+    }
+
+    /// Keep list selections within bounds after the data changes
+    fn clamp_selections(&mut self) {
+        let logs = self.visible_log_entries().count();
+        let ingredients = self.visible_ingredients().len();
+        self.reading_selected = self.reading_selected.min(self.gravity_readings.len().saturating_sub(1));
+        self.ingredient_selected = self.ingredient_selected.min(ingredients.saturating_sub(1));
+        self.reminder_selected = self.reminder_selected.min(self.reminders.len().saturating_sub(1));
+        self.log_selected = self.log_selected.min(logs.saturating_sub(1));
+    }
+
+    /// Switch to the next ingredient order, keeping the same ingredient
+    /// selected. Going back to manual needs a refresh to restore the saved
+    /// order, so this sets `needs_refresh` in that case.
+    pub fn cycle_ingredient_sort(&mut self) {
+        let selected_id = self.visible_ingredients().get(self.ingredient_selected).map(|i| i.id);
+        self.ingredient_sort = self.ingredient_sort.next();
+        if self.ingredient_sort == IngredientSort::Manual {
+            self.needs_refresh = true;
+        }
+        self.sort_ingredients();
+        if let Some(pos) = self.visible_ingredients().iter().position(|i| Some(i.id) == selected_id) {
+            self.ingredient_selected = pos;
+        }
+    }
+
+    /// Ingredients matching the filter, in pane order; all of them when the
+    /// filter is empty
+    pub fn visible_ingredients(&self) -> Vec<&Ingredient> {
+        let query = self.ingredient_filter_input.get_value();
+        self.ingredients
+            .iter()
+            .filter(|i| ingredient_matches(query, i))
+            .collect()
+    }
+
+    /// Whether the filter is hiding any ingredients
+    pub fn ingredient_filter_active(&self) -> bool {
+        !self.ingredient_filter_input.get_value().trim().is_empty()
+    }
+
+    /// Focus the ingredient filter box, keeping any filter already typed
+    pub fn open_ingredient_filter(&mut self) {
+        self.filtering_ingredients = true;
+        self.ingredient_filter_input.set_focused(true);
+    }
+
+    /// Take focus off the filter box; `clear` also drops the filter so the
+    /// full list shows again
+    pub fn close_ingredient_filter(&mut self, clear: bool) {
+        self.filtering_ingredients = false;
+        self.ingredient_filter_input.set_focused(false);
+        if clear {
+            self.ingredient_filter_input.clear();
+            self.clamp_selections();
+        }
+    }
+
+    /// Start again from the first match after the filter text changes
+    pub fn ingredient_filter_changed(&mut self) {
+        self.ingredient_selected = 0;
+        self.ingredient_offset.set(0);
+    }
+
+    fn sort_ingredients(&mut self) {
+        match self.ingredient_sort {
+            IngredientSort::Manual => {}
+            IngredientSort::Date => self
+                .ingredients
+                .sort_by(|a, b| a.added_date.cmp(&b.added_date).then(a.id.cmp(&b.id))),
+            IngredientSort::Name => self
+                .ingredients
+                .sort_by_key(|i| i.name.to_lowercase()),
+        }
+    }
+
+    /// Move the selected ingredient one place up or down. Returns the new
+    /// id order to persist, if anything moved. Only the manual order can be
+    /// rearranged.
+    pub fn move_selected_ingredient(&mut self, up: bool) -> Option<Vec<i64>> {
+        if self.ingredient_sort != IngredientSort::Manual || self.ingredient_filter_active() {
+            return None;
+        }
+        let from = self.ingredient_selected;
+        let to = if up { from.checked_sub(1)? } else { from + 1 };
+        if to >= self.ingredients.len() {
+            return None;
+        }
+        self.ingredients.swap(from, to);
+        self.ingredient_selected = to;
+        Some(self.ingredients.iter().map(|i| i.id).collect())
+    }
+
+    /// The reminder selected in the reminders pane
+    pub fn selected_reminder(&self) -> Option<&Reminder> {
+        self.reminders.get(self.reminder_selected)
+    }
+
+    /// Open the reminder form, due today, with the text field focused
+    pub fn open_reminder_input(&mut self) {
+        self.open_form(DetailForm::Reminder(ReminderForm::new()));
+    }
+
+    /// The gravity reading selected in the Readings pane
+    pub fn selected_reading(&self) -> Option<&GravityReading> {
+        self.gravity_readings.get(self.reading_selected)
+    }
+
+    /// The highlighted entry in the (filtered) log pane
+    pub fn selected_log_entry(&self) -> Option<&LogEntry> {
+        self.visible_log_entries().nth(self.log_selected)
+    }
+
+    /// Open the picker for copying the selected log entry to other meads
+    pub fn open_log_copy(&mut self, meads: &[Mead]) {
+        let (Some(entry), Some(mead)) = (self.selected_log_entry(), &self.mead) else {
+            return;
+        };
+        let meads = meads
+            .iter()
+            .filter(|m| m.id != mead.id)
+            .map(|m| (m.id, m.name.clone()))
+            .collect();
+        self.log_copy = Some(LogCopyPicker {
+            entry: entry.clone(),
+            meads,
+            picked: HashSet::new(),
+            selected: 0,
+        });
+    }
+
+    pub fn next_log_copy_mead(&mut self) {
+        if let Some(picker) = self.log_copy.as_mut() {
+            picker.selected = nav::next_index(picker.selected, picker.meads.len());
+        }
+    }
+
+    pub fn previous_log_copy_mead(&mut self) {
+        if let Some(picker) = self.log_copy.as_mut() {
+            picker.selected = nav::previous_index(picker.selected, picker.meads.len());
+        }
+    }
+
+    /// Pick or unpick the highlighted mead
+    pub fn toggle_log_copy_mead(&mut self) {
+        if let Some(picker) = self.log_copy.as_mut() {
+            if let Some(&(id, _)) = picker.meads.get(picker.selected) {
+                if !picker.picked.remove(&id) {
+                    picker.picked.insert(id);
+                }
+            }
+        }
+    }
+
+    /// Open the picker for copying another mead's ingredients into this one
+    pub fn open_ingredient_source(&mut self, meads: &[Mead]) {
+        let Some(mead) = &self.mead else {
+            return;
+        };
+        let meads = meads
+            .iter()
+            .filter(|m| m.id != mead.id)
+            .map(|m| (m.id, m.name.clone()))
+            .collect();
+        self.ingredient_source = Some(IngredientSourcePicker { meads, selected: 0 });
+    }
+
+    pub fn next_ingredient_source(&mut self) {
+        if let Some(picker) = self.ingredient_source.as_mut() {
+            picker.selected = nav::next_index(picker.selected, picker.meads.len());
+        }
+    }
+
+    pub fn previous_ingredient_source(&mut self) {
+        if let Some(picker) = self.ingredient_source.as_mut() {
+            picker.selected = nav::previous_index(picker.selected, picker.meads.len());
+        }
+    }
+
+    /// Open the form for labelling a new snapshot
+    pub fn open_snapshot_input(&mut self) {
+        let mut input = InputField::new("Label").with_placeholder("as planned");
+        input.set_focused(true);
+        self.editing = false;
+        self.set_field_focus(false);
+        self.open_form(DetailForm::Snapshot(input));
+    }
+
+    /// Open the form for a mead's gravity reading schedule, filled in with
+    /// the current interval
+    pub fn open_reading_interval_input(&mut self) {
+        let Some(mead) = &self.mead else {
+            return;
+        };
+        let days = mead.gravity_check_days.map(|d| d.to_string()).unwrap_or_default();
+        let mut input = InputField::new("Check gravity every (days)")
+            .numeric()
+            .with_placeholder("blank for no schedule")
+            .with_value(days);
+        input.set_focused(true);
+        self.editing = false;
+        self.set_field_focus(false);
+        self.open_form(DetailForm::ReadingInterval(input));
+    }
+
+    /// Open the acidity form, filled in with the mead's recorded pH and TA
+    pub fn open_acidity_input(&mut self) {
+        let Some(mead) = &self.mead else {
+            return;
+        };
+        let form = AcidityForm::new(mead);
+        self.editing = false;
+        self.set_field_focus(false);
+        self.open_form(DetailForm::Acidity(form));
+    }
+
+    /// Open the top-up form, assuming water until another gravity is typed
+    pub fn open_top_up_input(&mut self) {
+        self.editing = false;
+        self.set_field_focus(false);
+        self.open_form(DetailForm::TopUp(TopUpForm::new()));
+    }
+
+    /// The saved mead as it would be after the typed top-up: the current
+    /// gravity blended down (or up) with what was added, the starting
+    /// gravity blended the same way so the ABV reflects the dilution, and
+    /// the volume grown. None until both fields hold usable values.
+    pub fn topped_up_mead(&self) -> Option<Mead> {
+        let mead = self.mead.as_ref()?;
+        let Some(DetailForm::TopUp(form)) = &self.form else {
+            return None;
+        };
+        let added = form.volume_input.get_f64().filter(|v| *v > 0.0)?;
+        let added_gravity = form.gravity_input.get_f64().filter(|g| is_valid_gravity(*g))?;
+        Some(Mead {
+            current_gravity: calc::blended_gravity(mead.current_gravity, mead.volume_gallons, added_gravity, added)?,
+            starting_gravity: calc::blended_gravity(mead.starting_gravity, mead.volume_gallons, added_gravity, added)?,
+            volume_gallons: mead.volume_gallons + added,
+            ..mead.clone()
+        })
+    }
+
+    /// Lines comparing the saved gravity, ABV and volume with the topped-up ones
+    fn top_up_preview(&self) -> Vec<Line<'static>> {
+        if self.mead.as_ref().is_some_and(|m| !m.has_volume()) {
+            return vec![Line::from(Span::styled(format!(" {}", NO_VOLUME), Style::default().fg(NORD_YELLOW)))];
+        }
+        let (Some(mead), Some(topped)) = (&self.mead, self.topped_up_mead()) else {
+            return vec![Line::from(Span::styled(
+                " Enter the volume added to see the new gravity",
+                Style::default().fg(NORD_GRAY),
+            ))];
+        };
+        let label = Style::default().fg(NORD_GRAY);
+        let value = Style::default().fg(NORD_WHITE);
+        let changed = |name: &str, before: String, after: String| {
+            Line::from(vec![
+                Span::styled(format!(" {}: ", name), label),
+                Span::styled(format!("{} -> ", before), value),
+                Span::styled(after, Style::default().fg(NORD_GREEN)),
+            ])
+        };
+        vec![
+            changed("Gravity", fmt_gravity(mead.current_gravity), fmt_gravity(topped.current_gravity)),
+            changed(
+                "ABV",
+                format!("{}%", fmt_abv(mead.current_abv().max(0.0))),
+                format!("{}%", fmt_abv(topped.current_abv().max(0.0))),
+            ),
+            changed(
+                "Volume",
+                format!("{:.1} gal", mead.volume_gallons),
+                format!("{:.1} gal", topped.volume_gallons),
+            ),
+        ]
+    }
+
+    /// Open the competition entry form, filled in with what is recorded
+    pub fn open_competition_input(&mut self) {
+        let Some(mead) = &self.mead else {
+            return;
+        };
+        let form = CompetitionForm::new(mead);
+        self.editing = false;
+        self.set_field_focus(false);
+        self.open_form(DetailForm::Competition(form));
+    }
+
+    /// Open the snapshot viewer, comparing against `current`
+    pub fn open_snapshot_viewer(&mut self, snapshots: Vec<MeadSnapshot>, current: Mead) {
+        self.snapshot_viewer = Some(SnapshotViewer {
+            snapshots,
+            current,
+            selected: 0,
+        });
+    }
+
+    pub fn next_snapshot(&mut self) {
+        if let Some(viewer) = self.snapshot_viewer.as_mut() {
+            viewer.selected = nav::next_index(viewer.selected, viewer.snapshots.len());
+        }
+    }
+
+    pub fn previous_snapshot(&mut self) {
+        if let Some(viewer) = self.snapshot_viewer.as_mut() {
+            viewer.selected = nav::previous_index(viewer.selected, viewer.snapshots.len());
+        }
+    }
+
+    /// Open the correction form for the selected reading
+    pub fn open_reading_input(&mut self) {
+        let Some(gravity) = self.selected_reading().map(|r| r.gravity) else {
+            return;
+        };
+        let mut input = InputField::new("Corrected Gravity")
+            .gravity()
+            .with_value(format::edit_gravity(gravity));
+        input.set_focused(true);
+        self.open_form(DetailForm::Reading(input));
+    }
+
+    /// Start the quick measurement flow at the gravity step
+    pub fn open_measurement(&mut self) {
+        self.open_form(DetailForm::Measurement(MeasurementForm::new()));
+    }
+
+    /// Step the measurement flow is on, if it is open
+    pub fn measurement_step(&self) -> Option<MeasurementStep> {
+        match &self.form {
+            Some(DetailForm::Measurement(form)) => Some(form.step),
+            _ => None,
+        }
+    }
+
+    /// Switch the measurement flow's gravity between typing and the slider.
+    /// The slider starts from the typed gravity, or the current one.
+    pub fn toggle_measurement_slider(&mut self) {
+        let current = self.mead.as_ref().map(|m| m.current_gravity);
+        if let Some(DetailForm::Measurement(form)) = &mut self.form {
+            form.slider = !form.slider;
+            if form.slider {
+                let gravity = form.slider_value(current);
+                form.gravity_input.set_value(format!("{:.3}", gravity));
+            }
+        }
+    }
+
+    /// Move the slider's gravity a step up or down, a small one when `fine`
+    pub fn nudge_measurement_slider(&mut self, up: bool, fine: bool) {
+        let current = self.mead.as_ref().map(|m| m.current_gravity);
+        if let Some(DetailForm::Measurement(form)) = &mut self.form {
+            let gravity = GravitySlider::nudge(form.slider_value(current), up, fine);
+            form.gravity_input.set_value(format!("{:.3}", gravity));
+        }
+    }
+
+    /// Whether Left/Right move the gravity slider rather than the cursor
+    pub fn measurement_slider_active(&self) -> bool {
+        matches!(&self.form, Some(DetailForm::Measurement(form)) if form.slider && form.step == MeasurementStep::Gravity)
+    }
+
+    pub fn set_measurement_step(&mut self, step: MeasurementStep) {
+        if let Some(DetailForm::Measurement(form)) = &mut self.form {
+            form.set_step(step);
+        }
+    }
+
+    /// Start the session timer, or stop it and return how long it ran
+    pub fn toggle_timer(&mut self) -> Option<Duration> {
+        match self.timer_started.take() {
+            Some(started) => Some(started.elapsed()),
+            None => {
+                self.timer_started = Some(Instant::now());
+                None
+            }
+        }
+    }
+
+    /// Whether the session timer is counting, so the screen needs redrawing
+    pub fn timer_running(&self) -> bool {
+        self.timer_started.is_some()
+    }
+
+    /// Start the mark-as-finished flow, filled in with the current gravity,
+    /// today's date and the bottles the batch should fill
+    pub fn open_finish(&mut self, mead: &Mead) {
+        self.editing = false;
+        self.set_field_focus(false);
+        self.open_form(DetailForm::Finish(FinishForm::new(mead)));
+    }
+
+    /// Step the finish flow is on, if it is open
+    pub fn finish_step(&self) -> Option<FinishStep> {
+        match &self.form {
+            Some(DetailForm::Finish(form)) => Some(form.step),
+            _ => None,
+        }
+    }
+
+    pub fn set_finish_step(&mut self, step: FinishStep) {
+        if let Some(DetailForm::Finish(form)) = &mut self.form {
+            form.set_step(step);
+        }
+    }
+
+    /// Open the recipe scaling form
+    pub fn open_scale_input(&mut self) {
+        let mut input = InputField::new("Target Volume (gallons)").numeric().with_placeholder("5.0");
+        input.set_focused(true);
+        self.editing = false;
+        self.open_form(DetailForm::Scale(input));
+    }
+
+    /// Open the priming sugar calculator, keeping the last target typed
+    pub fn open_priming_input(&mut self) {
+        let mut input = InputField::new("Target CO2 (volumes)")
+            .numeric()
+            .with_value(self.priming_co2.to_string());
+        input.set_focused(true);
+        self.editing = false;
+        self.set_field_focus(false);
+        self.open_form(DetailForm::Priming(input));
+    }
+
+    /// Target carbonation for priming: what is typed while the calculator
+    /// is open, or the default while it doesn't hold a usable number
+    fn priming_target(&self) -> f64 {
+        match &self.form {
+            Some(DetailForm::Priming(input)) => input
+                .get_f64()
+                .filter(|v| *v > 0.0)
+                .unwrap_or(calc::DEFAULT_CO2_VOLUMES),
+            _ => self.priming_co2,
+        }
+    }
+
+    /// Corn sugar to prime the batch, e.g. "124 g (4.4 oz) at 2.5 vol CO2"
+    fn priming_text(&self, mead: &Mead) -> String {
+        if !mead.has_volume() {
+            return NO_VOLUME.to_string();
+        }
+        let target = self.priming_target();
+        let grams = calc::priming_sugar_grams(mead.volume_gallons, target);
+        format!("{:.0} g ({:.1} oz) at {} vol CO2", grams, calc::grams_to_oz(grams), target)
+    }
+
+    /// Factor the scaling form's target volume would scale the saved mead
+    /// by. None without a target, or when the mead has no volume to scale
+    /// from; `apply_scaling` then sets the volume instead.
+    pub fn scale_factor(&self) -> Option<f64> {
+        let Some(DetailForm::Scale(input)) = &self.form else {
+            return None;
+        };
+        let target = input.get_f64().filter(|v| *v > 0.0)?;
+        let mead = self.mead.as_ref()?;
+        (mead.volume_gallons > 0.0).then(|| target / mead.volume_gallons)
+    }
+
+    /// Open the nutrient addition form with the product selector focused
+    pub fn open_nutrient_input(&mut self) {
+        self.open_form(DetailForm::Nutrient(NutrientForm::new()));
+    }
+
+    /// YAN the nutrient form's addition would add to the saved mead; None
+    /// without an amount or a batch volume
+    pub fn nutrient_yan_ppm(&self) -> Option<f64> {
+        let Some(DetailForm::Nutrient(form)) = &self.form else {
+            return None;
+        };
+        let grams = form.amount_input.get_f64().filter(|g| *g > 0.0)?;
+        let mead = self.mead.as_ref().filter(|m| m.has_volume())?;
+        Some(form.nutrient().yan_ppm(grams, mead.volume_gallons))
+    }
+
+    pub fn next_field(&mut self) {
+        if self.measurement_step() == Some(MeasurementStep::Gravity) {
+            self.toggle_measurement_slider();
+            return;
+        }
+        if let Some(form) = &mut self.form {
+            form.cycle_field(true);
+            return;
+        }
+        if self.pane != DetailPane::Fields {
+            self.focus_pane(DetailPane::Fields);
+            return;
+        }
+        self.set_field_focus(false);
+        self.editing = false;
+        self.current_field = nav::next_index(self.current_field, DetailField::count());
+        self.set_field_focus(true);
+        if self.edit_all {
+            self.begin_text_edit();
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        if self.measurement_step() == Some(MeasurementStep::Gravity) {
+            self.toggle_measurement_slider();
+            return;
+        }
+        if let Some(form) = &mut self.form {
+            form.cycle_field(false);
+            return;
+        }
+        if self.pane != DetailPane::Fields {
+            self.focus_pane(DetailPane::Fields);
+            return;
+        }
+        self.set_field_focus(false);
+        self.editing = false;
+        self.current_field = nav::previous_index(self.current_field, DetailField::count());
+        self.set_field_focus(true);
+        if self.edit_all {
+            self.begin_text_edit();
+        }
+    }
+
+    /// Open the log entry form with the text field focused
+    pub fn open_log_input(&mut self) {
+        self.open_form(DetailForm::Log(LogForm::new()));
+    }
+
+    /// Cycle the log filter: all, then each entry type in turn
+    pub fn cycle_log_filter(&mut self) {
+        let types = LogEntryType::all();
+        self.log_filter = match &self.log_filter {
+            None => types.first().cloned(),
+            Some(current) => types
+                .iter()
+                .position(|t| t == current)
+                .and_then(|i| types.get(i + 1))
+                .cloned(),
+        };
+    }
+
+    /// Log entries passing the current filter
+    pub fn visible_log_entries(&self) -> impl Iterator<Item = &LogEntry> {
+        self.log_entries
+            .iter()
+            .filter(|e| self.log_filter.as_ref().is_none_or(|f| &e.entry_type == f))
+    }
+
+    /// Open the add ingredient form with the name field focused
+    pub fn open_ingredient_input(&mut self) {
+        self.open_form(DetailForm::Ingredient(Box::new(IngredientForm::new())));
+    }
+
+    /// Whether Up/Down would step the ingredient amount
+    pub fn amount_stepper_active(&self) -> bool {
+        matches!(&self.form, Some(DetailForm::Ingredient(form)) if form.field == 1)
+    }
+
+    /// Step the ingredient amount up or down (by a whole unit when `coarse`)
+    pub fn step_ingredient_amount(&mut self, up: bool, coarse: bool) {
+        let step = if coarse { AMOUNT_COARSE_STEP } else { AMOUNT_STEP };
+        if let Some(DetailForm::Ingredient(form)) = &mut self.form {
+            form.amount_input.step(up, step);
+        }
+    }
+
+    /// Whether the add ingredient form is open holding anything typed that
+    /// closing it would lose
+    pub fn ingredient_input_dirty(&self) -> bool {
+        matches!(&self.form, Some(DetailForm::Ingredient(form)) if form.dirty())
+    }
+
+    /// The ingredient as typed in the add ingredient form, for the open
+    /// mead; None while the form is closed
+    pub fn typed_ingredient(&self) -> Option<Ingredient> {
+        let mead = self.mead.as_ref()?;
+        let Some(DetailForm::Ingredient(form)) = &self.form else {
+            return None;
+        };
+        Some(Ingredient {
+            mead_id: mead.id,
+            name: form.name_input.get_value().to_string(),
+            amount: form.amount_input.get_f64().unwrap_or(0.0),
+            unit: form.unit_input.get_value().to_string(),
+            ingredient_type: form.ingredient_type(),
+            notes: form.notes_input.get_value().trim().to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn set_field_focus(&mut self, focused: bool) {
+        match DetailField::from_index(self.current_field) {
+            DetailField::Name => self.name_input.set_focused(focused),
+            DetailField::NextAction => self.next_action_input.set_focused(focused),
+            DetailField::Status | DetailField::Rating => {}
+            DetailField::CurrentGravity => self.current_gravity_input.set_focused(focused),
+            DetailField::YanAdded => self.yan_added_input.set_focused(focused),
+            DetailField::Notes => self.notes_input.set_focused(focused),
+            DetailField::Photo => self.photo_input.set_focused(focused),
+        }
+    }
+
+    fn get_current_field_mut(&mut self) -> Option<&mut InputField> {
+        if let Some(form) = &mut self.form {
+            return form.focused_input_mut();
+        }
+        match DetailField::from_index(self.current_field) {
+            DetailField::Name => Some(&mut self.name_input),
+            DetailField::NextAction => Some(&mut self.next_action_input),
+            DetailField::Status | DetailField::Rating => None,
+            DetailField::CurrentGravity => Some(&mut self.current_gravity_input),
+            DetailField::YanAdded => Some(&mut self.yan_added_input),
+            DetailField::Notes => Some(&mut self.notes_input),
+            DetailField::Photo => Some(&mut self.photo_input),
+        }
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Focus the field whose hotkey is `key`, leaving any field being
+    /// edited. Does nothing while a form is open; returns whether a field
+    /// matched.
+    pub fn jump_to_hotkey(&mut self, key: char) -> bool {
+        if self.form_open() {
+            return false;
+        }
+        let key = key.to_ascii_lowercase();
+        let Some(index) = (0..DetailField::count()).find(|i| DetailField::from_index(*i).hotkey() == key) else {
+            return false;
+        };
+        self.focus_pane(DetailPane::Fields);
+        self.set_field_focus(false);
+        self.current_field = index;
+        self.set_field_focus(true);
+        true
+    }
+
+    /// Whether `toggle_edit` would cycle a Finished status back round to
+    /// Planning
+    pub fn would_unfinish(&self) -> bool {
+        DetailField::from_index(self.current_field) == DetailField::Status
+            && self.current_status == MeadStatus::Finished
+    }
+
+    /// Whether the Rating field has focus, so Left/Right and the number
+    /// keys change the stars
+    pub fn rating_focused(&self) -> bool {
+        self.pane == DetailPane::Fields
+            && !self.form_open()
+            && DetailField::from_index(self.current_field) == DetailField::Rating
+    }
+
+    /// Add or take away a star; taking the last one away leaves the batch
+    /// unrated
+    pub fn step_rating(&mut self, up: bool) {
+        self.current_rating = match (self.current_rating, up) {
+            (None, true) => Some(1),
+            (Some(stars), true) => Some((stars + 1).min(MAX_RATING)),
+            (Some(stars), false) if stars > 1 => Some(stars - 1),
+            (_, false) => None,
+        };
+    }
+
+    /// Give the batch `stars` stars, 0 for unrated
+    pub fn set_rating(&mut self, stars: u8) {
+        self.current_rating = (stars > 0).then_some(stars.min(MAX_RATING));
+    }
+
+    pub fn toggle_edit(&mut self) {
+        let field = DetailField::from_index(self.current_field);
+        if field == DetailField::Status {
+            // Cycle status instead of editing
+            self.current_status = self.current_status.next();
+        } else if field == DetailField::Rating {
+            // Cycle round to unrated after the top rating
+            self.current_rating = match self.current_rating {
+                Some(stars) if stars >= MAX_RATING => None,
+                rating => Some(rating.map_or(1, |stars| stars + 1)),
+            };
+        } else {
+            self.editing = !self.editing;
+            // Finished batches are records, so the first edit gets a warning
+            let finished = self.mead.as_ref().is_some_and(|m| m.status == MeadStatus::Finished);
+            self.finished_warning = self.editing && finished && !self.finished_warned;
+            self.finished_warned |= self.finished_warning;
+        }
+    }
+
+    /// Start editing every field in turn from the first
+    pub fn start_edit_all(&mut self) {
+        self.focus_pane(DetailPane::Fields);
+        self.set_field_focus(false);
+        self.editing = false;
+        self.current_field = 0;
+        self.set_field_focus(true);
+        self.edit_all = true;
+        self.begin_text_edit();
+    }
+
+    /// Move "edit all" on to the next field. Returns false, ending the
+    /// mode, once the last field is done.
+    pub fn advance_edit_all(&mut self) -> bool {
+        self.editing = false;
+        if self.current_field + 1 >= DetailField::count() {
+            self.edit_all = false;
+            return false;
+        }
+        self.set_field_focus(false);
+        self.current_field += 1;
+        self.set_field_focus(true);
+        self.begin_text_edit();
+        true
+    }
+
+    /// Leave "edit all" mode, keeping any unsaved edits in the fields
+    pub fn stop_edit_all(&mut self) {
+        self.edit_all = false;
+        self.editing = false;
+    }
+
+    /// Start typing in the focused field if it is a text field
+    fn begin_text_edit(&mut self) {
+        let field = DetailField::from_index(self.current_field);
+        if !self.editing && !matches!(field, DetailField::Status | DetailField::Rating) {
+            self.toggle_edit();
+        }
+    }
+
+    /// Whether "edit all" is on the Status field, where Left/Right change
+    /// the status
+    pub fn edit_all_on_status(&self) -> bool {
+        self.edit_all && DetailField::from_index(self.current_field) == DetailField::Status
+    }
+
+    /// Move the status back one step
+    pub fn previous_status(&mut self) {
+        self.current_status = self.current_status.prev();
+    }
+
+    /// Restore the focused field to its saved value, discarding edits.
+    /// Returns the field's label, or None if there is nothing to revert.
+    pub fn revert_current_field(&mut self) -> Option<&'static str> {
+        if self.form_open() || self.pane != DetailPane::Fields {
+            return None;
+        }
+        let mead = self.mead.as_ref()?;
+        let field = DetailField::from_index(self.current_field);
+        match field {
+            DetailField::Name => self.name_input.set_value(&mead.name),
+            DetailField::NextAction => self.next_action_input.set_value(&mead.next_action),
+            DetailField::Status => self.current_status = mead.status.clone(),
+            DetailField::Rating => self.current_rating = mead.rating,
+            DetailField::CurrentGravity => self.current_gravity_input.set_value(format::edit_gravity(mead.current_gravity)),
+            DetailField::YanAdded => self.yan_added_input.set_value(format::edit_yan(mead.yan_added)),
+            DetailField::Notes => self.notes_input.set_value(&mead.notes),
+            DetailField::Photo => self.photo_input.set_value(&mead.image_path),
+        }
+        self.editing = false;
+        Some(field.label())
+    }
+
+    pub fn cancel_edit(&mut self) {
+        self.editing = false;
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        // Selectors have no field to type into, so this does nothing there
+        if let Some(field) = self.get_current_field_mut() {
+            field.insert_char(c);
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        if let Some(field) = self.get_current_field_mut() {
+            field.delete_char();
+        }
+    }
+
+    pub fn undo(&mut self) {
+        if let Some(field) = self.get_current_field_mut() {
+            field.undo();
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some(field) = self.get_current_field_mut() {
+            field.redo();
+        }
+    }
+
+    pub fn delete_char_forward(&mut self) {
+        if let Some(field) = self.get_current_field_mut() {
+            field.delete_char_forward();
+        }
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        if let Some(select) = self.form.as_mut().and_then(DetailForm::focused_select_mut) {
+            select.previous();
+            return;
+        }
         if let Some(field) = self.get_current_field_mut() {
             field.move_cursor_left();
         }
     }
 
-    pub fn move_cursor_right(&mut self) {
-        if self.show_ingredient_input && self.ingredient_field == 3 {
-            // Cycle ingredient type
-            self.selected_ingredient_type = match self.selected_ingredient_type {
-                IngredientType::Fruit => IngredientType::Spice,
-                IngredientType::Spice => IngredientType::Nutrient,
-                IngredientType::Nutrient => IngredientType::Adjunct,
-                IngredientType::Adjunct => IngredientType::Other,
-                IngredientType::Other => IngredientType::Fruit,
-            };
-            return;
-        }
-        if let Some(field) = self.get_current_field_mut() {
-            field.move_cursor_right();
-        }
+    pub fn move_cursor_right(&mut self) {
+        if let Some(select) = self.form.as_mut().and_then(DetailForm::focused_select_mut) {
+            select.next();
+            return;
+        }
+        if let Some(field) = self.get_current_field_mut() {
+            field.move_cursor_right();
+        }
+    }
+
+    /// Remember a submitted log entry for later recall
+    pub fn push_log_history(&mut self, text: String) {
+        if self.log_history.last() != Some(&text) {
+            self.log_history.push(text);
+            if self.log_history.len() > LOG_HISTORY_LIMIT {
+                self.log_history.remove(0);
+            }
+        }
+    }
+
+    /// Recall the previous (older) log entry into the log input
+    pub fn recall_previous_log(&mut self) {
+        let Some(DetailForm::Log(form)) = &mut self.form else {
+            return;
+        };
+        if form.field != 0 || self.log_history.is_empty() {
+            return;
+        }
+        let index = match form.history_index {
+            None => {
+                form.draft = form.input.get_value().to_string();
+                self.log_history.len() - 1
+            }
+            Some(i) => i.saturating_sub(1),
+        };
+        form.history_index = Some(index);
+        form.input.set_value(self.log_history[index].clone());
+    }
+
+    /// Move forward through log history, ending back at the draft
+    pub fn recall_next_log(&mut self) {
+        let Some(DetailForm::Log(form)) = &mut self.form else {
+            return;
+        };
+        if let Some(i) = form.history_index {
+            if i + 1 < self.log_history.len() {
+                form.history_index = Some(i + 1);
+                form.input.set_value(self.log_history[i + 1].clone());
+            } else {
+                form.history_index = None;
+                let draft = std::mem::take(&mut form.draft);
+                form.input.set_value(draft);
+            }
+        }
+    }
+
+    /// The mead with the field edits applied. Numbers that don't parse keep
+    /// the saved value, which suits live previews; saving goes through
+    /// `validated_mead` instead.
+    pub fn get_updated_mead(&self) -> Option<Mead> {
+        self.mead.as_ref().map(|m| {
+            let mut updated = m.clone();
+            updated.name = self.name_input.get_value().to_string();
+            updated.next_action = self.next_action_input.get_value().trim().to_string();
+            updated.current_gravity = self.current_gravity_input.get_f64().unwrap_or(m.current_gravity);
+            updated.yan_added = self.yan_added_input.get_f64().unwrap_or(m.yan_added);
+            updated.notes = self.notes_input.get_value().to_string();
+            updated.image_path = self.photo_input.get_value().trim().to_string();
+            updated.status = self.current_status.clone();
+            updated.rating = self.current_rating;
+            updated
+        })
+    }
+
+    /// Whether `updated` differs from the loaded mead in any field the form
+    /// edits
+    pub fn has_changes(&self, updated: &Mead) -> bool {
+        let Some(saved) = &self.mead else {
+            return false;
+        };
+        updated.name != saved.name
+            || updated.next_action != saved.next_action
+            || updated.current_gravity != saved.current_gravity
+            || updated.yan_added != saved.yan_added
+            || updated.notes != saved.notes
+            || updated.image_path != saved.image_path
+            || updated.status != saved.status
+            || updated.rating != saved.rating
+    }
+
+    /// The mead to save. An empty number field keeps the saved value, but
+    /// text that isn't a number is an error naming the field.
+    pub fn validated_mead(&self) -> Result<Option<Mead>, String> {
+        let Some(mut updated) = self.get_updated_mead() else {
+            return Ok(None);
+        };
+        if let Some(gravity) = parse_number_field(&self.current_gravity_input)? {
+            updated.current_gravity = gravity;
+        }
+        if let Some(yan) = parse_number_field(&self.yan_added_input)? {
+            updated.yan_added = yan;
+        }
+        if !updated.has_volume() {
+            return Err(format!("Volume: {}", NO_VOLUME));
+        }
+        Ok(Some(updated))
+    }
+
+    pub fn render(&self, frame: &mut Frame, status_message: &Option<String>) {
+        let area = frame.area();
+
+        if let Some(editor) = &self.notes_editor {
+            frame.render_widget(editor, area);
+            if let Some(indicator) = self.autosave_indicator() {
+                // Over the editor's bottom border, opposite its cursor position
+                let border = Rect::new(area.x + 1, area.bottom().saturating_sub(1), area.width.saturating_sub(2), 1);
+                frame.render_widget(Paragraph::new(indicator), border);
+            }
+            return;
+        }
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),  // Title
+                Constraint::Min(15),    // Content
+                Constraint::Length(4),  // Timeline
+                Constraint::Length(1),  // Timestamps
+                Constraint::Length(3),  // Controls
+            ])
+            .split(area);
+
+        // Title
+        let accent = theme::accent(ThemeView::Detail);
+        let title_style = Style::default()
+            .fg(accent)
+            .add_modifier(Modifier::BOLD);
+        let title_line = match &self.mead {
+            Some(m) => {
+                // Cut the name short so the status always fits inside the border
+                let status = m.status.as_str();
+                let room = (main_chunks[0].width as usize).saturating_sub(2 + " - ".len() + status.len());
+                Line::from(vec![
+                    Span::styled(format!("{} - ", truncate_with_ellipsis(&m.name, room)), title_style),
+                    Span::styled(status, title_style.fg(theme::status_color(&m.status))),
+                ])
+            }
+            None => Line::from(Span::styled("Mead Details", title_style)),
+        };
+
+        let mut title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(accent))
+            .border_set(border::ROUNDED);
+        if self.finished_warning && self.editing {
+            title_block = title_block.title_bottom(
+                Line::from(Span::styled(
+                    " ⚠ Editing a finished batch ",
+                    Style::default().fg(NORD_YELLOW).add_modifier(Modifier::BOLD),
+                ))
+                .centered(),
+            );
+        }
+        if let Some(indicator) = self.autosave_indicator() {
+            title_block = title_block.title_bottom(indicator);
+        }
+        let title = Paragraph::new(title_line)
+            .alignment(Alignment::Center)
+            .block(title_block);
+        frame.render_widget(title, main_chunks[0]);
+
+        // Content area - split into left (details) and right (logs/ingredients)
+        let content_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_chunks[1]);
+
+        // Left side - mead details
+        self.render_details(frame, content_chunks[0]);
+
+        // Right side - logs and ingredients
+        self.render_logs_and_ingredients(frame, content_chunks[1]);
+
+        self.render_timeline(frame, main_chunks[2]);
+
+        if let Some(mead) = &self.mead {
+            let timestamps = format!(
+                "Created {} · Updated {} ({})",
+                fmt_date(mead.created_at.date_naive()),
+                fmt_date(mead.updated_at.date_naive()),
+                fmt_relative(mead.updated_at)
+            );
+            frame.render_widget(
+                Paragraph::new(timestamps)
+                    .alignment(Alignment::Right)
+                    .style(Style::default().fg(NORD_GRAY)),
+                main_chunks[3],
+            );
+        }
+
+        // Controls, replaced by the status message when there is one
+        let controls = if let Some(message) = status_message {
+            Line::from(Span::styled(message.as_str(), Style::default().fg(NORD_FROST)))
+        } else if matches!(self.form, Some(DetailForm::Log(_))) {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" log entry  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" History  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Type  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if matches!(&self.form, Some(DetailForm::Ingredient(form)) if form.field == 3) {
+            Line::from(vec![
+                Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Change type  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Next field  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if matches!(
+            self.form,
+            Some(
+                DetailForm::Ingredient(_)
+                    | DetailForm::Reminder(_)
+                    | DetailForm::Nutrient(_)
+                    | DetailForm::Acidity(_)
+                    | DetailForm::TopUp(_)
+                    | DetailForm::Competition(_)
+            )
+        ) {
+            Line::from(vec![
+                Span::styled("Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Next field  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if self.measurement_slider_active() {
+            Line::from(vec![
+                Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Adjust (Shift: fine)  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Type instead  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Next  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Abort", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if self.measurement_step() == Some(MeasurementStep::Gravity) {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" value  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Slider  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Next  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Abort", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if self.measurement_step().is_some() || self.finish_step().is_some() {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" value  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Next  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Abort", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if matches!(self.form, Some(DetailForm::Priming(_))) {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" target volumes  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter/Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Close", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if matches!(self.form, Some(DetailForm::ReadingInterval(_))) {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" days  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if matches!(self.form, Some(DetailForm::Snapshot(_))) {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" label  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Snapshot  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if matches!(self.form, Some(DetailForm::Scale(_))) {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" target volume  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Apply  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if matches!(self.form, Some(DetailForm::Reading(_))) {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" gravity  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if self.filtering_ingredients {
+            Line::from(vec![
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Filter  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Select  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Keep  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Clear", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if self.pane == DetailPane::Readings {
+            Line::from(vec![
+                Span::styled("Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Select  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Home/End", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Newest/Oldest  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Pane  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Correct  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("d", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Delete  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if self.pane == DetailPane::Ingredients {
+            Line::from(vec![
+                Span::styled("Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Select  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Ctrl+Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Move  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("g", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Group  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("y", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Glyphs  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("o", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Sort  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("u", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Merge Duplicates  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("I", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Copy From  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("/", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Filter  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Pane  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("i", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Add  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if self.pane == DetailPane::Reminders {
+            Line::from(vec![
+                Span::styled("Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Select  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Pane  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("r", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Add  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("x", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Done  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+            ])
+        } else if self.edit_all {
+            let on_last = self.current_field + 1 == DetailField::count();
+            let mut keys = vec![
+                Span::styled("Editing all fields  ", Style::default().fg(NORD_FROST).add_modifier(Modifier::BOLD)),
+            ];
+            if self.edit_all_on_status() {
+                keys.push(Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)));
+                keys.push(Span::styled(" Change Status  ", Style::default().fg(NORD_WHITE)));
+            } else if self.rating_focused() {
+                keys.push(Span::styled("Left/Right/0-5", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)));
+                keys.push(Span::styled(" Stars  ", Style::default().fg(NORD_WHITE)));
+            }
+            keys.extend([
+                Span::styled("Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Skip  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(if on_last { " Save  " } else { " Next  " }, Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Stop Without Saving", Style::default().fg(NORD_WHITE)),
+            ]);
+            Line::from(keys)
+        } else if self.rating_focused() {
+            Line::from(vec![
+                Span::styled("Tab/Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Fewer/More Stars  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("1-5", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Rate  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("0", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Unrated  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Ctrl+R", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Revert  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled("Tab/Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Pane  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Edit  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Ctrl+E", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Edit All  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Ctrl+R", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Revert  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("l", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Log  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("L", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Quick Log  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("m", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Measure  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("R", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Reading Schedule  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("G", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Sync Gravity  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("A", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Acidity  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("E", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Competition Entry  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("n", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Nutrients  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Ctrl+W", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Auto Warnings  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("f", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Filter  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("o", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Log Order  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Home/End", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Newest/Oldest Log  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("t", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Copy Log To  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("i", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Ingredient  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("r", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Reminder  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("w", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Write Notes  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("v", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Scale  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("U", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Top Up  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("S", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Snapshot  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("C", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Compare  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("T", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Timer  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("c", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Copy  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("e", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Export  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("s", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+            ])
+        };
+
+        let mut controls_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_GRAY))
+            .border_set(border::ROUNDED);
+        if let Some(started) = self.timer_started {
+            controls_block = controls_block.title(
+                Line::from(Span::styled(
+                    format!(" Timer {} ", fmt_elapsed(started.elapsed())),
+                    Style::default().fg(NORD_YELLOW).add_modifier(Modifier::BOLD),
+                ))
+                .right_aligned(),
+            );
+        }
+        let controls_widget = Paragraph::new(controls)
+            .alignment(Alignment::Center)
+            .block(controls_block);
+        frame.render_widget(controls_widget, main_chunks[4]);
+
+        if let Some(picker) = &self.log_copy {
+            self.render_log_copy(frame, picker);
+        }
+        if let Some(picker) = &self.ingredient_source {
+            self.render_ingredient_source(frame, picker);
+        }
+        if let Some(viewer) = &self.snapshot_viewer {
+            self.render_snapshot_viewer(frame, viewer);
+        }
+    }
+
+    fn render_rating(&self, frame: &mut Frame, area: Rect) {
+        let focused = DetailField::from_index(self.current_field) == DetailField::Rating;
+        let block = Block::default()
+            .title(Line::from(vec![
+                Span::styled(
+                    " Rating ",
+                    if focused {
+                        Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(NORD_FROST)
+                    },
+                ),
+                hotkey_span(Some(DetailField::Rating.hotkey()), focused),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if focused { NORD_CYAN } else { NORD_GRAY }))
+            .border_set(border::ROUNDED);
+        let text = match self.current_rating {
+            Some(stars) => Span::styled(
+                format!("  {}", rating_stars(Some(stars))),
+                Style::default().fg(NORD_YELLOW),
+            ),
+            None => Span::styled("  Unrated", Style::default().fg(NORD_GRAY)),
+        };
+        frame.render_widget(Paragraph::new(Line::from(text)).block(block), area);
+    }
+
+    fn render_details(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // Name
+                Constraint::Length(3), // Status and Rating
+                Constraint::Length(3), // Next Action
+                Constraint::Length(3), // Current Gravity
+                Constraint::Length(3), // YAN Added
+                Constraint::Length(3), // Notes
+                Constraint::Length(3), // Photo
+                Constraint::Length(3), // ABV and YAN gauges
+                Constraint::Min(0),    // Info display
+            ])
+            .split(area);
+
+        // Editable fields
+        frame.render_widget(&self.name_input, chunks[0]);
+
+        let status_row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+
+        // Status selector
+        let status_focused = self.current_field == DetailField::Status as usize;
+        let status_style = if status_focused {
+            Style::default().fg(NORD_CYAN)
+        } else {
+            Style::default().fg(NORD_GRAY)
+        };
+        let status_block = Block::default()
+            .title(Line::from(vec![
+                Span::styled(" Status (Enter to cycle) ",
+                    if status_focused {
+                        Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(NORD_FROST)
+                    }
+                ),
+                hotkey_span(Some(DetailField::Status.hotkey()), status_focused),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(status_style)
+            .border_set(border::ROUNDED);
+        
+        let status_text = Paragraph::new(format!("  {}", self.current_status.as_str()))
+            .style(Style::default().fg(theme::status_color(&self.current_status)))
+            .block(status_block);
+        frame.render_widget(status_text, status_row[0]);
+        self.render_rating(frame, status_row[1]);
+        frame.render_widget(&self.next_action_input, chunks[2]);
+
+        frame.render_widget(&self.current_gravity_input, chunks[3]);
+        if self.editing && DetailField::from_index(self.current_field) == DetailField::CurrentGravity {
+            self.render_gravity_preview(frame, chunks[3]);
+        }
+        frame.render_widget(&self.yan_added_input, chunks[4]);
+        frame.render_widget(&self.notes_input, chunks[5]);
+        frame.render_widget(&self.photo_input, chunks[6]);
+
+        if let Some(mead) = self.get_updated_mead() {
+            let gauges = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(chunks[7]);
+
+            let progress = Gauge::default()
+                .block(
+                    Block::default()
+                        .title(Span::styled(" ABV Progress ", Style::default().fg(NORD_FROST)))
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(NORD_GRAY))
+                        .border_set(border::ROUNDED),
+                )
+                .gauge_style(Style::default().fg(NORD_GREEN).bg(NORD_GRAY))
+                .ratio(mead.abv_progress())
+                .label(format!(
+                    "{}% of {}% ({:.0}%)",
+                    fmt_abv(mead.current_abv().max(0.0)),
+                    fmt_abv(mead.target_abv),
+                    mead.abv_progress() * 100.0
+                ));
+            frame.render_widget(progress, gauges[0]);
+            frame.render_widget(self.yan_gauge(&mead), gauges[1]);
+        }
+
+        // Static info display
+        if let Some(mead) = &self.mead {
+            let mut info_lines = vec![
+                Line::from(vec![
+                    Span::styled("Start Date: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(fmt_date_str(&mead.start_date), Style::default().fg(NORD_WHITE)),
+                ]),
+                Line::from(vec![
+                    Span::styled(format!("{}: ", mead.base_type.fermentable_label()), Style::default().fg(NORD_GRAY)),
+                    Span::styled(fermentable_text(mead), Style::default().fg(NORD_WHITE)),
+                ]),
+                Line::from(vec![
+                    Span::styled("Yeast: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(&mead.yeast_strain, Style::default().fg(NORD_WHITE)),
+                ]),
+                Line::from(vec![
+                    Span::styled("OG: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(fmt_gravity(mead.starting_gravity), Style::default().fg(NORD_WHITE)),
+                    Span::styled("  Target ABV: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(format!("{}%", fmt_abv(mead.target_abv)), Style::default().fg(NORD_WHITE)),
+                    Span::styled("  Potential: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(
+                        format!("{}%", fmt_abv(mead.potential_abv())),
+                        Style::default().fg(if mead.target_abv > mead.potential_abv() { NORD_YELLOW } else { NORD_WHITE }),
+                    ),
+                ]),
+                Line::from(vec![
+                    Span::styled("Volume: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(format!("{:.1} gal", mead.volume_gallons), Style::default().fg(NORD_WHITE)),
+                    Span::styled("  YAN Req: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(format!("{} ppm", fmt_yan(mead.yan_required)), Style::default().fg(NORD_WHITE)),
+                ]),
+                Line::from(vec![
+                    Span::styled("Target FG: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(fmt_gravity(mead.target_fg()), Style::default().fg(NORD_WHITE)),
+                    Span::styled("  ", Style::default()),
+                    Span::styled(
+                        mead.fg_estimate(&self.gravity_readings).label(),
+                        Style::default().fg(NORD_FROST),
+                    ),
+                ]),
+            ];
+
+            info_lines.extend(ratio_line(mead));
+
+            if let Some(color) = self.show_color_estimate.then(|| calc::estimate_color(mead, &self.ingredients)).flatten() {
+                let (r, g, b) = color.rgb;
+                info_lines.push(Line::from(vec![
+                    Span::styled("Color: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled("████", Style::default().fg(Color::Rgb(r, g, b))),
+                    Span::styled(format!(" ~{:.0} SRM (estimate)", color.srm), Style::default().fg(NORD_WHITE)),
+                ]));
+            }
+
+            if !mead.auto_reminders {
+                info_lines.push(Line::from(Span::styled(
+                    "Overdue and stall warnings off",
+                    Style::default().fg(NORD_GRAY),
+                )));
+            }
+
+            if let Some((fg, abv)) = mead.bottling_snapshot() {
+                let mut text = format!("FG {}, {}% ABV", fmt_gravity(fg), fmt_abv(abv.max(0.0)));
+                if let Some(count) = mead.bottle_count {
+                    text.push_str(&format!(", {} bottles", count));
+                }
+                if !mead.bottled_date.is_empty() {
+                    text.push_str(&format!(" on {}", fmt_date_str(&mead.bottled_date)));
+                }
+                info_lines.push(Line::from(vec![
+                    Span::styled("As Bottled: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(text, Style::default().fg(NORD_WHITE)),
+                ]));
+            }
+
+            if mead.status == MeadStatus::Bottled {
+                info_lines.push(Line::from(vec![
+                    Span::styled("Priming Sugar (p): ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(self.priming_text(mead), Style::default().fg(NORD_WHITE)),
+                ]));
+            }
+
+            if !mead.image_path.is_empty() && self.photo_preview.is_none() {
+                let missing = !photo_file(&mead.image_path).exists();
+                info_lines.push(Line::from(vec![
+                    Span::styled("Photo: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(mead.image_path.clone(), Style::default().fg(NORD_WHITE)),
+                    Span::styled(if missing { " (not found)" } else { "" }, Style::default().fg(NORD_YELLOW)),
+                ]));
+            }
+
+            if mead.competition {
+                info_lines.push(Line::from(vec![
+                    Span::styled("Competition (J): ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(mead.competition_label(), Style::default().fg(NORD_WHITE)),
+                ]));
+                if !mead.judge_notes.is_empty() {
+                    info_lines.push(Line::from(vec![
+                        Span::styled("Judge Notes: ", Style::default().fg(NORD_GRAY)),
+                        Span::styled(mead.judge_notes.clone(), Style::default().fg(NORD_WHITE)),
+                    ]));
+                }
+            }
+
+            if let Some(acidity) = mead.acidity_label() {
+                info_lines.push(Line::from(vec![
+                    Span::styled("Acidity (A): ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(acidity, Style::default().fg(NORD_WHITE)),
+                ]));
+            }
+
+            if let (Some(days), Some(label)) =
+                (mead.gravity_check_days, mead.reading_label(&self.gravity_readings))
+            {
+                let color = if mead.reading_due(&self.gravity_readings) { NORD_YELLOW } else { NORD_WHITE };
+                info_lines.push(Line::from(vec![
+                    Span::styled("Next Reading (R): ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(format!("{} (every {}d)", label, days), Style::default().fg(color)),
+                ]));
+            }
+
+            if let (Some(days), Some(label)) = (mead.days_until_target(), mead.target_label()) {
+                let color = if days < 0 && mead.auto_reminders { NORD_RED } else { NORD_WHITE };
+                info_lines.insert(1, Line::from(vec![
+                    Span::styled("Target Date: ", Style::default().fg(NORD_GRAY)),
+                    Span::styled(format!("{} ({})", fmt_date_str(&mead.target_date), label), Style::default().fg(color)),
+                ]));
+            }
+            
+            let notes = self.notes_input.get_value();
+            let notes_height = if notes.trim().is_empty() {
+                Constraint::Length(0)
+            } else {
+                Constraint::Percentage(40)
+            };
+            let mut info_height = info_lines.len() as u16 + 2;
+            if self.photo_preview.is_some() {
+                info_height = info_height.max(PREVIEW_ROWS + 2);
+            }
+            let info_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(info_height),
+                    notes_height,
+                    Constraint::Min(0),
+                ])
+                .split(chunks[8]);
+            let [info_area, preview_area] = match &self.photo_preview {
+                Some(_) => Layout::horizontal([Constraint::Min(0), Constraint::Length(PREVIEW_COLS + 2)])
+                    .areas(info_chunks[0]),
+                None => [info_chunks[0], Rect::default()],
+            };
+
+            let info = Paragraph::new(info_lines)
+                .block(
+                    Block::default()
+                        .title(Span::styled(" Original Values ", Style::default().fg(NORD_FROST)))
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(NORD_GRAY))
+                        .border_set(border::ROUNDED),
+                );
+            frame.render_widget(info, info_area);
+
+            if let Some((_, protocol)) = &self.photo_preview {
+                let block = Block::default()
+                    .title(Span::styled(" Photo ", Style::default().fg(NORD_FROST)))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_GRAY))
+                    .border_set(border::ROUNDED);
+                let inner = block.inner(preview_area);
+                frame.render_widget(block, preview_area);
+                frame.render_widget(Image::new(protocol), inner);
+            }
+
+            if !notes.trim().is_empty() {
+                self.render_notes(frame, info_chunks[1], notes);
+            }
+
+            if self.pane == DetailPane::Readings || matches!(self.form, Some(DetailForm::Reading(_))) {
+                self.render_readings(frame, info_chunks[2]);
+            } else {
+                self.render_gravity_chart(frame, info_chunks[2]);
+            }
+        }
+    }
+
+    /// Render the notes wrapped to the block width, scrolled with PgUp/PgDn
+    fn render_notes(&self, frame: &mut Frame, area: Rect, notes: &str) {
+        let inner_width = area.width.saturating_sub(2).max(1) as usize;
+        let inner_height = area.height.saturating_sub(2);
+        let wrapped_lines: usize = notes
+            .lines()
+            .map(|line| line.chars().count().div_ceil(inner_width).max(1))
+            .sum();
+        let max_scroll = (wrapped_lines as u16).saturating_sub(inner_height);
+        self.notes_max_scroll.set(max_scroll);
+        let scroll = self.notes_scroll.min(max_scroll);
+
+        let title = if max_scroll > 0 {
+            format!(" Notes ({}/{}) ", scroll + 1, max_scroll + 1)
+        } else {
+            " Notes ".to_string()
+        };
+
+        let paragraph = Paragraph::new(notes)
+            .style(Style::default().fg(NORD_WHITE))
+            .wrap(Wrap { trim: false })
+            .scroll((scroll, 0))
+            .block(
+                Block::default()
+                    .title(Span::styled(title, Style::default().fg(NORD_FROST)))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_GRAY))
+                    .border_set(border::ROUNDED),
+            );
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Table of gravity readings with the change since the one before and
+    /// the ABV reached, for selection and correction
+    fn render_readings(&self, frame: &mut Frame, area: Rect) {
+        let area = if let Some(DetailForm::Reading(input)) = &self.form {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
+            frame.render_widget(input, chunks[0]);
+            chunks[1]
+        } else {
+            area
+        };
+
+        let rows: Vec<Row> = self
+            .gravity_readings
+            .iter()
+            .zip(gravity_deltas(&self.gravity_readings))
+            .map(|(reading, delta)| {
+                let delta = match delta {
+                    Some(d) if d < 0.0 => Span::styled(format!("-{}", fmt_gravity(-d)), Style::default().fg(NORD_GREEN)),
+                    Some(d) if d > 0.0 => Span::styled(format!("+{}", fmt_gravity(d)), Style::default().fg(NORD_YELLOW)),
+                    Some(_) => Span::styled("±0", Style::default().fg(NORD_GRAY)),
+                    None => Span::styled("-", Style::default().fg(NORD_GRAY)),
+                };
+                let abv = self.mead.as_ref().map_or(0.0, |m| m.abv_at(reading.gravity)).max(0.0);
+                Row::new(vec![
+                    Span::styled(fmt_datetime(reading.recorded_at), Style::default().fg(NORD_GRAY)),
+                    Span::raw(fmt_gravity(reading.gravity)),
+                    Span::raw(reading.temperature.map(fmt_temp).unwrap_or_default()),
+                    delta,
+                    Span::raw(format!("{}%", fmt_abv(abv))),
+                    Span::styled(reading.status.as_str(), Style::default().fg(theme::status_color(&reading.status))),
+                ])
+            })
+            .collect();
+
+        let mut title = vec![Span::styled(
+            format!(" Gravity Readings ({}) ", self.gravity_readings.len()),
+            Style::default().fg(NORD_FROST),
+        )];
+        if let Some(age) = Mead::reading_age(&self.gravity_readings) {
+            // Only a fermenting batch on a schedule can be judged stale
+            let interval = self
+                .mead
+                .as_ref()
+                .filter(|m| matches!(m.status, MeadStatus::Primary | MeadStatus::Secondary))
+                .and_then(|m| m.gravity_check_days);
+            let label = if age == 0 { "last today".to_string() } else { format!("last {}d ago", age) };
+            title.push(Span::styled(format!("{} ", label), Style::default().fg(reading_age_color(age, interval))));
+        }
+
+        let header = Row::new(["Date", "Gravity", "Temp", "Change", "ABV", "Stage"])
+            .style(Style::default().fg(NORD_FROST).add_modifier(Modifier::BOLD));
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(16),
+                Constraint::Length(7),
+                Constraint::Length(6),
+                Constraint::Length(7),
+                Constraint::Length(6),
+                Constraint::Min(8),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .block(
+            Block::default()
+                .title(Line::from(title))
+                .borders(Borders::ALL)
+                .border_style(self.pane_border_style(DetailPane::Readings, NORD_GRAY))
+                .border_set(border::ROUNDED),
+        );
+        let selected = (self.pane == DetailPane::Readings).then_some(self.reading_selected);
+        let mut state = TableState::default().with_selected(selected);
+        frame.render_stateful_widget(table, area, &mut state);
+    }
+
+    /// Days from the first gravity reading to the last
+    fn chart_span(&self) -> f64 {
+        match (self.gravity_readings.first(), self.gravity_readings.last()) {
+            (Some(first), Some(last)) => (last.recorded_at - first.recorded_at).num_minutes() as f64 / 1440.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Halve (or double) the days shown on the gravity chart around its
+    /// middle
+    pub fn zoom_chart(&mut self, zoom_in: bool) {
+        self.chart_window = zoom_window(self.chart_window, self.chart_span(), zoom_in);
+    }
+
+    /// Show or hide ABV on the gravity chart
+    pub fn toggle_abv_overlay(&mut self) {
+        self.show_abv_overlay = !self.show_abv_overlay;
+    }
+
+    /// Move a zoomed gravity chart a quarter of its width later or earlier
+    pub fn pan_chart(&mut self, later: bool) {
+        if let Some(window) = self.chart_window {
+            self.chart_window = Some(pan_window(window, self.chart_span(), later));
+        }
+    }
+
+    /// Chart gravity over time, with each point colored by the stage it was
+    /// recorded in. Zoomed in, only the chart window's days are shown.
+    fn render_gravity_chart(&self, frame: &mut Frame, area: Rect) {
+        let title = match self.chart_window {
+            Some((from, to)) => format!(" Gravity History: days {:.0}-{:.0} (+/- zoom, </> pan, b ABV) ", from, to),
+            None => " Gravity History (+ to zoom, b ABV) ".to_string(),
+        };
+        let block = Block::default()
+            .title(Span::styled(title, Style::default().fg(NORD_FROST)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_GRAY))
+            .border_set(border::ROUNDED);
+
+        let Some(first) = self.gravity_readings.first() else {
+            frame.render_widget(
+                Paragraph::new("No readings yet").style(Style::default().fg(NORD_GRAY)).block(block),
+                area,
+            );
+            return;
+        };
+
+        let start = first.recorded_at;
+        let points: Vec<(f64, f64)> = self
+            .gravity_readings
+            .iter()
+            .map(|r| ((r.recorded_at - start).num_minutes() as f64 / 1440.0, r.gravity))
+            .collect();
+
+        let stage_points: Vec<(MeadStatus, Vec<(f64, f64)>)> = MeadStatus::all()
+            .into_iter()
+            .map(|status| {
+                let stage: Vec<(f64, f64)> = self
+                    .gravity_readings
+                    .iter()
+                    .zip(&points)
+                    .filter(|(r, _)| r.status == status)
+                    .map(|(_, p)| *p)
+                    .collect();
+                (status, stage)
+            })
+            .filter(|(_, stage)| !stage.is_empty())
+            .collect();
+
+        let mut datasets = vec![Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(NORD_GRAY))
+            .data(&points)];
+        datasets.extend(stage_points.iter().map(|(status, stage)| {
+            Dataset::default()
+                .name(status.as_str())
+                .marker(Marker::Dot)
+                .graph_type(GraphType::Scatter)
+                .style(Style::default().fg(theme::status_color(status)))
+                .data(stage)
+        }));
+
+        let (from_day, to_day) = self
+            .chart_window
+            .unwrap_or((0.0, points.iter().map(|p| p.0).fold(0.0, f64::max).max(1.0)));
+        // Fit the gravity axis to the readings in view, or all of them if
+        // the window falls between readings
+        let shown: Vec<f64> = points.iter().filter(|p| (from_day..=to_day).contains(&p.0)).map(|p| p.1).collect();
+        let gravities = if shown.is_empty() { points.iter().map(|p| p.1).collect() } else { shown };
+        let min_gravity = gravities.iter().copied().fold(f64::INFINITY, f64::min) - 0.005;
+        let max_gravity = gravities.iter().copied().fold(f64::NEG_INFINITY, f64::max) + 0.005;
+
+        // ABV from the starting gravity, drawn against its own 0-top scale
+        // stretched over the gravity axis
+        let abv = self.mead.as_ref().filter(|_| self.show_abv_overlay).map(|mead| {
+            let abvs: Vec<(f64, f64)> = points.iter().map(|&(day, gravity)| (day, mead.abv_at(gravity).max(0.0))).collect();
+            let top = abv_axis_top(abvs.iter().map(|p| p.1).fold(0.0, f64::max));
+            let scaled: Vec<(f64, f64)> = abvs
+                .iter()
+                .map(|&(day, abv)| (day, abv_on_gravity_axis(abv, top, min_gravity, max_gravity)))
+                .collect();
+            (top, scaled)
+        });
+        if let Some((_, scaled)) = &abv {
+            datasets.push(
+                Dataset::default()
+                    .name("ABV")
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(NORD_YELLOW))
+                    .data(scaled),
+            );
+        }
+
+        let mut y_axis = Axis::default()
+            .style(Style::default().fg(NORD_GRAY))
+            .bounds([min_gravity, max_gravity])
+            .labels([fmt_gravity(min_gravity), fmt_gravity(max_gravity)]);
+        if abv.is_some() {
+            y_axis = y_axis.title(Span::styled("Gravity", Style::default().fg(NORD_GRAY)));
+        }
+
+        let inner = block.inner(area);
+        let chart = Chart::new(datasets)
+            .block(block)
+            .x_axis(
+                Axis::default()
+                    .style(Style::default().fg(NORD_GRAY))
+                    .bounds([from_day, to_day])
+                    .labels([format!("day {:.0}", from_day), format!("day {:.0}", to_day)]),
+            )
+            .y_axis(y_axis);
+        // Keep the top-right corner free for the ABV axis label
+        let chart = if abv.is_some() { chart.legend_position(Some(LegendPosition::TopLeft)) } else { chart };
+        frame.render_widget(chart, area);
+
+        // The second axis: ABV labels down the right edge, level with the
+        // gravity labels (the bottom two rows hold the x axis)
+        if let Some((top, _)) = abv {
+            let style = Style::default().fg(NORD_YELLOW);
+            let top_row = Rect { height: 1.min(inner.height), ..inner };
+            let top_label = Paragraph::new(Span::styled(format!("{:.0}% ABV", top), style));
+            frame.render_widget(top_label.alignment(Alignment::Right), top_row);
+            if inner.height > 3 {
+                let bottom_row = Rect { y: inner.bottom() - 3, height: 1, ..inner };
+                let bottom_label = Paragraph::new(Span::styled("0% ABV", style));
+                frame.render_widget(bottom_label.alignment(Alignment::Right), bottom_row);
+            }
+        }
+    }
+
+    /// Render the log copy picker as a popup over the view
+    fn render_log_copy(&self, frame: &mut Frame, picker: &LogCopyPicker) {
+        let [popup] = Layout::horizontal([Constraint::Length(40)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [popup] = Layout::vertical([Constraint::Length(picker.meads.len().clamp(1, 12) as u16 + 2)])
+            .flex(Flex::Center)
+            .areas(popup);
+
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Copy to ({} picked) ", picker.picked.len()),
+                Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_CYAN))
+            .border_set(border::ROUNDED)
+            .style(Style::default().bg(NORD_BG).fg(NORD_WHITE));
+        frame.render_widget(Clear, popup);
+
+        if picker.meads.is_empty() {
+            frame.render_widget(
+                Paragraph::new("No other meads").style(Style::default().fg(NORD_GRAY)).block(block),
+                popup,
+            );
+            return;
+        }
+
+        let items: Vec<ListItem> = picker
+            .meads
+            .iter()
+            .map(|(id, name)| {
+                let check = if picker.picked.contains(id) { "[x]" } else { "[ ]" };
+                ListItem::new(format!("{} {}", check, name))
+            })
+            .collect();
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .fg(NORD_BG)
+                .bg(NORD_CYAN)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut state = ListState::default();
+        state.select(Some(picker.selected));
+        frame.render_stateful_widget(list, popup, &mut state);
+    }
+
+    /// Render the ingredient source picker as a popup over the view
+    fn render_ingredient_source(&self, frame: &mut Frame, picker: &IngredientSourcePicker) {
+        let [popup] = Layout::horizontal([Constraint::Length(40)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [popup] = Layout::vertical([Constraint::Length(picker.meads.len().clamp(1, 12) as u16 + 2)])
+            .flex(Flex::Center)
+            .areas(popup);
+
+        let block = Block::default()
+            .title(Span::styled(
+                " Copy ingredients from ",
+                Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_CYAN))
+            .border_set(border::ROUNDED)
+            .style(Style::default().bg(NORD_BG).fg(NORD_WHITE));
+        frame.render_widget(Clear, popup);
+
+        if picker.meads.is_empty() {
+            frame.render_widget(
+                Paragraph::new("No other meads").style(Style::default().fg(NORD_GRAY)).block(block),
+                popup,
+            );
+            return;
+        }
+
+        let items: Vec<ListItem> = picker.meads.iter().map(|(_, name)| ListItem::new(name.as_str())).collect();
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .fg(NORD_BG)
+                .bg(NORD_CYAN)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut state = ListState::default();
+        state.select(Some(picker.selected));
+        frame.render_stateful_widget(list, popup, &mut state);
+    }
+
+    /// Render the snapshot viewer as a popup: the snapshots on top and what
+    /// has changed since the highlighted one below
+    fn render_snapshot_viewer(&self, frame: &mut Frame, viewer: &SnapshotViewer) {
+        let [popup] = Layout::horizontal([Constraint::Length(72)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [popup] = Layout::vertical([Constraint::Length(24)])
+            .flex(Flex::Center)
+            .areas(popup);
+        frame.render_widget(Clear, popup);
+
+        let popup_style = Style::default().bg(NORD_BG).fg(NORD_WHITE);
+        if viewer.snapshots.is_empty() {
+            let block = Block::default()
+                .title(Span::styled(" Snapshots ", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_CYAN))
+                .border_set(border::ROUNDED)
+                .style(popup_style);
+            frame.render_widget(
+                Paragraph::new("No snapshots yet. Press S to take one.")
+                    .style(Style::default().fg(NORD_GRAY))
+                    .block(block),
+                popup,
+            );
+            return;
+        }
+
+        let [list_area, changes_area] = Layout::vertical([
+            Constraint::Length(viewer.snapshots.len().min(6) as u16 + 2),
+            Constraint::Min(0),
+        ])
+        .areas(popup);
+
+        let items: Vec<ListItem> = viewer
+            .snapshots
+            .iter()
+            .map(|s| ListItem::new(format!("{}  {}", fmt_datetime(s.taken_at), s.label)))
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!(" Snapshots ({}) ", viewer.snapshots.len()),
+                        Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_CYAN))
+                    .border_set(border::ROUNDED)
+                    .style(popup_style),
+            )
+            .highlight_style(
+                Style::default()
+                    .fg(NORD_BG)
+                    .bg(NORD_CYAN)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let mut state = ListState::default();
+        state.select(Some(viewer.selected));
+        frame.render_stateful_widget(list, list_area, &mut state);
+
+        let Some(snapshot) = viewer.snapshots.get(viewer.selected) else {
+            return;
+        };
+        let changes = mead_changes(&snapshot.mead, &viewer.current);
+        let lines: Vec<Line> = if changes.is_empty() {
+            vec![Line::from(Span::styled("Nothing has changed.", Style::default().fg(NORD_GRAY)))]
+        } else {
+            changes
+                .iter()
+                .map(|change| {
+                    let or_blank = |value: &str| if value.is_empty() { "-".to_string() } else { value.to_string() };
+                    Line::from(vec![
+                        Span::styled(format!("{}: ", change.field), Style::default().fg(NORD_FROST)),
+                        Span::styled(or_blank(&change.before), Style::default().fg(NORD_GRAY)),
+                        Span::styled(" → ", Style::default().fg(NORD_WHITE)),
+                        Span::styled(or_blank(&change.after), Style::default().fg(NORD_GREEN)),
+                    ])
+                })
+                .collect()
+        };
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Since \"{}\" ", snapshot.label),
+                Style::default().fg(NORD_FROST),
+            ))
+            .title_bottom(Span::styled(" c Export CSV  m Export Markdown  Esc Close ", Style::default().fg(NORD_GRAY)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_CYAN))
+            .border_set(border::ROUNDED)
+            .style(popup_style);
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: false }).block(block),
+            changes_area,
+        );
+    }
+
+    /// Bar from the start date to the target date, or to the latest event
+    /// when there is no target. Elapsed days are filled in; status changes
+    /// are marked with a diamond in the stage color and readings with dots.
+    fn render_timeline(&self, frame: &mut Frame, area: Rect) {
+        let mut block = Block::default()
+            .title(Span::styled(" Timeline ", Style::default().fg(NORD_FROST)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_GRAY))
+            .border_set(border::ROUNDED);
+        if let Some(mead) = &self.mead {
+            // Room left on the top border after the corners and the title
+            let room = (area.width as usize).saturating_sub(" Timeline ".len() + 4);
+            block = block.title(status_stepper(&mead.status, room).right_aligned());
+        }
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let Some(mead) = &self.mead else {
+            return;
+        };
+        let width = inner.width as usize;
+        if width == 0 {
+            return;
+        }
+
+        let today = Local::now().date_naive();
+        let local_day = |at: DateTime<Utc>| at.with_timezone(&Local).date_naive();
+        let start = NaiveDate::parse_from_str(mead.start_date.trim(), "%Y-%m-%d")
+            .unwrap_or_else(|_| local_day(mead.created_at));
+        let target = NaiveDate::parse_from_str(mead.target_date.trim(), "%Y-%m-%d").ok();
+        let latest_event = self
+            .status_changes
+            .iter()
+            .map(|c| local_day(c.changed_at))
+            .chain(self.gravity_readings.iter().map(|r| local_day(r.recorded_at)))
+            .max()
+            .unwrap_or(today);
+        let end = target.unwrap_or(latest_event).max(latest_event);
+
+        let mut cells: Vec<Span> = (0..width)
+            .map(|col| {
+                if col <= timeline_column(today, start, end, width) {
+                    Span::styled("█", Style::default().fg(NORD_BLUE))
+                } else {
+                    Span::styled("░", Style::default().fg(NORD_GRAY))
+                }
+            })
+            .collect();
+        for reading in &self.gravity_readings {
+            let col = timeline_column(local_day(reading.recorded_at), start, end, width);
+            cells[col] = Span::styled("•", Style::default().fg(NORD_WHITE));
+        }
+        for change in &self.status_changes {
+            let col = timeline_column(local_day(change.changed_at), start, end, width);
+            cells[col] = Span::styled("◆", Style::default().fg(theme::status_color(&change.status)));
+        }
+
+        let start_label = fmt_date(start);
+        let end_label = match target {
+            Some(target) if target == end => format!("target {}", fmt_date(end)),
+            _ => fmt_date(end),
+        };
+        let gap = width.saturating_sub(start_label.len() + end_label.len());
+        let labels = Line::from(vec![
+            Span::styled(start_label, Style::default().fg(NORD_GRAY)),
+            Span::raw(" ".repeat(gap)),
+            Span::styled(end_label, Style::default().fg(NORD_GRAY)),
+        ]);
+
+        frame.render_widget(Paragraph::new(vec![Line::from(cells), labels]), inner);
+    }
+
+    /// Gauge of YAN added against YAN required, red when overdosed
+    fn yan_gauge(&self, mead: &Mead) -> Gauge<'static> {
+        let ratio = if mead.yan_required > 0.0 {
+            (mead.yan_added / mead.yan_required).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let overage = mead.yan_overage_percent().filter(|p| *p > self.yan_overdose_margin);
+        let (color, title, label) = match overage {
+            Some(percent) => (
+                NORD_RED,
+                " YAN Overdosed ",
+                format!("{}/{} ppm (+{:.0}%)", fmt_yan(mead.yan_added), fmt_yan(mead.yan_required), percent),
+            ),
+            None => (
+                NORD_GREEN,
+                " YAN ",
+                format!("{}/{} ppm", fmt_yan(mead.yan_added), fmt_yan(mead.yan_required)),
+            ),
+        };
+
+        Gauge::default()
+            .block(
+                Block::default()
+                    .title(Span::styled(title, Style::default().fg(color)))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(if overage.is_some() { NORD_RED } else { NORD_GRAY }))
+                    .border_set(border::ROUNDED),
+            )
+            .gauge_style(Style::default().fg(color).bg(NORD_GRAY))
+            .ratio(ratio)
+            .label(label)
+    }
+
+    /// Draw the ABV and attenuation the gravity being typed would give on
+    /// the right of the field's top border, using the saved gravity until
+    /// the input parses
+    fn render_gravity_preview(&self, frame: &mut Frame, field: Rect) {
+        let Some(mead) = &self.mead else {
+            return;
+        };
+        let gravity = self.current_gravity_input.get_f64().unwrap_or(mead.current_gravity);
+        let mut label = format!(" ABV {}%", fmt_abv(mead.abv_at(gravity).max(0.0)));
+        if let Some(attenuation) = mead.attenuation_at(gravity) {
+            label.push_str(&format!(", {:.0}% attenuation", attenuation));
+        }
+        label.push(' ');
+        let width = (label.chars().count() as u16).min(field.width.saturating_sub(2));
+        let area = Rect {
+            x: field.right().saturating_sub(width + 1),
+            y: field.y,
+            width,
+            height: 1.min(field.height),
+        };
+        frame.render_widget(Paragraph::new(label).style(Style::default().fg(NORD_GREEN)), area);
+    }
+
+    fn render_logs_and_ingredients(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(35),
+                Constraint::Percentage(30),
+                Constraint::Percentage(35),
+            ])
+            .split(area);
+
+        // Ingredients section
+        self.render_ingredients(frame, chunks[0]);
+
+        // Reminders section
+        self.render_reminders(frame, chunks[1]);
+
+        // Log entries section
+        self.render_logs(frame, chunks[2]);
+    }
+
+    /// Border style for a pane, highlighted while it has focus
+    fn pane_border_style(&self, pane: DetailPane, color: Color) -> Style {
+        if self.pane == pane {
+            Style::default().fg(NORD_CYAN)
+        } else {
+            Style::default().fg(color)
+        }
+    }
+
+    /// List state selecting `selected` while `pane` has focus
+    fn pane_list_state(&self, pane: DetailPane, selected: usize) -> ListState {
+        let selected = if self.pane == pane { Some(selected) } else { None };
+        ListState::default().with_selected(selected)
     }
 
-    pub fn clear_ingredient_inputs(&mut self) {
-        self.ingredient_name_input.clear();
-        self.ingredient_amount_input.clear();
-        self.ingredient_unit_input.set_value("oz");
-        self.selected_ingredient_type = IngredientType::Fruit;
-        self.ingredient_field = 0;
-    }
+    /// Ingredients grouped by type, with totals summed in canonical units
+    fn render_grouped_ingredients(&self, frame: &mut Frame, area: Rect) {
+        let focused = self.pane == DetailPane::Ingredients;
+        let selected_id = self.visible_ingredients().get(self.ingredient_selected).map(|i| i.id);
+        let mut selected_line = None;
+        let mut lines = Vec::new();
+        for ingredient_type in IngredientType::all() {
+            let members: Vec<&Ingredient> = self
+                .visible_ingredients()
+                .into_iter()
+                .filter(|i| i.ingredient_type == ingredient_type)
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
 
-    /// Get the updated mead with current form values
-    pub fn get_updated_mead(&self) -> Option<Mead> {
-        self.mead.as_ref().map(|m| {
-            let mut updated = m.clone();
-            updated.name = self.name_input.get_value().to_string();
-            updated.current_gravity = self.current_gravity_input.get_f64().unwrap_or(m.current_gravity);
-            updated.yan_added = self.yan_added_input.get_f64().unwrap_or(m.yan_added);
-            updated.notes = self.notes_input.get_value().to_string();
-            updated.status = self.current_status.clone();
-            updated
-        })
-    }
+            let mut totals = Vec::new();
+            for measure in [Measure::Mass, Measure::Volume, Measure::Count] {
+                let amounts: Vec<f64> = members
+                    .iter()
+                    .filter_map(|i| i.canonical_amount())
+                    .filter(|(m, _)| *m == measure)
+                    .map(|(_, amount)| amount)
+                    .collect();
+                if !amounts.is_empty() {
+                    totals.push(measure.fmt_total(amounts.iter().sum()));
+                }
+            }
+            let unknown = members.iter().filter(|i| i.canonical_amount().is_none()).count();
+            if unknown > 0 {
+                totals.push(format!("{} unknown unit", unknown));
+            }
 
-    pub fn render(&self, frame: &mut Frame) {
-        let area = frame.area();
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{}{} ({})", glyph_prefix(ingredient_type.glyph(self.ingredient_glyphs)), ingredient_type.as_str(), members.len()),
+                    Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("  {}", totals.join(" + ")), Style::default().fg(NORD_FROST)),
+            ]));
+            for ing in members {
+                let mut style = Style::default().fg(NORD_WHITE);
+                if Some(ing.id) == selected_id {
+                    selected_line = Some(lines.len());
+                    if focused {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                }
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {} - {:.1} {}", ing.name, ing.amount, ing.unit), style),
+                    Span::styled(ingredient_notes(ing), Style::default().fg(NORD_GRAY).add_modifier(Modifier::ITALIC)),
+                ]));
+            }
+        }
 
-        let main_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .margin(1)
-            .constraints([
-                Constraint::Length(3),  // Title
-                Constraint::Min(15),    // Content
-                Constraint::Length(3),  // Controls
-            ])
-            .split(area);
+        // Scroll just far enough to keep the selected ingredient in view
+        let height = area.height.saturating_sub(2) as usize;
+        let mut offset = self.ingredient_offset.get().min(lines.len().saturating_sub(height));
+        if let Some(line) = selected_line {
+            offset = offset.min(line).max((line + 1).saturating_sub(height));
+        }
+        self.ingredient_offset.set(offset);
 
-        // Title
-        let title_text = self.mead.as_ref()
-            .map(|m| format!("{} - {}", m.name, m.status.as_str()))
-            .unwrap_or_else(|| "Mead Details".to_string());
-        
-        let title = Paragraph::new(Line::from(Span::styled(
-            title_text,
-            Style::default()
-                .fg(NORD_FROST)
-                .add_modifier(Modifier::BOLD),
-        )))
-        .alignment(Alignment::Center)
-        .block(
+        let mut title = format!(" Ingredients by type ({}) ", self.ingredients.len());
+        if lines.len() > height {
+            title.push_str(&format!("[{}-{} of {} lines] ", offset + 1, (offset + height).min(lines.len()), lines.len()));
+        }
+        let paragraph = Paragraph::new(lines).scroll((offset as u16, 0)).block(
             Block::default()
+                .title(Span::styled(title, Style::default().fg(NORD_FROST)))
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(NORD_FROST))
+                .border_style(self.pane_border_style(DetailPane::Ingredients, NORD_BLUE))
                 .border_set(border::ROUNDED),
         );
-        frame.render_widget(title, main_chunks[0]);
+        frame.render_widget(paragraph, area);
+    }
 
-        // Content area - split into left (details) and right (logs/ingredients)
-        let content_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(main_chunks[1]);
+    fn render_reminders(&self, frame: &mut Frame, area: Rect) {
+        if let Some(DetailForm::Reminder(form)) = &self.form {
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
 
-        // Left side - mead details
-        self.render_details(frame, content_chunks[0]);
+            let block = Block::default()
+                .title(Span::styled(" Add Reminder ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_YELLOW))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
 
-        // Right side - logs and ingredients
-        self.render_logs_and_ingredients(frame, content_chunks[1]);
+            frame.render_widget(&form.text_input, input_chunks[0]);
+            frame.render_widget(&form.date_input, input_chunks[1]);
+        } else {
+            let items: Vec<ListItem> = self.reminders
+                .iter()
+                .map(|reminder| {
+                    let (mark, date_color, text_color) = if reminder.done {
+                        ("✓ ", NORD_GRAY, NORD_GRAY)
+                    } else if reminder.is_overdue() {
+                        ("  ", NORD_RED, NORD_RED)
+                    } else {
+                        ("  ", NORD_YELLOW, NORD_WHITE)
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(mark, Style::default().fg(NORD_GREEN)),
+                        Span::styled(format!("[{}] ", fmt_date_str(&reminder.due_date)), Style::default().fg(date_color)),
+                        Span::styled(&reminder.text, Style::default().fg(text_color)),
+                    ]))
+                })
+                .collect();
 
-        // Controls
-        let controls = if self.show_log_input {
-            Line::from(vec![
-                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" log entry  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
-            ])
-        } else if self.show_ingredient_input {
-            Line::from(vec![
-                Span::styled("Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Next field  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Cancel", Style::default().fg(NORD_WHITE)),
-            ])
+            let open = self.reminders.iter().filter(|r| !r.done).count();
+            let list = List::new(items)
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                .block(
+                    Block::default()
+                        .title(Span::styled(format!(" Reminders ({} open) ", open), Style::default().fg(NORD_FROST)))
+                        .borders(Borders::ALL)
+                        .border_style(self.pane_border_style(DetailPane::Reminders, NORD_YELLOW))
+                        .border_set(border::ROUNDED),
+                );
+            let mut state = self.pane_list_state(DetailPane::Reminders, self.reminder_selected);
+            frame.render_stateful_widget(list, area, &mut state);
+        }
+    }
+
+    fn render_ingredients(&self, frame: &mut Frame, area: Rect) {
+        if let Some(DetailForm::Ingredient(form)) = &self.form {
+            // Show ingredient input form
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([
+                    Constraint::Length(3), // Name
+                    Constraint::Length(3), // Amount
+                    Constraint::Length(3), // Unit
+                    Constraint::Length(3), // Type
+                    Constraint::Length(3), // Notes
+                ])
+                .split(area);
+
+            let mut block = Block::default()
+                .title(Span::styled(" Add Ingredient ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_BLUE))
+                .border_set(border::ROUNDED);
+            if let Some(warning) = self.typed_ingredient().and_then(|i| i.unit_warning()) {
+                block = block.title_bottom(Span::styled(format!(" {} ", warning), Style::default().fg(NORD_YELLOW)));
+            }
+            frame.render_widget(block, area);
+
+            frame.render_widget(&form.name_input, input_chunks[0]);
+            frame.render_widget(&form.amount_input, input_chunks[1]);
+            frame.render_widget(&form.unit_input, input_chunks[2]);
+            frame.render_widget(&form.type_select, input_chunks[3]);
+            frame.render_widget(&form.notes_input, input_chunks[4]);
         } else {
-            Line::from(vec![
-                Span::styled("Tab/Arrows", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Edit  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("l", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Log  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("i", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Ingredient  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("s", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Save  ", Style::default().fg(NORD_WHITE)),
-                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Back", Style::default().fg(NORD_WHITE)),
-            ])
+            let area = if self.filtering_ingredients || self.ingredient_filter_active() {
+                let [filter_area, list_area] =
+                    Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+                frame.render_widget(&self.ingredient_filter_input, filter_area);
+                list_area
+            } else {
+                area
+            };
+            if self.group_ingredients {
+                self.render_grouped_ingredients(frame, area);
+            } else {
+                self.render_ingredient_list(frame, area);
+            }
+        }
+    }
+
+    /// The ingredients pane as a flat list, narrowed by the filter
+    fn render_ingredient_list(&self, frame: &mut Frame, area: Rect) {
+        let visible = self.visible_ingredients();
+        let count = if self.ingredient_filter_active() {
+            format!("{} of {}", visible.len(), self.ingredients.len())
+        } else {
+            self.ingredients.len().to_string()
         };
+        let items: Vec<ListItem> = visible
+            .iter()
+            .map(|ing| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!(
+                            "{}[{}] ",
+                            glyph_prefix(ing.ingredient_type.glyph(self.ingredient_glyphs)),
+                            ing.ingredient_type.as_str()
+                        ),
+                        Style::default().fg(NORD_CYAN),
+                    ),
+                    Span::styled(format!("{} - {:.1} {}", ing.name, ing.amount, ing.unit), Style::default().fg(NORD_WHITE)),
+                    Span::styled(ingredient_notes(ing), Style::default().fg(NORD_GRAY).add_modifier(Modifier::ITALIC)),
+                    Span::styled(format!("  {}", fmt_date_str(&ing.added_date)), Style::default().fg(NORD_GRAY)),
+                ]))
+            })
+            .collect();
 
-        let controls_widget = Paragraph::new(controls)
-            .alignment(Alignment::Center)
+        let mut title = match self.ingredient_sort {
+            IngredientSort::Manual => format!(" Ingredients ({}) ", count),
+            sort => format!(" Ingredients ({}, {}) ", count, sort.as_str()),
+        };
+        // Where the selection sits once the list no longer fits
+        if items.len() > area.height.saturating_sub(2) as usize {
+            title.push_str(&format!("[{}/{}] ", self.ingredient_selected + 1, items.len()));
+        }
+        let list = List::new(items)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
             .block(
                 Block::default()
+                    .title(Span::styled(title, Style::default().fg(NORD_FROST)))
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(NORD_GRAY))
+                    .border_style(self.pane_border_style(DetailPane::Ingredients, NORD_BLUE))
                     .border_set(border::ROUNDED),
             );
-        frame.render_widget(controls_widget, main_chunks[2]);
+        let mut state = self
+            .pane_list_state(DetailPane::Ingredients, self.ingredient_selected)
+            .with_offset(self.ingredient_offset.get());
+        frame.render_stateful_widget(list, area, &mut state);
+        self.ingredient_offset.set(state.offset());
     }
 
-    fn render_details(&self, frame: &mut Frame, area: Rect) {
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .margin(1)
-            .constraints([
-                Constraint::Length(3), // Name
-                Constraint::Length(3), // Status
-                Constraint::Length(3), // Current Gravity
-                Constraint::Length(3), // YAN Added
-                Constraint::Length(3), // Notes
-                Constraint::Min(0),    // Info display
+    /// Lines showing the saved amounts next to their scaled values
+    fn scale_preview(&self) -> Vec<Line<'static>> {
+        if self.mead.as_ref().is_some_and(|m| !m.has_volume()) {
+            return vec![Line::from(Span::styled(
+                " No volume yet: Enter sets it without scaling anything",
+                Style::default().fg(NORD_YELLOW),
+            ))];
+        }
+        let (Some(mead), Some(factor)) = (&self.mead, self.scale_factor()) else {
+            return vec![Line::from(Span::styled(
+                " Enter a volume to see the scaled amounts",
+                Style::default().fg(NORD_GRAY),
+            ))];
+        };
+        let label = Style::default().fg(NORD_GRAY);
+        let value = Style::default().fg(NORD_WHITE);
+        let scaled = |name: String, amount: f64, unit: &str| {
+            Line::from(vec![
+                Span::styled(format!(" {}: ", name), label),
+                Span::styled(format!("{:.1} {} -> ", amount, unit), value),
+                Span::styled(format!("{:.1} {}", amount * factor, unit), Style::default().fg(NORD_GREEN)),
             ])
-            .split(area);
+        };
 
-        // Editable fields
-        frame.render_widget(&self.name_input, chunks[0]);
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!(" x{:.2}, gravity unchanged", factor),
+                Style::default().fg(NORD_FROST),
+            )),
+            scaled("Volume".to_string(), mead.volume_gallons, "gal"),
+            scaled(mead.base_type.fermentable_label().to_string(), mead.honey_amount_lbs, "lbs"),
+        ];
+        lines.extend(
+            self.ingredients
+                .iter()
+                .map(|ing| scaled(ing.name.clone(), ing.amount, &ing.unit)),
+        );
+        lines
+    }
 
-        // Status selector
-        let status_style = if self.current_field == 1 {
-            Style::default().fg(NORD_CYAN)
-        } else {
-            Style::default().fg(NORD_GRAY)
-        };
-        let status_block = Block::default()
-            .title(Span::styled(" Status (Enter to cycle) ", 
-                if self.current_field == 1 {
-                    Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(NORD_FROST)
-                }
-            ))
-            .borders(Borders::ALL)
-            .border_style(status_style)
-            .border_set(border::ROUNDED);
-        
-        let status_text = Paragraph::new(format!("  {}", self.current_status.as_str()))
-            .style(Style::default().fg(NORD_WHITE))
-            .block(status_block);
-        frame.render_widget(status_text, chunks[1]);
+    fn render_logs(&self, frame: &mut Frame, area: Rect) {
+        if let Some(DetailForm::Finish(form)) = &self.form {
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Min(0),
+                ])
+                .split(area);
 
-        frame.render_widget(&self.current_gravity_input, chunks[2]);
-        frame.render_widget(&self.yan_added_input, chunks[3]);
-        frame.render_widget(&self.notes_input, chunks[4]);
+            let block = Block::default()
+                .title(Span::styled(" Mark as Finished ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_CYAN))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
 
-        // Static info display
-        if let Some(mead) = &self.mead {
-            let info_lines = vec![
-                Line::from(vec![
-                    Span::styled("Start Date: ", Style::default().fg(NORD_GRAY)),
-                    Span::styled(&mead.start_date, Style::default().fg(NORD_WHITE)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Honey: ", Style::default().fg(NORD_GRAY)),
-                    Span::styled(format!("{} ({:.1} lbs)", &mead.honey_type, mead.honey_amount_lbs), Style::default().fg(NORD_WHITE)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Yeast: ", Style::default().fg(NORD_GRAY)),
-                    Span::styled(&mead.yeast_strain, Style::default().fg(NORD_WHITE)),
-                ]),
-                Line::from(vec![
-                    Span::styled("OG: ", Style::default().fg(NORD_GRAY)),
-                    Span::styled(format!("{:.3}", mead.starting_gravity), Style::default().fg(NORD_WHITE)),
-                    Span::styled("  Target ABV: ", Style::default().fg(NORD_GRAY)),
-                    Span::styled(format!("{:.1}%", mead.target_abv), Style::default().fg(NORD_WHITE)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Volume: ", Style::default().fg(NORD_GRAY)),
-                    Span::styled(format!("{:.1} gal", mead.volume_gallons), Style::default().fg(NORD_WHITE)),
-                    Span::styled("  YAN Req: ", Style::default().fg(NORD_GRAY)),
-                    Span::styled(format!("{:.0} ppm", mead.yan_required), Style::default().fg(NORD_WHITE)),
-                ]),
-            ];
-            
-            let info = Paragraph::new(info_lines)
-                .block(
-                    Block::default()
-                        .title(Span::styled(" Original Values ", Style::default().fg(NORD_FROST)))
-                        .borders(Borders::ALL)
-                        .border_style(Style::default().fg(NORD_GRAY))
-                        .border_set(border::ROUNDED),
+            frame.render_widget(&form.gravity_input, input_chunks[0]);
+            if form.step != FinishStep::Gravity {
+                frame.render_widget(&form.date_input, input_chunks[1]);
+            }
+            if form.step == FinishStep::BottleCount {
+                frame.render_widget(&form.bottles_input, input_chunks[2]);
+                frame.render_widget(
+                    Paragraph::new(Span::styled(
+                        format!(" Suggested for {:.0} ml bottles", calc::BOTTLE_ML),
+                        Style::default().fg(NORD_GRAY),
+                    )),
+                    input_chunks[3],
                 );
-            frame.render_widget(info, chunks[5]);
-        }
-    }
+            }
+        } else if let Some(DetailForm::Measurement(form)) = &self.form {
+            let gravity_height = if form.slider { 4 } else { 3 };
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(gravity_height), Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
 
-    fn render_logs_and_ingredients(&self, frame: &mut Frame, area: Rect) {
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(area);
+            let block = Block::default()
+                .title(Span::styled(" Record Measurement ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_CYAN))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
+
+            if form.slider {
+                let current = self.mead.as_ref().map(|m| m.current_gravity);
+                let mut slider = GravitySlider::new("Gravity", form.slider_value(current));
+                slider.focused = form.step == MeasurementStep::Gravity;
+                frame.render_widget(&slider, input_chunks[0]);
+            } else {
+                frame.render_widget(&form.gravity_input, input_chunks[0]);
+            }
+            if form.step == MeasurementStep::Temperature {
+                frame.render_widget(&form.temp_input, input_chunks[1]);
+            }
+        } else if let Some(DetailForm::Priming(input)) = &self.form {
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
+
+            let block = Block::default()
+                .title(Span::styled(" Priming Sugar ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_CYAN))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
+
+            frame.render_widget(input, input_chunks[0]);
+            if let Some(mead) = &self.mead {
+                let lines = vec![
+                    Line::from(Span::styled(
+                        format!(" Corn sugar for {:.1} gal:", mead.volume_gallons),
+                        Style::default().fg(NORD_FROST),
+                    )),
+                    Line::from(Span::styled(format!(" {}", self.priming_text(mead)), Style::default().fg(NORD_GREEN))),
+                    Line::from(Span::styled(
+                        " Typical: 1.5-2 still-ish, 2.5 sparkling, 3+ champagne-style",
+                        Style::default().fg(NORD_GRAY),
+                    )),
+                ];
+                frame.render_widget(Paragraph::new(lines), input_chunks[1]);
+            }
+        } else if let Some(DetailForm::Scale(input)) = &self.form {
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
 
-        // Ingredients section
-        self.render_ingredients(frame, chunks[0]);
+            let block = Block::default()
+                .title(Span::styled(" Scale Recipe ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_CYAN))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
 
-        // Log entries section
-        self.render_logs(frame, chunks[1]);
-    }
+            frame.render_widget(input, input_chunks[0]);
+            frame.render_widget(Paragraph::new(self.scale_preview()), input_chunks[1]);
+        } else if let Some(DetailForm::ReadingInterval(input)) = &self.form {
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
 
-    fn render_ingredients(&self, frame: &mut Frame, area: Rect) {
-        if self.show_ingredient_input {
-            // Show ingredient input form
+            let block = Block::default()
+                .title(Span::styled(" Reading Schedule ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_CYAN))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
+
+            frame.render_widget(input, input_chunks[0]);
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    " Flags the batch once this many days pass without a reading.",
+                    Style::default().fg(NORD_GRAY),
+                )),
+                input_chunks[1],
+            );
+        } else if let Some(DetailForm::Competition(form)) = &self.form {
             let input_chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(1)
                 .constraints([
-                    Constraint::Length(3), // Name
-                    Constraint::Length(3), // Amount
-                    Constraint::Length(3), // Unit
-                    Constraint::Length(3), // Type
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Min(0),
                 ])
                 .split(area);
 
             let block = Block::default()
-                .title(Span::styled(" Add Ingredient ", Style::default().fg(NORD_FROST)))
+                .title(Span::styled(" Competition Entry ", Style::default().fg(NORD_FROST)))
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(NORD_BLUE))
+                .border_style(Style::default().fg(NORD_CYAN))
                 .border_set(border::ROUNDED);
             frame.render_widget(block, area);
 
-            frame.render_widget(&self.ingredient_name_input, input_chunks[0]);
-            frame.render_widget(&self.ingredient_amount_input, input_chunks[1]);
-            frame.render_widget(&self.ingredient_unit_input, input_chunks[2]);
+            frame.render_widget(&form.category_input, input_chunks[0]);
+            frame.render_widget(&form.score_input, input_chunks[1]);
+            frame.render_widget(&form.notes_input, input_chunks[2]);
+        } else if let Some(DetailForm::Acidity(form)) = &self.form {
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
 
-            // Type selector
-            let type_style = if self.ingredient_field == 3 {
-                Style::default().fg(NORD_CYAN)
-            } else {
-                Style::default().fg(NORD_GRAY)
-            };
-            let type_block = Block::default()
-                .title(Span::styled(" Type (Left/Right to change) ", 
-                    if self.ingredient_field == 3 {
-                        Style::default().fg(NORD_CYAN)
-                    } else {
-                        Style::default().fg(NORD_FROST)
-                    }
-                ))
+            let block = Block::default()
+                .title(Span::styled(" Acidity ", Style::default().fg(NORD_FROST)))
                 .borders(Borders::ALL)
-                .border_style(type_style)
+                .border_style(Style::default().fg(NORD_CYAN))
                 .border_set(border::ROUNDED);
-            let type_text = Paragraph::new(format!("  {}", self.selected_ingredient_type.as_str()))
-                .style(Style::default().fg(NORD_WHITE))
-                .block(type_block);
-            frame.render_widget(type_text, input_chunks[3]);
-        } else {
-            // Show ingredients list
-            let items: Vec<ListItem> = self.ingredients
-                .iter()
-                .map(|ing| {
-                    ListItem::new(Line::from(vec![
-                        Span::styled(
-                            format!("[{}] ", ing.ingredient_type.as_str()),
-                            Style::default().fg(NORD_CYAN),
-                        ),
-                        Span::styled(format!("{} - {:.1} {}", ing.name, ing.amount, ing.unit), Style::default().fg(NORD_WHITE)),
-                    ]))
-                })
-                .collect();
+            frame.render_widget(block, area);
 
-            let list = List::new(items)
-                .block(
-                    Block::default()
-                        .title(Span::styled(format!(" Ingredients ({}) ", self.ingredients.len()), Style::default().fg(NORD_FROST)))
-                        .borders(Borders::ALL)
-                        .border_style(Style::default().fg(NORD_BLUE))
-                        .border_set(border::ROUNDED),
-                );
-            frame.render_widget(list, area);
-        }
-    }
+            frame.render_widget(&form.ph_input, input_chunks[0]);
+            frame.render_widget(&form.ta_input, input_chunks[1]);
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    " Leave a field blank to clear it.",
+                    Style::default().fg(NORD_GRAY),
+                )),
+                input_chunks[2],
+            );
+        } else if let Some(DetailForm::TopUp(form)) = &self.form {
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
 
-    fn render_logs(&self, frame: &mut Frame, area: Rect) {
-        if self.show_log_input {
-            // Show log input
+            let block = Block::default()
+                .title(Span::styled(" Top Up ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_CYAN))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
+
+            frame.render_widget(&form.volume_input, input_chunks[0]);
+            frame.render_widget(&form.gravity_input, input_chunks[1]);
+            frame.render_widget(Paragraph::new(self.top_up_preview()), input_chunks[2]);
+        } else if let Some(DetailForm::Snapshot(input)) = &self.form {
             let input_chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(1)
                 .constraints([Constraint::Length(3), Constraint::Min(0)])
                 .split(area);
 
+            let block = Block::default()
+                .title(Span::styled(" Snapshot ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_CYAN))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
+
+            frame.render_widget(input, input_chunks[0]);
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    " Saves the batch as it is now, to compare with later (C).",
+                    Style::default().fg(NORD_GRAY),
+                )),
+                input_chunks[1],
+            );
+        } else if let Some(DetailForm::Nutrient(form)) = &self.form {
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(1),
+                    Constraint::Min(0),
+                ])
+                .split(area);
+
+            let block = Block::default()
+                .title(Span::styled(" Add Nutrients ", Style::default().fg(NORD_FROST)))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_GREEN))
+                .border_set(border::ROUNDED);
+            frame.render_widget(block, area);
+
+            frame.render_widget(&form.select, input_chunks[0]);
+            frame.render_widget(&form.amount_input, input_chunks[1]);
+
+            let preview = match self.nutrient_yan_ppm() {
+                Some(ppm) => format!(" +{} ppm YAN", fmt_yan(ppm)),
+                None if self.mead.as_ref().is_some_and(|m| !m.has_volume()) => format!(" {}", NO_VOLUME),
+                None => " Enter grams to see the YAN added".to_string(),
+            };
+            frame.render_widget(
+                Paragraph::new(preview).style(Style::default().fg(NORD_GREEN)),
+                input_chunks[2],
+            );
+        } else if let Some(DetailForm::Log(form)) = &self.form {
+            // Show log input
+            let input_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
+
             let block = Block::default()
                 .title(Span::styled(" Add Log Entry ", Style::default().fg(NORD_FROST)))
                 .borders(Borders::ALL)
@@ -567,32 +3944,283 @@ impl MeadDetailView {
                 .border_set(border::ROUNDED);
             frame.render_widget(block, area);
 
-            frame.render_widget(&self.log_input, input_chunks[0]);
+            frame.render_widget(&form.input, input_chunks[0]);
+            frame.render_widget(&form.type_select, input_chunks[1]);
         } else {
             // Show log entries
-            let items: Vec<ListItem> = self.log_entries
-                .iter()
+            let items: Vec<ListItem> = self.visible_log_entries()
                 .map(|entry| {
-                    ListItem::new(Line::from(vec![
+                    let mut spans = vec![
                         Span::styled(
-                            format!("[{}] ", entry.timestamp.format("%Y-%m-%d %H:%M")),
+                            format!("[{}] ", fmt_datetime(entry.timestamp)),
                             Style::default().fg(NORD_GRAY),
                         ),
-                        Span::styled(&entry.entry_text, Style::default().fg(NORD_WHITE)),
-                    ]))
+                        Span::styled(
+                            format!("{} ", entry.entry_type.as_str()),
+                            Style::default().fg(log_type_color(&entry.entry_type)),
+                        ),
+                    ];
+                    spans.extend(highlight_gravities(&entry.entry_text));
+                    ListItem::new(Line::from(spans))
                 })
                 .collect();
 
+            let order = if self.log_newest_first { "" } else { ", oldest first" };
+            let title = match &self.log_filter {
+                Some(filter) => format!(" Log Entries: {} ({}{}) ", filter.as_str(), items.len(), order),
+                None => format!(" Log Entries ({}{}) ", items.len(), order),
+            };
+
             let list = List::new(items)
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
                 .block(
                     Block::default()
-                        .title(Span::styled(format!(" Log Entries ({}) ", self.log_entries.len()), Style::default().fg(NORD_FROST)))
+                        .title(Span::styled(title, Style::default().fg(NORD_FROST)))
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(NORD_FROST))
+                        .border_style(self.pane_border_style(DetailPane::Logs, NORD_FROST))
                         .border_set(border::ROUNDED),
                 );
-            frame.render_widget(list, area);
+            let mut state = self.pane_list_state(DetailPane::Logs, self.log_selected);
+            frame.render_stateful_widget(list, area, &mut state);
+        }
+    }
+}
+
+/// Tag color for a log entry type
+fn log_type_color(entry_type: &LogEntryType) -> Color {
+    match entry_type {
+        LogEntryType::Measurement => NORD_CYAN,
+        LogEntryType::Addition => NORD_GREEN,
+        LogEntryType::Observation => NORD_FROST,
+        LogEntryType::Action => NORD_YELLOW,
+    }
+}
+
+/// Split log text into spans, picking out gravity values like 1.050 in a
+/// bold accent color
+fn highlight_gravities(text: &str) -> Vec<Span<'_>> {
+    let plain = Style::default().fg(NORD_WHITE);
+    let gravity = Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD);
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for (start, end) in gravity_ranges(text) {
+        if start > last {
+            spans.push(Span::styled(&text[last..start], plain));
+        }
+        spans.push(Span::styled(&text[start..end], gravity));
+        last = end;
+    }
+    if last < text.len() {
+        spans.push(Span::styled(&text[last..], plain));
+    }
+    spans
+}
+
+/// Byte ranges of gravity-looking numbers: 0 or 1, a dot, then three or
+/// four digits, not part of a longer number
+fn gravity_ranges(text: &str) -> Vec<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i + 5 <= bytes.len() {
+        let preceded = i > 0 && (bytes[i - 1].is_ascii_digit() || bytes[i - 1] == b'.');
+        if !preceded && matches!(bytes[i], b'0' | b'1') && bytes.get(i + 1) == Some(&b'.') {
+            let digits = bytes[i + 2..].iter().take_while(|b| b.is_ascii_digit()).count();
+            if (3..=4).contains(&digits) {
+                let end = i + 2 + digits;
+                ranges.push((i, end));
+                i = end;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    ranges
+}
+
+/// Column of `date` on a timeline `width` cells wide running from `start`
+/// to `end`, clamped to the bar
+fn timeline_column(date: NaiveDate, start: NaiveDate, end: NaiveDate, width: usize) -> usize {
+    let span = (end - start).num_days().max(1) as f64;
+    let offset = (date - start).num_days() as f64 / span;
+    ((offset * (width - 1) as f64).round().max(0.0) as usize).min(width - 1)
+}
+
+/// Where a photo path points, with a leading `~` expanded to the home
+/// directory
+fn photo_file(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Honey type and amount, led by the base type for ciders and wines
+/// "Water:Honey: 0.33 gal/lb" with a warning when the ratio is unusual
+fn ratio_line(mead: &Mead) -> Option<Line<'static>> {
+    let ratio = calc::gallons_per_lb(mead)?;
+    let warning = calc::ratio_warning(ratio);
+    let mut spans = vec![
+        Span::styled("Water:Honey: ", Style::default().fg(NORD_GRAY)),
+        Span::styled(
+            format!("{:.2} gal/lb", ratio),
+            Style::default().fg(if warning.is_some() { NORD_YELLOW } else { NORD_WHITE }),
+        ),
+    ];
+    if let Some(warning) = warning {
+        spans.push(Span::styled(format!("  {}", warning), Style::default().fg(NORD_YELLOW)));
+    }
+    Some(Line::from(spans))
+}
+
+/// Shown by the per-volume calculators for a batch without a volume
+pub const NO_VOLUME: &str = "Set a volume first (v)";
+
+/// Top of the ABV axis on the gravity chart: the highest ABV shown, rounded
+/// up to a whole percent
+fn abv_axis_top(max_abv: f64) -> f64 {
+    max_abv.ceil().max(1.0)
+}
+
+/// Height on the gravity axis for `abv`, with 0% at the bottom of the axis
+/// and `top` at its top
+fn abv_on_gravity_axis(abv: f64, top: f64, min_gravity: f64, max_gravity: f64) -> f64 {
+    min_gravity + abv / top * (max_gravity - min_gravity)
+}
+
+/// Fewest days the gravity chart zooms in to
+const MIN_CHART_DAYS: f64 = 1.0;
+
+/// A chart window half (or twice) as wide around the same middle, kept
+/// within `0..=span` days. None once it would cover every day.
+fn zoom_window(window: Option<(f64, f64)>, span: f64, zoom_in: bool) -> Option<(f64, f64)> {
+    let (from, to) = window.unwrap_or((0.0, span));
+    let width = if zoom_in { (to - from) / 2.0 } else { (to - from) * 2.0 };
+    if width >= span {
+        return None;
+    }
+    let width = width.max(MIN_CHART_DAYS.min(span));
+    let middle = (from + to) / 2.0;
+    let from = (middle - width / 2.0).clamp(0.0, span - width);
+    Some((from, from + width))
+}
+
+/// The window moved a quarter of its width later or earlier, stopping at
+/// the first and last reading
+fn pan_window((from, to): (f64, f64), span: f64, later: bool) -> (f64, f64) {
+    let width = to - from;
+    let step = if later { width / 4.0 } else { -width / 4.0 };
+    let from = (from + step).clamp(0.0, (span - width).max(0.0));
+    (from, from + width)
+}
+
+/// `text` cut to at most `max` characters, ending in "…" when shortened
+fn truncate_with_ellipsis(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    if max == 0 {
+        return String::new();
+    }
+    let kept: String = text.chars().take(max - 1).collect();
+    format!("{}…", kept)
+}
+
+/// A type glyph followed by a space, or nothing when glyphs are off
+fn glyph_prefix(glyph: &str) -> String {
+    if glyph.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", glyph)
+    }
+}
+
+/// "  (notes)" after an ingredient in the list, or nothing without notes
+fn ingredient_notes(ingredient: &Ingredient) -> String {
+    if ingredient.notes.is_empty() {
+        String::new()
+    } else {
+        format!("  ({})", ingredient.notes)
+    }
+}
+
+/// Change in gravity since the reading before each one; None for the first
+fn gravity_deltas(readings: &[GravityReading]) -> Vec<Option<f64>> {
+    let previous = std::iter::once(None).chain(readings.iter().map(|r| Some(r.gravity)));
+    readings
+        .iter()
+        .zip(previous)
+        .map(|(reading, previous)| previous.map(|p| reading.gravity - p))
+        .collect()
+}
+
+fn fermentable_text(mead: &Mead) -> String {
+    let text = format!("{} ({:.1} lbs)", mead.honey_type, mead.honey_amount_lbs);
+    match mead.base_type {
+        BaseType::Honey => text,
+        base => format!("{} · {}", base.as_str(), text),
+    }
+}
+
+/// Whether every word of `query` fuzzily matches the ingredient's name or
+/// type
+fn ingredient_matches(query: &str, ingredient: &Ingredient) -> bool {
+    query.split_whitespace().all(|word| {
+        fuzzy_score(word, &ingredient.name).is_some()
+            || fuzzy_score(word, ingredient.ingredient_type.as_str()).is_some()
+    })
+}
+
+/// Parse a number field: Ok(None) when it is empty, an error when it holds
+/// anything that isn't a number
+fn parse_number_field(field: &InputField) -> Result<Option<f64>, String> {
+    if field.get_value().trim().is_empty() {
+        return Ok(None);
+    }
+    field
+        .get_f64()
+        .map(Some)
+        .ok_or_else(|| format!("{}: \"{}\" is not a number", field.label, field.get_value().trim()))
+}
+
+/// The statuses in order as "✓ Planning → ● Primary → Secondary ...",
+/// with past ones checked and the current one highlighted. Names are cut
+/// to three letters when the full ones don't fit in `width`.
+fn status_stepper(current: &MeadStatus, width: usize) -> Line<'static> {
+    let statuses = MeadStatus::all();
+    let position = statuses.iter().position(|s| s == current).unwrap_or(0);
+    let build = |short: bool| {
+        let mut spans = vec![Span::raw(" ")];
+        for (i, status) in statuses.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(" → ", Style::default().fg(NORD_GRAY)));
+            }
+            let name: String = if short { status.as_str().chars().take(3).collect() } else { status.as_str().to_string() };
+            let span = match i.cmp(&position) {
+                std::cmp::Ordering::Less => Span::styled(format!("✓ {}", name), Style::default().fg(NORD_GREEN)),
+                std::cmp::Ordering::Equal => Span::styled(
+                    format!("● {}", name),
+                    Style::default().fg(theme::status_color(status)).add_modifier(Modifier::BOLD),
+                ),
+                std::cmp::Ordering::Greater => Span::styled(name, Style::default().fg(NORD_GRAY)),
+            };
+            spans.push(span);
         }
+        spans.push(Span::raw(" "));
+        Line::from(spans)
+    };
+    let full = build(false);
+    if full.width() <= width { full } else { build(true) }
+}
+
+/// Green while the latest reading is within the reading interval, yellow
+/// up to half an interval past it and red beyond; gray without an interval
+fn reading_age_color(age_days: i64, interval: Option<u32>) -> Color {
+    match interval.filter(|days| *days > 0).map(i64::from) {
+        None => NORD_GRAY,
+        Some(days) if age_days <= days => NORD_GREEN,
+        Some(days) if age_days * 2 <= days * 3 => NORD_YELLOW,
+        Some(_) => NORD_RED,
     }
 }
 
@@ -602,3 +4230,465 @@ impl Default for MeadDetailView {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use crate::test_support::{detail_form, screen_rows, screen_text};
+
+    fn sample_mead(id: i64, status: MeadStatus) -> Mead {
+        Mead {
+            id,
+            name: format!("Batch {}", id),
+            status,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn status_stepper_checks_past_stages() {
+        let text = |line: Line| line.spans.iter().map(|s| s.content.to_string()).collect::<String>();
+        let full = status_stepper(&MeadStatus::Secondary, 100);
+        assert_eq!(
+            text(full),
+            " ✓ Planning → ✓ Primary → ● Secondary → Aging → Bottled → Finished "
+        );
+        assert_eq!(
+            text(status_stepper(&MeadStatus::Secondary, 40)),
+            " ✓ Pla → ✓ Pri → ● Sec → Agi → Bot → Fin "
+        );
+    }
+
+    #[test]
+    fn reading_age_color_follows_the_interval() {
+        assert_eq!(reading_age_color(3, None), NORD_GRAY);
+        assert_eq!(reading_age_color(3, Some(0)), NORD_GRAY);
+        assert_eq!(reading_age_color(3, Some(4)), NORD_GREEN);
+        assert_eq!(reading_age_color(6, Some(4)), NORD_YELLOW);
+        assert_eq!(reading_age_color(7, Some(4)), NORD_RED);
+    }
+
+    #[test]
+    fn readings_table_shows_change_since_previous_reading() {
+        let reading = |gravity: f64, temperature: Option<f64>| GravityReading {
+            id: 0,
+            mead_id: 1,
+            gravity,
+            temperature,
+            status: MeadStatus::Primary,
+            recorded_at: Utc::now(),
+        };
+        let readings = vec![reading(1.100, None), reading(1.060, Some(68.0)), reading(1.062, None)];
+        let deltas = gravity_deltas(&readings);
+        assert_eq!(deltas[0], None);
+        assert!((deltas[1].unwrap() + 0.040).abs() < 1e-9);
+        assert!((deltas[2].unwrap() - 0.002).abs() < 1e-9);
+
+        let mut view = MeadDetailView::new();
+        let mead = Mead {
+            starting_gravity: 1.100,
+            ..sample_mead(1, MeadStatus::Primary)
+        };
+        view.set_mead(mead, vec![], vec![], vec![], vec![]);
+        view.gravity_readings = readings;
+        view.pane = DetailPane::Readings;
+        let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+        terminal.draw(|frame| view.render_readings(frame, frame.area())).unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("-0.040"), "{}", text);
+        assert!(text.contains("+0.002"));
+        assert!(text.contains("68°F"));
+        assert!(text.contains("5.0%"));
+    }
+
+    #[test]
+    fn home_and_end_jump_to_the_newest_and_oldest_entries() {
+        let reading = GravityReading {
+            id: 0,
+            mead_id: 1,
+            gravity: 1.050,
+            temperature: None,
+            status: MeadStatus::Primary,
+            recorded_at: Utc::now(),
+        };
+        let logs = vec![LogEntry::default(); 4];
+        let mut view = MeadDetailView::new();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![], logs, vec![], vec![reading; 3]);
+
+        view.pane = DetailPane::Readings;
+        view.jump_to_end(true);
+        assert_eq!(view.reading_selected, 2);
+        view.jump_to_end(false);
+        assert_eq!(view.reading_selected, 0);
+
+        view.pane = DetailPane::Logs;
+        view.jump_to_end(false);
+        assert_eq!(view.log_selected, 3);
+        view.jump_to_end(true);
+        assert_eq!(view.log_selected, 0);
+        view.log_newest_first = false;
+        view.jump_to_end(true);
+        assert_eq!(view.log_selected, 3);
+    }
+
+    #[test]
+    fn long_ingredient_lists_scroll_to_the_selection() {
+        let ingredients = (1..=30)
+            .map(|n| Ingredient {
+                id: n,
+                mead_id: 1,
+                ingredient_type: IngredientType::Fruit,
+                name: format!("Fruit {}", n),
+                amount: 1.0,
+                unit: "lbs".to_string(),
+                added_date: "2026-01-01".to_string(),
+                notes: String::new(),
+            })
+            .collect();
+        let mut view = MeadDetailView::new();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), ingredients, vec![], vec![], vec![]);
+        view.pane = DetailPane::Ingredients;
+        for _ in 0..25 {
+            view.select_next();
+        }
+        let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+        let render = |terminal: &mut Terminal<TestBackend>, view: &MeadDetailView| {
+            terminal.draw(|frame| view.render_ingredients(frame, frame.area())).unwrap();
+            screen_text(terminal)
+        };
+        let text = render(&mut terminal, &view);
+        assert!(text.contains("Ingredients (30) [26/30]"), "{}", text);
+        assert!(text.contains("Fruit 26 "));
+        assert!(!text.contains("Fruit 1 "));
+
+        // Another pane taking focus leaves the list scrolled where it was
+        view.pane = DetailPane::Logs;
+        assert!(render(&mut terminal, &view).contains("Fruit 26 "));
+
+        view.group_ingredients = true;
+        view.pane = DetailPane::Ingredients;
+        let text = render(&mut terminal, &view);
+        assert!(text.contains("Fruit 26 "), "{}", text);
+    }
+
+    #[test]
+    fn ingredient_types_get_the_chosen_glyph_beside_their_label() {
+        let ingredient = Ingredient {
+            ingredient_type: IngredientType::Spice,
+            name: "Cinnamon".to_string(),
+            unit: "sticks".to_string(),
+            ..Default::default()
+        };
+        let mut view = MeadDetailView::new();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![ingredient], vec![], vec![], vec![]);
+        let mut terminal = Terminal::new(TestBackend::new(60, 6)).unwrap();
+        let mut render = |view: &MeadDetailView| {
+            terminal.draw(|frame| view.render_ingredients(frame, frame.area())).unwrap();
+            screen_text(&terminal)
+        };
+
+        assert!(render(&view).contains("🌿  [Spice] Cinnamon"), "the emoji takes two cells");
+        view.ingredient_glyphs = IngredientGlyphs::Ascii;
+        assert!(render(&view).contains("~ [Spice] Cinnamon"));
+        view.ingredient_glyphs = IngredientGlyphs::Off;
+        assert!(render(&view).contains("│[Spice] Cinnamon"));
+        assert_eq!(IngredientGlyphs::parse("ASCII"), Some(IngredientGlyphs::Ascii));
+    }
+
+    #[test]
+    fn abv_overlay_scales_onto_the_gravity_axis() {
+        assert_eq!(abv_axis_top(11.2), 12.0);
+        assert_eq!(abv_axis_top(0.0), 1.0);
+        assert_eq!(abv_on_gravity_axis(0.0, 12.0, 1.000, 1.100), 1.000);
+        assert!((abv_on_gravity_axis(6.0, 12.0, 1.000, 1.100) - 1.050).abs() < 1e-9);
+        assert!((abv_on_gravity_axis(12.0, 12.0, 1.000, 1.100) - 1.100).abs() < 1e-9);
+    }
+
+    #[test]
+    fn chart_window_zooms_and_pans_within_the_readings() {
+        let window = zoom_window(None, 100.0, true);
+        assert_eq!(window, Some((25.0, 75.0)));
+        assert_eq!(pan_window(window.unwrap(), 100.0, true), (37.5, 87.5));
+        // Panning stops at either end
+        assert_eq!(pan_window((50.0, 100.0), 100.0, true), (50.0, 100.0));
+        assert_eq!(pan_window((5.0, 55.0), 100.0, false), (0.0, 50.0));
+        // Zooming out past everything shows all readings again
+        assert_eq!(zoom_window(window, 100.0, false), None);
+        // Zoom stops at a day
+        assert_eq!(zoom_window(Some((10.0, 11.0)), 100.0, true), Some((10.0, 11.0)));
+    }
+
+    #[test]
+    fn long_names_are_cut_to_fit_the_title() {
+        assert_eq!(truncate_with_ellipsis("Cyser", 5), "Cyser");
+        assert_eq!(truncate_with_ellipsis("Blackberry", 6), "Black…");
+        assert_eq!(truncate_with_ellipsis("Blackberry", 0), "");
+
+        let mut view = MeadDetailView::new();
+        let mead = Mead {
+            name: "Melomel ".repeat(25),
+            ..sample_mead(1, MeadStatus::Primary)
+        };
+        view.set_mead(mead, vec![], vec![], vec![], vec![]);
+        let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+
+        // Title row, inside the outer margin
+        let row = &screen_rows(&terminal)[2];
+        assert!(row.contains("… - Primary"), "{}", row);
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(1, 2)].symbol(), "│");
+        assert_eq!(buffer[(78, 2)].symbol(), "│");
+    }
+
+    #[test]
+    fn tab_cycles_every_ingredient_field() {
+        let mut view = MeadDetailView::new();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+        view.open_ingredient_input();
+        assert!(detail_form!(view, Ingredient).name_input.focused);
+
+        // Name, amount, unit, type, notes and back to name
+        let mut visited = Vec::new();
+        for _ in 0..5 {
+            view.next_field();
+            visited.push(detail_form!(view, Ingredient).field);
+        }
+        assert_eq!(visited, vec![1, 2, 3, 4, 0]);
+        view.previous_field();
+        assert!(detail_form!(view, Ingredient).notes_input.focused);
+        view.previous_field();
+        assert_eq!(detail_form!(view, Ingredient).field, 3);
+        assert!(detail_form!(view, Ingredient).type_select.focused);
+        assert!(!detail_form!(view, Ingredient).unit_input.focused);
+
+        // On the type field, typing does nothing and Left/Right change it
+        view.insert_char('x');
+        assert_eq!(detail_form!(view, Ingredient).unit_input.get_value(), "oz");
+        view.move_cursor_right();
+        assert_eq!(detail_form!(view, Ingredient).ingredient_type(), IngredientType::Spice);
+        view.move_cursor_left();
+        view.move_cursor_left();
+        assert_eq!(detail_form!(view, Ingredient).ingredient_type(), IngredientType::Other);
+
+        // Reopening starts on the name field again
+        view.close_form();
+        view.open_ingredient_input();
+        assert_eq!(detail_form!(view, Ingredient).field, 0);
+        assert!(!detail_form!(view, Ingredient).type_select.focused);
+    }
+
+    #[test]
+    fn field_tabbing_wraps_around_the_fields() {
+        let mut view = MeadDetailView::new();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+        assert_eq!(view.current_field, DetailField::Name as usize);
+
+        view.previous_field();
+        assert_eq!(view.current_field, DetailField::Photo as usize);
+        assert!(view.photo_input.focused);
+        assert!(!view.name_input.focused);
+
+        view.next_field();
+        assert_eq!(view.current_field, DetailField::Name as usize);
+        assert!(view.name_input.focused);
+        assert!(!view.photo_input.focused);
+    }
+
+    #[test]
+    fn editing_gravity_previews_abv_and_attenuation() {
+        let mut view = MeadDetailView::new();
+        let mead = Mead {
+            starting_gravity: 1.100,
+            current_gravity: 1.100,
+            ..sample_mead(1, MeadStatus::Primary)
+        };
+        view.set_mead(mead, vec![], vec![], vec![], vec![]);
+        view.current_field = DetailField::CurrentGravity as usize;
+        view.toggle_edit();
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).unwrap();
+        let mut render = |view: &MeadDetailView| {
+            terminal.draw(|frame| view.render_details(frame, frame.area())).unwrap();
+            screen_text(&terminal)
+        };
+
+        view.current_gravity_input.set_value("20");
+        assert!(render(&view).contains("ABV 10.5%, 80% attenuation"));
+
+        // Input that doesn't parse falls back to the saved gravity
+        view.current_gravity_input.set_value("-");
+        assert!(render(&view).contains("ABV 0.0%, 0% attenuation"));
+    }
+
+    #[test]
+    fn refresh_after_status_save_keeps_focus() {
+        let mut view = MeadDetailView::new();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+
+        // Move to the status field and cycle it, as the 's' save flow does
+        view.next_field();
+        assert_eq!(view.current_field, 1);
+        view.toggle_edit();
+        let saved = view.get_updated_mead().unwrap();
+        view.set_mead(saved, vec![], vec![], vec![], vec![]);
+
+        assert_eq!(view.current_field, 1);
+        assert_eq!(view.pane, DetailPane::Fields);
+        assert!(!view.is_editing());
+        assert_eq!(view.current_status, MeadStatus::Primary.next());
+        assert!(!view.name_input.focused);
+    }
+
+    #[test]
+    fn refresh_keeps_field_being_edited() {
+        let mut view = MeadDetailView::new();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+
+        view.jump_to_hotkey(DetailField::CurrentGravity.hotkey());
+        view.toggle_edit();
+        view.insert_char('9');
+        let typed = view.current_gravity_input.get_value().to_string();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+
+        assert_eq!(view.current_field, DetailField::CurrentGravity as usize);
+        assert!(view.is_editing());
+        assert!(view.current_gravity_input.focused);
+        assert_eq!(view.current_gravity_input.get_value(), typed);
+    }
+
+    #[test]
+    fn opening_another_mead_restores_its_place() {
+        let mut view = MeadDetailView::new();
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+        view.jump_to_hotkey(DetailField::CurrentGravity.hotkey());
+
+        view.set_mead(sample_mead(2, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+
+        assert_eq!(view.current_field, 0);
+        assert!(view.name_input.focused);
+        assert!(!view.current_gravity_input.focused);
+
+        // Going back to the first mead returns to where it was left
+        view.pane = DetailPane::Logs;
+        view.set_mead(sample_mead(1, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+        assert_eq!(view.current_field, DetailField::CurrentGravity as usize);
+        assert_eq!(view.pane, DetailPane::Fields);
+        assert!(view.current_gravity_input.focused);
+        view.set_mead(sample_mead(2, MeadStatus::Primary), vec![], vec![], vec![], vec![]);
+        assert_eq!(view.pane, DetailPane::Logs);
+        assert!(!view.name_input.focused);
+    }
+
+    #[test]
+    fn reverting_a_field_restores_the_stored_value_in_full() {
+        let mut view = MeadDetailView::new();
+        let mead = Mead { current_gravity: 1.0506, yan_added: 87.5, ..sample_mead(1, MeadStatus::Primary) };
+        view.set_mead(mead, vec![], vec![], vec![], vec![]);
+
+        view.current_field = DetailField::CurrentGravity as usize;
+        view.current_gravity_input.set_value("1.020");
+        assert_eq!(view.revert_current_field(), Some(DetailField::CurrentGravity.label()));
+        assert_eq!(view.current_gravity_input.get_value(), "1.0506");
+
+        view.current_field = DetailField::YanAdded as usize;
+        view.yan_added_input.set_value("90");
+        view.revert_current_field();
+        assert_eq!(view.yan_added_input.get_value(), "87.5");
+    }
+
+    #[test]
+    fn save_keeps_empty_gravity_and_rejects_text() {
+        let mut view = MeadDetailView::new();
+        let mead = Mead { current_gravity: 1.100, ..sample_mead(1, MeadStatus::Primary) };
+        view.set_mead(mead, vec![], vec![], vec![], vec![]);
+
+        view.current_gravity_input.value = String::new();
+        assert_eq!(view.validated_mead().unwrap().unwrap().current_gravity, 1.100);
+
+        view.current_gravity_input.value = "abc".to_string();
+        let err = view.validated_mead().unwrap_err();
+        assert!(err.contains("Current Gravity"), "{}", err);
+
+        view.current_gravity_input.value = "1.05".to_string();
+        assert_eq!(view.validated_mead().unwrap().unwrap().current_gravity, 1.05);
+    }
+
+    #[test]
+    fn timeline_columns_scale_between_start_and_end() {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+        assert_eq!(timeline_column(day(1), day(1), day(11), 21), 0);
+        assert_eq!(timeline_column(day(6), day(1), day(11), 21), 10);
+        assert_eq!(timeline_column(day(11), day(1), day(11), 21), 20);
+        // Dates outside the span stay on the bar
+        assert_eq!(timeline_column(day(20), day(1), day(11), 21), 20);
+        assert_eq!(timeline_column(day(1), day(5), day(11), 21), 0);
+        // A same-day batch doesn't divide by zero
+        assert_eq!(timeline_column(day(1), day(1), day(1), 10), 0);
+    }
+
+    #[test]
+    fn ingredient_filter_matches_every_word_against_name_or_type() {
+        let ingredient = |id, name: &str, ingredient_type| Ingredient {
+            id,
+            name: name.to_string(),
+            ingredient_type,
+            ..Default::default()
+        };
+        let mut view = MeadDetailView::new();
+        view.set_mead(
+            sample_mead(1, MeadStatus::Primary),
+            vec![
+                ingredient(1, "Blackberries", IngredientType::Fruit),
+                ingredient(2, "Black tea", IngredientType::Adjunct),
+                ingredient(3, "Cinnamon stick", IngredientType::Spice),
+            ],
+            vec![],
+            vec![],
+            vec![],
+        );
+
+        view.ingredient_filter_input.set_value("blk");
+        assert_eq!(view.visible_ingredients().len(), 2);
+        view.ingredient_filter_input.set_value("black fruit");
+        let ids: Vec<i64> = view.visible_ingredients().iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![1]);
+        assert!(view.move_selected_ingredient(false).is_none());
+
+        view.close_ingredient_filter(true);
+        assert_eq!(view.visible_ingredients().len(), 3);
+    }
+
+    #[test]
+    fn finishing_starts_from_the_exact_current_gravity() {
+        let mut view = MeadDetailView::new();
+        view.open_finish(&Mead { current_gravity: 0.9985, ..sample_mead(1, MeadStatus::Bottled) });
+        assert_eq!(detail_form!(view, Finish).gravity_input.get_value(), "0.9985");
+        view.open_finish(&Mead { current_gravity: 1.002, ..sample_mead(1, MeadStatus::Bottled) });
+        assert_eq!(detail_form!(view, Finish).gravity_input.get_value(), "1.002");
+    }
+
+    #[test]
+    fn reading_intervals_are_whole_days_up_to_a_year() {
+        let typed = |text: &str| {
+            let mut input = InputField::new("Check every (days)");
+            input.set_value(text);
+            typed_reading_interval(&input)
+        };
+        assert_eq!(typed(""), Ok(None));
+        assert_eq!(typed("0"), Ok(None));
+        assert_eq!(typed("365"), Ok(Some(365)));
+        assert_eq!(typed("366"), Err("Check gravity at most 365 days apart".to_string()));
+        assert!(typed("4294967295").is_err());
+        assert!(typed("2.5").is_err());
+    }
+
+    #[test]
+    fn acidity_form_starts_from_the_exact_recorded_values() {
+        let mut view = MeadDetailView::new();
+        let mead = Mead { ph: Some(3.456), ta: Some(6.0), ..sample_mead(1, MeadStatus::Aging) };
+        view.set_mead(mead, vec![], vec![], vec![], vec![]);
+        view.open_acidity_input();
+        assert_eq!(detail_form!(view, Acidity).ph_input.get_value(), "3.456");
+        assert_eq!(detail_form!(view, Acidity).ta_input.get_value(), "6.0");
+    }
+}
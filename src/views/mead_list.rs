@@ -1,44 +1,309 @@
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols::border,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState},
 };
 
-use crate::models::Mead;
+use crate::format::{self, fmt_abv, fmt_date_str, fmt_gravity};
+use crate::models::{rating_stars, CopyPart, GravityTrend, Mead, MeadStatus, ShoppingItem};
+use crate::nav;
+use crate::theme::{self, ThemeView};
+use crate::widgets::InputField;
 
 // Nord-adjacent color palette
 const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
 const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
 const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
 const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+const NORD_STRIPE: Color = Color::Rgb(59, 66, 82);      // #3B4252
 const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
 const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+const NORD_RED: Color = Color::Rgb(191, 97, 106);       // #BF616A
+const NORD_YELLOW: Color = Color::Rgb(235, 203, 139);   // #EBCB8B
+const NORD_GREEN: Color = Color::Rgb(163, 190, 140);    // #A3BE8C
+
+/// How long after the last keystroke type-ahead keeps adding to the name
+/// typed so far before starting over
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Width in cells of the progress bar in the table
+const PROGRESS_BAR_WIDTH: usize = 8;
+
+/// Combined ingredients of the chosen batches
+#[derive(Debug, Clone)]
+pub struct ShoppingList {
+    /// Names of the batches it covers
+    pub batches: Vec<String>,
+    pub items: Vec<ShoppingItem>,
+    /// Highlighted row
+    pub selected: usize,
+}
+
+/// A column of the mead table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListColumn {
+    Name,
+    Status,
+    StartDate,
+    Target,
+    Honey,
+    Yeast,
+    Og,
+    Current,
+    Abv,
+    Progress,
+    Rating,
+    NextAction,
+}
+
+impl ListColumn {
+    pub const ALL: [ListColumn; 12] = [
+        ListColumn::Name,
+        ListColumn::Status,
+        ListColumn::NextAction,
+        ListColumn::StartDate,
+        ListColumn::Target,
+        ListColumn::Honey,
+        ListColumn::Yeast,
+        ListColumn::Og,
+        ListColumn::Current,
+        ListColumn::Abv,
+        ListColumn::Progress,
+        ListColumn::Rating,
+    ];
+
+    pub fn header(&self) -> &'static str {
+        match self {
+            ListColumn::Name => "Name",
+            ListColumn::Status => "Status",
+            ListColumn::StartDate => "Start Date",
+            ListColumn::Target => "Target",
+            ListColumn::Honey => "Honey",
+            ListColumn::Yeast => "Yeast",
+            ListColumn::Og => "OG",
+            ListColumn::Current => "Current",
+            ListColumn::Abv => "ABV",
+            ListColumn::Progress => "Progress",
+            ListColumn::Rating => "Rating",
+            ListColumn::NextAction => "Next",
+        }
+    }
+
+    /// Header spelled out, used when the column is wide enough
+    fn long_header(&self) -> &'static str {
+        match self {
+            ListColumn::Target => "Target Date",
+            ListColumn::Og => "Orig. Gravity",
+            ListColumn::Current => "Current Gravity",
+            ListColumn::Abv => "ABV %",
+            ListColumn::Progress => "ABV Progress",
+            ListColumn::NextAction => "Next Action",
+            _ => self.header(),
+        }
+    }
+
+    /// What an abbreviated header means, for the legend line
+    fn legend(&self) -> Option<&'static str> {
+        match self {
+            ListColumn::Og => Some("gravity before fermenting"),
+            ListColumn::Current => Some("latest gravity (FG once bottled)"),
+            ListColumn::Abv => Some("alcohol by volume, estimated from gravity"),
+            ListColumn::Progress => Some("ABV so far against the target"),
+            _ => None,
+        }
+    }
+
+    /// Name used for the column in the config file
+    pub fn key(&self) -> &'static str {
+        match self {
+            ListColumn::Name => "name",
+            ListColumn::Status => "status",
+            ListColumn::StartDate => "start_date",
+            ListColumn::Target => "target",
+            ListColumn::Honey => "honey",
+            ListColumn::Yeast => "yeast",
+            ListColumn::Og => "og",
+            ListColumn::Current => "current",
+            ListColumn::Abv => "abv",
+            ListColumn::Progress => "progress",
+            ListColumn::Rating => "rating",
+            ListColumn::NextAction => "next_action",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<ListColumn> {
+        Self::ALL.into_iter().find(|c| c.key() == key)
+    }
+
+    /// Share of the table width, relative to the other visible columns
+    fn weight(&self) -> u16 {
+        match self {
+            ListColumn::Name => 16,
+            ListColumn::Status | ListColumn::StartDate | ListColumn::Target => 10,
+            ListColumn::Honey | ListColumn::Yeast => 11,
+            ListColumn::Og | ListColumn::Abv | ListColumn::Rating => 7,
+            ListColumn::Current => 11,
+            ListColumn::Progress | ListColumn::NextAction => 14,
+        }
+    }
+}
 
 /// Mead list view state
 pub struct MeadListView {
-    /// List of meads
+    /// Meads shown, after the attention filter
     pub meads: Vec<Mead>,
+    /// Every mead, before filtering
+    all_meads: Vec<Mead>,
+    /// Ids of meads that need attention
+    attention: HashSet<i64>,
+    /// Ids of meads whose scheduled gravity reading is due
+    readings_due: HashSet<i64>,
+    /// Whether only meads needing attention are shown
+    pub attention_only: bool,
+    /// Whether only competition entries are shown
+    pub competition_only: bool,
+    /// Whether the highest rated meads are listed first, unrated ones last
+    pub sort_by_rating: bool,
+    /// Direction of the last gravity change, by mead id
+    trends: HashMap<i64, GravityTrend>,
     /// Currently selected index
     pub selected: usize,
     /// Whether the list needs to be refreshed from DB
     pub needs_refresh: bool,
+    /// Mead to select once the list is refreshed, instead of the one
+    /// selected now
+    pub select_after_refresh: Option<i64>,
+    /// Meads marked for bulk actions
+    pub marked: HashSet<i64>,
+    /// Table columns that are hidden
+    pub hidden_columns: HashSet<ListColumn>,
+    /// Selected row of the column picker, while it is open
+    pub column_picker: Option<usize>,
+    /// Inline current gravity editor for the selected row
+    pub gravity_input: InputField,
+    /// Whether the inline gravity editor is open
+    pub editing_gravity: bool,
+    /// Checklist for duplicating the selected mead as (highlighted row,
+    /// parts to copy), while it is open
+    pub duplicate_picker: Option<(usize, HashSet<CopyPart>)>,
+    /// Shopping list for the marked meads, while it is open
+    pub shopping_list: Option<ShoppingList>,
+    /// Start of a name typed after `/` to jump to, and when `/` or the last
+    /// letter was typed
+    type_ahead: String,
+    type_ahead_at: Option<Instant>,
+    /// First table row shown at the last render, so scrolling carries over
+    /// between frames
+    table_offset: std::cell::Cell<usize>,
+    /// Whether a line explaining the column headers is shown under the table
+    pub show_legend: bool,
+    /// Whether every other row is shaded
+    pub zebra_rows: bool,
+    /// Whether rows are two lines tall
+    pub tall_rows: bool,
 }
 
 impl MeadListView {
     pub fn new() -> Self {
         Self {
             meads: Vec::new(),
+            all_meads: Vec::new(),
+            attention: HashSet::new(),
+            readings_due: HashSet::new(),
+            attention_only: false,
+            competition_only: false,
+            sort_by_rating: false,
+            trends: HashMap::new(),
             selected: 0,
             needs_refresh: true,
+            select_after_refresh: None,
+            marked: HashSet::new(),
+            hidden_columns: HashSet::new(),
+            column_picker: None,
+            gravity_input: InputField::new("Current Gravity (Enter to save)").gravity(),
+            editing_gravity: false,
+            duplicate_picker: None,
+            shopping_list: None,
+            type_ahead: String::new(),
+            type_ahead_at: None,
+            table_offset: std::cell::Cell::new(0),
+            show_legend: false,
+            zebra_rows: false,
+            tall_rows: false,
         }
     }
 
-    pub fn set_meads(&mut self, meads: Vec<Mead>) {
-        self.meads = meads;
+    /// Keep the selection on a mead after the terminal is resized. The table
+    /// offset is re-fitted around the selection on the next render.
+    pub fn clamp_selection(&mut self) {
+        self.selected = self.selected.min(self.meads.len().saturating_sub(1));
+        self.table_offset.set(self.table_offset.get().min(self.selected));
+    }
+
+    /// Set the meads, which of them need attention or a gravity reading, and
+    /// their gravity trends
+    pub fn set_meads(
+        &mut self,
+        meads: Vec<Mead>,
+        attention: HashSet<i64>,
+        readings_due: HashSet<i64>,
+        trends: HashMap<i64, GravityTrend>,
+    ) {
+        self.all_meads = meads;
+        self.attention = attention;
+        self.readings_due = readings_due;
+        self.trends = trends;
         self.needs_refresh = false;
+        self.apply_filter();
+    }
+
+    /// Show only meads needing attention, or everything again
+    pub fn toggle_attention_only(&mut self) {
+        self.attention_only = !self.attention_only;
+        self.apply_filter();
+    }
+
+    /// Show only competition entries, or everything again
+    pub fn toggle_competition_only(&mut self) {
+        self.competition_only = !self.competition_only;
+        self.apply_filter();
+    }
+
+    /// List the highest rated meads first, or go back to the usual order
+    pub fn toggle_rating_sort(&mut self) {
+        self.sort_by_rating = !self.sort_by_rating;
+        self.apply_filter();
+    }
+
+    /// Rebuild the shown meads from the full list. The selection follows
+    /// the selected mead by id, so it stays put when rows come and go
+    /// around it; only if that mead is no longer shown does it fall back to
+    /// the nearest row.
+    fn apply_filter(&mut self) {
+        let keep = self.select_after_refresh.take().or_else(|| self.get_selected().map(|m| m.id));
+        self.meads = self
+            .all_meads
+            .iter()
+            .filter(|m| !self.attention_only || self.attention.contains(&m.id))
+            .filter(|m| !self.competition_only || m.competition)
+            .cloned()
+            .collect();
+        if self.sort_by_rating {
+            // Pinned meads stay on top; stable, so meads with the same
+            // rating keep the usual order
+            self.meads.sort_by_key(|m| (std::cmp::Reverse(m.pinned), std::cmp::Reverse(m.rating)));
+        }
+        // Drop marks for meads that are no longer shown
+        self.marked.retain(|id| self.meads.iter().any(|m| m.id == *id));
+        if let Some(index) = keep.and_then(|id| self.meads.iter().position(|m| m.id == id)) {
+            self.selected = index;
+        }
         // Ensure selected index is valid
         if self.selected >= self.meads.len() && !self.meads.is_empty() {
             self.selected = self.meads.len() - 1;
@@ -46,18 +311,59 @@ impl MeadListView {
     }
 
     pub fn next(&mut self) {
-        if !self.meads.is_empty() {
-            self.selected = (self.selected + 1) % self.meads.len();
-        }
+        self.selected = nav::next_index(self.selected, self.meads.len());
     }
 
     pub fn previous(&mut self) {
-        if !self.meads.is_empty() {
-            if self.selected == 0 {
-                self.selected = self.meads.len() - 1;
-            } else {
-                self.selected -= 1;
+        self.selected = nav::previous_index(self.selected, self.meads.len());
+    }
+
+    /// Whether `/` was pressed or a name is partly typed, so the next key
+    /// continues it rather than running a command
+    pub fn type_ahead_active(&self, now: Instant) -> bool {
+        self.type_ahead_at
+            .is_some_and(|at| now.duration_since(at) < TYPE_AHEAD_TIMEOUT)
+    }
+
+    /// Start typing a name to jump to; until it times out every letter,
+    /// including command keys, goes to `type_ahead`
+    pub fn start_type_ahead(&mut self, now: Instant) {
+        self.type_ahead.clear();
+        self.type_ahead_at = Some(now);
+    }
+
+    /// Stop typing a name, leaving the selection where it is
+    pub fn cancel_type_ahead(&mut self) {
+        self.type_ahead.clear();
+        self.type_ahead_at = None;
+    }
+
+    /// Add `c` to the name typed so far and select the next mead whose name
+    /// starts with it. Repeating a single letter moves on to the next match.
+    /// Returns false, keeping the selection, when nothing matches.
+    pub fn type_ahead(&mut self, c: char, now: Instant) -> bool {
+        if !self.type_ahead_active(now) {
+            self.type_ahead.clear();
+        }
+        self.type_ahead_at = Some(now);
+        let c = c.to_lowercase().collect::<String>();
+        let repeated = !self.type_ahead.is_empty() && self.type_ahead.chars().all(|t| t.to_string() == c);
+        let query = if repeated { c.clone() } else { format!("{}{}", self.type_ahead, c) };
+        // A single letter looks past the current row; a longer name may
+        // still match it
+        let skip = if query.chars().count() == 1 { 1 } else { 0 };
+
+        let count = self.meads.len();
+        let found = (skip..count + skip)
+            .map(|offset| (self.selected + offset) % count)
+            .find(|&i| self.meads[i].name.to_lowercase().starts_with(&query));
+        match found {
+            Some(index) => {
+                self.selected = index;
+                self.type_ahead.push_str(&c);
+                true
             }
+            None => false,
         }
     }
 
@@ -65,7 +371,148 @@ impl MeadListView {
         self.meads.get(self.selected)
     }
 
-    pub fn render(&self, frame: &mut Frame) {
+    /// Mark or unmark the selected mead
+    pub fn toggle_marked(&mut self) {
+        if let Some(id) = self.get_selected().map(|m| m.id) {
+            if !self.marked.remove(&id) {
+                self.marked.insert(id);
+            }
+        }
+    }
+
+    /// Open the inline gravity editor on the selected mead
+    pub fn open_gravity_input(&mut self) {
+        let Some(gravity) = self.get_selected().map(|m| m.current_gravity) else {
+            return;
+        };
+        self.gravity_input.set_value(format::edit_gravity(gravity));
+        self.gravity_input.set_focused(true);
+        self.editing_gravity = true;
+    }
+
+    pub fn close_gravity_input(&mut self) {
+        self.editing_gravity = false;
+        self.gravity_input.set_focused(false);
+    }
+
+    /// Open the duplicate checklist with the default parts ticked
+    pub fn open_duplicate_picker(&mut self) {
+        if self.get_selected().is_some() {
+            let parts = CopyPart::ALL.into_iter().filter(CopyPart::copied_by_default).collect();
+            self.duplicate_picker = Some((0, parts));
+        }
+    }
+
+    pub fn next_duplicate_part(&mut self) {
+        if let Some((row, _)) = self.duplicate_picker.as_mut() {
+            *row = nav::next_index(*row, CopyPart::ALL.len());
+        }
+    }
+
+    pub fn previous_duplicate_part(&mut self) {
+        if let Some((row, _)) = self.duplicate_picker.as_mut() {
+            *row = nav::previous_index(*row, CopyPart::ALL.len());
+        }
+    }
+
+    /// Tick or untick the highlighted part
+    pub fn toggle_duplicate_part(&mut self) {
+        if let Some((row, parts)) = self.duplicate_picker.as_mut() {
+            let part = CopyPart::ALL[*row];
+            if !parts.remove(&part) {
+                parts.insert(part);
+            }
+        }
+    }
+
+    /// Show a shopping list for these batches
+    pub fn open_shopping_list(&mut self, batches: Vec<String>, items: Vec<ShoppingItem>) {
+        self.shopping_list = Some(ShoppingList { batches, items, selected: 0 });
+    }
+
+    pub fn next_shopping_item(&mut self) {
+        if let Some(list) = &mut self.shopping_list {
+            list.selected = nav::next_index(list.selected, list.items.len());
+        }
+    }
+
+    pub fn previous_shopping_item(&mut self) {
+        if let Some(list) = &mut self.shopping_list {
+            list.selected = nav::previous_index(list.selected, list.items.len());
+        }
+    }
+
+    /// Open or close the column picker
+    pub fn toggle_column_picker(&mut self) {
+        self.column_picker = match self.column_picker {
+            Some(_) => None,
+            None => Some(0),
+        };
+    }
+
+    pub fn next_picker_column(&mut self) {
+        if let Some(row) = self.column_picker.as_mut() {
+            *row = nav::next_index(*row, ListColumn::ALL.len());
+        }
+    }
+
+    pub fn previous_picker_column(&mut self) {
+        if let Some(row) = self.column_picker.as_mut() {
+            *row = nav::previous_index(*row, ListColumn::ALL.len());
+        }
+    }
+
+    /// Show or hide the column selected in the picker. The name column
+    /// always stays visible.
+    pub fn toggle_picker_column(&mut self) {
+        let Some(column) = self.column_picker.map(|row| ListColumn::ALL[row]) else {
+            return;
+        };
+        if column != ListColumn::Name && !self.hidden_columns.remove(&column) {
+            self.hidden_columns.insert(column);
+        }
+    }
+
+    /// Hidden columns as stored in the config file
+    pub fn hidden_columns_value(&self) -> String {
+        ListColumn::ALL
+            .iter()
+            .filter(|c| self.hidden_columns.contains(c))
+            .map(|c| c.key())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Lines each table row takes
+    fn row_height(&self) -> u16 {
+        if self.tall_rows { 2 } else { 1 }
+    }
+
+    fn visible_columns(&self) -> Vec<ListColumn> {
+        // Next Action only takes space once some batch has one
+        let any_next_action = self.meads.iter().any(|m| !m.next_action.is_empty());
+        ListColumn::ALL
+            .into_iter()
+            .filter(|c| !self.hidden_columns.contains(c))
+            .filter(|c| *c != ListColumn::NextAction || any_next_action)
+            .collect()
+    }
+
+    /// Ids a bulk action applies to: the marked meads, or the selected one
+    /// when nothing is marked
+    pub fn action_targets(&self) -> Vec<i64> {
+        if self.marked.is_empty() {
+            self.get_selected().map(|m| vec![m.id]).unwrap_or_default()
+        } else {
+            self.meads
+                .iter()
+                .filter(|m| self.marked.contains(&m.id))
+                .map(|m| m.id)
+                .collect()
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, status_message: &Option<String>) {
         let area = frame.area();
 
         let chunks = Layout::default()
@@ -74,31 +521,55 @@ impl MeadListView {
             .constraints([
                 Constraint::Length(3),  // Title
                 Constraint::Min(10),    // Table
+                Constraint::Length(1),  // Totals
+                Constraint::Length(if self.show_legend { 1 } else { 0 }), // Legend
                 Constraint::Length(3),  // Controls
             ])
             .split(area);
 
         // Title
-        let title = Paragraph::new(Line::from(vec![
-            Span::styled(
-                "Current Meads",
-                Style::default()
-                    .fg(NORD_FROST)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]))
+        let heading = match (self.attention_only, self.competition_only) {
+            (true, true) => "Competition Entries Needing Attention",
+            (true, false) => "Needs Attention",
+            (false, true) => "Competition Entries",
+            (false, false) => "Current Meads",
+        };
+        let accent = theme::accent(ThemeView::List);
+        let mut title_spans = vec![Span::styled(
+            heading,
+            Style::default()
+                .fg(accent)
+                .add_modifier(Modifier::BOLD),
+        )];
+        if self.sort_by_rating {
+            title_spans.push(Span::styled("  by rating", Style::default().fg(NORD_FROST)));
+        }
+        if !self.attention.is_empty() {
+            title_spans.push(Span::styled(
+                format!("  ! {} need attention", self.attention.len()),
+                Style::default().fg(NORD_YELLOW).add_modifier(Modifier::BOLD),
+            ));
+        }
+        let title = Paragraph::new(Line::from(title_spans))
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(NORD_FROST))
+                .border_style(Style::default().fg(accent))
                 .border_set(border::ROUNDED),
         );
         frame.render_widget(title, chunks[0]);
 
         // Mead table/list
         if self.meads.is_empty() {
-            let empty_msg = Paragraph::new("No meads yet! Press Esc to go back and create one.")
+            let message = if self.attention_only {
+                "Nothing needs attention. Press ! to show all meads."
+            } else if self.competition_only {
+                "No competition entries. Press * to show all meads."
+            } else {
+                "No meads yet! Press Esc to go back and create one."
+            };
+            let empty_msg = Paragraph::new(message)
                 .alignment(Alignment::Center)
                 .style(Style::default().fg(NORD_GRAY))
                 .block(
@@ -109,15 +580,17 @@ impl MeadListView {
                 );
             frame.render_widget(empty_msg, chunks[1]);
         } else {
-            let header = Row::new(vec![
-                "Name",
-                "Status",
-                "Start Date",
-                "Honey",
-                "Yeast",
-                "OG",
-                "Current",
-            ])
+            let columns = self.visible_columns();
+            let widths = column_widths(&columns, chunks[1].width.saturating_sub(2));
+            // Spell headers out in columns that have room for them
+            let spelled_out: Vec<bool> = columns
+                .iter()
+                .zip(&widths)
+                .map(|(c, width)| c.long_header().chars().count() <= *width as usize)
+                .collect();
+            let header = Row::new(columns.iter().zip(&spelled_out).map(|(c, &long)| {
+                if long { c.long_header() } else { c.header() }
+            }))
             .style(
                 Style::default()
                     .fg(NORD_CYAN)
@@ -130,46 +603,84 @@ impl MeadListView {
                 .iter()
                 .enumerate()
                 .map(|(i, mead)| {
+                    // The selection's cyan background covers the stripe
                     let style = if i == self.selected {
                         Style::default()
                             .fg(NORD_BG)
                             .bg(NORD_CYAN)
                             .add_modifier(Modifier::BOLD)
+                    } else if self.zebra_rows && i % 2 == 1 {
+                        Style::default().fg(NORD_WHITE).bg(NORD_STRIPE)
                     } else {
                         Style::default().fg(NORD_WHITE)
                     };
 
-                    Row::new(vec![
-                        mead.name.clone(),
-                        mead.status.as_str().to_string(),
-                        mead.start_date.clone(),
-                        mead.honey_type.clone(),
-                        mead.yeast_strain.clone(),
-                        format!("{:.3}", mead.starting_gravity),
-                        format!("{:.3}", mead.current_gravity),
-                    ])
+                    let mark = if self.marked.contains(&mead.id) { "● " } else { "" };
+                    let flag = if self.attention.contains(&mead.id) { "! " } else { "" };
+                    let name = if mead.pinned {
+                        format!("{}{}★ {}", mark, flag, mead.name)
+                    } else {
+                        format!("{}{}{}", mark, flag, mead.name)
+                    };
+
+                    let target = match mead.days_until_target() {
+                        Some(days) if days < 0 && mead.auto_reminders => Cell::from(mead.target_label().unwrap_or_default())
+                            .style(Style::default().fg(NORD_RED)),
+                        _ => Cell::from(mead.target_label().unwrap_or_default()),
+                    };
+
+                    Row::new(columns.iter().map(|column| match column {
+                        ListColumn::Name => Cell::from(name.clone()),
+                        ListColumn::Status => Cell::from(mead.status.as_str())
+                            .style(Style::default().fg(theme::status_color(&mead.status))),
+                        ListColumn::StartDate => Cell::from(fmt_date_str(&mead.start_date)),
+                        ListColumn::Target => target.clone(),
+                        ListColumn::Honey => Cell::from(mead.honey_type.clone()),
+                        ListColumn::Yeast => Cell::from(mead.yeast_strain.clone()),
+                        ListColumn::Og => Cell::from(fmt_gravity(mead.starting_gravity)),
+                        // Bottled batches show what was recorded at bottling
+                        ListColumn::Current => {
+                            let gravity = fmt_gravity(
+                                mead.bottling_snapshot().map_or(mead.current_gravity, |(fg, _)| fg),
+                            );
+                            let mut spans = vec![Span::raw(gravity)];
+                            if let Some(trend) = self.trends.get(&mead.id) {
+                                spans.push(Span::styled(
+                                    format!(" {}", trend.symbol()),
+                                    Style::default().fg(trend_color(*trend)),
+                                ));
+                            }
+                            if self.readings_due.contains(&mead.id) {
+                                spans.push(Span::styled(" due", Style::default().fg(NORD_YELLOW)));
+                            }
+                            Cell::from(Line::from(spans))
+                        }
+                        ListColumn::Abv => Cell::from(format!(
+                            "{}%",
+                            fmt_abv(mead.bottling_snapshot().map_or(mead.current_abv(), |(_, abv)| abv).max(0.0))
+                        )),
+                        ListColumn::Progress => Cell::from(progress_bar(mead.abv_progress())),
+                        ListColumn::Rating => Cell::from(rating_stars(mead.rating))
+                            .style(Style::default().fg(NORD_YELLOW)),
+                        ListColumn::NextAction if mead.next_action.is_empty() => Cell::from(""),
+                        ListColumn::NextAction => Cell::from(format!("→ {}", mead.next_action))
+                            .style(Style::default().fg(NORD_YELLOW)),
+                    }).collect::<Vec<_>>())
                     .style(style)
-                    .height(1)
+                    .height(self.row_height())
                 })
                 .collect();
 
-            let table = Table::new(
-                rows,
-                [
-                    Constraint::Percentage(20),
-                    Constraint::Percentage(12),
-                    Constraint::Percentage(12),
-                    Constraint::Percentage(15),
-                    Constraint::Percentage(15),
-                    Constraint::Percentage(10),
-                    Constraint::Percentage(10),
-                ],
-            )
+            let table = Table::new(rows, columns.iter().map(|c| Constraint::Fill(c.weight())))
             .header(header)
             .block(
                 Block::default()
                     .title(Span::styled(
-                        format!(" {} meads ", self.meads.len()),
+                        if self.marked.is_empty() {
+                            format!(" {} meads ", self.meads.len())
+                        } else {
+                            format!(" {} meads, {} marked ", self.meads.len(), self.marked.len())
+                        },
                         Style::default().fg(NORD_FROST),
                     ))
                     .borders(Borders::ALL)
@@ -177,20 +688,83 @@ impl MeadListView {
                     .border_set(border::ROUNDED),
             );
 
-            frame.render_widget(table, chunks[1]);
+            let mut state = TableState::default()
+                .with_offset(self.table_offset.get())
+                .with_selected(Some(self.selected));
+            frame.render_stateful_widget(table, chunks[1], &mut state);
+            self.table_offset.set(state.offset());
+
+            let totals = Paragraph::new(ListTotals::from_meads(&self.meads).line())
+                .alignment(Alignment::Center);
+            frame.render_widget(totals, chunks[2]);
+
+            if self.show_legend {
+                let abbreviated: Vec<ListColumn> = columns
+                    .iter()
+                    .zip(&spelled_out)
+                    .filter(|(_, &long)| !long)
+                    .map(|(c, _)| *c)
+                    .collect();
+                frame.render_widget(legend_line(&abbreviated, chunks[3].width as usize), chunks[3]);
+            }
         }
 
-        // Controls
-        let controls = Line::from(vec![
-            Span::styled("Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-            Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
-            Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-            Span::styled(" View Details  ", Style::default().fg(NORD_WHITE)),
-            Span::styled("d", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-            Span::styled(" Delete  ", Style::default().fg(NORD_WHITE)),
-            Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
-            Span::styled(" Back", Style::default().fg(NORD_WHITE)),
-        ]);
+        // Controls, replaced by the status message when there is one
+        let controls = if let Some(message) = status_message {
+            Line::from(Span::styled(message.as_str(), Style::default().fg(NORD_FROST)))
+        } else {
+            Line::from(vec![
+                Span::styled("Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" View Details  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("p", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Pin  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Space", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Mark  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("a", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Advance  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("!", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    if self.attention_only { " Show All  " } else { " Needs Attention  " },
+                    Style::default().fg(NORD_WHITE),
+                ),
+                Span::styled("*", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    if self.competition_only { " All Batches  " } else { " Competition  " },
+                    Style::default().fg(NORD_WHITE),
+                ),
+                Span::styled("r", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    if self.sort_by_rating { " Usual Order  " } else { " Sort by Rating  " },
+                    Style::default().fg(NORD_WHITE),
+                ),
+                Span::styled("g", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Gravity  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("c", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Columns  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("?", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Legend  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Z", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Stripes  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("H", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(if self.tall_rows { " Compact Rows  " } else { " Tall Rows  " }, Style::default().fg(NORD_WHITE)),
+                Span::styled("D", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Duplicate  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("M", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Merge Two Marked  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("x/X", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Export Shown JSON/CSV  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("s", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Shopping List  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("d", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Delete  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("/", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Jump to Name  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+            ])
+        };
 
         let controls_widget = Paragraph::new(controls)
             .alignment(Alignment::Center)
@@ -201,13 +775,517 @@ impl MeadListView {
                     .border_set(border::ROUNDED),
             );
 
-        frame.render_widget(controls_widget, chunks[2]);
+        frame.render_widget(controls_widget, chunks[4]);
+
+        if let Some(row) = self.column_picker {
+            self.render_column_picker(frame, row);
+        }
+
+        if let Some((row, parts)) = &self.duplicate_picker {
+            self.render_duplicate_picker(frame, *row, parts);
+        }
+
+        if let Some(list) = &self.shopping_list {
+            render_shopping_list(frame, list);
+        }
+
+        if self.editing_gravity {
+            // Just below the selected row: border, header, then the rows
+            let table = chunks[1];
+            let shown = self.selected.saturating_sub(self.table_offset.get()) as u16 + 1;
+            let y = (table.y + 2 + shown * self.row_height()).min(table.bottom().saturating_sub(3));
+            let [input] = Layout::horizontal([Constraint::Length(36)])
+                .flex(Flex::Center)
+                .areas(Rect { y, height: 3, ..table });
+            frame.render_widget(Clear, input);
+            frame.render_widget(&self.gravity_input, input);
+        }
+    }
+
+    /// Render the column picker popup over the table
+    fn render_column_picker(&self, frame: &mut Frame, row: usize) {
+        let [popup] = Layout::horizontal([Constraint::Length(30)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [popup] = Layout::vertical([Constraint::Length(ListColumn::ALL.len() as u16 + 2)])
+            .flex(Flex::Center)
+            .areas(popup);
+
+        let items: Vec<ListItem> = ListColumn::ALL
+            .iter()
+            .map(|column| {
+                let check = if self.hidden_columns.contains(column) { "[ ]" } else { "[x]" };
+                ListItem::new(format!("{} {}", check, column.header()))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        " Columns (Space toggles) ",
+                        Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_CYAN))
+                    .border_set(border::ROUNDED)
+                    .style(Style::default().bg(NORD_BG).fg(NORD_WHITE)),
+            )
+            .highlight_style(
+                Style::default()
+                    .fg(NORD_BG)
+                    .bg(NORD_CYAN)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        let mut state = ListState::default();
+        state.select(Some(row));
+        frame.render_widget(Clear, popup);
+        frame.render_stateful_widget(list, popup, &mut state);
+    }
+
+    /// Render the duplicate checklist as a popup over the table
+    fn render_duplicate_picker(&self, frame: &mut Frame, row: usize, parts: &HashSet<CopyPart>) {
+        let [popup] = Layout::horizontal([Constraint::Length(44)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [popup] = Layout::vertical([Constraint::Length(CopyPart::ALL.len() as u16 + 2)])
+            .flex(Flex::Center)
+            .areas(popup);
+
+        let items: Vec<ListItem> = CopyPart::ALL
+            .iter()
+            .map(|part| {
+                let check = if parts.contains(part) { "[x]" } else { "[ ]" };
+                ListItem::new(format!("{} {}", check, part.as_str()))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        " Duplicate (Space toggles, Enter creates) ",
+                        Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_CYAN))
+                    .border_set(border::ROUNDED)
+                    .style(Style::default().bg(NORD_BG).fg(NORD_WHITE)),
+            )
+            .highlight_style(
+                Style::default()
+                    .fg(NORD_BG)
+                    .bg(NORD_CYAN)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        let mut state = ListState::default();
+        state.select(Some(row));
+        frame.render_widget(Clear, popup);
+        frame.render_stateful_widget(list, popup, &mut state);
+    }
+}
+
+/// Widths the table gives `columns` across `width` cells, sharing the
+/// space by weight after the one-cell gaps between columns
+fn column_widths(columns: &[ListColumn], width: u16) -> Vec<u16> {
+    let gaps = columns.len().saturating_sub(1) as u16;
+    Layout::horizontal(columns.iter().map(|c| Constraint::Fill(c.weight())))
+        .spacing(1)
+        .split(Rect::new(0, 0, width.max(gaps), 1))
+        .iter()
+        .map(|area| area.width)
+        .collect()
+}
+
+/// "OG: gravity before fermenting · ..." for the abbreviated `columns`,
+/// keeping only the entries that fit in `width`
+fn legend_line(columns: &[ListColumn], width: usize) -> Paragraph<'static> {
+    let mut spans = Vec::new();
+    let mut used = 0;
+    for column in columns {
+        let Some(meaning) = column.legend() else {
+            continue;
+        };
+        let separator = if spans.is_empty() { "" } else { "  ·  " };
+        let length = separator.len() + column.header().len() + 2 + meaning.len();
+        if used + length > width {
+            break;
+        }
+        used += length;
+        spans.push(Span::styled(separator, Style::default().fg(NORD_GRAY)));
+        spans.push(Span::styled(format!("{}: ", column.header()), Style::default().fg(NORD_CYAN)));
+        spans.push(Span::styled(meaning, Style::default().fg(NORD_GRAY)));
+    }
+    Paragraph::new(Line::from(spans)).alignment(Alignment::Center)
+}
+
+/// Totals over the meads shown in the list
+struct ListTotals {
+    active: usize,
+    finished: usize,
+    honey_lbs: f64,
+    /// Average ABV of finished meads, if there are any
+    finished_abv: Option<f64>,
+}
+
+impl ListTotals {
+    fn from_meads(meads: &[Mead]) -> Self {
+        let finished: Vec<&Mead> = meads
+            .iter()
+            .filter(|m| matches!(m.status, MeadStatus::Bottled | MeadStatus::Finished))
+            .collect();
+        let active = meads
+            .iter()
+            .filter(|m| matches!(m.status, MeadStatus::Primary | MeadStatus::Secondary | MeadStatus::Aging))
+            .count();
+        let finished_abv = (!finished.is_empty()).then(|| {
+            finished
+                .iter()
+                .map(|m| m.bottling_snapshot().map_or(m.current_abv(), |(_, abv)| abv).max(0.0))
+                .sum::<f64>()
+                / finished.len() as f64
+        });
+
+        Self {
+            active,
+            finished: finished.len(),
+            honey_lbs: meads.iter().map(|m| m.honey_amount_lbs).sum(),
+            finished_abv,
+        }
+    }
+
+    fn line(&self) -> Line<'static> {
+        let label = Style::default().fg(NORD_GRAY);
+        let value = Style::default().fg(NORD_WHITE);
+        Line::from(vec![
+            Span::styled("Active: ", label),
+            Span::styled(self.active.to_string(), value),
+            Span::styled("  Finished: ", label),
+            Span::styled(self.finished.to_string(), value),
+            Span::styled("  Honey: ", label),
+            Span::styled(format!("{:.1} lbs", self.honey_lbs), value),
+            Span::styled("  Avg finished ABV: ", label),
+            Span::styled(
+                self.finished_abv.map_or("-".to_string(), |abv| format!("{}%", fmt_abv(abv))),
+                value,
+            ),
+        ])
     }
 }
 
+/// Color of a gravity trend arrow: falling gravity means fermentation is
+/// moving, a rise is worth a look
+fn trend_color(trend: GravityTrend) -> Color {
+    match trend {
+        GravityTrend::Down => NORD_GREEN,
+        GravityTrend::Up => NORD_YELLOW,
+        GravityTrend::Steady => NORD_GRAY,
+    }
+}
+
+/// Compact text progress bar, e.g. "█████░░░ 62%"
+fn progress_bar(progress: f64) -> String {
+    let filled = (progress * PROGRESS_BAR_WIDTH as f64).round() as usize;
+    format!(
+        "{}{} {:.0}%",
+        "█".repeat(filled),
+        "░".repeat(PROGRESS_BAR_WIDTH - filled),
+        progress * 100.0
+    )
+}
+
+/// Render the shopping list as a popup over the table: the batches it
+/// covers, then each ingredient with its total
+fn render_shopping_list(frame: &mut Frame, list: &ShoppingList) {
+    let area = frame.area();
+    let height = (list.items.len().max(1) as u16 + 4).min(area.height.saturating_sub(4));
+    let [popup] = Layout::horizontal([Constraint::Length(60)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(popup);
+
+    let block = Block::default()
+        .title(Span::styled(
+            " Shopping List ",
+            Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD),
+        ))
+        .title_bottom(Span::styled(" x Export  Esc Close ", Style::default().fg(NORD_GRAY)))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(NORD_CYAN))
+        .border_set(border::ROUNDED)
+        .style(Style::default().bg(NORD_BG).fg(NORD_WHITE));
+    let inner = block.inner(popup);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(block, popup);
+
+    let [header, body] = Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).areas(inner);
+    frame.render_widget(
+        Paragraph::new(format!("For {}", list.batches.join(", "))).style(Style::default().fg(NORD_FROST)),
+        header,
+    );
+
+    if list.items.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No ingredients recorded.").style(Style::default().fg(NORD_GRAY)),
+            body,
+        );
+        return;
+    }
+
+    let name_width = list.items.iter().map(|i| i.name.chars().count()).max().unwrap_or(0);
+    let items: Vec<ListItem> = list
+        .items
+        .iter()
+        .map(|item| {
+            let used_in = if item.batches > 1 { format!("  ({} batches)", item.batches) } else { String::new() };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{:<width$}  ", item.name, width = name_width)),
+                Span::styled(item.amount_text(), Style::default().fg(NORD_YELLOW)),
+                Span::styled(used_in, Style::default().fg(NORD_GRAY)),
+            ]))
+        })
+        .collect();
+    let highlight = Style::default().fg(NORD_BG).bg(NORD_CYAN).add_modifier(Modifier::BOLD);
+    let mut state = ListState::default().with_selected(Some(list.selected));
+    frame.render_stateful_widget(List::new(items).highlight_style(highlight), body, &mut state);
+}
+
 impl Default for MeadListView {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use crate::test_support::screen_text;
+
+    fn view_with_meads(count: i64) -> MeadListView {
+        let mut view = MeadListView::new();
+        let meads = (1..=count)
+            .map(|id| Mead {
+                id,
+                name: format!("Batch {:02}", id),
+                ..Default::default()
+            })
+            .collect();
+        view.set_meads(meads, HashSet::new(), HashSet::new(), HashMap::new());
+        view
+    }
+
+    #[test]
+    fn current_gravity_shows_trend_arrow() {
+        let mut view = view_with_meads(2);
+        let meads = view.all_meads.clone();
+        view.set_meads(meads, HashSet::new(), HashSet::new(), HashMap::from([(1, GravityTrend::Down), (2, GravityTrend::Up)]));
+        let mut terminal = Terminal::new(TestBackend::new(140, 20)).unwrap();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("1.100 ▼"));
+        assert!(text.contains("1.100 ▲"));
+    }
+
+    #[test]
+    fn headers_spell_out_on_wide_terminals_and_legend_explains_them() {
+        let mut view = view_with_meads(1);
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("OG"));
+        assert!(!text.contains("Orig. Gravity"));
+        assert!(!text.contains("gravity before fermenting"));
+
+        view.show_legend = true;
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        assert!(screen_text(&terminal).contains("OG: gravity before fermenting"));
+
+        let mut terminal = Terminal::new(TestBackend::new(240, 20)).unwrap();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("Orig. Gravity"));
+        assert!(text.contains("Current Gravity"));
+
+        // Spelled-out headers drop out of the legend
+        view.show_legend = true;
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        let text = screen_text(&terminal);
+        assert!(!text.contains("OG: ") && !text.contains("Current: "));
+    }
+
+    #[test]
+    fn competition_filter_shows_only_entries() {
+        let mut view = view_with_meads(4);
+        let mut meads = view.all_meads.clone();
+        meads[2].competition = true;
+        view.set_meads(meads, HashSet::new(), HashSet::new(), HashMap::new());
+        view.toggle_competition_only();
+        let shown: Vec<i64> = view.meads.iter().map(|m| m.id).collect();
+        assert_eq!(shown, vec![view.all_meads[2].id]);
+        view.toggle_competition_only();
+        assert_eq!(view.meads.len(), 4);
+    }
+
+    #[test]
+    fn selection_follows_the_mead_across_filter_changes() {
+        let mut view = view_with_meads(5);
+        view.attention = HashSet::from([2, 4]);
+        view.selected = 3;
+        view.toggle_attention_only();
+        assert_eq!(view.get_selected().map(|m| m.id), Some(4));
+        view.previous();
+        assert_eq!(view.get_selected().map(|m| m.id), Some(2));
+
+        // Clearing the filter keeps the mead chosen while it was on
+        view.toggle_attention_only();
+        assert_eq!(view.selected, 1);
+        assert_eq!(view.get_selected().map(|m| m.id), Some(2));
+
+        // A refresh that moves the mead, e.g. pinning it, keeps it selected
+        let mut meads = view.all_meads.clone();
+        let pinned = meads.remove(1);
+        meads.insert(0, pinned);
+        view.set_meads(meads, HashSet::new(), HashSet::new(), HashMap::new());
+        assert_eq!(view.selected, 0);
+
+        // Once it is gone the selection stays on the same row
+        view.selected = 4;
+        let meads = view.all_meads[..4].to_vec();
+        view.set_meads(meads, HashSet::new(), HashSet::new(), HashMap::new());
+        assert_eq!(view.selected, 3);
+    }
+
+    #[test]
+    fn rating_sort_keeps_pinned_meads_first() {
+        let mut view = MeadListView::new();
+        let meads = [(1, true, Some(2)), (2, false, Some(5)), (3, false, None), (4, false, Some(4))]
+            .into_iter()
+            .map(|(id, pinned, rating)| Mead { id, pinned, rating, ..Default::default() })
+            .collect();
+        view.set_meads(meads, HashSet::new(), HashSet::new(), HashMap::new());
+
+        view.toggle_rating_sort();
+        let ids: Vec<i64> = view.meads.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![1, 2, 4, 3]);
+    }
+
+    #[test]
+    fn selection_stays_visible_when_terminal_shrinks() {
+        let mut view = view_with_meads(30);
+        view.selected = 24;
+        let mut terminal = Terminal::new(TestBackend::new(120, 50)).unwrap();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        assert!(screen_text(&terminal).contains("Batch 25"));
+
+        terminal.backend_mut().resize(120, 20);
+        view.clamp_selection();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        assert!(screen_text(&terminal).contains("Batch 25"));
+    }
+
+    #[test]
+    fn scroll_carries_over_between_frames() {
+        let mut view = view_with_meads(30);
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        view.selected = 29;
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+
+        // Moving up one row shouldn't jump the view back to the top
+        view.previous();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("Batch 30"));
+        assert!(!text.contains("Batch 01"));
+    }
+
+    #[test]
+    fn stripes_shade_alternate_rows_and_tall_rows_show_fewer() {
+        let mut view = view_with_meads(30);
+        view.zebra_rows = true;
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        let row_bg = |terminal: &Terminal<TestBackend>, y: u16| terminal.backend().buffer()[(5, y)].bg;
+        // The margin, title, table border and header put the first row on
+        // line 6
+        assert_eq!(row_bg(&terminal, 6), NORD_CYAN, "the selection keeps its highlight");
+        assert_eq!(row_bg(&terminal, 7), NORD_STRIPE);
+        assert_ne!(row_bg(&terminal, 8), NORD_STRIPE);
+
+        let compact = screen_text(&terminal);
+        view.tall_rows = true;
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        let tall = screen_text(&terminal);
+        assert!(compact.contains("Batch 20"));
+        assert!(!tall.contains("Batch 20"));
+    }
+
+    #[test]
+    fn next_actions_show_in_their_column_only_when_set() {
+        let mut view = view_with_meads(2);
+        view.all_meads[0].next_action = "rack".to_string();
+        view.apply_filter();
+        let mut terminal = Terminal::new(TestBackend::new(160, 20)).unwrap();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("Next Action"));
+        assert!(text.contains("→ rack "));
+        assert_eq!(text.matches('→').count(), 1, "an empty next action shows nothing");
+
+        view.all_meads[0].next_action.clear();
+        view.apply_filter();
+        terminal.draw(|frame| view.render(frame, &None)).unwrap();
+        assert!(!screen_text(&terminal).contains("Next"), "the column goes once no batch has one");
+    }
+
+    #[test]
+    fn type_ahead_jumps_to_matching_names() {
+        let mut view = MeadListView::new();
+        let meads = ["Blackberry", "Bochet", "Cyser", "Blueberry"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Mead {
+                id: i as i64 + 1,
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        view.set_meads(meads, HashSet::new(), HashSet::new(), HashMap::new());
+        let start = Instant::now();
+        view.start_type_ahead(start);
+        assert!(view.type_ahead_active(start));
+
+        assert!(view.type_ahead('b', start));
+        assert_eq!(view.selected, 1);
+        assert!(view.type_ahead('L', start));
+        assert_eq!(view.selected, 3);
+        assert!(view.type_ahead('a', start));
+        assert_eq!(view.selected, 0);
+        assert!(!view.type_ahead('z', start));
+        assert_eq!(view.selected, 0);
+
+        // After a pause typing starts over; repeating a letter cycles
+        let later = start + TYPE_AHEAD_TIMEOUT;
+        assert!(!view.type_ahead_active(later));
+        view.start_type_ahead(later);
+        assert!(view.type_ahead('b', later));
+        assert_eq!(view.selected, 1);
+        assert!(view.type_ahead('b', later));
+        assert_eq!(view.selected, 3);
+        assert!(view.type_ahead('b', later));
+        assert_eq!(view.selected, 0);
+    }
+
+    #[test]
+    fn clamp_selection_handles_a_shorter_list() {
+        let mut view = view_with_meads(10);
+        view.selected = 9;
+        view.meads.truncate(3);
+        view.clamp_selection();
+        assert_eq!(view.selected, 2);
+    }
+}
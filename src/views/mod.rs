@@ -1,10 +1,20 @@
+pub mod integrity;
 pub mod main_menu;
 pub mod mead_detail;
 pub mod mead_list;
 pub mod new_mead;
+pub mod recent_activity;
+pub mod reference;
+pub mod stats;
+pub mod welcome;
 
+pub use integrity::IntegrityView;
 pub use main_menu::MainMenuView;
 pub use mead_detail::MeadDetailView;
 pub use mead_list::MeadListView;
 pub use new_mead::NewMeadView;
+pub use recent_activity::RecentActivityView;
+pub use reference::ReferenceView;
+pub use stats::StatsView;
+pub use welcome::WelcomeView;
 
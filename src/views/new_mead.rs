@@ -1,31 +1,43 @@
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols::border,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Wrap},
 };
 
-use crate::models::{Mead, MeadStatus};
-use crate::widgets::InputField;
+use chrono::Local;
+
+use crate::calc;
+use crate::format::{self, fmt_abv};
+use crate::fuzzy::fuzzy_score;
+use crate::models::{BaseType, Mead, MeadStatus};
+use crate::nav;
+use crate::nutrients::{self, NitrogenDemand};
+use crate::theme::{self, ThemeView};
+use crate::widgets::{hotkey_span, InputField, SelectField};
 
 // Nord-adjacent color palette
 const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
-const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
 const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
 const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
 const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
 const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+const NORD_YELLOW: Color = Color::Rgb(235, 203, 139);   // #EBCB8B
 
 /// Field indices for navigation
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum NewMeadField {
-    Name = 0,
+    Base = 0,
+    Name,
+    Status,
     StartDate,
+    TargetDate,
     HoneyType,
     HoneyAmount,
     YeastStrain,
+    NitrogenDemand,
     TargetAbv,
     StartingGravity,
     VolumeGallons,
@@ -37,33 +49,92 @@ pub enum NewMeadField {
 impl NewMeadField {
     fn from_index(i: usize) -> Self {
         match i {
-            0 => NewMeadField::Name,
-            1 => NewMeadField::StartDate,
-            2 => NewMeadField::HoneyType,
-            3 => NewMeadField::HoneyAmount,
-            4 => NewMeadField::YeastStrain,
-            5 => NewMeadField::TargetAbv,
-            6 => NewMeadField::StartingGravity,
-            7 => NewMeadField::VolumeGallons,
-            8 => NewMeadField::YanRequired,
-            9 => NewMeadField::Notes,
+            0 => NewMeadField::Base,
+            1 => NewMeadField::Name,
+            2 => NewMeadField::Status,
+            3 => NewMeadField::StartDate,
+            4 => NewMeadField::TargetDate,
+            5 => NewMeadField::HoneyType,
+            6 => NewMeadField::HoneyAmount,
+            7 => NewMeadField::YeastStrain,
+            8 => NewMeadField::NitrogenDemand,
+            9 => NewMeadField::TargetAbv,
+            10 => NewMeadField::StartingGravity,
+            11 => NewMeadField::VolumeGallons,
+            12 => NewMeadField::YanRequired,
+            13 => NewMeadField::Notes,
             _ => NewMeadField::Submit,
         }
     }
 
     fn count() -> usize {
-        11
+        15
+    }
+
+    /// Name used for the field in the config file
+    pub fn key(self) -> &'static str {
+        match self {
+            NewMeadField::Base => "base",
+            NewMeadField::Name => "name",
+            NewMeadField::Status => "status",
+            NewMeadField::StartDate => "start_date",
+            NewMeadField::TargetDate => "target_date",
+            NewMeadField::HoneyType => "honey_type",
+            NewMeadField::HoneyAmount => "honey_amount",
+            NewMeadField::YeastStrain => "yeast_strain",
+            NewMeadField::NitrogenDemand => "nitrogen_demand",
+            NewMeadField::TargetAbv => "target_abv",
+            NewMeadField::StartingGravity => "starting_gravity",
+            NewMeadField::VolumeGallons => "volume_gallons",
+            NewMeadField::YanRequired => "yan_required",
+            NewMeadField::Notes => "notes",
+            NewMeadField::Submit => "submit",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<NewMeadField> {
+        (0..Self::count()).map(Self::from_index).find(|f| f.key() == key)
+    }
+
+    /// Letter that jumps to the field with Alt
+    fn hotkey(self) -> char {
+        match self {
+            NewMeadField::Base => 'b',
+            NewMeadField::Name => 'n',
+            NewMeadField::Status => 's',
+            NewMeadField::StartDate => 'd',
+            NewMeadField::TargetDate => 't',
+            NewMeadField::HoneyType => 'h',
+            NewMeadField::HoneyAmount => 'a',
+            NewMeadField::YeastStrain => 'y',
+            NewMeadField::NitrogenDemand => 'e',
+            NewMeadField::TargetAbv => 'v',
+            NewMeadField::StartingGravity => 'g',
+            NewMeadField::VolumeGallons => 'l',
+            NewMeadField::YanRequired => 'r',
+            NewMeadField::Notes => 'o',
+            NewMeadField::Submit => 'c',
+        }
+    }
+
+    /// Fields in the left column; the rest are in the right column
+    fn left_column_len() -> usize {
+        NewMeadField::TargetAbv as usize
     }
 }
 
 /// New mead form view state
 pub struct NewMeadView {
     /// Input fields
+    pub base_select: SelectField,
     pub name: InputField,
+    pub status_select: SelectField,
     pub start_date: InputField,
+    pub target_date: InputField,
     pub honey_type: InputField,
     pub honey_amount: InputField,
     pub yeast_strain: InputField,
+    pub demand_select: SelectField,
     pub target_abv: InputField,
     pub starting_gravity: InputField,
     pub volume_gallons: InputField,
@@ -73,53 +144,345 @@ pub struct NewMeadView {
     pub current_field: usize,
     /// Whether currently editing a field
     pub editing: bool,
+    /// Status the new mead starts in
+    status: MeadStatus,
+    /// What the batch is fermented from
+    base_type: BaseType,
+    /// How much nitrogen the yeast needs, for the suggested YAN
+    demand: NitrogenDemand,
+    /// Text typed on a select field to pick an option by name
+    select_query: String,
 }
 
 impl NewMeadView {
     pub fn new() -> Self {
-        let now = chrono::Utc::now();
-        Self {
-            name: InputField::new("Name").with_placeholder("My First Mead"),
-            start_date: InputField::new("Start Date").with_value(now.format("%Y-%m-%d").to_string()),
-            honey_type: InputField::new("Honey Type").with_placeholder("Wildflower, Clover, etc."),
-            honey_amount: InputField::new("Honey (lbs)").with_value("3.0"),
-            yeast_strain: InputField::new("Yeast Strain").with_placeholder("Lalvin 71B, D47, etc."),
-            target_abv: InputField::new("Target ABV %").with_value("14.0"),
-            starting_gravity: InputField::new("Starting Gravity").with_value("1.100"),
-            volume_gallons: InputField::new("Volume (gallons)").with_value("1.0"),
-            yan_required: InputField::new("YAN Required (ppm)").with_value("200"),
-            notes: InputField::new("Notes").with_placeholder("Any additional notes..."),
+        let status = MeadStatus::Primary;
+        let base_type = BaseType::Honey;
+        let demand = NitrogenDemand::Medium;
+        let mut view = Self {
+            base_select: SelectField::new(
+                "Base (type or Left/Right)",
+                BaseType::all().iter().map(|b| b.as_str()).collect(),
+            )
+            .with_value(base_type.as_str())
+            .with_hotkey(NewMeadField::Base.hotkey()),
+            name: InputField::new("Name")
+                .with_placeholder("My First Mead")
+                .with_hotkey(NewMeadField::Name.hotkey()),
+            status_select: SelectField::new(
+                "Status (type or Left/Right)",
+                MeadStatus::all().iter().map(|s| s.as_str()).collect(),
+            )
+            .with_value(status.as_str())
+            .with_value_color(theme::status_color(&status))
+            .with_hotkey(NewMeadField::Status.hotkey()),
+            start_date: InputField::new("Start Date")
+                .with_value(default_start_date(&status))
+                .with_placeholder("YYYY-MM-DD")
+                .with_hint("Ctrl+T fills in today's date")
+                .with_hotkey(NewMeadField::StartDate.hotkey()),
+            target_date: InputField::new("Target Date")
+                .with_placeholder("YYYY-MM-DD (optional)")
+                .with_hotkey(NewMeadField::TargetDate.hotkey()),
+            honey_type: InputField::new("Honey Type")
+                .with_placeholder("Wildflower, Clover, etc.")
+                .with_hotkey(NewMeadField::HoneyType.hotkey()),
+            honey_amount: InputField::new("Honey (lbs)")
+                .numeric()
+                .with_value("3.0")
+                .with_hint("About 3 lbs per gallon for a standard-strength mead")
+                .with_hotkey(NewMeadField::HoneyAmount.hotkey()),
+            yeast_strain: InputField::new("Yeast Strain")
+                .with_placeholder("Lalvin 71B, D47, etc.")
+                .with_hotkey(NewMeadField::YeastStrain.hotkey()),
+            demand_select: SelectField::new(
+                "Yeast Nitrogen Demand (type or Left/Right)",
+                NitrogenDemand::all().iter().map(|d| d.as_str()).collect(),
+            )
+            .with_value(demand.as_str())
+            .with_hotkey(NewMeadField::NitrogenDemand.hotkey()),
+            target_abv: InputField::new("Target ABV %")
+                .numeric()
+                .with_value("14.0")
+                .with_hint("Check it is within your yeast's alcohol tolerance")
+                .with_hotkey(NewMeadField::TargetAbv.hotkey()),
+            starting_gravity: InputField::new("Starting Gravity")
+                .gravity()
+                .with_value(default_gravity(base_type))
+                .with_hint("Hydrometer reading before pitching, e.g. 1.080 or just 080")
+                .with_hotkey(NewMeadField::StartingGravity.hotkey()),
+            volume_gallons: InputField::new("Volume (gallons)")
+                .numeric()
+                .with_value("1.0")
+                .with_hint("Volume in the fermenter, not counting headspace")
+                .with_hotkey(NewMeadField::VolumeGallons.hotkey()),
+            yan_required: InputField::new("YAN Required (ppm)")
+                .numeric()
+                .with_value("200")
+                .with_hint("Ctrl+F fills in the suggestion for this gravity and yeast")
+                .with_hotkey(NewMeadField::YanRequired.hotkey()),
+            notes: InputField::new("Notes")
+                .with_placeholder("Any additional notes...")
+                .with_hotkey(NewMeadField::Notes.hotkey()),
             current_field: 0,
             editing: false,
+            status,
+            base_type,
+            demand,
+            select_query: String::new(),
+        };
+        view.set_base_type(base_type);
+        view.set_field_focus(true);
+        view
+    }
+
+    /// Change the base type, relabelling the honey fields and updating the
+    /// starting gravity if it still holds the default for the previous base
+    fn set_base_type(&mut self, base_type: BaseType) {
+        if self.starting_gravity.get_value() == default_gravity(self.base_type) {
+            self.starting_gravity.set_value(default_gravity(base_type));
+        }
+        let label = base_type.fermentable_label();
+        if base_type == BaseType::Honey {
+            self.honey_type.label = "Honey Type".to_string();
+            self.honey_type.placeholder = "Wildflower, Clover, etc.".to_string();
+            self.honey_amount.hint = "About 3 lbs per gallon for a standard-strength mead".to_string();
+        } else {
+            self.honey_type.label = format!("{} Ingredient", label);
+            self.honey_type.placeholder = "Juice, fruit or sugar used".to_string();
+            self.honey_amount.hint = "Weight of fruit or sugar; leave 0 for straight juice".to_string();
         }
+        self.honey_amount.label = format!("{} (lbs)", label);
+        self.base_type = base_type;
+    }
+
+    /// Change the starting status, updating the start date if it still holds
+    /// the default for the previous status
+    fn set_status(&mut self, status: MeadStatus) {
+        if self.start_date.get_value() == default_start_date(&self.status) {
+            self.start_date.set_value(default_start_date(&status));
+        }
+        self.status_select.value_color = theme::status_color(&status);
+        self.status = status;
+    }
+
+    /// Type a character of an option name on the base or status field,
+    /// selecting the best match. Characters that would match nothing are
+    /// ignored.
+    pub fn type_select_char(&mut self, c: char) {
+        let query = format!("{}{}", self.select_query, c);
+        if self.apply_select_query(&query) {
+            self.select_query = query;
+        }
+    }
+
+    /// Remove the last typed character of the option name
+    pub fn delete_select_char(&mut self) {
+        self.select_query.pop();
+        let query = self.select_query.clone();
+        self.apply_select_query(&query);
+    }
+
+    /// Select the option best matching `query` on the current select field
+    fn apply_select_query(&mut self, query: &str) -> bool {
+        match NewMeadField::from_index(self.current_field) {
+            NewMeadField::Base => {
+                let Some(base_type) = best_match(query, BaseType::all(), |b| b.as_str()) else {
+                    return false;
+                };
+                self.base_select.set_value(base_type.as_str());
+                self.set_base_type(base_type);
+            }
+            NewMeadField::NitrogenDemand => {
+                let Some(demand) = best_match(query, NitrogenDemand::all(), |d| d.as_str()) else {
+                    return false;
+                };
+                self.demand_select.set_value(demand.as_str());
+                self.demand = demand;
+            }
+            _ => {
+                let Some(status) = best_match(query, MeadStatus::all(), |s| s.as_str()) else {
+                    return false;
+                };
+                self.status_select.set_value(status.as_str());
+                self.set_status(status);
+            }
+        }
+        true
+    }
+
+    /// Suggested YAN in ppm for the starting gravity and nitrogen demand
+    pub fn suggested_yan(&self) -> f64 {
+        nutrients::suggested_yan(self.build_mead().starting_gravity, self.demand)
+    }
+
+    /// Put the suggested YAN in the YAN Required field
+    pub fn fill_suggested_yan(&mut self) {
+        self.yan_required.set_value(format!("{:.0}", self.suggested_yan()));
+    }
+
+    /// Draw the suggested YAN, and the total it comes to over the batch, on
+    /// the right of the YAN field's top border
+    fn render_suggested_yan(&self, frame: &mut Frame, field: Rect) {
+        let ppm = self.suggested_yan();
+        let mg = nutrients::total_yan_mg(ppm, self.build_mead().volume_gallons);
+        let label = format!(" Suggested: {:.0} ppm, {:.0} mg ", ppm, mg);
+        let width = (label.len() as u16).min(field.width.saturating_sub(2));
+        let area = Rect {
+            x: field.right().saturating_sub(width + 1),
+            y: field.y,
+            width,
+            height: 1.min(field.height),
+        };
+        frame.render_widget(Paragraph::new(label).style(Style::default().fg(NORD_GRAY)), area);
+    }
+
+    /// Draw "Potential: x%" from the starting gravity on the right of the
+    /// target ABV field's top border, yellow when the target is higher
+    fn render_potential_abv(&self, frame: &mut Frame, field: Rect) {
+        let mead = self.build_mead();
+        let potential = mead.potential_abv();
+        let color = if mead.target_abv > potential { NORD_YELLOW } else { NORD_GRAY };
+        let label = format!(" Potential: {}% ", fmt_abv(potential));
+        let width = (label.len() as u16).min(field.width.saturating_sub(2));
+        let area = Rect {
+            x: field.right().saturating_sub(width + 1),
+            y: field.y,
+            width,
+            height: 1.min(field.height),
+        };
+        frame.render_widget(Paragraph::new(label).style(Style::default().fg(color)), area);
+    }
+
+    /// Draw the water to honey ratio on the right of the volume field's top
+    /// border, yellow when it is unusual
+    fn render_honey_ratio(&self, frame: &mut Frame, field: Rect) {
+        let Some(ratio) = calc::gallons_per_lb(&self.build_mead()) else {
+            return;
+        };
+        let color = if calc::ratio_warning(ratio).is_some() { NORD_YELLOW } else { NORD_GRAY };
+        let label = format!(" {:.2} gal/lb honey ", ratio);
+        let width = (label.len() as u16).min(field.width.saturating_sub(2));
+        let area = Rect {
+            x: field.right().saturating_sub(width + 1),
+            y: field.y,
+            width,
+            height: 1.min(field.height),
+        };
+        frame.render_widget(Paragraph::new(label).style(Style::default().fg(color)), area);
+    }
+
+    /// Warning about an unusual water to honey ratio, if any
+    pub fn ratio_warning(&self) -> Option<&'static str> {
+        calc::gallons_per_lb(&self.build_mead()).and_then(calc::ratio_warning)
+    }
+
+    /// Why the form can't be saved as it is, if it can't
+    pub fn validation_error(&self) -> Option<&'static str> {
+        if let Some(volume) = self.volume_gallons.get_f64() {
+            if volume <= 0.0 {
+                return Some("Volume must be above 0 gallons");
+            }
+        }
+        let target_date = self.target_date.get_value().trim();
+        if !target_date.is_empty() && format::parse_date(target_date).is_none() {
+            return Some("Target date must be a real date, e.g. 2025-09-01, or left empty");
+        }
+        let start_date = self.start_date.get_value().trim();
+        if start_date.is_empty() {
+            return (self.status != MeadStatus::Planning).then_some("Start date is needed once a batch has started");
+        }
+        match format::parse_date(start_date) {
+            Some(_) => None,
+            None => Some("Start date must be a real date, e.g. 2025-06-01 (Ctrl+T for today)"),
+        }
+    }
+
+    /// Set the start date to today
+    pub fn fill_today(&mut self) {
+        self.start_date.set_value(Local::now().format(format::ISO_DATE).to_string());
+    }
+
+    /// Advisory warning about the start date, if any
+    pub fn start_date_warning(&self) -> Option<&'static str> {
+        if self.status == MeadStatus::Planning {
+            return None;
+        }
+        let date = format::parse_date(self.start_date.get_value())?;
+        if date > Local::now().date_naive() {
+            Some("Start date is in the future")
+        } else {
+            None
+        }
+    }
+
+    /// Move focus to a field
+    pub fn focus_field(&mut self, field: NewMeadField) {
+        self.set_field_focus(false);
+        self.editing = false;
+        self.current_field = field as usize;
+        self.set_field_focus(true);
+    }
+
+    /// Focus the field whose hotkey is `key`, returning whether one matched
+    pub fn jump_to_hotkey(&mut self, key: char) -> bool {
+        let key = key.to_ascii_lowercase();
+        let Some(index) = (0..NewMeadField::count()).find(|i| NewMeadField::from_index(*i).hotkey() == key) else {
+            return false;
+        };
+        self.set_field_focus(false);
+        self.editing = false;
+        self.current_field = index;
+        self.set_field_focus(true);
+        true
     }
 
     pub fn next_field(&mut self) {
         self.set_field_focus(false);
         self.editing = false;
-        self.current_field = (self.current_field + 1) % NewMeadField::count();
+        self.current_field = nav::next_index(self.current_field, NewMeadField::count());
         self.set_field_focus(true);
     }
 
     pub fn previous_field(&mut self) {
         self.set_field_focus(false);
         self.editing = false;
-        if self.current_field == 0 {
-            self.current_field = NewMeadField::count() - 1;
-        } else {
-            self.current_field -= 1;
+        self.current_field = nav::previous_index(self.current_field, NewMeadField::count());
+        self.set_field_focus(true);
+    }
+
+    /// Move to the field at the same row in the other column, or the last
+    /// field of a shorter column. `right` picks the column to move to.
+    pub fn move_to_column(&mut self, right: bool) {
+        let left_len = NewMeadField::left_column_len();
+        let on_right = self.current_field >= left_len;
+        if on_right == right {
+            return;
         }
+        let target = if right {
+            (left_len + self.current_field).min(NewMeadField::count() - 1)
+        } else {
+            (self.current_field - left_len).min(left_len - 1)
+        };
+        self.set_field_focus(false);
+        self.editing = false;
+        self.current_field = target;
         self.set_field_focus(true);
     }
 
     fn set_field_focus(&mut self, focused: bool) {
+        self.select_query.clear();
         let field = NewMeadField::from_index(self.current_field);
         match field {
+            NewMeadField::Base => self.base_select.set_focused(focused),
             NewMeadField::Name => self.name.set_focused(focused),
+            NewMeadField::Status => self.status_select.set_focused(focused),
             NewMeadField::StartDate => self.start_date.set_focused(focused),
+            NewMeadField::TargetDate => self.target_date.set_focused(focused),
             NewMeadField::HoneyType => self.honey_type.set_focused(focused),
             NewMeadField::HoneyAmount => self.honey_amount.set_focused(focused),
             NewMeadField::YeastStrain => self.yeast_strain.set_focused(focused),
+            NewMeadField::NitrogenDemand => self.demand_select.set_focused(focused),
             NewMeadField::TargetAbv => self.target_abv.set_focused(focused),
             NewMeadField::StartingGravity => self.starting_gravity.set_focused(focused),
             NewMeadField::VolumeGallons => self.volume_gallons.set_focused(focused),
@@ -131,11 +494,15 @@ impl NewMeadView {
 
     fn get_current_field_mut(&mut self) -> Option<&mut InputField> {
         match NewMeadField::from_index(self.current_field) {
+            NewMeadField::Base => None,
             NewMeadField::Name => Some(&mut self.name),
+            NewMeadField::Status => None,
             NewMeadField::StartDate => Some(&mut self.start_date),
+            NewMeadField::TargetDate => Some(&mut self.target_date),
             NewMeadField::HoneyType => Some(&mut self.honey_type),
             NewMeadField::HoneyAmount => Some(&mut self.honey_amount),
             NewMeadField::YeastStrain => Some(&mut self.yeast_strain),
+            NewMeadField::NitrogenDemand => None,
             NewMeadField::TargetAbv => Some(&mut self.target_abv),
             NewMeadField::StartingGravity => Some(&mut self.starting_gravity),
             NewMeadField::VolumeGallons => Some(&mut self.volume_gallons),
@@ -153,8 +520,16 @@ impl NewMeadView {
         NewMeadField::from_index(self.current_field) == NewMeadField::Submit
     }
 
+    /// Whether the current field is a selector rather than a text input
+    pub fn is_on_select(&self) -> bool {
+        matches!(
+            NewMeadField::from_index(self.current_field),
+            NewMeadField::Base | NewMeadField::Status | NewMeadField::NitrogenDemand
+        )
+    }
+
     pub fn toggle_edit(&mut self) {
-        if !self.is_on_submit() {
+        if !self.is_on_submit() && !self.is_on_select() {
             self.editing = !self.editing;
         }
     }
@@ -175,6 +550,18 @@ impl NewMeadView {
         }
     }
 
+    pub fn undo(&mut self) {
+        if let Some(field) = self.get_current_field_mut() {
+            field.undo();
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some(field) = self.get_current_field_mut() {
+            field.redo();
+        }
+    }
+
     pub fn delete_char_forward(&mut self) {
         if let Some(field) = self.get_current_field_mut() {
             field.delete_char_forward();
@@ -182,12 +569,48 @@ impl NewMeadView {
     }
 
     pub fn move_cursor_left(&mut self) {
+        if self.is_on_select() {
+            self.select_query.clear();
+            match NewMeadField::from_index(self.current_field) {
+                NewMeadField::Base => {
+                    self.base_select.previous();
+                    self.set_base_type(BaseType::from_str(self.base_select.get_value()));
+                }
+                NewMeadField::NitrogenDemand => {
+                    self.demand_select.previous();
+                    self.demand = NitrogenDemand::from_str(self.demand_select.get_value());
+                }
+                _ => {
+                    self.status_select.previous();
+                    self.set_status(MeadStatus::from_str(self.status_select.get_value()));
+                }
+            }
+            return;
+        }
         if let Some(field) = self.get_current_field_mut() {
             field.move_cursor_left();
         }
     }
 
     pub fn move_cursor_right(&mut self) {
+        if self.is_on_select() {
+            self.select_query.clear();
+            match NewMeadField::from_index(self.current_field) {
+                NewMeadField::Base => {
+                    self.base_select.next();
+                    self.set_base_type(BaseType::from_str(self.base_select.get_value()));
+                }
+                NewMeadField::NitrogenDemand => {
+                    self.demand_select.next();
+                    self.demand = NitrogenDemand::from_str(self.demand_select.get_value());
+                }
+                _ => {
+                    self.status_select.next();
+                    self.set_status(MeadStatus::from_str(self.status_select.get_value()));
+                }
+            }
+            return;
+        }
         if let Some(field) = self.get_current_field_mut() {
             field.move_cursor_right();
         }
@@ -205,22 +628,56 @@ impl NewMeadView {
         }
     }
 
+    /// Whether anything differs from a freshly opened form, so leaving
+    /// would throw away what was typed
+    pub fn has_entries(&self) -> bool {
+        let fresh = Self::new();
+        let inputs = |view: &Self| {
+            [
+                &view.name,
+                &view.start_date,
+                &view.target_date,
+                &view.honey_type,
+                &view.honey_amount,
+                &view.yeast_strain,
+                &view.target_abv,
+                &view.starting_gravity,
+                &view.volume_gallons,
+                &view.yan_required,
+                &view.notes,
+            ]
+            .map(|input| input.get_value().trim().to_string())
+        };
+        inputs(self) != inputs(&fresh)
+            || self.status != fresh.status
+            || self.base_type != fresh.base_type
+            || self.demand != fresh.demand
+    }
+
     /// Build a Mead struct from the form data
     pub fn build_mead(&self) -> Mead {
         Mead {
             name: self.name.get_value().to_string(),
-            start_date: self.start_date.get_value().to_string(),
+            start_date: match format::parse_date(self.start_date.get_value()) {
+                Some(date) => date.format(format::ISO_DATE).to_string(),
+                None => self.start_date.get_value().to_string(),
+            },
             honey_type: self.honey_type.get_value().to_string(),
             honey_amount_lbs: self.honey_amount.get_f64().unwrap_or(0.0),
             yeast_strain: self.yeast_strain.get_value().to_string(),
             target_abv: self.target_abv.get_f64().unwrap_or(14.0),
-            starting_gravity: self.starting_gravity.get_f64().unwrap_or(1.100),
-            current_gravity: self.starting_gravity.get_f64().unwrap_or(1.100),
+            starting_gravity: self.starting_gravity.get_f64().unwrap_or(self.base_type.default_gravity()),
+            current_gravity: self.starting_gravity.get_f64().unwrap_or(self.base_type.default_gravity()),
             volume_gallons: self.volume_gallons.get_f64().unwrap_or(1.0),
             yan_required: self.yan_required.get_f64().unwrap_or(0.0),
             yan_added: 0.0,
-            status: MeadStatus::Primary,
+            status: self.status.clone(),
             notes: self.notes.get_value().to_string(),
+            target_date: match format::parse_date(self.target_date.get_value()) {
+                Some(date) => date.format(format::ISO_DATE).to_string(),
+                None => self.target_date.get_value().trim().to_string(),
+            },
+            base_type: self.base_type,
             ..Default::default()
         }
     }
@@ -233,17 +690,18 @@ impl NewMeadView {
             .margin(1)
             .constraints([
                 Constraint::Length(3),  // Title
-                Constraint::Min(20),    // Form
+                Constraint::Min(29),    // Form
                 Constraint::Length(3),  // Controls
             ])
             .split(area);
 
         // Title
+        let accent = theme::accent(ThemeView::NewMead);
         let title = Paragraph::new(Line::from(vec![
             Span::styled(
                 "New Mead",
                 Style::default()
-                    .fg(NORD_FROST)
+                    .fg(accent)
                     .add_modifier(Modifier::BOLD),
             ),
         ]))
@@ -251,7 +709,7 @@ impl NewMeadView {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(NORD_FROST))
+                .border_style(Style::default().fg(accent))
                 .border_set(border::ROUNDED),
         );
         frame.render_widget(title, chunks[0]);
@@ -268,11 +726,15 @@ impl NewMeadView {
             .direction(Direction::Vertical)
             .margin(1)
             .constraints([
+                Constraint::Length(3), // Base
                 Constraint::Length(3), // Name
+                Constraint::Length(3), // Status
                 Constraint::Length(3), // Start Date
+                Constraint::Length(3), // Target Date
                 Constraint::Length(3), // Honey Type
                 Constraint::Length(3), // Honey Amount
                 Constraint::Length(3), // Yeast Strain
+                Constraint::Length(3), // Nitrogen Demand
                 Constraint::Min(0),
             ])
             .split(form_columns[0]);
@@ -293,17 +755,30 @@ impl NewMeadView {
             .split(form_columns[1]);
 
         // Render left column
-        frame.render_widget(&self.name, left_fields[0]);
-        frame.render_widget(&self.start_date, left_fields[1]);
-        frame.render_widget(&self.honey_type, left_fields[2]);
-        frame.render_widget(&self.honey_amount, left_fields[3]);
-        frame.render_widget(&self.yeast_strain, left_fields[4]);
+        frame.render_widget(&self.base_select, left_fields[0]);
+        frame.render_widget(&self.name, left_fields[1]);
+        frame.render_widget(&self.status_select, left_fields[2]);
+        frame.render_widget(&self.start_date, left_fields[3]);
+        frame.render_widget(&self.target_date, left_fields[4]);
+        frame.render_widget(&self.honey_type, left_fields[5]);
+        frame.render_widget(&self.honey_amount, left_fields[6]);
+        frame.render_widget(&self.yeast_strain, left_fields[7]);
+        frame.render_widget(&self.demand_select, left_fields[8]);
+
+        if let Some(warning) = self.start_date_warning() {
+            let warning = Paragraph::new(format!(" ! {}", warning))
+                .style(Style::default().fg(NORD_YELLOW));
+            frame.render_widget(warning, left_fields[9]);
+        }
 
         // Render right column
         frame.render_widget(&self.target_abv, right_fields[0]);
+        self.render_potential_abv(frame, right_fields[0]);
         frame.render_widget(&self.starting_gravity, right_fields[1]);
         frame.render_widget(&self.volume_gallons, right_fields[2]);
+        self.render_honey_ratio(frame, right_fields[2]);
         frame.render_widget(&self.yan_required, right_fields[3]);
+        self.render_suggested_yan(frame, right_fields[3]);
         frame.render_widget(&self.notes, right_fields[4]);
 
         // Submit button
@@ -322,6 +797,7 @@ impl NewMeadView {
             .style(submit_style)
             .block(
                 Block::default()
+                    .title(hotkey_span(Some(NewMeadField::Submit.hotkey()), is_submit_selected))
                     .borders(Borders::ALL)
                     .border_style(if is_submit_selected {
                         Style::default().fg(NORD_CYAN)
@@ -332,12 +808,21 @@ impl NewMeadView {
             );
         frame.render_widget(submit_btn, right_fields[5]);
 
+        if let Some(warning) = self.ratio_warning() {
+            let warning = Paragraph::new(format!(" ! {}", warning))
+                .style(Style::default().fg(NORD_YELLOW))
+                .wrap(Wrap { trim: false });
+            frame.render_widget(warning, right_fields[6]);
+        }
+
         // Controls
         let controls = Line::from(vec![
             Span::styled("Tab/Arrows", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
             Span::styled(" Navigate  ", Style::default().fg(NORD_WHITE)),
             Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
             Span::styled(" to edit  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("Alt+key", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled(" Jump  ", Style::default().fg(NORD_WHITE)),
             Span::styled("Enter", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
             Span::styled(" Submit  ", Style::default().fg(NORD_WHITE)),
             Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
@@ -357,9 +842,168 @@ impl NewMeadView {
     }
 }
 
+/// Option best matching a typed name: a prefix match first, otherwise the
+/// best fuzzy match. None for an empty or unmatched query.
+fn best_match<T: Clone>(query: &str, options: Vec<T>, name: impl Fn(&T) -> &'static str) -> Option<T> {
+    if query.trim().is_empty() {
+        return None;
+    }
+    let lower = query.to_lowercase();
+    if let Some(option) = options.iter().find(|o| name(o).to_lowercase().starts_with(&lower)) {
+        return Some(option.clone());
+    }
+    options
+        .into_iter()
+        .filter_map(|o| fuzzy_score(query, name(&o)).map(|score| (score, o)))
+        .max_by_key(|(score, _)| *score)
+        .map(|(_, o)| o)
+}
+
+/// Starting gravity field text for a base type's typical gravity
+fn default_gravity(base_type: BaseType) -> String {
+    format!("{:.3}", base_type.default_gravity())
+}
+
+/// Default start date for a status: empty while planning, today otherwise
+fn default_start_date(status: &MeadStatus) -> String {
+    match status {
+        MeadStatus::Planning => String::new(),
+        _ => Local::now().format("%Y-%m-%d").to_string(),
+    }
+}
+
 impl Default for NewMeadView {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_dates_are_optional_but_must_parse() {
+        let mut view = NewMeadView::new();
+        assert_eq!(view.validation_error(), None, "no target is fine");
+
+        view.target_date.set_value("next spring");
+        assert!(view.validation_error().unwrap().starts_with("Target date must be a real date"));
+        view.target_date.set_value("2025-02-30");
+        assert!(view.validation_error().is_some());
+
+        view.target_date.set_value(" 2025-09-01 ");
+        assert_eq!(view.validation_error(), None);
+        assert_eq!(view.build_mead().target_date, "2025-09-01");
+    }
+
+    #[test]
+    fn left_and_right_move_between_columns_at_the_same_row() {
+        let mut view = NewMeadView::new();
+        view.current_field = NewMeadField::Name as usize;
+        view.move_to_column(true);
+        assert_eq!(NewMeadField::from_index(view.current_field), NewMeadField::StartingGravity);
+        view.move_to_column(true);
+        assert_eq!(NewMeadField::from_index(view.current_field), NewMeadField::StartingGravity);
+        view.move_to_column(false);
+        assert_eq!(NewMeadField::from_index(view.current_field), NewMeadField::Name);
+    }
+
+    #[test]
+    fn suggested_yan_follows_the_nitrogen_demand() {
+        let mut view = NewMeadView::new();
+        view.starting_gravity.set_value("1.100");
+        view.current_field = NewMeadField::NitrogenDemand as usize;
+        view.type_select_char('h');
+        view.fill_suggested_yan();
+        assert_eq!(view.yan_required.get_value(), "297");
+        view.move_cursor_left();
+        view.fill_suggested_yan();
+        assert_eq!(view.yan_required.get_value(), "214");
+    }
+
+    #[test]
+    fn hotkeys_jump_to_their_field() {
+        let mut view = NewMeadView::new();
+        view.editing = true;
+        assert!(view.jump_to_hotkey('G'));
+        assert_eq!(NewMeadField::from_index(view.current_field), NewMeadField::StartingGravity);
+        assert!(view.starting_gravity.focused);
+        assert!(!view.base_select.focused);
+        assert!(!view.is_editing());
+        assert!(!view.jump_to_hotkey('z'));
+        assert_eq!(NewMeadField::from_index(view.current_field), NewMeadField::StartingGravity);
+    }
+
+    #[test]
+    fn start_dates_must_parse_before_saving() {
+        let mut view = NewMeadView::new();
+        view.set_status(MeadStatus::Primary);
+        assert_eq!(view.validation_error(), None, "today's date is filled in");
+
+        view.start_date.set_value("2025-13-01");
+        assert!(view.validation_error().unwrap().starts_with("Start date must be a real date"));
+        view.start_date.set_value("");
+        assert!(view.validation_error().is_some());
+        view.set_status(MeadStatus::Planning);
+        assert_eq!(view.validation_error(), None, "plans need no start date");
+
+        view.start_date.set_value(" 2025-06-01");
+        assert_eq!(view.validation_error(), None);
+        assert_eq!(view.build_mead().start_date, "2025-06-01");
+        view.start_date.set_value("soon");
+        view.fill_today();
+        assert_eq!(view.validation_error(), None);
+    }
+
+    #[test]
+    fn changed_fields_count_as_entries_to_keep() {
+        let mut view = NewMeadView::new();
+        assert!(!view.has_entries());
+        view.set_status(MeadStatus::Secondary);
+        assert!(view.has_entries(), "a changed status counts");
+
+        let mut view = NewMeadView::new();
+        view.honey_amount.set_value("3.0 ");
+        assert!(!view.has_entries(), "defaults left as they were don't");
+        view.notes.set_value("Use the orange blossom");
+        assert!(view.has_entries());
+    }
+
+    #[test]
+    fn tab_wraps_between_the_first_field_and_submit() {
+        let mut view = NewMeadView::new();
+        assert!(view.base_select.focused);
+        view.previous_field();
+        assert!(view.is_on_submit());
+        assert!(!view.base_select.focused);
+        view.next_field();
+        assert_eq!(NewMeadField::from_index(view.current_field), NewMeadField::Base);
+        view.next_field();
+        assert!(view.name.focused && !view.base_select.focused, "focus follows the field");
+    }
+
+    #[test]
+    fn form_renders_its_title_and_field_labels() {
+        use ratatui::{backend::TestBackend, Terminal};
+        use crate::test_support::screen_text;
+
+        let view = NewMeadView::new();
+        let mut terminal = Terminal::new(TestBackend::new(140, 40)).unwrap();
+        terminal.draw(|frame| view.render(frame)).unwrap();
+        let text = screen_text(&terminal);
+        for label in ["New Mead", "Name", "Starting Gravity", "Yeast Strain", "Volume (gallons)", "Notes"] {
+            assert!(text.contains(label), "missing {}: {}", label, text);
+        }
+    }
+
+    #[test]
+    fn rows_below_the_right_column_land_on_submit() {
+        let mut view = NewMeadView::new();
+        view.current_field = NewMeadField::YeastStrain as usize;
+        view.move_to_column(true);
+        assert!(view.is_on_submit());
+        view.move_to_column(false);
+        assert_eq!(NewMeadField::from_index(view.current_field), NewMeadField::HoneyType);
+    }
+}
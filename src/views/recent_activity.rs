@@ -0,0 +1,473 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::format::{self, fmt_relative};
+use crate::models::{Activity, ActivityFilter, ActivityKind, ActivityType};
+use crate::nav;
+use crate::theme::{self, ThemeView};
+use crate::widgets::InputField;
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+const NORD_GREEN: Color = Color::Rgb(163, 190, 140);    // #A3BE8C
+const NORD_YELLOW: Color = Color::Rgb(235, 203, 139);   // #EBCB8B
+
+/// Number of events loaded into one page of the feed
+pub const PAGE_SIZE: usize = 100;
+
+/// Activity feed across all meads, filtered and a page at a time
+pub struct RecentActivityView {
+    /// Events on the current page, newest first
+    pub items: Vec<Activity>,
+    /// Currently selected index
+    pub selected: usize,
+    /// Whether the feed needs to be reloaded from DB
+    pub needs_refresh: bool,
+    /// What the feed shows
+    pub filter: ActivityFilter,
+    /// Page shown, from 0
+    pub page: usize,
+    /// Events passing the filter across all pages
+    pub total: usize,
+    /// Text search box
+    pub search_input: InputField,
+    /// Whether the search box has focus
+    pub searching: bool,
+    /// First day in the date range form
+    pub since_input: InputField,
+    /// Last day in the date range form
+    pub until_input: InputField,
+    /// Whether the date range form is open
+    pub editing_dates: bool,
+    /// Current date range field (0 = from, 1 = to)
+    pub date_field: usize,
+}
+
+impl RecentActivityView {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            selected: 0,
+            needs_refresh: true,
+            filter: ActivityFilter::default(),
+            page: 0,
+            total: 0,
+            search_input: InputField::new("Search").with_placeholder("Log text or mead name"),
+            searching: false,
+            since_input: InputField::new("From").with_placeholder("blank for no start"),
+            until_input: InputField::new("To").with_placeholder("blank for no end"),
+            editing_dates: false,
+            date_field: 0,
+        }
+    }
+
+    /// Show a page of events out of `total`
+    pub fn set_items(&mut self, items: Vec<Activity>, total: usize) {
+        self.items = items;
+        self.total = total;
+        self.needs_refresh = false;
+        if self.selected >= self.items.len() {
+            self.selected = self.items.len().saturating_sub(1);
+        }
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.total.div_ceil(PAGE_SIZE).max(1)
+    }
+
+    /// Offset of the first event on the current page
+    pub fn offset(&self) -> usize {
+        self.page * PAGE_SIZE
+    }
+
+    pub fn next_page(&mut self) {
+        if self.page + 1 < self.page_count() {
+            self.page += 1;
+            self.selected = 0;
+            self.needs_refresh = true;
+        }
+    }
+
+    pub fn previous_page(&mut self) {
+        if self.page > 0 {
+            self.page -= 1;
+            self.selected = 0;
+            self.needs_refresh = true;
+        }
+    }
+
+    /// Go back to the first page to show a changed filter
+    fn filter_changed(&mut self) {
+        self.page = 0;
+        self.selected = 0;
+        self.needs_refresh = true;
+    }
+
+    /// Move the type filter on to the next event type, then back to all
+    pub fn cycle_type(&mut self) {
+        let types = ActivityType::all();
+        self.filter.activity_type = match &self.filter.activity_type {
+            None => types.first().cloned(),
+            Some(current) => types
+                .iter()
+                .position(|t| t == current)
+                .and_then(|i| types.get(i + 1))
+                .cloned(),
+        };
+        self.filter_changed();
+    }
+
+    /// Show only the selected event's mead, or every mead again
+    pub fn toggle_mead_filter(&mut self) {
+        if self.filter.mead.is_some() {
+            self.filter.mead = None;
+        } else if let Some(item) = self.get_selected() {
+            self.filter.mead = Some((item.mead_id, item.mead_name.clone()));
+        } else {
+            return;
+        }
+        self.filter_changed();
+    }
+
+    /// Drop every filter
+    pub fn clear_filter(&mut self) {
+        self.filter = ActivityFilter::default();
+        self.search_input.clear();
+        self.filter_changed();
+    }
+
+    pub fn open_search(&mut self) {
+        self.search_input.set_focused(true);
+        self.searching = true;
+    }
+
+    /// Stop typing in the search box; `clear` also drops the search
+    pub fn close_search(&mut self, clear: bool) {
+        self.searching = false;
+        self.search_input.set_focused(false);
+        if clear {
+            self.search_input.clear();
+            self.search_changed();
+        }
+    }
+
+    /// Search again for what the search box holds
+    pub fn search_changed(&mut self) {
+        self.filter.query = self.search_input.get_value().trim().to_string();
+        self.filter_changed();
+    }
+
+    /// Open the date range form, filled in with the current range
+    pub fn open_dates(&mut self) {
+        self.since_input.set_value(self.filter.since.map(format::fmt_date).unwrap_or_default());
+        self.until_input.set_value(self.filter.until.map(format::fmt_date).unwrap_or_default());
+        self.date_field = 0;
+        self.update_date_focus();
+        self.editing_dates = true;
+    }
+
+    pub fn next_date_field(&mut self) {
+        self.date_field = nav::next_index(self.date_field, 2);
+        self.update_date_focus();
+    }
+
+    fn update_date_focus(&mut self) {
+        self.since_input.set_focused(self.date_field == 0);
+        self.until_input.set_focused(self.date_field == 1);
+    }
+
+    /// The date range form's field with focus
+    pub fn focused_date_input(&mut self) -> &mut InputField {
+        if self.date_field == 0 {
+            &mut self.since_input
+        } else {
+            &mut self.until_input
+        }
+    }
+
+    /// Filter to the typed date range, leaving the form open with an error
+    /// for a date that doesn't parse or a range that ends before it starts
+    pub fn apply_dates(&mut self) -> Result<(), String> {
+        let parse = |input: &InputField, name: &str| {
+            let text = input.get_value().trim();
+            if text.is_empty() {
+                return Ok(None);
+            }
+            format::parse_date(text)
+                .map(Some)
+                .ok_or_else(|| format!("{} date must be a real date, e.g. 2025-06-01", name))
+        };
+        let since = parse(&self.since_input, "From")?;
+        let until = parse(&self.until_input, "To")?;
+        if let (Some(since), Some(until)) = (since, until) {
+            if until < since {
+                return Err("The range ends before it starts".to_string());
+            }
+        }
+        self.filter.since = since;
+        self.filter.until = until;
+        self.editing_dates = false;
+        self.filter_changed();
+        Ok(())
+    }
+
+    pub fn next(&mut self) {
+        self.selected = nav::next_index(self.selected, self.items.len());
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = nav::previous_index(self.selected, self.items.len());
+    }
+
+    pub fn get_selected(&self) -> Option<&Activity> {
+        self.items.get(self.selected)
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let show_search = self.searching || !self.filter.query.is_empty();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),                              // Title
+                Constraint::Length(1),                              // Filters
+                Constraint::Length(if show_search { 3 } else { 0 }), // Search
+                Constraint::Min(5),                                 // Feed
+                Constraint::Length(3),                              // Controls
+            ])
+            .split(area);
+
+        let accent = theme::accent(ThemeView::Activity);
+        let title = Paragraph::new(Span::styled(
+            "Recent Activity",
+            Style::default().fg(accent).add_modifier(Modifier::BOLD),
+        ))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(accent))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(title, chunks[0]);
+
+        let filters = if self.filter.is_empty() {
+            Span::styled(" All events", Style::default().fg(NORD_GRAY))
+        } else {
+            Span::styled(format!(" {}", self.filter.summary()), Style::default().fg(NORD_YELLOW))
+        };
+        frame.render_widget(Paragraph::new(filters), chunks[1]);
+
+        if show_search {
+            frame.render_widget(&self.search_input, chunks[2]);
+        }
+
+        let feed_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_BLUE))
+            .border_set(border::ROUNDED);
+
+        if self.items.is_empty() {
+            let message = if self.filter.is_empty() {
+                "Nothing has happened yet."
+            } else {
+                "No events match the filters."
+            };
+            let empty = Paragraph::new(message)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(NORD_GRAY))
+                .block(feed_block);
+            frame.render_widget(empty, chunks[3]);
+        } else {
+            let items: Vec<ListItem> = self
+                .items
+                .iter()
+                .map(|item| {
+                    let kind_color = match item.kind {
+                        ActivityKind::Log => NORD_FROST,
+                        ActivityKind::Gravity => NORD_GREEN,
+                        ActivityKind::Status => NORD_CYAN,
+                    };
+                    let kind = item.entry_type.as_ref().map_or(item.kind.as_str(), |t| t.as_str());
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!("{:<14}", fmt_relative(item.at)), Style::default().fg(NORD_GRAY)),
+                        Span::styled(format!("{:<12}", kind), Style::default().fg(kind_color)),
+                        Span::styled(
+                            format!("{}: ", item.mead_name),
+                            Style::default().fg(NORD_WHITE).add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(item.text.clone(), Style::default().fg(NORD_WHITE)),
+                    ]))
+                })
+                .collect();
+
+            let mut title = format!(
+                " Events {}-{} of {} ",
+                self.offset() + 1,
+                self.offset() + self.items.len(),
+                self.total
+            );
+            if self.page_count() > 1 {
+                title.push_str(&format!("· page {}/{} ", self.page + 1, self.page_count()));
+            }
+            let list = List::new(items)
+                .block(feed_block.title(Span::styled(title, Style::default().fg(NORD_FROST))))
+                .highlight_style(
+                    Style::default()
+                        .fg(NORD_BG)
+                        .bg(NORD_CYAN)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("> ");
+
+            let mut state = ListState::default();
+            state.select(Some(self.selected));
+            frame.render_stateful_widget(list, chunks[3], &mut state);
+        }
+
+        if self.editing_dates {
+            self.render_date_form(frame, chunks[3]);
+        }
+
+        let key_style = Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD);
+        let keys: &[(&str, &str)] = if self.editing_dates {
+            &[("Tab", " Next field  "), ("Enter", " Apply  "), ("Esc", " Cancel")]
+        } else if self.searching {
+            &[("Type", " Search  "), ("Enter", " Keep  "), ("Esc", " Clear")]
+        } else {
+            &[
+                ("Up/Down", " Navigate  "),
+                ("Enter", " Open Mead  "),
+                ("/", " Search  "),
+                ("t", " Type  "),
+                ("m", " This Mead  "),
+                ("r", " Dates  "),
+                ("c", " Clear  "),
+                ("[/]", " Page  "),
+                ("x", " Export CSV  "),
+                ("Esc", " Back"),
+            ]
+        };
+        let controls = Line::from(
+            keys.iter()
+                .flat_map(|(key, label)| {
+                    [Span::styled(*key, key_style), Span::styled(*label, Style::default().fg(NORD_WHITE))]
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let controls_widget = Paragraph::new(controls)
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_GRAY))
+                    .border_set(border::ROUNDED),
+            );
+        frame.render_widget(controls_widget, chunks[4]);
+    }
+
+    /// Render the date range form as a popup over the feed
+    fn render_date_form(&self, frame: &mut Frame, area: Rect) {
+        let [popup] = Layout::horizontal([Constraint::Length(40)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(9)])
+            .flex(Flex::Center)
+            .areas(popup);
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(Span::styled(" Date Range ", Style::default().fg(NORD_FROST)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_CYAN))
+            .border_set(border::ROUNDED)
+            .style(Style::default().bg(NORD_BG));
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let [since, until, _] = Layout::vertical([Constraint::Length(3), Constraint::Length(3), Constraint::Min(0)])
+            .horizontal_margin(1)
+            .areas(inner);
+        frame.render_widget(&self.since_input, since);
+        frame.render_widget(&self.until_input, until);
+    }
+}
+
+impl Default for RecentActivityView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LogEntryType;
+    use chrono::Utc;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn screen_text(view: &RecentActivityView) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal.draw(|frame| view.render(frame)).unwrap();
+        crate::test_support::screen_text(&terminal)
+    }
+
+    fn addition(mead_name: &str, text: &str) -> Activity {
+        Activity {
+            mead_id: 1,
+            mead_name: mead_name.to_string(),
+            kind: ActivityKind::Log,
+            entry_type: Some(LogEntryType::Addition),
+            text: text.to_string(),
+            at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn events_render_under_the_filters_in_use() {
+        let mut view = RecentActivityView::new();
+        assert!(screen_text(&view).contains("Nothing has happened yet."));
+
+        view.filter.activity_type = Some(ActivityType::Log(LogEntryType::Addition));
+        assert!(screen_text(&view).contains("No events match the filters."));
+
+        view.set_items(vec![addition("Cyser", "Added juice"), addition("Melomel", "Added cherries")], 2);
+        let text = screen_text(&view);
+        assert!(text.contains("Recent Activity"), "{}", text);
+        assert!(text.contains("Type: Addition"));
+        assert!(text.contains("Events 1-2 of 2"));
+        assert!(text.contains("> just now"), "{}", text);
+        assert!(text.contains("Addition    Cyser: Added juice"));
+        assert!(!text.contains("page 1/"), "one page needs no page count");
+    }
+
+    #[test]
+    fn paging_stops_at_either_end() {
+        let mut view = RecentActivityView::new();
+        view.set_items(Vec::new(), PAGE_SIZE * 2 + 1);
+        view.previous_page();
+        assert_eq!(view.page, 0);
+        for _ in 0..5 {
+            view.next_page();
+        }
+        assert_eq!((view.page, view.page_count(), view.offset()), (2, 3, PAGE_SIZE * 2));
+
+        view.cycle_type();
+        assert_eq!(view.page, 0, "a new filter starts from the first page");
+        assert!(view.needs_refresh);
+    }
+}
@@ -0,0 +1,265 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::format;
+use crate::fuzzy::fuzzy_score;
+use crate::nav;
+use crate::reference::{HoneyInfo, YeastInfo, HONEYS, YEASTS};
+use crate::theme::{self, ThemeView};
+use crate::widgets::InputField;
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+
+/// Which reference table is shown
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReferenceTab {
+    Yeast,
+    Honey,
+}
+
+/// Searchable yeast and honey reference tables
+pub struct ReferenceView {
+    pub tab: ReferenceTab,
+    /// Filters the current table as you type
+    pub search_input: InputField,
+    /// Selected row among the matches
+    pub selected: usize,
+}
+
+impl ReferenceView {
+    pub fn new() -> Self {
+        let mut search_input = InputField::new("Search").with_placeholder("Name, notes or flavor");
+        search_input.set_focused(true);
+        Self {
+            tab: ReferenceTab::Yeast,
+            search_input,
+            selected: 0,
+        }
+    }
+
+    /// Switch between the yeast and honey tables
+    pub fn toggle_tab(&mut self) {
+        self.tab = match self.tab {
+            ReferenceTab::Yeast => ReferenceTab::Honey,
+            ReferenceTab::Honey => ReferenceTab::Yeast,
+        };
+        self.selected = 0;
+    }
+
+    /// Call after the search text changes
+    pub fn search_changed(&mut self) {
+        self.selected = 0;
+    }
+
+    fn match_count(&self) -> usize {
+        match self.tab {
+            ReferenceTab::Yeast => self.yeasts().len(),
+            ReferenceTab::Honey => self.honeys().len(),
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = nav::next_index(self.selected, self.match_count());
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = nav::previous_index(self.selected, self.match_count());
+    }
+
+    /// Entries best matching the search, in table order when the search is empty
+    fn ranked<'a, T>(&self, items: &'a [T], fields: impl Fn(&T) -> [&'static str; 2]) -> Vec<&'a T> {
+        let query = self.search_input.get_value();
+        let mut scored: Vec<(i32, &T)> = items
+            .iter()
+            .filter_map(|item| {
+                let [name, detail] = fields(item);
+                // Rank name matches above notes matches
+                let score = fuzzy_score(query, name)
+                    .map(|s| s + 10)
+                    .max(fuzzy_score(query, detail))?;
+                Some((score, item))
+            })
+            .collect();
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        scored.into_iter().map(|(_, item)| item).collect()
+    }
+
+    fn yeasts(&self) -> Vec<&'static YeastInfo> {
+        self.ranked(YEASTS, |y| [y.name, y.notes])
+    }
+
+    fn honeys(&self) -> Vec<&'static HoneyInfo> {
+        self.ranked(HONEYS, |h| [h.name, h.flavor])
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // Title and tabs
+                Constraint::Length(3), // Search
+                Constraint::Min(5),    // Table
+                Constraint::Length(3), // Controls
+            ])
+            .split(area);
+
+        let tab_style = |tab: ReferenceTab| {
+            if self.tab == tab {
+                Style::default().fg(NORD_BG).bg(NORD_CYAN).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(NORD_GRAY)
+            }
+        };
+        let accent = theme::accent(ThemeView::Reference);
+        let title = Paragraph::new(Line::from(vec![
+            Span::styled("Reference  ", Style::default().fg(accent).add_modifier(Modifier::BOLD)),
+            Span::styled(" Yeast ", tab_style(ReferenceTab::Yeast)),
+            Span::raw(" "),
+            Span::styled(" Honey ", tab_style(ReferenceTab::Honey)),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(accent))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(title, chunks[0]);
+
+        frame.render_widget(&self.search_input, chunks[1]);
+
+        let unit = format::temp_unit();
+        let temp_header = format!("Temp {}", unit.symbol());
+        let (header, rows, widths): (Vec<&str>, Vec<Row>, Vec<Constraint>) = match self.tab {
+            ReferenceTab::Yeast => (
+                vec!["Strain", "Attenuation", &temp_header, "Tolerance", "Notes"],
+                self.yeasts()
+                    .into_iter()
+                    .map(|y| {
+                        Row::new(vec![
+                            y.name.to_string(),
+                            y.attenuation.to_string(),
+                            format!(
+                                "{:.0}-{:.0}",
+                                unit.display(y.temp_range_f.0 as f64),
+                                unit.display(y.temp_range_f.1 as f64)
+                            ),
+                            format!("{:.0}%", y.alcohol_tolerance),
+                            y.notes.to_string(),
+                        ])
+                    })
+                    .collect(),
+                vec![
+                    Constraint::Percentage(22),
+                    Constraint::Percentage(12),
+                    Constraint::Percentage(10),
+                    Constraint::Percentage(10),
+                    Constraint::Percentage(46),
+                ],
+            ),
+            ReferenceTab::Honey => (
+                vec!["Varietal", "Flavor"],
+                self.honeys()
+                    .into_iter()
+                    .map(|h| Row::new(vec![h.name.to_string(), h.flavor.to_string()]))
+                    .collect(),
+                vec![Constraint::Percentage(22), Constraint::Percentage(78)],
+            ),
+        };
+        let count = rows.len();
+
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(header).style(Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            )
+            .style(Style::default().fg(NORD_WHITE))
+            .row_highlight_style(
+                Style::default()
+                    .fg(NORD_BG)
+                    .bg(NORD_CYAN)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .title(Span::styled(format!(" {} matches ", count), Style::default().fg(NORD_FROST)))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_BLUE))
+                    .border_set(border::ROUNDED),
+            );
+
+        let mut state = TableState::default();
+        if count > 0 {
+            state.select(Some(self.selected));
+        }
+        frame.render_stateful_widget(table, chunks[2], &mut state);
+
+        let controls = Line::from(vec![
+            Span::styled("Type", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled(" Search  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled(" Yeast/Honey  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("Up/Down", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled(" Scroll  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+        ]);
+        let controls_widget = Paragraph::new(controls).alignment(Alignment::Center).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_GRAY))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(controls_widget, chunks[3]);
+    }
+}
+
+impl Default for ReferenceView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn screen_text(view: &ReferenceView) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| view.render(frame)).unwrap();
+        crate::test_support::screen_text(&terminal)
+    }
+
+    #[test]
+    fn search_narrows_the_table_shown() {
+        let mut view = ReferenceView::new();
+        let text = screen_text(&view);
+        assert!(text.contains("Strain") && text.contains("Attenuation"), "{}", text);
+        assert!(text.contains(&format!(" {} matches ", YEASTS.len())));
+
+        view.toggle_tab();
+        "buckwheat".chars().for_each(|c| view.search_input.insert_char(c));
+        view.search_changed();
+        let text = screen_text(&view);
+        assert!(text.contains("Varietal"), "{}", text);
+        assert!(text.contains("Buckwheat"));
+        assert!(!text.contains("Orange Blossom"));
+        view.next();
+        assert_eq!(view.selected, 0, "a single match wraps onto itself");
+    }
+}
@@ -0,0 +1,372 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph, Row, Table},
+    Frame,
+};
+
+use chrono::{Datelike, Local};
+
+use crate::models::{AbvBucket, HoneyUsage, MonthCount, StatusDuration};
+use crate::theme::{self, ThemeView};
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+const NORD_YELLOW: Color = Color::Rgb(235, 203, 139);   // #EBCB8B
+
+/// Widest honey name shown before it is cut short
+const MAX_NAME_WIDTH: usize = 24;
+
+/// Totals across all batches
+pub struct StatsView {
+    /// Honey used per variety, most first
+    pub honey_usage: Vec<HoneyUsage>,
+    /// Bottled and finished batches by ABV range, weakest first
+    pub abv_histogram: Vec<AbvBucket>,
+    /// Days batches typically spend in each status, in status order
+    pub status_durations: Vec<StatusDuration>,
+    /// Batches started in each of the past twelve months, oldest first
+    pub monthly_starts: Vec<MonthCount>,
+    /// Whether only batches started this year are counted
+    pub this_year_only: bool,
+    /// Whether the stats need to be reloaded from DB
+    pub needs_refresh: bool,
+}
+
+impl StatsView {
+    pub fn new() -> Self {
+        Self {
+            honey_usage: Vec::new(),
+            abv_histogram: Vec::new(),
+            status_durations: Vec::new(),
+            monthly_starts: Vec::new(),
+            this_year_only: true,
+            needs_refresh: true,
+        }
+    }
+
+    /// Earliest start date counted, or None for all time
+    pub fn since(&self) -> Option<String> {
+        self.this_year_only
+            .then(|| format!("{}-01-01", Local::now().year()))
+    }
+
+    /// Switch between this year and all time
+    pub fn toggle_period(&mut self) {
+        self.this_year_only = !self.this_year_only;
+        self.needs_refresh = true;
+    }
+
+    pub fn set_stats(
+        &mut self,
+        usage: Vec<HoneyUsage>,
+        abv_histogram: Vec<AbvBucket>,
+        durations: Vec<StatusDuration>,
+        monthly_starts: Vec<MonthCount>,
+    ) {
+        self.honey_usage = usage;
+        self.abv_histogram = abv_histogram;
+        self.status_durations = durations;
+        self.monthly_starts = monthly_starts;
+        self.needs_refresh = false;
+    }
+
+    /// "this year (2026)" or "all time"
+    fn period_label(&self) -> String {
+        match self.since() {
+            Some(_) => format!("this year ({})", Local::now().year()),
+            None => "all time".to_string(),
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // Title
+                Constraint::Min(5),    // Honey usage
+                Constraint::Length(6), // ABV histogram
+                Constraint::Length(self.status_durations.len().max(1) as u16 + 3), // Time in status
+                Constraint::Length(3), // Controls
+            ])
+            .split(area);
+
+        let accent = theme::accent(ThemeView::Stats);
+        let title = Paragraph::new(Span::styled(
+            "Statistics",
+            Style::default().fg(accent).add_modifier(Modifier::BOLD),
+        ))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(accent))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(title, chunks[0]);
+
+        let [honey, months] = Layout::horizontal([Constraint::Min(30), Constraint::Length(36)]).areas(chunks[1]);
+        self.render_honey_usage(frame, honey);
+        self.render_monthly_starts(frame, months);
+        self.render_abv_histogram(frame, chunks[2]);
+        self.render_status_durations(frame, chunks[3]);
+
+        let controls = Line::from(vec![
+            Span::styled("y", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled(" This Year/All Time  ", Style::default().fg(NORD_WHITE)),
+            Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled(" Back", Style::default().fg(NORD_WHITE)),
+        ]);
+
+        let controls_widget = Paragraph::new(controls)
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(NORD_GRAY))
+                    .border_set(border::ROUNDED),
+            );
+        frame.render_widget(controls_widget, chunks[4]);
+    }
+
+    /// Honey per variety as bars scaled to the largest amount
+    fn render_honey_usage(&self, frame: &mut Frame, area: Rect) {
+        let period = self.period_label();
+        let total: f64 = self.honey_usage.iter().map(|u| u.lbs).sum();
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Honey used, {}: {:.1} lbs ", period, total),
+                Style::default().fg(NORD_FROST),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_BLUE))
+            .border_set(border::ROUNDED);
+
+        if self.honey_usage.is_empty() {
+            let empty = Paragraph::new("No honey used yet.")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(NORD_GRAY))
+                .block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let names: Vec<String> = self
+            .honey_usage
+            .iter()
+            .map(|u| {
+                let name = if u.honey_type.is_empty() { "(unspecified)" } else { u.honey_type.as_str() };
+                name.chars().take(MAX_NAME_WIDTH).collect()
+            })
+            .collect();
+        let name_width = names.iter().map(|n| n.chars().count()).max().unwrap_or(0);
+        let amounts: Vec<String> = self
+            .honey_usage
+            .iter()
+            .map(|u| {
+                let noun = if u.batches == 1 { "batch" } else { "batches" };
+                format!("{:.1} lbs ({} {})", u.lbs, u.batches, noun)
+            })
+            .collect();
+        let amount_width = amounts.iter().map(|a| a.len()).max().unwrap_or(0);
+
+        // Space left for the bar between the name and amount columns
+        let inner_width = area.width.saturating_sub(4) as usize;
+        let bar_width = inner_width.saturating_sub(name_width + amount_width + 2).max(1);
+        let max_lbs = self.honey_usage.iter().map(|u| u.lbs).fold(0.0, f64::max);
+
+        let lines: Vec<Line> = self
+            .honey_usage
+            .iter()
+            .zip(names.iter().zip(&amounts))
+            .map(|(usage, (name, amount))| {
+                let filled = if max_lbs > 0.0 {
+                    ((usage.lbs / max_lbs) * bar_width as f64).round() as usize
+                } else {
+                    0
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:<width$} ", name, width = name_width), Style::default().fg(NORD_WHITE)),
+                    Span::styled("█".repeat(filled), Style::default().fg(NORD_YELLOW)),
+                    Span::raw(" ".repeat(bar_width - filled + 1)),
+                    Span::styled(format!("{:>width$}", amount, width = amount_width), Style::default().fg(NORD_GRAY)),
+                ])
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(block.padding(Padding::horizontal(1))), area);
+    }
+
+    /// Batches started per month over the past year as bars scaled to the
+    /// busiest month. When the area is short the latest months are kept.
+    fn render_monthly_starts(&self, frame: &mut Frame, area: Rect) {
+        let total: usize = self.monthly_starts.iter().map(|m| m.batches).sum();
+        let block = Block::default()
+            .title(Span::styled(format!(" Started, past year: {} ", total), Style::default().fg(NORD_FROST)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_BLUE))
+            .border_set(border::ROUNDED);
+
+        let count_width = total.to_string().len();
+        let inner_width = area.width.saturating_sub(4) as usize;
+        // "YYYY-MM " before the bar and the count after it
+        let bar_width = inner_width.saturating_sub(8 + count_width + 1).max(1);
+        let max = self.monthly_starts.iter().map(|m| m.batches).max().unwrap_or(0);
+        let shown = self.monthly_starts.len().min(area.height.saturating_sub(2) as usize);
+
+        let lines: Vec<Line> = self.monthly_starts[self.monthly_starts.len() - shown..]
+            .iter()
+            .map(|month| {
+                let filled = if max > 0 {
+                    (month.batches as f64 / max as f64 * bar_width as f64).round() as usize
+                } else {
+                    0
+                };
+                Line::from(vec![
+                    Span::styled(format!("{} ", month.month), Style::default().fg(NORD_WHITE)),
+                    Span::styled("█".repeat(filled), Style::default().fg(NORD_YELLOW)),
+                    Span::raw(" ".repeat(bar_width - filled + 1)),
+                    Span::styled(format!("{:>width$}", month.batches, width = count_width), Style::default().fg(NORD_GRAY)),
+                ])
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(block.padding(Padding::horizontal(1))), area);
+    }
+
+    /// Bottled and finished batches per ABV range as bars scaled to the
+    /// fullest range
+    fn render_abv_histogram(&self, frame: &mut Frame, area: Rect) {
+        let total: usize = self.abv_histogram.iter().map(|b| b.batches).sum();
+        let noun = if total == 1 { "batch" } else { "batches" };
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Finished ABV, {}: {} {} ", self.period_label(), total, noun),
+                Style::default().fg(NORD_FROST),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_BLUE))
+            .border_set(border::ROUNDED);
+
+        if total == 0 {
+            let empty = Paragraph::new("No finished batches yet.")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(NORD_GRAY))
+                .block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let label_width = self.abv_histogram.iter().map(|b| b.label.len()).max().unwrap_or(0);
+        let count_width = total.to_string().len();
+        let inner_width = area.width.saturating_sub(4) as usize;
+        let bar_width = inner_width.saturating_sub(label_width + count_width + 2).max(1);
+        let max = self.abv_histogram.iter().map(|b| b.batches).max().unwrap_or(0);
+
+        let lines: Vec<Line> = self
+            .abv_histogram
+            .iter()
+            .map(|bucket| {
+                let filled = if max > 0 {
+                    (bucket.batches as f64 / max as f64 * bar_width as f64).round() as usize
+                } else {
+                    0
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:>width$} ", bucket.label, width = label_width), Style::default().fg(NORD_WHITE)),
+                    Span::styled("█".repeat(filled), Style::default().fg(NORD_YELLOW)),
+                    Span::raw(" ".repeat(bar_width - filled + 1)),
+                    Span::styled(format!("{:>width$}", bucket.batches, width = count_width), Style::default().fg(NORD_GRAY)),
+                ])
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(block.padding(Padding::horizontal(1))), area);
+    }
+
+    /// Average and median days per status as a table
+    fn render_status_durations(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Time in status, {} ", self.period_label()),
+                Style::default().fg(NORD_FROST),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_BLUE))
+            .border_set(border::ROUNDED);
+
+        if self.status_durations.is_empty() {
+            let empty = Paragraph::new("No batch has moved on from a status yet.")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(NORD_GRAY))
+                .block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let days = |d: f64| format!("{:.1} days", d);
+        let rows: Vec<Row> = self
+            .status_durations
+            .iter()
+            .map(|duration| {
+                Row::new(vec![
+                    Span::styled(duration.status.as_str(), Style::default().fg(theme::status_color(&duration.status))),
+                    Span::styled(duration.stays.to_string(), Style::default().fg(NORD_GRAY)),
+                    Span::styled(days(duration.average_days), Style::default().fg(NORD_WHITE)),
+                    Span::styled(days(duration.median_days), Style::default().fg(NORD_WHITE)),
+                ])
+            })
+            .collect();
+        let header = Row::new(["Status", "Batches", "Average", "Median"])
+            .style(Style::default().fg(NORD_FROST).add_modifier(Modifier::BOLD));
+        let table = Table::new(
+            rows,
+            [Constraint::Length(10), Constraint::Length(8), Constraint::Length(12), Constraint::Length(12)],
+        )
+        .header(header)
+        .block(block.padding(Padding::horizontal(1)));
+        frame.render_widget(table, area);
+    }
+}
+
+impl Default for StatsView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn screen_text(view: &StatsView) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|frame| view.render(frame)).unwrap();
+        crate::test_support::screen_text(&terminal)
+    }
+
+    #[test]
+    fn stats_render_each_section_or_its_empty_state() {
+        let mut view = StatsView::new();
+        assert!(screen_text(&view).contains("No honey used yet."));
+
+        view.toggle_period();
+        let usage = vec![HoneyUsage { honey_type: "Orange Blossom".to_string(), lbs: 6.0, batches: 2 }];
+        view.set_stats(usage, crate::models::abv_histogram(&[11.0]), Vec::new(), Vec::new());
+        let text = screen_text(&view);
+        assert!(text.contains("Statistics"), "{}", text);
+        assert!(text.contains("Honey used, all time: 6.0 lbs"));
+        assert!(text.contains("Orange Blossom"));
+        assert!(text.contains("This Year/All Time"));
+    }
+}
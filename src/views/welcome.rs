@@ -0,0 +1,221 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::nav;
+use crate::models::{Ingredient, IngredientType, LogEntry, LogEntryType, Mead, MeadStatus};
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_BLUE: Color = Color::Rgb(0, 103, 230);       // #0067E6
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+
+/// What the user picked on the welcome screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WelcomeChoice {
+    SampleMead,
+    NewMead,
+    Skip,
+}
+
+/// One-time welcome screen shown on first launch with an empty database
+pub struct WelcomeView {
+    /// Currently selected option
+    pub selected: usize,
+}
+
+impl WelcomeView {
+    const CHOICES: [(WelcomeChoice, &'static str); 3] = [
+        (WelcomeChoice::SampleMead, "Create a sample mead to explore"),
+        (WelcomeChoice::NewMead, "Start my first mead"),
+        (WelcomeChoice::Skip, "Skip to the main menu"),
+    ];
+
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = nav::next_index(self.selected, Self::CHOICES.len());
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = nav::previous_index(self.selected, Self::CHOICES.len());
+    }
+
+    pub fn get_choice(&self) -> WelcomeChoice {
+        Self::CHOICES[self.selected].0
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([
+                Constraint::Min(12),   // Introduction
+                Constraint::Length(5), // Choices
+                Constraint::Length(3), // Controls
+            ])
+            .split(area);
+
+        let label = Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD);
+        let text = Style::default().fg(NORD_WHITE);
+        let intro = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                " Welcome to Mead Tracker ",
+                Style::default().fg(NORD_FROST).add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Here's how a batch moves through the app:",
+                Style::default().fg(NORD_GRAY),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("1. New Mead  ", label),
+                Span::styled("Record the honey, yeast, volume and starting gravity.", text),
+            ]),
+            Line::from(vec![
+                Span::styled("2. Details   ", label),
+                Span::styled("Open a batch to update gravity, add ingredients and log notes.", text),
+            ]),
+            Line::from(vec![
+                Span::styled("3. Status    ", label),
+                Span::styled("Advance it from Primary through Aging to Bottled as it progresses.", text),
+            ]),
+            Line::from(vec![
+                Span::styled("4. Reminders ", label),
+                Span::styled("Set due dates for nutrient additions and racking.", text),
+            ]),
+        ];
+
+        let intro_widget = Paragraph::new(intro).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_FROST))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(intro_widget, chunks[0]);
+
+        let items: Vec<ListItem> = Self::CHOICES
+            .iter()
+            .enumerate()
+            .map(|(i, (_, label))| {
+                let style = if i == self.selected {
+                    Style::default()
+                        .fg(NORD_BG)
+                        .bg(NORD_CYAN)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(NORD_WHITE)
+                };
+                let prefix = if i == self.selected { "> " } else { "  " };
+                ListItem::new(Line::from(format!("{}{}", prefix, label))).style(style)
+            })
+            .collect();
+
+        let choices = List::new(items).block(
+            Block::default()
+                .title(Span::styled(" Get Started ", label))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_BLUE))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(choices, chunks[1]);
+
+        let controls = Line::from(vec![
+            Span::styled("Up/Down", label),
+            Span::styled(" Navigate  ", text),
+            Span::styled("Enter", label),
+            Span::styled(" Select  ", text),
+            Span::styled("Esc", label),
+            Span::styled(" Skip", text),
+        ]);
+        let controls_widget = Paragraph::new(controls).alignment(Alignment::Center).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(NORD_GRAY))
+                .border_set(border::ROUNDED),
+        );
+        frame.render_widget(controls_widget, chunks[2]);
+    }
+}
+
+/// A traditional mead in primary, for exploring the app
+pub fn sample_mead() -> Mead {
+    Mead {
+        name: "Sample Traditional".to_string(),
+        honey_type: "Wildflower".to_string(),
+        honey_amount_lbs: 3.0,
+        yeast_strain: "Lalvin 71B".to_string(),
+        target_abv: 12.0,
+        starting_gravity: 1.100,
+        current_gravity: 1.060,
+        yan_required: 180.0,
+        yan_added: 90.0,
+        volume_gallons: 1.0,
+        status: MeadStatus::Primary,
+        notes: "A sample batch. Edit or delete it from the mead list.".to_string(),
+        ..Default::default()
+    }
+}
+
+/// Ingredients and a log entry to go with the sample mead
+pub fn sample_extras(mead_id: i64) -> (Ingredient, LogEntry) {
+    let ingredient = Ingredient {
+        mead_id,
+        ingredient_type: IngredientType::Nutrient,
+        name: "Fermaid O".to_string(),
+        amount: 2.0,
+        unit: "g".to_string(),
+        ..Default::default()
+    };
+    let entry = LogEntry {
+        mead_id,
+        entry_text: "Pitched yeast and added the first nutrient dose.".to_string(),
+        entry_type: LogEntryType::Action,
+        ..Default::default()
+    };
+    (ingredient, entry)
+}
+
+impl Default for WelcomeView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use crate::test_support::screen_text;
+
+    #[test]
+    fn choices_render_and_wrap_around() {
+        let mut view = WelcomeView::new();
+        view.previous();
+        assert_eq!(view.get_choice(), WelcomeChoice::Skip);
+        view.next();
+        assert_eq!(view.get_choice(), WelcomeChoice::SampleMead);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|frame| view.render(frame)).unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("Welcome to Mead Tracker"), "{}", text);
+        assert!(text.contains("> Create a sample mead to explore"));
+        assert!(text.contains("  Skip to the main menu"));
+    }
+}
@@ -0,0 +1,69 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+// Nord-adjacent color palette
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+const NORD_YELLOW: Color = Color::Rgb(235, 203, 139);   // #EBCB8B
+
+/// A centered yes/no prompt drawn over the current view
+#[derive(Debug, Clone)]
+pub struct ConfirmDialog {
+    /// Question shown to the user, e.g. "Overwrite meads.csv?"
+    pub message: String,
+}
+
+impl ConfirmDialog {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Widget for &ConfirmDialog {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [popup] = Layout::horizontal([Constraint::Percentage(50)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(6)])
+            .flex(Flex::Center)
+            .areas(popup);
+
+        Clear.render(popup, buf);
+
+        let block = Block::default()
+            .title(Span::styled(
+                " Confirm ",
+                Style::default().fg(NORD_YELLOW).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_YELLOW))
+            .border_set(border::ROUNDED)
+            .style(Style::default().bg(NORD_BG));
+
+        let text = vec![
+            Line::from(Span::styled(self.message.as_str(), Style::default().fg(NORD_WHITE))),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("y", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Yes  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("n/Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" No", Style::default().fg(NORD_WHITE)),
+            ]),
+        ];
+
+        Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(block)
+            .render(popup, buf);
+    }
+}
@@ -0,0 +1,114 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use crate::format::fmt_gravity;
+use crate::models::{MAX_GRAVITY, MIN_GRAVITY};
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+
+/// Gravity moved by one Left/Right press
+const STEP: f64 = 0.005;
+/// Gravity moved by one Shift+Left/Right press
+const FINE_STEP: f64 = 0.001;
+
+/// A horizontal slider across the accepted gravity range, for approximate
+/// entry without typing. Takes four rows: the value sits above the track.
+#[derive(Debug, Clone)]
+pub struct GravitySlider {
+    /// The label for this field
+    pub label: String,
+    /// Gravity the handle sits at
+    pub value: f64,
+    /// Whether this field is currently focused
+    pub focused: bool,
+}
+
+impl GravitySlider {
+    pub fn new(label: impl Into<String>, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            focused: false,
+        }
+    }
+
+    /// `value` moved a step up or down (a small one when `fine`), kept to
+    /// the accepted gravity range and whole thousandths
+    pub fn nudge(value: f64, up: bool, fine: bool) -> f64 {
+        let step = if fine { FINE_STEP } else { STEP };
+        let moved = if up { value + step } else { value - step };
+        ((moved * 1000.0).round() / 1000.0).clamp(MIN_GRAVITY, MAX_GRAVITY)
+    }
+
+    /// Column of the handle on a track `width` cells wide
+    fn handle_column(&self, width: usize) -> usize {
+        let fraction = ((self.value - MIN_GRAVITY) / (MAX_GRAVITY - MIN_GRAVITY)).clamp(0.0, 1.0);
+        (fraction * width.saturating_sub(1) as f64).round() as usize
+    }
+}
+
+impl Widget for &GravitySlider {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let border_style = if self.focused {
+            Style::default().fg(NORD_CYAN)
+        } else {
+            Style::default().fg(NORD_GRAY)
+        };
+
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" {} ", self.label),
+                if self.focused {
+                    Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(NORD_FROST)
+                },
+            ))
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .border_set(border::ROUNDED);
+
+        let width = block.inner(area).width as usize;
+        let handle = self.handle_column(width);
+        let lines = vec![
+            Line::from(Span::styled(
+                fmt_gravity(self.value),
+                Style::default().fg(NORD_WHITE).add_modifier(Modifier::BOLD),
+            ))
+            .centered(),
+            Line::from(vec![
+                Span::styled("━".repeat(handle), Style::default().fg(NORD_CYAN)),
+                Span::styled("●", Style::default().fg(NORD_WHITE)),
+                Span::styled("─".repeat(width.saturating_sub(handle + 1)), Style::default().fg(NORD_GRAY)),
+            ]),
+        ];
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nudges_stay_on_thousandths_within_range() {
+        assert_eq!(GravitySlider::nudge(1.050, true, false), 1.055);
+        assert_eq!(GravitySlider::nudge(1.050, false, true), 1.049);
+        assert_eq!(GravitySlider::nudge(MAX_GRAVITY, true, false), MAX_GRAVITY);
+        assert_eq!(GravitySlider::nudge(0.982, false, false), MIN_GRAVITY);
+
+        let slider = GravitySlider::new("Gravity", MIN_GRAVITY);
+        assert_eq!(slider.handle_column(21), 0);
+        assert_eq!(GravitySlider { value: MAX_GRAVITY, ..slider }.handle_column(21), 20);
+    }
+}
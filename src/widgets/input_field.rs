@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -13,6 +15,40 @@ const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
 const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
 const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
 
+/// Maximum number of undo steps kept per field
+const UNDO_LIMIT: usize = 100;
+
+thread_local! {
+    /// Whether a blinking cursor is in its off phase; set by the app's tick
+    static CURSOR_OFF: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Hide or show the cursor in every text field, for blinking
+pub fn set_cursor_off(off: bool) {
+    CURSOR_OFF.set(off);
+}
+
+/// The character under the cursor, as an inverse block, or as plain text
+/// while a blink has it hidden. Past the end of the text it is a space so
+/// the block still shows and nothing after it shifts when it blinks.
+pub fn cursor_span(at: Option<char>, text_style: Style) -> Span<'static> {
+    let style = if CURSOR_OFF.get() {
+        text_style
+    } else {
+        Style::default().bg(NORD_CYAN).fg(NORD_BG)
+    };
+    Span::styled(at.unwrap_or(' ').to_string(), style)
+}
+
+/// Kind of the most recent edit, used to coalesce typing into one undo step
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EditKind {
+    None,
+    Insert,
+    Delete,
+    Step,
+}
+
 /// A text input field widget
 #[derive(Debug, Clone)]
 pub struct InputField {
@@ -26,6 +62,22 @@ pub struct InputField {
     pub focused: bool,
     /// Placeholder text when empty
     pub placeholder: String,
+    /// Help shown dimmed along the bottom border while focused
+    pub hint: String,
+    /// Letter that jumps to this field with Alt, shown dimmed after the
+    /// label while unfocused
+    pub hotkey: Option<char>,
+    /// Snapshots of (value, cursor) to restore on undo
+    undo_stack: Vec<(String, usize)>,
+    /// Snapshots of (value, cursor) to restore on redo
+    redo_stack: Vec<(String, usize)>,
+    /// Kind of the last edit
+    last_edit: EditKind,
+    /// Only accept characters that can make up a number
+    numeric: bool,
+    /// Read two or three bare digits as a specific gravity, e.g. "050"
+    /// as 1.050
+    gravity: bool,
 }
 
 impl InputField {
@@ -36,9 +88,30 @@ impl InputField {
             cursor: 0,
             focused: false,
             placeholder: String::new(),
+            hint: String::new(),
+            hotkey: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            last_edit: EditKind::None,
+            numeric: false,
+            gravity: false,
         }
     }
 
+    /// Restrict typing to digits, a sign and a decimal point
+    pub fn numeric(mut self) -> Self {
+        self.numeric = true;
+        self
+    }
+
+    /// Numeric, and also accept gravity shorthand: "50" or "050" for
+    /// 1.050, and 900 up for 0.9xx
+    pub fn gravity(mut self) -> Self {
+        self.numeric = true;
+        self.gravity = true;
+        self
+    }
+
     pub fn with_value(mut self, value: impl Into<String>) -> Self {
         self.value = value.into();
         self.cursor = self.value.len();
@@ -50,8 +123,60 @@ impl InputField {
         self
     }
 
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = hint.into();
+        self
+    }
+
+    pub fn with_hotkey(mut self, hotkey: char) -> Self {
+        self.hotkey = Some(hotkey);
+        self
+    }
+
+    /// Record the current state before an edit. Consecutive edits of the
+    /// same kind share one undo step.
+    fn snapshot(&mut self, kind: EditKind) {
+        if kind != self.last_edit {
+            self.undo_stack.push((self.value.clone(), self.cursor));
+            if self.undo_stack.len() > UNDO_LIMIT {
+                self.undo_stack.remove(0);
+            }
+        }
+        self.redo_stack.clear();
+        self.last_edit = kind;
+    }
+
+    /// Forget undo/redo history
+    fn reset_history(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.last_edit = EditKind::None;
+    }
+
+    /// Undo the last edit
+    pub fn undo(&mut self) {
+        if let Some((value, cursor)) = self.undo_stack.pop() {
+            self.redo_stack.push((std::mem::replace(&mut self.value, value), self.cursor));
+            self.cursor = cursor;
+            self.last_edit = EditKind::None;
+        }
+    }
+
+    /// Redo the last undone edit
+    pub fn redo(&mut self) {
+        if let Some((value, cursor)) = self.redo_stack.pop() {
+            self.undo_stack.push((std::mem::replace(&mut self.value, value), self.cursor));
+            self.cursor = cursor;
+            self.last_edit = EditKind::None;
+        }
+    }
+
     /// Insert a character at the cursor position
     pub fn insert_char(&mut self, c: char) {
+        if self.numeric && !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')) {
+            return;
+        }
+        self.snapshot(EditKind::Insert);
         self.value.insert(self.cursor, c);
         self.cursor += 1;
     }
@@ -59,6 +184,7 @@ impl InputField {
     /// Delete the character before the cursor (backspace)
     pub fn delete_char(&mut self) {
         if self.cursor > 0 {
+            self.snapshot(EditKind::Delete);
             self.cursor -= 1;
             self.value.remove(self.cursor);
         }
@@ -67,6 +193,7 @@ impl InputField {
     /// Delete the character at the cursor (delete key)
     pub fn delete_char_forward(&mut self) {
         if self.cursor < self.value.len() {
+            self.snapshot(EditKind::Delete);
             self.value.remove(self.cursor);
         }
     }
@@ -76,6 +203,7 @@ impl InputField {
         if self.cursor > 0 {
             self.cursor -= 1;
         }
+        self.last_edit = EditKind::None;
     }
 
     /// Move cursor right
@@ -83,22 +211,26 @@ impl InputField {
         if self.cursor < self.value.len() {
             self.cursor += 1;
         }
+        self.last_edit = EditKind::None;
     }
 
     /// Move cursor to start
     pub fn move_cursor_start(&mut self) {
         self.cursor = 0;
+        self.last_edit = EditKind::None;
     }
 
     /// Move cursor to end
     pub fn move_cursor_end(&mut self) {
         self.cursor = self.value.len();
+        self.last_edit = EditKind::None;
     }
 
     /// Clear the field
     pub fn clear(&mut self) {
         self.value.clear();
         self.cursor = 0;
+        self.reset_history();
     }
 
     /// Get the value as a string
@@ -110,15 +242,49 @@ impl InputField {
     pub fn set_value(&mut self, value: impl Into<String>) {
         self.value = value.into();
         self.cursor = self.value.len();
+        self.reset_history();
     }
 
-    /// Parse the value as f64
+    /// Parse the value as f64. Rejects "inf", "nan" and anything else
+    /// that parses to a non-finite number.
     pub fn get_f64(&self) -> Option<f64> {
-        self.value.parse().ok()
+        self.shorthand_gravity()
+            .or_else(|| self.value.trim().parse().ok())
+            .filter(|v: &f64| v.is_finite())
     }
 
-    /// Set focus state
+    /// Add `step` to a numeric value, or take it away when going down,
+    /// stopping at 0. A blank value counts as 0, and the result keeps the
+    /// decimal places typed or the step's, whichever is more.
+    pub fn step(&mut self, up: bool, step: f64) {
+        if !self.numeric {
+            return;
+        }
+        let decimals = |text: &str| text.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+        let current = self.get_f64().unwrap_or(0.0);
+        let moved = if up { current + step } else { current - step }.max(0.0);
+        let places = decimals(self.value.trim()).max(decimals(&step.to_string()));
+        self.snapshot(EditKind::Step);
+        self.value = format!("{:.*}", places, moved);
+        self.cursor = self.value.len();
+    }
+
+    /// The gravity typed as shorthand, if this is a gravity field and the
+    /// value is two or three bare digits
+    fn shorthand_gravity(&self) -> Option<f64> {
+        let digits = self.value.trim();
+        if !self.gravity || !(2..=3).contains(&digits.len()) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let points: f64 = digits.parse().ok()?;
+        Some(if points >= 900.0 { points / 1000.0 } else { 1.0 + points / 1000.0 })
+    }
+
+    /// Focus or unfocus the field; leaving it ends the current undo step
     pub fn set_focused(&mut self, focused: bool) {
+        if focused != self.focused {
+            self.last_edit = EditKind::None;
+        }
         self.focused = focused;
     }
 }
@@ -131,17 +297,26 @@ impl Widget for &InputField {
             Style::default().fg(NORD_GRAY)
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .border_style(border_style)
-            .title(Span::styled(
-                format!(" {} ", self.label),
-                if self.focused {
-                    Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(NORD_FROST)
-                },
+            .title(Line::from(vec![
+                Span::styled(
+                    format!(" {} ", self.label),
+                    if self.focused {
+                        Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(NORD_FROST)
+                    },
+                ),
+                hotkey_span(self.hotkey, self.focused),
+            ]));
+        if self.focused && !self.hint.is_empty() {
+            block = block.title_bottom(Span::styled(
+                format!(" {} ", self.hint),
+                Style::default().fg(NORD_GRAY).add_modifier(Modifier::DIM),
             ));
+        }
 
         let inner = block.inner(area);
         block.render(area, buf);
@@ -155,16 +330,17 @@ impl Widget for &InputField {
         } else if self.focused {
             // Show cursor
             let before_cursor: String = self.value.chars().take(self.cursor).collect();
-            let cursor_char = self.value.chars().nth(self.cursor).unwrap_or(' ');
+            let cursor_char = self.value.chars().nth(self.cursor);
             let after_cursor: String = self.value.chars().skip(self.cursor + 1).collect();
 
             Line::from(vec![
                 Span::styled(before_cursor, Style::default().fg(NORD_WHITE)),
+                cursor_span(cursor_char, Style::default().fg(NORD_WHITE)),
+                Span::styled(after_cursor, Style::default().fg(NORD_WHITE)),
                 Span::styled(
-                    cursor_char.to_string(),
-                    Style::default().bg(NORD_CYAN).fg(NORD_BG),
+                    self.shorthand_gravity().map(|g| format!(" = {:.3}", g)).unwrap_or_default(),
+                    Style::default().fg(NORD_GRAY),
                 ),
-                Span::styled(after_cursor, Style::default().fg(NORD_WHITE)),
             ])
         } else {
             Line::from(Span::styled(self.value.as_str(), Style::default().fg(NORD_WHITE)))
@@ -174,3 +350,115 @@ impl Widget for &InputField {
     }
 }
 
+/// "Alt+G " dimmed, for a field's hotkey; empty while focused or without one
+pub fn hotkey_span(hotkey: Option<char>, focused: bool) -> Span<'static> {
+    match hotkey {
+        Some(key) if !focused => Span::styled(
+            format!("Alt+{} ", key.to_ascii_uppercase()),
+            Style::default().fg(NORD_GRAY).add_modifier(Modifier::DIM),
+        ),
+        _ => Span::raw(""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_typing_undoes_as_one_step() {
+        let mut field = InputField::new("t");
+        "wild".chars().for_each(|c| field.insert_char(c));
+        field.delete_char();
+        field.delete_char();
+        assert_eq!(field.get_value(), "wi");
+
+        // Both deletes go together, then all the typing
+        field.undo();
+        assert_eq!(field.get_value(), "wild");
+        field.undo();
+        assert_eq!(field.get_value(), "");
+        field.redo();
+        assert_eq!(field.get_value(), "wild");
+    }
+
+    #[test]
+    fn leaving_the_field_starts_a_new_undo_step() {
+        let mut field = InputField::new("t");
+        field.set_focused(true);
+        "clover".chars().for_each(|c| field.insert_char(c));
+        field.set_focused(false);
+        field.set_focused(true);
+        " honey".chars().for_each(|c| field.insert_char(c));
+
+        field.undo();
+        assert_eq!(field.get_value(), "clover");
+        field.undo();
+        assert_eq!(field.get_value(), "");
+    }
+
+    #[test]
+    fn get_f64_rejects_non_finite_values() {
+        for text in ["inf", "-inf", "infinity", "NaN", "1e400"] {
+            assert_eq!(InputField::new("n").with_value(text).get_f64(), None, "{}", text);
+        }
+        assert_eq!(InputField::new("n").with_value(" 1.050 ").get_f64(), Some(1.050));
+    }
+
+    #[test]
+    fn gravity_fields_read_bare_digits_as_points() {
+        let gravity = |text: &str| InputField::new("g").gravity().with_value(text).get_f64();
+        assert_eq!(gravity("050"), Some(1.050));
+        assert_eq!(gravity("50"), Some(1.050));
+        assert_eq!(gravity("120"), Some(1.120));
+        assert_eq!(gravity("996"), Some(0.996));
+        assert_eq!(gravity("1.050"), Some(1.050));
+        assert_eq!(gravity("1"), Some(1.0));
+        // Plain numeric fields keep the number as typed
+        assert_eq!(InputField::new("n").numeric().with_value("050").get_f64(), Some(50.0));
+    }
+
+    #[test]
+    fn numeric_mode_ignores_letters() {
+        let mut field = InputField::new("n").numeric();
+        for c in "1.0x5inf".chars() {
+            field.insert_char(c);
+        }
+        assert_eq!(field.get_value(), "1.05");
+    }
+
+    #[test]
+    fn stepping_keeps_decimals_and_stops_at_zero() {
+        let mut field = InputField::new("n").numeric().with_value("2");
+        field.step(true, 0.1);
+        assert_eq!(field.get_value(), "2.1");
+        field.step(true, 1.0);
+        assert_eq!(field.get_value(), "3.1");
+        field.step(false, 0.1);
+        field.step(false, 0.1);
+        assert_eq!(field.get_value(), "2.9");
+        field.undo();
+        assert_eq!(field.get_value(), "2");
+
+        let mut blank = InputField::new("n").numeric();
+        blank.step(false, 1.0);
+        assert_eq!(blank.get_value(), "0");
+        let mut text = InputField::new("t").with_value("oz");
+        text.step(true, 1.0);
+        assert_eq!(text.get_value(), "oz");
+    }
+
+    #[test]
+    fn blinking_cursor_keeps_its_cell_at_the_end_of_the_text() {
+        let text = Style::default().fg(NORD_WHITE);
+        let shown = cursor_span(None, text);
+        assert_eq!(shown.content, " ");
+        assert_eq!(shown.style.bg, Some(NORD_CYAN));
+
+        set_cursor_off(true);
+        let hidden = cursor_span(Some('a'), text);
+        set_cursor_off(false);
+        assert_eq!(hidden.content, "a");
+        assert_eq!(hidden.style, text);
+    }
+}
@@ -1,4 +1,13 @@
+pub mod confirm_dialog;
+pub mod gravity_slider;
 pub mod input_field;
+pub mod quick_calc;
+pub mod select_field;
+pub mod text_area;
 
-pub use input_field::InputField;
-
+pub use confirm_dialog::ConfirmDialog;
+pub use gravity_slider::GravitySlider;
+pub use input_field::{hotkey_span, InputField};
+pub use quick_calc::QuickCalculator;
+pub use select_field::SelectField;
+pub use text_area::TextArea;
@@ -0,0 +1,240 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::calc::{abv_from_gravities, gravity_from_brix, honey_gravity};
+use crate::format::{celsius_to_fahrenheit, fahrenheit_to_celsius, fmt_abv, fmt_gravity};
+use crate::nutrients::brix;
+
+use super::{InputField, SelectField};
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+const NORD_BG: Color = Color::Rgb(46, 52, 64);          // #2E3440
+
+/// A conversion the quick calculator can do
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalcMode {
+    BrixGravity,
+    Temperature,
+    Abv,
+    HoneyGravity,
+}
+
+impl CalcMode {
+    pub const ALL: [CalcMode; 4] = [CalcMode::BrixGravity, CalcMode::Temperature, CalcMode::Abv, CalcMode::HoneyGravity];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CalcMode::BrixGravity => "Brix ↔ SG",
+            CalcMode::Temperature => "°F ↔ °C",
+            CalcMode::Abv => "ABV from OG/FG",
+            CalcMode::HoneyGravity => "Honey → OG",
+        }
+    }
+
+    /// Whether each field converts into the other, rather than both
+    /// feeding one result
+    fn two_way(&self) -> bool {
+        matches!(self, CalcMode::BrixGravity | CalcMode::Temperature)
+    }
+
+    /// The two input fields for this conversion
+    fn inputs(&self) -> [InputField; 2] {
+        match self {
+            CalcMode::BrixGravity => [InputField::new("°Brix").numeric(), InputField::new("Specific Gravity").gravity()],
+            CalcMode::Temperature => [InputField::new("°F").numeric(), InputField::new("°C").numeric()],
+            CalcMode::Abv => [
+                InputField::new("Original Gravity").gravity(),
+                InputField::new("Final Gravity").gravity(),
+            ],
+            CalcMode::HoneyGravity => [
+                InputField::new("Honey (lbs)").numeric(),
+                InputField::new("Volume (gal)").numeric(),
+            ],
+        }
+    }
+}
+
+/// A small modal for the common conversions, usable from any view. The
+/// first row picks the conversion; two-way conversions work from whichever
+/// field was typed in last.
+#[derive(Debug, Clone)]
+pub struct QuickCalculator {
+    pub mode_select: SelectField,
+    pub inputs: [InputField; 2],
+    /// Focused row: 0 for the mode, 1 and 2 for the inputs
+    pub field: usize,
+    /// Input the last edit went to, which a two-way conversion reads
+    source: usize,
+}
+
+impl QuickCalculator {
+    pub fn new() -> Self {
+        let mut calculator = Self {
+            mode_select: SelectField::new("Convert", CalcMode::ALL.iter().map(CalcMode::as_str).collect()),
+            inputs: CalcMode::BrixGravity.inputs(),
+            field: 1,
+            source: 0,
+        };
+        calculator.update_focus();
+        calculator
+    }
+
+    pub fn mode(&self) -> CalcMode {
+        CalcMode::ALL[self.mode_select.selected]
+    }
+
+    /// Switch to the next or previous conversion, clearing the inputs
+    pub fn cycle_mode(&mut self, forward: bool) {
+        if forward {
+            self.mode_select.next();
+        } else {
+            self.mode_select.previous();
+        }
+        self.inputs = self.mode().inputs();
+        self.source = 0;
+        self.update_focus();
+    }
+
+    pub fn next_field(&mut self) {
+        self.field = crate::nav::next_index(self.field, 3);
+        self.update_focus();
+    }
+
+    pub fn previous_field(&mut self) {
+        self.field = crate::nav::previous_index(self.field, 3);
+        self.update_focus();
+    }
+
+    fn update_focus(&mut self) {
+        self.mode_select.set_focused(self.field == 0);
+        for (i, input) in self.inputs.iter_mut().enumerate() {
+            input.set_focused(self.field == i + 1);
+        }
+    }
+
+    /// The focused input, or None on the mode row
+    pub fn focused_input(&mut self) -> Option<&mut InputField> {
+        let index = self.field.checked_sub(1)?;
+        self.source = index;
+        self.inputs.get_mut(index)
+    }
+
+    /// The converted value, e.g. "SG 1.083", or None until the fields it
+    /// needs hold numbers
+    pub fn result(&self) -> Option<String> {
+        let [a, b] = [self.inputs[0].get_f64(), self.inputs[1].get_f64()];
+        match (self.mode(), self.source) {
+            (CalcMode::BrixGravity, 0) => gravity_from_brix(a?).map(|g| format!("SG {}", fmt_gravity(g))),
+            (CalcMode::BrixGravity, _) => Some(format!("{:.1} °Brix", brix(b?))),
+            (CalcMode::Temperature, 0) => Some(format!("{:.1} °C", fahrenheit_to_celsius(a?))),
+            (CalcMode::Temperature, _) => Some(format!("{:.1} °F", celsius_to_fahrenheit(b?))),
+            (CalcMode::Abv, _) => Some(format!("ABV {}%", fmt_abv(abv_from_gravities(a?, b?).max(0.0)))),
+            (CalcMode::HoneyGravity, _) => honey_gravity(a?, b?).map(|g| format!("OG {}", fmt_gravity(g))),
+        }
+    }
+}
+
+impl Widget for &QuickCalculator {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [popup] = Layout::horizontal([Constraint::Length(48)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(15)])
+            .flex(Flex::Center)
+            .areas(popup);
+
+        Clear.render(popup, buf);
+
+        let block = Block::default()
+            .title(Span::styled(
+                " Quick Calculator ",
+                Style::default().fg(NORD_FROST).add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::from(vec![
+                Span::styled(" Tab", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Field  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Left/Right", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Mode  ", Style::default().fg(NORD_WHITE)),
+                Span::styled("Esc", Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(" Close ", Style::default().fg(NORD_WHITE)),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_FROST))
+            .border_set(border::ROUNDED)
+            .style(Style::default().bg(NORD_BG));
+        let inner = block.inner(popup);
+        block.render(popup, buf);
+
+        let [mode, first, second, _, result] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .horizontal_margin(1)
+        .areas(inner);
+        self.mode_select.render(mode, buf);
+        self.inputs[0].render(first, buf);
+        self.inputs[1].render(second, buf);
+
+        let mode_hint = if self.mode().two_way() { "Type either value" } else { "Fill in both values" };
+        let line = match self.result() {
+            Some(value) => Line::from(vec![
+                Span::styled("= ", Style::default().fg(NORD_GRAY)),
+                Span::styled(value, Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)),
+            ]),
+            None => Line::from(Span::styled(mode_hint, Style::default().fg(NORD_GRAY))),
+        };
+        Paragraph::new(line).alignment(Alignment::Center).render(result, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(calculator: &mut QuickCalculator, field: usize, text: &str) {
+        calculator.field = field;
+        let input = calculator.focused_input().unwrap();
+        input.clear();
+        text.chars().for_each(|c| input.insert_char(c));
+    }
+
+    #[test]
+    fn converts_from_the_field_typed_in_last() {
+        let mut calculator = QuickCalculator::new();
+        assert_eq!(calculator.result(), None);
+        typed(&mut calculator, 1, "20");
+        assert_eq!(calculator.result().as_deref(), Some("SG 1.083"));
+        typed(&mut calculator, 2, "1.100");
+        assert_eq!(calculator.result().as_deref(), Some("23.8 °Brix"));
+
+        calculator.cycle_mode(true);
+        assert_eq!(calculator.mode(), CalcMode::Temperature);
+        assert_eq!(calculator.inputs[1].get_value(), "", "switching mode clears the inputs");
+        typed(&mut calculator, 2, "20");
+        assert_eq!(calculator.result().as_deref(), Some("68.0 °F"));
+
+        calculator.cycle_mode(true);
+        typed(&mut calculator, 1, "1.100");
+        assert_eq!(calculator.result(), None, "needs both gravities");
+        typed(&mut calculator, 2, "1.000");
+        assert_eq!(calculator.result().as_deref(), Some("ABV 13.1%"));
+
+        calculator.cycle_mode(true);
+        typed(&mut calculator, 1, "3");
+        typed(&mut calculator, 2, "1");
+        assert_eq!(calculator.result().as_deref(), Some("OG 1.105"));
+    }
+}
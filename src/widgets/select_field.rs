@@ -0,0 +1,128 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use crate::nav;
+
+use super::hotkey_span;
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+const NORD_GRAY: Color = Color::Rgb(76, 86, 106);       // #4C566A
+
+/// A field that cycles through a fixed set of options
+#[derive(Debug, Clone)]
+pub struct SelectField {
+    /// The label for this field
+    pub label: String,
+    /// The available options
+    pub options: Vec<&'static str>,
+    /// Index of the selected option
+    pub selected: usize,
+    /// Whether this field is currently focused
+    pub focused: bool,
+    /// Color of the selected value
+    pub value_color: Color,
+    /// Letter that jumps to this field with Alt, shown dimmed after the
+    /// label while unfocused
+    pub hotkey: Option<char>,
+}
+
+impl SelectField {
+    pub fn new(label: impl Into<String>, options: Vec<&'static str>) -> Self {
+        Self {
+            label: label.into(),
+            options,
+            selected: 0,
+            focused: false,
+            value_color: NORD_WHITE,
+            hotkey: None,
+        }
+    }
+
+    pub fn with_value(mut self, value: &str) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    pub fn with_value_color(mut self, color: Color) -> Self {
+        self.value_color = color;
+        self
+    }
+
+    pub fn with_hotkey(mut self, hotkey: char) -> Self {
+        self.hotkey = Some(hotkey);
+        self
+    }
+
+    /// Select the next option, wrapping around if navigation wraps
+    pub fn next(&mut self) {
+        self.selected = nav::next_index(self.selected, self.options.len());
+    }
+
+    /// Select the previous option, wrapping around if navigation wraps
+    pub fn previous(&mut self) {
+        self.selected = nav::previous_index(self.selected, self.options.len());
+    }
+
+    /// Get the selected option
+    pub fn get_value(&self) -> &'static str {
+        self.options.get(self.selected).copied().unwrap_or("")
+    }
+
+    /// Select the option matching `value`, if present
+    pub fn set_value(&mut self, value: &str) {
+        if let Some(index) = self.options.iter().position(|o| *o == value) {
+            self.selected = index;
+        }
+    }
+
+    /// Set focus state
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl Widget for &SelectField {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let border_style = if self.focused {
+            Style::default().fg(NORD_CYAN)
+        } else {
+            Style::default().fg(NORD_GRAY)
+        };
+
+        let block = Block::default()
+            .title(Line::from(vec![
+                Span::styled(
+                    format!(" {} ", self.label),
+                    if self.focused {
+                        Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(NORD_FROST)
+                    },
+                ),
+                hotkey_span(self.hotkey, self.focused),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .border_set(border::ROUNDED);
+
+        // Arrows while focused show that Left/Right change the value
+        let value = if self.focused {
+            format!("◀ {} ▶", self.get_value())
+        } else {
+            format!("  {}", self.get_value())
+        };
+        Paragraph::new(value)
+            .style(Style::default().fg(self.value_color))
+            .block(block)
+            .render(area, buf);
+    }
+}
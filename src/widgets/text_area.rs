@@ -0,0 +1,192 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use super::input_field::cursor_span;
+
+// Nord-adjacent color palette
+const NORD_FROST: Color = Color::Rgb(136, 192, 208);    // #88C0D0
+const NORD_CYAN: Color = Color::Rgb(0, 255, 255);       // #00FFFF
+const NORD_WHITE: Color = Color::Rgb(255, 255, 255);    // #FFFFFF
+
+/// A multi-line text editing widget
+#[derive(Debug, Clone)]
+pub struct TextArea {
+    /// The label for this field
+    pub label: String,
+    /// Lines of text, without their newlines
+    lines: Vec<String>,
+    /// Cursor line
+    row: usize,
+    /// Cursor position within the line, in characters
+    col: usize,
+}
+
+impl TextArea {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            lines: vec![String::new()],
+            row: 0,
+            col: 0,
+        }
+    }
+
+    pub fn with_value(mut self, value: &str) -> Self {
+        self.lines = value.split('\n').map(str::to_string).collect();
+        self.row = self.lines.len() - 1;
+        self.col = self.line_len(self.row);
+        self
+    }
+
+    /// Get the text, lines joined with newlines
+    pub fn get_value(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    fn byte_index(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices().nth(self.col).map(|(i, _)| i).unwrap_or(line.len())
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let index = self.byte_index();
+        self.lines[self.row].insert(index, c);
+        self.col += 1;
+    }
+
+    /// Split the line at the cursor
+    pub fn insert_newline(&mut self) {
+        let index = self.byte_index();
+        let rest = self.lines[self.row].split_off(index);
+        self.row += 1;
+        self.lines.insert(self.row, rest);
+        self.col = 0;
+    }
+
+    /// Delete before the cursor, joining with the previous line at its start
+    pub fn delete_char(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            let index = self.byte_index();
+            self.lines[self.row].remove(index);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.line_len(self.row);
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    /// Delete at the cursor, joining with the next line at its end
+    pub fn delete_char_forward(&mut self) {
+        if self.col < self.line_len(self.row) {
+            let index = self.byte_index();
+            self.lines[self.row].remove(index);
+        } else if self.row + 1 < self.lines.len() {
+            let line = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.line_len(self.row);
+        }
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        if self.col < self.line_len(self.row) {
+            self.col += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        }
+    }
+
+    pub fn move_cursor_up(&mut self) {
+        if self.row > 0 {
+            self.row -= 1;
+            self.col = self.col.min(self.line_len(self.row));
+        }
+    }
+
+    pub fn move_cursor_down(&mut self) {
+        if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = self.col.min(self.line_len(self.row));
+        }
+    }
+
+    pub fn move_cursor_start(&mut self) {
+        self.col = 0;
+    }
+
+    pub fn move_cursor_end(&mut self) {
+        self.col = self.line_len(self.row);
+    }
+}
+
+impl Widget for &TextArea {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(NORD_CYAN))
+            .border_set(border::ROUNDED)
+            .title(Span::styled(
+                format!(" {} ", self.label),
+                Style::default().fg(NORD_CYAN).add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Span::styled(
+                format!(" Ln {}, Col {} ", self.row + 1, self.col + 1),
+                Style::default().fg(NORD_FROST),
+            ));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        // Scroll just far enough to keep the cursor line visible
+        let height = inner.height.max(1) as usize;
+        let width = inner.width.max(1) as usize;
+        let top = self.row.saturating_sub(height - 1);
+        let left = self.col.saturating_sub(width - 1);
+
+        let text_style = Style::default().fg(NORD_WHITE);
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .enumerate()
+            .skip(top)
+            .take(height)
+            .map(|(i, line)| {
+                let visible: Vec<char> = line.chars().skip(if i == self.row { left } else { 0 }).collect();
+                if i != self.row {
+                    return Line::from(Span::styled(visible.into_iter().collect::<String>(), text_style));
+                }
+                let cursor = self.col - left;
+                let before: String = visible.iter().take(cursor).collect();
+                let at = visible.get(cursor).copied();
+                let after: String = visible.iter().skip(cursor + 1).collect();
+                Line::from(vec![
+                    Span::styled(before, text_style),
+                    cursor_span(at, text_style),
+                    Span::styled(after, text_style),
+                ])
+            })
+            .collect();
+
+        Paragraph::new(lines).render(inner, buf);
+    }
+}